@@ -0,0 +1,45 @@
+use crate::{player_state::PlayerState, WIDTH};
+
+// Collision rules between the bunner and the rows. The children are passed as (center x, width) pairs.
+
+/// Margin of the logs; negative, since the bunner must stand well inside them.
+pub const LOG_MARGIN: i32 = -4;
+/// Margin of the hedges, which block the movement.
+pub const HEDGE_MARGIN: i32 = 8;
+
+/// Whether the x is inside any of the children, extended by the margin.
+pub fn collide(x: i32, margin: i32, children: impl IntoIterator<Item = (i32, i32)>) -> bool {
+    children.into_iter().any(|(child_x, child_width)| {
+        x >= child_x - (child_width / 2) - margin && x < child_x + (child_width / 2) + margin
+    })
+}
+
+/// Whether the bunner can move to the x, regardless of the row content.
+pub fn is_within_bounds(x: i32) -> bool {
+    (16..=WIDTH - 16).contains(&x)
+}
+
+pub fn road_state(hit: bool) -> PlayerState {
+    if hit {
+        PlayerState::Splat(0)
+    } else {
+        PlayerState::Alive
+    }
+}
+
+/// The trains can hit the bunner only on the third rail row (index 2), where they run; the splat is drawn higher.
+pub fn rail_state(index: i32, hit: bool) -> PlayerState {
+    if index == 2 && hit {
+        PlayerState::Splat(8)
+    } else {
+        PlayerState::Alive
+    }
+}
+
+pub fn water_state(on_log: bool) -> PlayerState {
+    if on_log {
+        PlayerState::Alive
+    } else {
+        PlayerState::Splash
+    }
+}
@@ -0,0 +1,7 @@
+#[derive(Copy, Clone, Default, Eq, PartialEq)]
+pub enum HedgeRow {
+    First,
+    Second,
+    #[default]
+    None,
+}
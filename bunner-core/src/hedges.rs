@@ -0,0 +1,100 @@
+use crate::{hedge_mask::HedgeMask, hedge_row::HedgeRow, hedge_tile::HedgeTile, row_rng::RowRng};
+
+/// Number of the mask entries; the 12 columns, plus the first and last duplicated.
+pub const MASK_LENGTH: usize = 14;
+
+/// Invokes `add_hedge` with the x (center) and the tile of each hedge of a mask.
+pub fn for_each_hedge(hedge_mask: &[HedgeMask], mut add_hedge: impl FnMut(i32, HedgeTile)) {
+    // See comments in classify_hedge_segment for explanation of previous_mid_segment
+    #[allow(unused_assignments)]
+    let mut hedge_tile = HedgeTile::Grass;
+    let mut previous_mid_segment = None;
+    for i in 1..MASK_LENGTH - 1 {
+        (hedge_tile, previous_mid_segment) =
+            classify_hedge_segment(&hedge_mask[i - 1..i + 2], previous_mid_segment);
+        if hedge_tile != HedgeTile::Grass {
+            add_hedge(i as i32 * 40 - 20, hedge_tile);
+        }
+    }
+}
+
+pub fn classify_hedge_segment(
+    mask_window: &[HedgeMask],
+    previous_mid_segment: Option<HedgeTile>,
+) -> (HedgeTile, Option<HedgeTile>) {
+    if mask_window[1] == HedgeMask::Empty {
+        (HedgeTile::Grass, None)
+    } else if mask_window[0] == HedgeMask::Empty && mask_window[2] == HedgeMask::Empty {
+        (HedgeTile::SingleWidth, None)
+    } else if mask_window[0] == HedgeMask::Empty {
+        (HedgeTile::LeftMost, None)
+    } else if mask_window[2] == HedgeMask::Empty {
+        (HedgeTile::RightMost, None)
+    } else {
+        match previous_mid_segment {
+            Some(HedgeTile::Middle4) if mask_window[2] == HedgeMask::Empty => {
+                (HedgeTile::Middle5, None)
+            }
+            Some(HedgeTile::Middle4) if mask_window[2] == HedgeMask::Hedge => {
+                (HedgeTile::Middle3, Some(HedgeTile::Middle3))
+            }
+            Some(HedgeTile::Middle3) => (HedgeTile::Middle3, Some(HedgeTile::Middle3)),
+            _ => (HedgeTile::Middle3, Some(HedgeTile::Middle3)),
+        }
+    }
+}
+
+/// Rust: The mask is written to the passed (empty) vector, so that the row storage is reused.
+pub fn first_hedge_row(index: i32, hedge_mask: &mut Vec<HedgeMask>, rng: &mut RowRng) -> HedgeRow {
+    if rng.gen_range::<u8>(0, 1) == 0 && index > 7 && index < 14 {
+        generate_hedge_mask(hedge_mask, rng);
+        HedgeRow::First
+    } else {
+        HedgeRow::None
+    }
+}
+
+pub fn generate_hedge_mask(hedge_mask: &mut Vec<HedgeMask>, rng: &mut RowRng) {
+    let mut mask = [HedgeMask::Hedge; 12];
+    for item in mask.iter_mut() {
+        if rng.gen_range::<u8>(0, 100) < 1 {
+            *item = HedgeMask::Empty;
+        }
+    }
+    // Ensure there is at least one gap
+    mask[rng.gen_range(0, 11)] = HedgeMask::Empty;
+
+    let mut new_mask = [HedgeMask::Hedge; 12];
+    for (i, item) in new_mask.iter_mut().enumerate() {
+        let low_index = 0.max(i as i32 - 1) as usize;
+        let high_index = 11.min(i + 1);
+        if mask[low_index..=high_index].contains(&HedgeMask::Empty) {
+            *item = HedgeMask::Empty;
+        }
+    }
+
+    // Duplicate first and last elements
+    hedge_mask.push(new_mask[0]);
+    hedge_mask.extend_from_slice(&new_mask);
+    hedge_mask.push(new_mask[11]);
+}
+
+/// Hedge row of a grass row, given the previous one (None if it's not a grass row, or it has no hedges to
+/// continue). The mask is written to the passed (empty) vector.
+pub fn next_hedge_row(
+    previous_hedge_mask: Option<&[HedgeMask]>,
+    previous_hedge_row: HedgeRow,
+    index: i32,
+    hedge_mask: &mut Vec<HedgeMask>,
+    rng: &mut RowRng,
+) -> HedgeRow {
+    match previous_hedge_mask {
+        Some(_) if previous_hedge_row == HedgeRow::None => first_hedge_row(index, hedge_mask, rng),
+        Some(mask) if previous_hedge_row == HedgeRow::First => {
+            hedge_mask.extend_from_slice(mask);
+            HedgeRow::Second
+        }
+        Some(_) => HedgeRow::None,
+        None => first_hedge_row(index, hedge_mask, rng),
+    }
+}
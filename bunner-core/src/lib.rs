@@ -0,0 +1,23 @@
+#![allow(clippy::all)]
+#![deny(clippy::correctness)]
+
+//! Game logic of Bunner which doesn't depend on the engine: the generation of the rows (their sequence, the
+//! hedges and the traffic spacing), and the collision rules. The ports provide the rows and their children, and
+//! the drawing, input and audio layers.
+//!
+//! Positions and sizes are in pixels, and times in frames (updates).
+
+pub mod collision;
+pub mod hedge_mask;
+pub mod hedge_row;
+pub mod hedge_tile;
+pub mod hedges;
+pub mod player_state;
+pub mod row_rng;
+pub mod row_sequence;
+pub mod traffic;
+
+pub const WIDTH: i32 = 480;
+pub const HEIGHT: i32 = 800;
+pub const ROW_HEIGHT: i32 = 40;
+pub const TILE_WIDTH: i32 = 40;
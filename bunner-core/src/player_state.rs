@@ -0,0 +1,8 @@
+#[derive(Clone, Copy, Default, Eq, PartialEq)]
+pub enum PlayerState {
+    #[default]
+    Alive,
+    Splat(i32),
+    Splash,
+    Eagle(i32),
+}
@@ -0,0 +1,92 @@
+// Rust: Not in the original game. The generation of the rows and of their children (cars, logs, trains) uses a
+// dedicated generator, so that seeding it reproduces the same game, given the same inputs; the randomness that
+// doesn't affect the gameplay (e.g. the sound variants) uses the engine one. Each game owns its generator, so that
+// games updated side by side (e.g. in the race mode) don't draw values from each other.
+//
+// The generator is reseeded before creating each row, based on the row position, so that the sequence of rows
+// doesn't depend on the children spawned in the meantime (which depend on the player timing); this allows two
+// games with the same seed to have the same rows.
+//
+// The algorithm (PCG) is the one of the Macroquad generator, which was used before the logic was shared, so that
+// the seeds (and the ghosts recorded with them) generate the same games.
+
+/// Arbitrary odd constant (golden ratio based), which spreads the row positions over the seed bits.
+const ROW_SEED_MULTIPLIER: u64 = 0x9E37_79B9_7F4A_7C15;
+
+const PCG_INCREMENT: u64 = 1442695040888963407;
+const PCG_MULTIPLIER: u64 = 6364136223846793005;
+
+pub trait RandomRange {
+    fn from_ratio(low: Self, high: Self, ratio: f64) -> Self;
+}
+
+macro_rules! impl_random_range {
+    ($($ty:ty),*) => {
+        $(
+            impl RandomRange for $ty {
+                fn from_ratio(low: Self, high: Self, ratio: f64) -> Self {
+                    (low as f64 + (high as f64 - low as f64) * ratio) as Self
+                }
+            }
+        )*
+    };
+}
+
+impl_random_range!(f32, i32, u8, usize);
+
+#[derive(Clone, Debug)]
+pub struct RowRng {
+    state: u64,
+    seed: u64,
+}
+
+impl Default for RowRng {
+    fn default() -> Self {
+        Self::new(0)
+    }
+}
+
+impl RowRng {
+    pub fn new(seed: u64) -> Self {
+        let mut rng = Self { state: 0, seed };
+        rng.reseed(seed);
+        rng
+    }
+
+    pub fn seed(&self) -> u64 {
+        self.seed
+    }
+
+    /// Reseeds the generator for the row at the given y.
+    pub fn reseed_row(&mut self, y: i32) {
+        let row_seed = (y as i64 as u64).wrapping_mul(ROW_SEED_MULTIPLIER);
+        self.reseed(self.seed ^ row_seed);
+    }
+
+    /// Returns a value in [low, high).
+    pub fn gen_range<T: RandomRange>(&mut self, low: T, high: T) -> T {
+        let ratio = self.next_u32() as f64 / (u32::MAX as f64 + 1.);
+        T::from_ratio(low, high, ratio)
+    }
+
+    pub fn choose<'a, T>(&mut self, values: &'a [T]) -> Option<&'a T> {
+        values.get(self.gen_range(0, values.len()))
+    }
+
+    fn reseed(&mut self, seed: u64) {
+        self.state = 0;
+        self.next_u32();
+        self.state = self.state.wrapping_add(seed);
+        self.next_u32();
+    }
+
+    fn next_u32(&mut self) -> u32 {
+        let old_state = self.state;
+        self.state = old_state
+            .wrapping_mul(PCG_MULTIPLIER)
+            .wrapping_add(PCG_INCREMENT);
+        let xor_shifted = (((old_state >> 18) ^ old_state) >> 27) as u32;
+        let rotation = (old_state >> 59) as u32;
+        xor_shifted.rotate_right(rotation)
+    }
+}
@@ -0,0 +1,97 @@
+use crate::row_rng::RowRng;
+
+// The rules deciding which row follows each one; the ports build the rows from the returned descriptions.
+//
+// Each function corresponds to the `next()` method of a row type of the original game, and draws the random
+// values in the same order.
+
+/// Type and index (the image variant, which also tracks the position in a section) of the next row.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum NextRow {
+    Grass(i32),
+    Dirt(i32),
+    Road(i32),
+    Pavement(i32),
+    Rail(i32),
+    Water(i32),
+}
+
+/// A grass row following another one continues its hedges, if any.
+pub fn after_grass(index: i32, rng: &mut RowRng) -> NextRow {
+    if index <= 5 {
+        NextRow::Grass(index + 8)
+    } else if index == 6 {
+        NextRow::Grass(7)
+    } else if index == 7 {
+        NextRow::Grass(15)
+    } else if index >= 8 && index <= 14 {
+        NextRow::Grass(index + 1)
+    } else if rng.gen_range::<u8>(0, 2) == 0 {
+        NextRow::Road(0)
+    } else {
+        NextRow::Water(0)
+    }
+}
+
+pub fn after_dirt(index: i32, rng: &mut RowRng) -> NextRow {
+    if index <= 5 {
+        NextRow::Dirt(index + 8)
+    } else if index == 6 {
+        NextRow::Dirt(7)
+    } else if index == 7 {
+        NextRow::Dirt(15)
+    } else if index >= 8 && index <= 14 {
+        NextRow::Dirt(index + 1)
+    } else if rng.gen_range::<u8>(0, 2) == 1 {
+        NextRow::Road(0)
+    } else {
+        NextRow::Water(0)
+    }
+}
+
+/// Rust: The probability that the section ends (20% in the original game, from the second row) is scaled by
+/// `safe_row_frequency` (1 in the original game); the proportions of the following rows are the original ones.
+pub fn after_road(index: i32, safe_row_frequency: f32, rng: &mut RowRng) -> NextRow {
+    let end_chance = 20. * safe_row_frequency;
+    if index == 0 || (index < 5 && rng.gen_range::<f32>(0., 100.) >= end_chance) {
+        NextRow::Road(index + 1)
+    } else {
+        let (grass_chance, rail_chance) = if index < 5 { (40, 70) } else { (60, 90) };
+        let random = rng.gen_range::<u8>(0, 100);
+        if random < grass_chance {
+            NextRow::Grass(rng.gen_range(0, 7))
+        } else if random < rail_chance {
+            NextRow::Rail(0)
+        } else {
+            NextRow::Pavement(0)
+        }
+    }
+}
+
+pub fn after_pavement(index: i32) -> NextRow {
+    if index < 2 {
+        NextRow::Pavement(index + 1)
+    } else {
+        NextRow::Road(0)
+    }
+}
+
+pub fn after_rail(index: i32, rng: &mut RowRng) -> NextRow {
+    if index < 3 {
+        NextRow::Rail(index + 1)
+    } else if rng.gen_range::<u8>(0, 2) == 0 {
+        NextRow::Road(0)
+    } else {
+        NextRow::Water(0)
+    }
+}
+
+/// Rust: The probability that the section ends (50% in the original game) is scaled by `safe_row_frequency`.
+pub fn after_water(index: i32, safe_row_frequency: f32, rng: &mut RowRng) -> NextRow {
+    let end_chance = 0.5 * safe_row_frequency;
+    if index == 7 || (index >= 1 && rng.gen_range::<f32>(0., 1.) < end_chance) {
+        NextRow::Dirt(rng.gen_range(4, 7))
+    } else {
+        NextRow::Water(index + 1)
+    }
+}
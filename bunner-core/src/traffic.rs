@@ -0,0 +1,67 @@
+use crate::{row_rng::RowRng, WIDTH};
+
+// Spacing of the children moving along the roads and rivers (cars and logs).
+
+/// Distance, beyond the screen edges, at which the children are spawned and removed.
+pub const OFFSCREEN_MARGIN: i32 = 70;
+
+/// Speed of a new road; any nonzero speed up to the maximum (5 in the original game), in either direction,
+/// except the one of the previous road.
+pub fn road_dx(previous_dx: i32, max_speed: i32, rng: &mut RowRng) -> i32 {
+    // Rust: The candidates are counted, rather than collected, in order to avoid an allocation.
+    let candidates = || (-max_speed..=max_speed).filter(|&dx| dx != 0 && dx != previous_dx);
+    candidates()
+        .nth(rng.gen_range(0, candidates().count()))
+        .unwrap()
+}
+
+/// Speed of a new river, up to the maximum (2 in the original game); the direction alternates.
+pub fn water_dx(previous_dx: i32, max_speed: i32, rng: &mut RowRng) -> i32 {
+    let speed = rng.gen_range(1, max_speed + 1);
+    if previous_dx >= 0 {
+        -speed
+    } else {
+        speed
+    }
+}
+
+/// Invokes `add_child` with the x of each child present when the row is created (and the generator, which the
+/// child may draw from). The density multiplies the frequency of the children (1 in the original game).
+pub fn for_each_initial_child(
+    dx: i32,
+    traffic_density: f32,
+    rng: &mut RowRng,
+    mut add_child: impl FnMut(i32, &mut RowRng),
+) {
+    let mut x = -WIDTH / 2 - OFFSCREEN_MARGIN;
+    while x < WIDTH / 2 + OFFSCREEN_MARGIN {
+        x += (rng.gen_range::<i32>(240, 481) as f32 / traffic_density) as i32;
+        if dx > 0 {
+            add_child(WIDTH / 2 + x, rng);
+        } else {
+            add_child(WIDTH / 2 - x, rng);
+        }
+    }
+}
+
+/// The new children enter from the screen edge opposite to their direction.
+pub fn spawn_x(dx: i32) -> i32 {
+    if dx < 0 {
+        WIDTH + OFFSCREEN_MARGIN
+    } else {
+        -OFFSCREEN_MARGIN
+    }
+}
+
+/// Updates until the next child is spawned.
+pub fn random_interval(dx: i32, traffic_density: f32, rng: &mut RowRng) -> f32 {
+    // 240 is minimum distance between the start of one child object and the start of the next, assuming its
+    // speed is 1. If the speed is 2, they can occur twice as frequently without risk of overlapping with
+    // each other. The maximum distance is double the minimum distance (1 + random value of 1)
+    (1. + rng.gen_range::<f32>(0.0, 1.0)) * (240 / dx.abs()) as f32 / traffic_density
+}
+
+/// Whether a child is still on the row; the ones that moved beyond the margin are removed.
+pub fn is_on_row(x: i32) -> bool {
+    x > -OFFSCREEN_MARGIN && x < WIDTH + OFFSCREEN_MARGIN
+}
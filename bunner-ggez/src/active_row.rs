@@ -0,0 +1,24 @@
+use bunner_core::traffic;
+
+use crate::{child::Child, position::Position, row_rng::RowRng};
+
+// The spacing of the children is shared with the Macroquad port; see `bunner_core::traffic`.
+pub trait ActiveRow: Sized {
+    fn build_children(dx: i32, rng: &mut RowRng) -> Vec<Child> {
+        let mut children = vec![];
+        traffic::for_each_initial_child(dx, 1., rng, |x, rng| {
+            children.push(Self::build_child(dx, Position::new(x, 0), rng));
+        });
+        children
+    }
+
+    fn build_child(dx: i32, position: Position, rng: &mut RowRng) -> Child;
+
+    fn create_random_child(&self, dx: i32, rng: &mut RowRng) -> Child {
+        Self::build_child(dx, Position::new(traffic::spawn_x(dx), 0), rng)
+    }
+
+    fn random_interval(&self, dx: i32, rng: &mut RowRng) -> f32 {
+        traffic::random_interval(dx, 1., rng)
+    }
+}
@@ -0,0 +1,16 @@
+use ggez::{Context, GameResult};
+
+use crate::resources::Resources;
+
+pub trait Actor {
+    fn x(&self) -> i32;
+    fn width(&self) -> i32;
+    fn update(&mut self);
+    fn draw(
+        &self,
+        context: &mut Context,
+        resources: &Resources,
+        offset_x: i32,
+        offset_y: i32,
+    ) -> GameResult;
+}
@@ -0,0 +1,87 @@
+use ggez::{Context, GameResult};
+use std::collections::HashSet;
+
+use crate::{
+    actor::Actor, drawing::draw_image_anchored, position::Position, resources::Resources,
+    row_rng::RowRng, sound::play_random_sound,
+};
+
+#[derive(Clone)]
+pub struct Car {
+    pub dx: i32,
+    position: Position,
+    image_index: usize,
+    played_sounds: HashSet<CarSound>,
+}
+
+impl Actor for Car {
+    fn update(&mut self) {
+        self.position.x += self.dx;
+    }
+
+    fn draw(
+        &self,
+        context: &mut Context,
+        resources: &Resources,
+        offset_x: i32,
+        offset_y: i32,
+    ) -> GameResult {
+        draw_image_anchored(
+            context,
+            &resources.car_images[self.image_index],
+            self.position.x + offset_x,
+            self.position.y + offset_y,
+        )
+    }
+
+    fn x(&self) -> i32 {
+        self.position.x
+    }
+
+    fn width(&self) -> i32 {
+        90
+    }
+}
+
+impl Car {
+    pub fn new(dx: i32, position: Position, rng: &mut RowRng) -> Self {
+        let image_index = if dx < 0 {
+            *rng.choose(&[0, 2, 4]).unwrap()
+        } else {
+            *rng.choose(&[1, 3, 5]).unwrap()
+        };
+        Self {
+            dx,
+            position,
+            image_index,
+            played_sounds: HashSet::new(),
+        }
+    }
+
+    pub fn play_sound(
+        &mut self,
+        context: &mut Context,
+        resources: &mut Resources,
+        sound: CarSound,
+    ) -> GameResult {
+        if self.played_sounds.insert(sound) {
+            match sound {
+                CarSound::Zoom => play_random_sound(context, &mut resources.zoom_sounds)?,
+                CarSound::Honk => play_random_sound(context, &mut resources.honk_sounds)?,
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum CarSound {
+    Zoom,
+    Honk,
+}
+
+pub struct TrafficSound {
+    pub y_offset: i32,
+    pub sound: CarSound,
+}
@@ -0,0 +1,49 @@
+use ggez::{Context, GameResult};
+
+use crate::{
+    actor::Actor, car::Car, hedge::Hedge, log::Log, resources::Resources, splat::Splat,
+    train::Train,
+};
+
+#[derive(Clone)]
+pub enum Child {
+    Hedge(Hedge),
+    Train(Train),
+    Splat(Splat),
+    Car(Car),
+    Log(Log),
+}
+
+impl Child {
+    fn actor(&self) -> &dyn Actor {
+        match self {
+            Child::Hedge(hedge) => hedge,
+            Child::Train(train) => train,
+            Child::Splat(splat) => splat,
+            Child::Car(car) => car,
+            Child::Log(log) => log,
+        }
+    }
+
+    pub fn update(&mut self) {
+        match self {
+            Child::Hedge(hedge) => hedge.update(),
+            Child::Train(train) => train.update(),
+            Child::Splat(splat) => splat.update(),
+            Child::Car(car) => car.update(),
+            Child::Log(log) => log.update(),
+        }
+    }
+
+    pub fn draw(&self, context: &mut Context, resources: &Resources, x: i32, y: i32) -> GameResult {
+        self.actor().draw(context, resources, x, y)
+    }
+
+    pub fn x(&self) -> i32 {
+        self.actor().x()
+    }
+
+    pub fn width(&self) -> i32 {
+        self.actor().width()
+    }
+}
@@ -0,0 +1,58 @@
+use bunner_core::row_sequence;
+use ggez::{graphics::Image, Context, GameResult};
+
+use crate::{
+    child::Child,
+    resources::Resources,
+    row::{build_row, Row},
+    row_rng::RowRng,
+    sound::play_sound_once,
+    ROW_HEIGHT,
+};
+
+pub struct Dirt {
+    index: i32,
+    y: i32,
+    children: Vec<Child>,
+}
+
+impl Row for Dirt {
+    fn y(&self) -> i32 {
+        self.y
+    }
+
+    fn children(&self) -> &[Child] {
+        &self.children
+    }
+
+    fn children_mut(&mut self) -> &mut Vec<Child> {
+        self.children.as_mut()
+    }
+
+    fn image<'a>(&self, resources: &'a Resources) -> &'a Image {
+        &resources.dirt_images[self.index as usize]
+    }
+
+    fn play_sound(&self, context: &mut Context, resources: &mut Resources) -> GameResult {
+        play_sound_once(context, &mut resources.dirt_sound)
+    }
+
+    fn next(&self, rng: &mut RowRng) -> Box<dyn Row> {
+        build_row(
+            row_sequence::after_dirt(self.index, rng),
+            0,
+            self.y - ROW_HEIGHT,
+            rng,
+        )
+    }
+}
+
+impl Dirt {
+    pub fn new(index: i32, y: i32) -> Self {
+        Self {
+            index,
+            y,
+            children: vec![],
+        }
+    }
+}
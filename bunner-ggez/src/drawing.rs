@@ -0,0 +1,57 @@
+use ggez::{
+    graphics::{self, DrawParam, Image},
+    Context, GameResult,
+};
+use glam::Vec2;
+
+use crate::resources::Resources;
+
+pub enum NumberAlign {
+    Left,
+    Right,
+}
+
+pub enum NumberColor {
+    Blue,
+    Yellow,
+}
+
+pub fn draw_image(context: &mut Context, image: &Image, x: i32, y: i32) -> GameResult {
+    let dest = Vec2::new(x as f32, y as f32);
+    graphics::draw(context, image, DrawParam::new().dest(dest))
+}
+
+/// ggez doesn't support anchoring, so the image is drawn with its bottom center at the given position, like the
+/// actors of the original game.
+pub fn draw_image_anchored(context: &mut Context, image: &Image, x: i32, y: i32) -> GameResult {
+    draw_image(
+        context,
+        image,
+        x - image.width() as i32 / 2,
+        y - image.height() as i32,
+    )
+}
+
+pub fn display_number(
+    context: &mut Context,
+    resources: &Resources,
+    number: u32,
+    color: NumberColor,
+    x: i32,
+    align: NumberAlign,
+) -> GameResult {
+    let n = number.to_string();
+    for (i, digit) in n.chars().enumerate() {
+        let digit_index: usize = match color {
+            NumberColor::Yellow => digit.to_digit(10).unwrap() as usize + 10,
+            NumberColor::Blue => digit.to_digit(10).unwrap() as usize,
+        };
+        let x_pos = match align {
+            NumberAlign::Right => x + ((i as i32 - n.len() as i32) * 25),
+            NumberAlign::Left => x + (i as i32 * 25),
+        };
+        draw_image(context, &resources.digit_images[digit_index], x_pos, 0)?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,42 @@
+use ggez::{Context, GameResult};
+
+use crate::{actor::Actor, drawing::draw_image, position::Position, resources::Resources};
+
+pub struct Eagle {
+    position: Position,
+}
+
+impl Actor for Eagle {
+    fn update(&mut self) {
+        self.position.y += 12;
+    }
+
+    fn draw(
+        &self,
+        context: &mut Context,
+        resources: &Resources,
+        offset_x: i32,
+        offset_y: i32,
+    ) -> GameResult {
+        let shadow_image = &resources.eagles_image;
+        let x = self.position.x + offset_x - shadow_image.width() as i32 / 2;
+        let y = self.position.y + offset_y - shadow_image.height() as i32;
+        draw_image(context, shadow_image, x, y)?;
+
+        draw_image(context, &resources.eagle_image, x, y + 32)
+    }
+
+    fn x(&self) -> i32 {
+        self.position.x
+    }
+
+    fn width(&self) -> i32 {
+        166
+    }
+}
+
+impl Eagle {
+    pub fn new(position: Position) -> Self {
+        Self { position }
+    }
+}
@@ -0,0 +1,189 @@
+use ggez::{
+    audio::SoundSource,
+    event::KeyCode,
+    graphics::{self, Color},
+    Context, GameResult,
+};
+use std::collections::{HashMap, VecDeque};
+
+use crate::{
+    actor::Actor, bunner::Bunner, eagle::Eagle, grass::Grass, player_state::PlayerState,
+    position::Position, resources::Resources, row::Row, row::RowSound, row_rng::RowRng, HEIGHT,
+    ROW_HEIGHT,
+};
+
+pub struct Game {
+    pub bunner: Option<Bunner>,
+    pub scroll_pos: i32,
+    eagle: Option<Eagle>,
+    rows: Vec<Box<dyn Row>>,
+    /// Looped sounds currently playing, with the index of the chosen variant.
+    looped_sounds: HashMap<RowSound, usize>,
+    row_rng: RowRng,
+}
+
+impl Game {
+    pub fn new(bunner: Option<Bunner>, seed: u64) -> Self {
+        let mut row_rng = RowRng::new(seed);
+        Self {
+            bunner,
+            scroll_pos: -HEIGHT,
+            eagle: None,
+            rows: vec![Box::new(Grass::without_hedge(0, 0, &mut row_rng))],
+            looped_sounds: HashMap::new(),
+            row_rng,
+        }
+    }
+
+    pub fn update(
+        &mut self,
+        context: &mut Context,
+        resources: &mut Resources,
+        input_queue: VecDeque<KeyCode>,
+    ) -> GameResult {
+        if let Some(bunner) = &self.bunner {
+            // Scroll faster if the player is close to the top of the screen. Limit scroll speed to
+            // between 1 and 3 pixels per frame.
+            self.scroll_pos -=
+                1.max(3.min(self.scroll_pos + HEIGHT - bunner.position.y) / (HEIGHT / 4));
+        } else {
+            self.scroll_pos -= 1;
+        }
+
+        // Remove rows that have scrolled past the bottom of the screen.
+        let scroll_pos = self.scroll_pos;
+        self.rows
+            .retain(|row| row.y() < (scroll_pos + HEIGHT + ROW_HEIGHT * 2));
+
+        // Add rows
+        while let Some(last_row) = self.rows.last() {
+            if last_row.y() > self.scroll_pos + ROW_HEIGHT {
+                let new_row = last_row.next(&mut self.row_rng);
+                self.rows.push(new_row)
+            } else {
+                break;
+            }
+        }
+
+        let bunner_pos = self.bunner.as_ref().map(|bunner| bunner.position);
+        for row in self.rows.iter_mut() {
+            row.update(
+                context,
+                resources,
+                self.scroll_pos,
+                bunner_pos,
+                &mut self.row_rng,
+            )?;
+        }
+        if let Some(bunner) = self.bunner.as_mut() {
+            bunner.update(
+                context,
+                resources,
+                self.scroll_pos,
+                &mut self.rows,
+                input_queue,
+            )?;
+            if let PlayerState::Eagle(x) = bunner.state {
+                self.eagle
+                    .get_or_insert_with(|| Eagle::new(Position::new(x, self.scroll_pos)));
+            };
+        }
+        if let Some(eagle) = self.eagle.as_mut() {
+            eagle.update();
+        }
+
+        if let Some(bunner) = &self.bunner {
+            let mut sounds: HashMap<RowSound, f32> = HashMap::new();
+            for row in self.rows.iter() {
+                if let Some(sound_name) = row.sound() {
+                    let volume = sounds.get(&sound_name).unwrap_or(&0.)
+                        + 16.0 / 16.0_f32.max((row.y() - bunner.position.y).abs() as f32);
+                    sounds.insert(sound_name, volume);
+                }
+            }
+            for (sound, volume) in sounds.drain() {
+                self.loop_sound(context, resources, sound, volume - 0.2)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    pub fn draw(&self, context: &mut Context, resources: &Resources) -> GameResult {
+        graphics::clear(context, Color::BLACK);
+
+        for row in self.rows.iter().rev() {
+            row.draw(context, resources, 0, -self.scroll_pos)?;
+        }
+        if let Some(bunner) = &self.bunner {
+            bunner.draw(context, 0, -self.scroll_pos)?;
+        }
+        if let Some(eagle) = &self.eagle {
+            eagle.draw(context, resources, 0, -self.scroll_pos)?;
+        }
+
+        Ok(())
+    }
+
+    pub fn game_over(&self) -> bool {
+        if let Some(bunner) = &self.bunner {
+            bunner.state != PlayerState::Alive && bunner.timer < 0
+        } else {
+            false
+        }
+    }
+
+    pub fn score(&self) -> u32 {
+        if let Some(bunner) = &self.bunner {
+            0.max((-320 - bunner.min_y as i32) / 40) as u32
+        } else {
+            0
+        }
+    }
+
+    /// Stops the looped sounds; ggez doesn't stop them when the game is replaced.
+    pub fn stop_sounds(&mut self, context: &mut Context, resources: &mut Resources) -> GameResult {
+        for (row_sound, sound_i) in self.looped_sounds.drain() {
+            Self::row_sounds(resources, row_sound)[sound_i].stop(context)?;
+        }
+
+        Ok(())
+    }
+
+    fn row_sounds(resources: &mut Resources, row_sound: RowSound) -> &mut [ggez::audio::Source] {
+        match row_sound {
+            RowSound::River => &mut resources.river_sounds,
+            RowSound::Traffic => &mut resources.traffic_sounds,
+        }
+    }
+
+    fn loop_sound(
+        &mut self,
+        context: &mut Context,
+        resources: &mut Resources,
+        row_sound: RowSound,
+        volume: f32,
+    ) -> GameResult {
+        let sounds = Self::row_sounds(resources, row_sound);
+
+        if volume > 0. && !self.looped_sounds.contains_key(&row_sound) {
+            let sound_i = fastrand::usize(..sounds.len());
+            let sound = &mut sounds[sound_i];
+            sound.set_repeat(true);
+            sound.set_volume(volume);
+            sound.play(context)?;
+            self.looped_sounds.insert(row_sound, sound_i);
+        }
+
+        if let Some(&sound_i) = self.looped_sounds.get(&row_sound) {
+            if volume > 0. {
+                sounds[sound_i].set_volume(volume);
+            } else {
+                sounds[sound_i].stop(context)?;
+                self.looped_sounds.remove(&row_sound);
+            }
+        }
+
+        Ok(())
+    }
+}
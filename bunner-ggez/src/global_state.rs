@@ -0,0 +1,169 @@
+use ggez::{
+    audio::SoundSource,
+    event::{EventHandler, KeyCode, KeyMods},
+    graphics, timer, Context, GameResult,
+};
+use std::{collections::VecDeque, fs};
+
+use crate::{
+    bunner::Bunner,
+    drawing::{display_number, draw_image, NumberAlign, NumberColor},
+    game::Game,
+    position::Position,
+    resources::Resources,
+    state::State,
+    HEIGHT, WIDTH,
+};
+
+/// Pygame Zero runs the update at 60 Hz.
+const UPDATES_PER_SECOND: u32 = 60;
+
+const HIGH_SCORE_FILE: &str = "high.txt";
+
+/// Global state, not to be confused with the game state (which is a part of it).
+pub struct GlobalState {
+    state: State,
+    game: Game,
+    high_score: u32,
+    input_queue: VecDeque<KeyCode>,
+
+    resources: Resources,
+}
+
+impl GlobalState {
+    pub fn new(context: &mut Context) -> GameResult<Self> {
+        let resources = Resources::new(context)?;
+
+        Ok(Self {
+            // Set the initial game state
+            state: State::Menu,
+            game: Game::new(None, fastrand::u64(..)),
+            high_score: 0,
+            input_queue: VecDeque::new(),
+            resources,
+        })
+    }
+
+    pub fn init(&mut self, context: &mut Context) -> GameResult {
+        self.high_score = fs::read_to_string(HIGH_SCORE_FILE)
+            .map_or(Ok(0), |i| i.parse::<u32>())
+            .unwrap_or(0);
+
+        let music = &mut self.resources.music;
+        music.set_repeat(true);
+        music.set_volume(1.);
+        music.play(context)
+    }
+}
+
+impl EventHandler for GlobalState {
+    /// The logic is run at a fixed rate, independently of the display refresh rate.
+    fn update(&mut self, context: &mut Context) -> GameResult {
+        while timer::check_update_time(context, UPDATES_PER_SECOND) {
+            let resources = &mut self.resources;
+
+            match self.state {
+                State::Menu => {
+                    if self.input_queue.contains(&KeyCode::Space) {
+                        // Switch to play state, and create a new Game object, passing it a new Player object to use
+                        self.state = State::Play;
+                        self.game = Game::new(
+                            Some(Bunner::new(resources, Position::new(240, -320))),
+                            fastrand::u64(..),
+                        );
+                        self.input_queue.clear();
+                        resources.music.set_volume(0.3);
+                    } else {
+                        let input_queue = self.input_queue.drain(..).collect();
+                        self.game.update(context, resources, input_queue)?;
+                    }
+                }
+                State::Play => {
+                    if self.game.game_over() {
+                        self.high_score = self.high_score.max(self.game.score());
+                        fs::write(HIGH_SCORE_FILE, self.high_score.to_string()).ok();
+
+                        self.game.stop_sounds(context, resources)?;
+                        self.state = State::GameOver;
+                    } else {
+                        let input_queue = self.input_queue.drain(..).collect();
+                        self.game.update(context, resources, input_queue)?;
+                    }
+                }
+                State::GameOver => {
+                    if self.input_queue.contains(&KeyCode::Space) {
+                        // Switch to menu state, and create a new game object
+                        self.state = State::Menu;
+                        self.game = Game::new(None, fastrand::u64(..));
+                        self.input_queue.clear();
+                        resources.music.set_volume(1.);
+                    }
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    fn draw(&mut self, context: &mut Context) -> GameResult {
+        let resources = &self.resources;
+
+        self.game.draw(context, resources)?;
+
+        match self.state {
+            State::Menu => {
+                // Draw title screen
+                draw_image(context, &resources.title_image, 0, 0)?;
+                let index = ((self.game.scroll_pos.abs() / 6) % 4) as usize;
+                let start_index = [0, 1, 2, 1][index];
+                draw_image(
+                    context,
+                    &resources.start_images[start_index],
+                    (WIDTH - 270) / 2,
+                    HEIGHT - 240,
+                )?;
+            }
+            State::Play => {
+                // Display score and high score
+                display_number(
+                    context,
+                    resources,
+                    self.game.score(),
+                    NumberColor::Blue,
+                    0,
+                    NumberAlign::Left,
+                )?;
+                display_number(
+                    context,
+                    resources,
+                    self.high_score,
+                    NumberColor::Yellow,
+                    WIDTH - 10,
+                    NumberAlign::Right,
+                )?;
+            }
+            State::GameOver => {
+                // Display "Game Over" image
+                draw_image(context, &resources.gameover_image, 0, 0)?;
+            }
+        }
+
+        graphics::present(context)?;
+
+        timer::yield_now();
+
+        Ok(())
+    }
+
+    fn key_down_event(
+        &mut self,
+        _context: &mut Context,
+        keycode: KeyCode,
+        _keymods: KeyMods,
+        repeat: bool,
+    ) {
+        if !repeat {
+            self.input_queue.push_back(keycode);
+        }
+    }
+}
@@ -0,0 +1,106 @@
+use bunner_core::{
+    collision, hedges,
+    row_sequence::{self, NextRow},
+};
+use ggez::{graphics::Image, Context, GameResult};
+
+use crate::{
+    child::Child,
+    hedge::Hedge,
+    hedge_mask::HedgeMask,
+    hedge_row::HedgeRow,
+    position::Position,
+    resources::Resources,
+    row::{build_row, Row},
+    row_rng::RowRng,
+    sound::play_sound_once,
+    ROW_HEIGHT,
+};
+
+pub struct Grass {
+    index: i32,
+    y: i32,
+    hedge_row: HedgeRow,
+    hedge_mask: Vec<HedgeMask>,
+    children: Vec<Child>,
+}
+
+impl Row for Grass {
+    fn y(&self) -> i32 {
+        self.y
+    }
+
+    fn children(&self) -> &[Child] {
+        &self.children
+    }
+
+    fn children_mut(&mut self) -> &mut Vec<Child> {
+        self.children.as_mut()
+    }
+
+    fn image<'a>(&self, resources: &'a Resources) -> &'a Image {
+        &resources.grass_images[self.index as usize]
+    }
+
+    fn play_sound(&self, context: &mut Context, resources: &mut Resources) -> GameResult {
+        play_sound_once(context, &mut resources.grass_sound)
+    }
+
+    fn next(&self, rng: &mut RowRng) -> Box<dyn Row> {
+        let y = self.y - ROW_HEIGHT;
+        match row_sequence::after_grass(self.index, rng) {
+            NextRow::Grass(index) => Box::new(Grass::new(
+                Some(&self.hedge_mask),
+                self.hedge_row,
+                index,
+                y,
+                rng,
+            )),
+            next => build_row(next, 0, y, rng),
+        }
+    }
+
+    fn allow_movement(&self, x: i32) -> bool {
+        collision::is_within_bounds(x) && !self.collide(x, collision::HEDGE_MARGIN)
+    }
+}
+
+impl Grass {
+    pub fn new(
+        previous_hedge_mask: Option<&[HedgeMask]>,
+        previous_hedge_row: HedgeRow,
+        index: i32,
+        y: i32,
+        rng: &mut RowRng,
+    ) -> Self {
+        let mut hedge_mask = vec![];
+        let mut children = vec![];
+
+        let hedge_row = hedges::next_hedge_row(
+            previous_hedge_mask,
+            previous_hedge_row,
+            index,
+            &mut hedge_mask,
+            rng,
+        );
+        hedges::for_each_hedge(&hedge_mask, |x, hedge_tile| {
+            children.push(Child::Hedge(Hedge::new(
+                hedge_tile,
+                hedge_row,
+                Position::new(x, 0),
+            )));
+        });
+
+        Self {
+            index,
+            y,
+            hedge_row,
+            hedge_mask,
+            children,
+        }
+    }
+
+    pub fn without_hedge(index: i32, y: i32, rng: &mut RowRng) -> Self {
+        Self::new(None, HedgeRow::None, index, y, rng)
+    }
+}
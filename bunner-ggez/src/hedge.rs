@@ -0,0 +1,51 @@
+use ggez::{Context, GameResult};
+
+use crate::{
+    actor::Actor, drawing::draw_image_anchored, hedge_row::HedgeRow, hedge_tile::HedgeTile,
+    position::Position, resources::Resources, TILE_WIDTH,
+};
+
+#[derive(Clone)]
+pub struct Hedge {
+    hedge_tile: HedgeTile,
+    hedge_row: HedgeRow,
+    position: Position,
+}
+
+impl Actor for Hedge {
+    fn update(&mut self) {}
+
+    fn draw(
+        &self,
+        context: &mut Context,
+        resources: &Resources,
+        offset_x: i32,
+        offset_y: i32,
+    ) -> GameResult {
+        let image_index = self.hedge_tile as usize * 2 + self.hedge_row as usize;
+        draw_image_anchored(
+            context,
+            &resources.bush_images[image_index],
+            self.position.x + offset_x,
+            self.position.y + offset_y,
+        )
+    }
+
+    fn x(&self) -> i32 {
+        self.position.x
+    }
+
+    fn width(&self) -> i32 {
+        TILE_WIDTH
+    }
+}
+
+impl Hedge {
+    pub fn new(hedge_tile: HedgeTile, hedge_row: HedgeRow, position: Position) -> Self {
+        Self {
+            hedge_tile,
+            hedge_row,
+            position,
+        }
+    }
+}
@@ -0,0 +1,57 @@
+use ggez::{Context, GameResult};
+
+use crate::{
+    actor::Actor, drawing::draw_image_anchored, position::Position, resources::Resources,
+    row_rng::RowRng,
+};
+
+#[derive(Clone)]
+pub struct Log {
+    dx: i32,
+    position: Position,
+    image_index: usize,
+}
+
+impl Actor for Log {
+    fn update(&mut self) {
+        self.position.x += self.dx;
+    }
+
+    fn draw(
+        &self,
+        context: &mut Context,
+        resources: &Resources,
+        offset_x: i32,
+        offset_y: i32,
+    ) -> GameResult {
+        draw_image_anchored(
+            context,
+            &resources.log_images[self.image_index],
+            self.position.x + offset_x,
+            self.position.y + offset_y,
+        )
+    }
+
+    fn x(&self) -> i32 {
+        self.position.x
+    }
+
+    fn width(&self) -> i32 {
+        if self.image_index == 0 {
+            84
+        } else {
+            138
+        }
+    }
+}
+
+impl Log {
+    pub fn new(dx: i32, position: Position, rng: &mut RowRng) -> Self {
+        let image_index = rng.gen_range::<usize>(0, 2);
+        Self {
+            dx,
+            position,
+            image_index,
+        }
+    }
+}
@@ -0,0 +1,83 @@
+#![allow(clippy::all)]
+#![deny(clippy::correctness)]
+
+mod active_row;
+mod actor;
+mod bunner;
+mod car;
+mod child;
+mod dirt;
+mod drawing;
+mod eagle;
+mod game;
+mod global_state;
+mod grass;
+mod hedge;
+mod log;
+mod pavement;
+mod player_direction;
+mod position;
+mod rail;
+mod resources;
+mod road;
+mod row;
+mod sound;
+mod splat;
+mod state;
+mod train;
+mod water;
+
+use std::env;
+use std::path::PathBuf;
+
+use ggez::{event, GameResult};
+
+use global_state::GlobalState;
+
+// The row generation (sequence, hedges, traffic spacing) and the collision rules are shared with the Macroquad
+// port, via the engine-agnostic core crate.
+pub use bunner_core::{
+    hedge_mask, hedge_row, hedge_tile, player_state, row_rng, HEIGHT, ROW_HEIGHT, TILE_WIDTH, WIDTH,
+};
+
+const RESOURCES_DIR_NAME: &str = "resources";
+const RESOURCE_SUBDIRS: [&str; 3] = ["images", "music", "sounds"];
+
+const GAME_ID: &str = "Bunner";
+const AUTHOR: &str = "Olle Wreede";
+
+const WINDOW_TITLE: &str = "Bunner ggez";
+
+fn get_resource_dirs() -> Vec<PathBuf> {
+    let resources_root_dir = if let Ok(manifest_dir) = env::var("CARGO_MANIFEST_DIR") {
+        let mut path = PathBuf::from(manifest_dir);
+        path.push(RESOURCES_DIR_NAME);
+        path
+    } else {
+        PathBuf::from(RESOURCES_DIR_NAME)
+    };
+
+    RESOURCE_SUBDIRS
+        .iter()
+        .map(|subdir| resources_root_dir.join(subdir).canonicalize().unwrap())
+        .collect()
+}
+
+fn main() -> GameResult {
+    let resource_dirs = get_resource_dirs();
+
+    let mut context_builder = ggez::ContextBuilder::new(GAME_ID, AUTHOR)
+        .window_setup(ggez::conf::WindowSetup::default().title(WINDOW_TITLE))
+        .window_mode(ggez::conf::WindowMode::default().dimensions(WIDTH as f32, HEIGHT as f32));
+
+    for dir in resource_dirs {
+        context_builder = context_builder.add_resource_path(dir);
+    }
+
+    let (mut context, event_loop) = context_builder.build()?;
+    let mut state = GlobalState::new(&mut context)?;
+
+    state.init(&mut context)?;
+
+    event::run(context, event_loop, state)
+}
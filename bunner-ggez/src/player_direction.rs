@@ -0,0 +1,8 @@
+#[derive(Clone, Copy, Default, Debug, Eq, PartialEq)]
+pub enum PlayerDirection {
+    Up,
+    Right,
+    #[default]
+    Down,
+    Left,
+}
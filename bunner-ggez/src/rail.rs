@@ -0,0 +1,97 @@
+use bunner_core::{collision, row_sequence};
+use ggez::{graphics::Image, Context, GameResult};
+
+use crate::{
+    child::Child,
+    player_state::PlayerState,
+    position::Position,
+    resources::Resources,
+    row::{build_row, Row},
+    row_rng::RowRng,
+    sound::{play_random_sound, play_sound_once},
+    train::Train,
+    HEIGHT, ROW_HEIGHT, WIDTH,
+};
+
+pub struct Rail {
+    index: i32,
+    y: i32,
+    children: Vec<Child>,
+}
+
+impl Row for Rail {
+    fn y(&self) -> i32 {
+        self.y
+    }
+
+    fn children(&self) -> &[Child] {
+        &self.children
+    }
+
+    fn children_mut(&mut self) -> &mut Vec<Child> {
+        self.children.as_mut()
+    }
+
+    fn update(
+        &mut self,
+        context: &mut Context,
+        resources: &mut Resources,
+        scroll_pos: i32,
+        _bunner_pos: Option<Position>,
+        rng: &mut RowRng,
+    ) -> GameResult {
+        self.update_children();
+        if self.index == 2 {
+            self.children
+                .retain(|c| c.x() > -1000 && c.x() < WIDTH + 1000);
+            if self.y < scroll_pos + HEIGHT
+                && self.children.is_empty()
+                && rng.gen_range::<u8>(0, 100) < 1
+            {
+                let dx = *rng.choose(&[-20, 20]).unwrap();
+                let position = if dx < 0 {
+                    Position::new(WIDTH + 1000, 47)
+                } else {
+                    Position::new(WIDTH - 1000, 47)
+                };
+                self.children
+                    .push(Child::Train(Train::new(dx, position, rng)));
+                play_sound_once(context, &mut resources.bell_sound)?;
+                play_random_sound(context, &mut resources.train_sounds)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    fn image<'a>(&self, resources: &'a Resources) -> &'a Image {
+        &resources.rail_images[self.index as usize]
+    }
+
+    fn play_sound(&self, context: &mut Context, resources: &mut Resources) -> GameResult {
+        play_sound_once(context, &mut resources.grass_sound)
+    }
+
+    fn next(&self, rng: &mut RowRng) -> Box<dyn Row> {
+        build_row(
+            row_sequence::after_rail(self.index, rng),
+            0,
+            self.y - ROW_HEIGHT,
+            rng,
+        )
+    }
+
+    fn check_collision(&self, x: i32) -> PlayerState {
+        collision::rail_state(self.index, self.collide(x, 0))
+    }
+}
+
+impl Rail {
+    pub fn new(index: i32, y: i32) -> Self {
+        Self {
+            index,
+            y,
+            children: vec![],
+        }
+    }
+}
@@ -0,0 +1,172 @@
+use ggez::{audio, graphics::Image, Context, GameResult};
+
+fn load_images_list(
+    context: &mut Context,
+    name_prefix: &str,
+    number: u8,
+) -> GameResult<Vec<Image>> {
+    (0..number)
+        .map(|i| Image::new(context, format!("/{}{}.png", name_prefix, i)))
+        .collect()
+}
+
+/// Load images that have multiple states, e.g. cars of four types, with two directions each.
+/// The images are stored in a contiguous array, respecting the order of the states passed.
+fn load_multi_state_images(
+    context: &mut Context,
+    name_prefix: &str,
+    states: &[&str],
+    state_number: u8,
+) -> GameResult<Vec<Image>> {
+    let mut images = vec![];
+
+    for state in states {
+        let prefix = format!("{}{}", name_prefix, state);
+        images.extend(load_images_list(context, &prefix, state_number)?);
+    }
+
+    Ok(images)
+}
+
+fn load_sounds_list(
+    context: &mut Context,
+    name_prefix: &str,
+    number: u8,
+    extension: &str,
+) -> GameResult<Vec<audio::Source>> {
+    (0..number)
+        .map(|i| audio::Source::new(context, format!("/{}{}.{}", name_prefix, i, extension)))
+        .collect()
+}
+
+// Differently from Macroquad, ggez has no global storage, so the resources are passed around explicitly.
+// Sounds need to be mutable in order to be played.
+//
+pub struct Resources {
+    pub title_image: Image,
+    pub gameover_image: Image,
+    pub blank_image: Image,
+    pub bush_images: Vec<Image>,
+    pub car_images: Vec<Image>,
+    pub digit_images: Vec<Image>,
+    pub dirt_images: Vec<Image>,
+    pub eagle_image: Image,
+    pub eagles_image: Image,
+    pub grass_images: Vec<Image>,
+    pub jump_images: Vec<Image>,
+    pub log_images: Vec<Image>,
+    pub rail_images: Vec<Image>,
+    pub road_images: Vec<Image>,
+    pub side_images: Vec<Image>,
+    pub sit_images: Vec<Image>,
+    pub splash_images: Vec<Image>,
+    pub splat_images: Vec<Image>,
+    pub start_images: Vec<Image>,
+    pub train_images: Vec<Image>,
+    pub water_images: Vec<Image>,
+
+    pub bell_sound: audio::Source,
+    pub dirt_sound: audio::Source,
+    pub eagle_sound: audio::Source,
+    pub grass_sound: audio::Source,
+    pub honk_sounds: Vec<audio::Source>,
+    pub jump_sound: audio::Source,
+    pub log_sound: audio::Source,
+    pub river_sounds: Vec<audio::Source>,
+    pub road_sound: audio::Source,
+    pub sidewalk_sound: audio::Source,
+    pub splash_sound: audio::Source,
+    pub splat_sound: audio::Source,
+    pub traffic_sounds: Vec<audio::Source>,
+    pub train_sounds: Vec<audio::Source>,
+    pub zoom_sounds: Vec<audio::Source>,
+
+    pub music: audio::Source,
+}
+
+impl Resources {
+    pub fn new(context: &mut Context) -> GameResult<Self> {
+        let title_image = Image::new(context, "/title.png")?;
+        let gameover_image = Image::new(context, "/gameover.png")?;
+        let blank_image = Image::new(context, "/blank.png")?;
+        let bush_images =
+            load_multi_state_images(context, "bush", &["0", "1", "2", "3", "4", "5"], 2)?;
+        let car_images = load_multi_state_images(context, "car", &["0", "1", "2", "3"], 2)?;
+        let digit_images = load_multi_state_images(context, "digit", &["0", "1"], 10)?;
+        let dirt_images = load_images_list(context, "dirt", 16)?;
+        let eagle_image = Image::new(context, "/eagle.png")?;
+        let eagles_image = Image::new(context, "/eagles.png")?;
+        let grass_images = load_images_list(context, "grass", 16)?;
+        let jump_images = load_images_list(context, "jump", 4)?;
+        let log_images = load_images_list(context, "log", 2)?;
+        let rail_images = load_images_list(context, "rail", 4)?;
+        let road_images = load_images_list(context, "road", 6)?;
+        let side_images = load_images_list(context, "side", 3)?;
+        let sit_images = load_images_list(context, "sit", 4)?;
+        let splash_images = load_images_list(context, "splash", 8)?;
+        let splat_images = load_images_list(context, "splat", 4)?;
+        let start_images = load_images_list(context, "start", 3)?;
+        let train_images = load_multi_state_images(context, "train", &["0", "1", "2"], 2)?;
+        let water_images = load_images_list(context, "water", 8)?;
+
+        let bell_sound = audio::Source::new(context, "/bell0.wav")?;
+        let dirt_sound = audio::Source::new(context, "/dirt0.wav")?;
+        let eagle_sound = audio::Source::new(context, "/eagle0.wav")?;
+        let grass_sound = audio::Source::new(context, "/grass0.wav")?;
+        let honk_sounds = load_sounds_list(context, "honk", 4, "wav")?;
+        let jump_sound = audio::Source::new(context, "/jump0.wav")?;
+        let log_sound = audio::Source::new(context, "/log0.wav")?;
+        let river_sounds = load_sounds_list(context, "river", 2, "ogg")?;
+        let road_sound = audio::Source::new(context, "/road0.wav")?;
+        let sidewalk_sound = audio::Source::new(context, "/sidewalk0.wav")?;
+        let splash_sound = audio::Source::new(context, "/splash0.wav")?;
+        let splat_sound = audio::Source::new(context, "/splat0.wav")?;
+        let traffic_sounds = load_sounds_list(context, "traffic", 3, "ogg")?;
+        let train_sounds = load_sounds_list(context, "train", 2, "wav")?;
+        let zoom_sounds = load_sounds_list(context, "zoom", 6, "wav")?;
+
+        let music = audio::Source::new(context, "/theme.ogg")?;
+
+        Ok(Self {
+            title_image,
+            gameover_image,
+            blank_image,
+            bush_images,
+            car_images,
+            digit_images,
+            dirt_images,
+            eagle_image,
+            eagles_image,
+            grass_images,
+            jump_images,
+            log_images,
+            rail_images,
+            road_images,
+            side_images,
+            sit_images,
+            splash_images,
+            splat_images,
+            start_images,
+            train_images,
+            water_images,
+
+            bell_sound,
+            dirt_sound,
+            eagle_sound,
+            grass_sound,
+            honk_sounds,
+            jump_sound,
+            log_sound,
+            river_sounds,
+            road_sound,
+            sidewalk_sound,
+            splash_sound,
+            splat_sound,
+            traffic_sounds,
+            train_sounds,
+            zoom_sounds,
+
+            music,
+        })
+    }
+}
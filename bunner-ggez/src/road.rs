@@ -0,0 +1,146 @@
+use bunner_core::{collision, row_sequence, traffic};
+use ggez::{graphics::Image, Context, GameResult};
+
+use crate::{
+    active_row::ActiveRow,
+    actor::Actor,
+    car::{Car, CarSound, TrafficSound},
+    child::Child,
+    player_state::PlayerState,
+    position::Position,
+    resources::Resources,
+    row::{build_row, Row, RowSound},
+    row_rng::RowRng,
+    sound::play_sound_once,
+    ROW_HEIGHT,
+};
+
+/// Maximum (absolute) car speed, in pixels per frame.
+const MAX_SPEED: i32 = 5;
+
+pub struct Road {
+    dx: i32,
+    timer: f32,
+    index: i32,
+    y: i32,
+    children: Vec<Child>,
+}
+
+impl Row for Road {
+    fn y(&self) -> i32 {
+        self.y
+    }
+
+    fn children(&self) -> &[Child] {
+        &self.children
+    }
+
+    fn children_mut(&mut self) -> &mut Vec<Child> {
+        self.children.as_mut()
+    }
+
+    fn update(
+        &mut self,
+        context: &mut Context,
+        resources: &mut Resources,
+        _scroll_pos: i32,
+        bunner_pos: Option<Position>,
+        rng: &mut RowRng,
+    ) -> GameResult {
+        self.update_children();
+        self.children.retain(|c| traffic::is_on_row(c.x()));
+        self.timer -= 1.;
+
+        // Create new child objects on a random interval
+        if self.timer < 0. {
+            self.children.push(self.create_random_child(self.dx, rng));
+            self.timer = self.random_interval(self.dx, rng);
+        }
+
+        if let Some(bunner_pos) = bunner_pos {
+            for traffic_sound in Road::CAR_SOUNDS.iter() {
+                // Is the player on the appropriate row?
+                if bunner_pos.y == self.y + traffic_sound.y_offset {
+                    for child in self.children.iter_mut() {
+                        if let Child::Car(car) = child {
+                            // The car must be within 100 pixels of the player on the x-axis, and moving towards the player
+                            // child_obj.dx < 0 is True or False depending on whether the car is moving left or right, and
+                            // dx < 0 is True or False depending on whether the player is to the left or right of the car.
+                            // If the results of these two comparisons are different, the car is moving towards the player.
+                            // Also, for the zoom sound, the car must be travelling faster than one pixel per frame
+                            let dx = car.x() - bunner_pos.x;
+                            if dx.abs() < 100
+                                && ((car.dx < 0) != (dx < 0))
+                                && (traffic_sound.y_offset == 0 || car.dx.abs() > 1)
+                            {
+                                car.play_sound(context, resources, traffic_sound.sound)?;
+                            }
+                        };
+                    }
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    fn image<'a>(&self, resources: &'a Resources) -> &'a Image {
+        &resources.road_images[self.index as usize]
+    }
+
+    fn play_sound(&self, context: &mut Context, resources: &mut Resources) -> GameResult {
+        play_sound_once(context, &mut resources.road_sound)
+    }
+
+    fn next(&self, rng: &mut RowRng) -> Box<dyn Row> {
+        build_row(
+            row_sequence::after_road(self.index, 1., rng),
+            self.dx,
+            self.y - ROW_HEIGHT,
+            rng,
+        )
+    }
+
+    fn check_collision(&self, x: i32) -> PlayerState {
+        collision::road_state(self.collide(x, 0))
+    }
+
+    fn sound(&self) -> Option<RowSound> {
+        Some(RowSound::Traffic)
+    }
+}
+
+impl ActiveRow for Road {
+    fn build_child(dx: i32, position: Position, rng: &mut RowRng) -> Child {
+        Child::Car(Car::new(dx, position, rng))
+    }
+}
+
+impl Road {
+    const CAR_SOUNDS: &'static [TrafficSound] = &[
+        TrafficSound {
+            y_offset: -ROW_HEIGHT,
+            sound: CarSound::Zoom,
+        },
+        TrafficSound {
+            y_offset: 0,
+            sound: CarSound::Honk,
+        },
+        TrafficSound {
+            y_offset: ROW_HEIGHT,
+            sound: CarSound::Zoom,
+        },
+    ];
+
+    pub fn new(previous_dx: i32, index: i32, y: i32, rng: &mut RowRng) -> Self {
+        // Populate the row with child objects (cars or logs). Without this, the row would initially be empty.
+        let dx = traffic::road_dx(previous_dx, MAX_SPEED, rng);
+        Self {
+            dx,
+            timer: 0.,
+            index,
+            y,
+            children: Self::build_children(dx, rng),
+        }
+    }
+}
@@ -0,0 +1,99 @@
+use bunner_core::{collision, row_sequence::NextRow};
+use ggez::{graphics::Image, Context, GameResult};
+
+use crate::{
+    child::Child, dirt::Dirt, drawing::draw_image, grass::Grass, pavement::Pavement,
+    player_state::PlayerState, position::Position, rail::Rail, resources::Resources, road::Road,
+    row_rng::RowRng, water::Water,
+};
+
+pub trait Row {
+    fn y(&self) -> i32;
+    fn children(&self) -> &[Child];
+    fn children_mut(&mut self) -> &mut Vec<Child>;
+
+    fn update(
+        &mut self,
+        _context: &mut Context,
+        _resources: &mut Resources,
+        _scroll_pos: i32,
+        _bunner_pos: Option<Position>,
+        _rng: &mut RowRng,
+    ) -> GameResult {
+        self.update_children();
+        Ok(())
+    }
+
+    fn update_children(&mut self) {
+        for child in self.children_mut().iter_mut() {
+            child.update();
+        }
+    }
+
+    fn image<'a>(&self, resources: &'a Resources) -> &'a Image;
+
+    fn draw(
+        &self,
+        context: &mut Context,
+        resources: &Resources,
+        offset_x: i32,
+        offset_y: i32,
+    ) -> GameResult {
+        let image = self.image(resources);
+        let y = self.y() + offset_y;
+        draw_image(context, image, offset_x, y - image.height() as i32)?;
+
+        for child in self.children() {
+            child.draw(context, resources, offset_x, y)?;
+        }
+
+        Ok(())
+    }
+
+    fn play_sound(&self, context: &mut Context, resources: &mut Resources) -> GameResult;
+
+    fn next(&self, rng: &mut RowRng) -> Box<dyn Row>;
+
+    fn check_collision(&self, _x: i32) -> PlayerState {
+        PlayerState::Alive
+    }
+
+    fn allow_movement(&self, x: i32) -> bool {
+        collision::is_within_bounds(x)
+    }
+
+    fn collide(&self, x: i32, margin: i32) -> bool {
+        let children = self
+            .children()
+            .iter()
+            .map(|child| (child.x(), child.width()));
+        collision::collide(x, margin, children)
+    }
+
+    fn push(&self) -> i32 {
+        0
+    }
+
+    fn sound(&self) -> Option<RowSound> {
+        None
+    }
+}
+
+#[derive(Eq, Hash, PartialEq, Copy, Clone)]
+pub enum RowSound {
+    Traffic,
+    River,
+}
+
+/// Builds the next row chosen by the generation rules (see `bunner_core::row_sequence`). A grass row is built
+/// without hedges, since the grass rows continuing the hedges of the previous one are built by `Grass` itself.
+pub fn build_row(next: NextRow, previous_dx: i32, y: i32, rng: &mut RowRng) -> Box<dyn Row> {
+    match next {
+        NextRow::Grass(index) => Box::new(Grass::without_hedge(index, y, rng)),
+        NextRow::Dirt(index) => Box::new(Dirt::new(index, y)),
+        NextRow::Road(index) => Box::new(Road::new(previous_dx, index, y, rng)),
+        NextRow::Pavement(index) => Box::new(Pavement::new(index, y)),
+        NextRow::Rail(index) => Box::new(Rail::new(index, y)),
+        NextRow::Water(index) => Box::new(Water::new(previous_dx, index, y, rng)),
+    }
+}
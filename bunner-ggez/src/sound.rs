@@ -0,0 +1,15 @@
+use ggez::{
+    audio::{self, SoundSource},
+    Context, GameResult,
+};
+
+// Detached playback is used, so that the same sound can overlap itself, like in Pygame Zero.
+
+pub fn play_sound_once(context: &mut Context, sound: &mut audio::Source) -> GameResult {
+    sound.play_detached(context)
+}
+
+pub fn play_random_sound(context: &mut Context, sounds: &mut [audio::Source]) -> GameResult {
+    let sound_i = fastrand::usize(..sounds.len());
+    play_sound_once(context, &mut sounds[sound_i])
+}
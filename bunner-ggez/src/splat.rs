@@ -0,0 +1,48 @@
+use ggez::{Context, GameResult};
+
+use crate::{
+    actor::Actor, drawing::draw_image_anchored, player_direction::PlayerDirection,
+    position::Position, resources::Resources,
+};
+
+#[derive(Clone)]
+pub struct Splat {
+    direction: PlayerDirection,
+    position: Position,
+}
+
+impl Actor for Splat {
+    fn update(&mut self) {}
+
+    fn draw(
+        &self,
+        context: &mut Context,
+        resources: &Resources,
+        offset_x: i32,
+        offset_y: i32,
+    ) -> GameResult {
+        draw_image_anchored(
+            context,
+            &resources.splat_images[self.direction as usize],
+            self.position.x + offset_x,
+            self.position.y + offset_y,
+        )
+    }
+
+    fn x(&self) -> i32 {
+        self.position.x
+    }
+
+    fn width(&self) -> i32 {
+        60
+    }
+}
+
+impl Splat {
+    pub fn new(direction: PlayerDirection, position: Position) -> Self {
+        Self {
+            direction,
+            position,
+        }
+    }
+}
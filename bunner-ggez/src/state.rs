@@ -0,0 +1,6 @@
+#[derive(Clone, Copy, Eq, PartialEq)]
+pub enum State {
+    Menu,
+    Play,
+    GameOver,
+}
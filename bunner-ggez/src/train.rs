@@ -0,0 +1,57 @@
+use ggez::{Context, GameResult};
+
+use crate::{
+    actor::Actor, drawing::draw_image_anchored, position::Position, resources::Resources,
+    row_rng::RowRng,
+};
+
+#[derive(Clone)]
+pub struct Train {
+    dx: i32,
+    position: Position,
+    image_index: usize,
+}
+
+impl Actor for Train {
+    fn update(&mut self) {
+        self.position.x += self.dx;
+    }
+
+    fn draw(
+        &self,
+        context: &mut Context,
+        resources: &Resources,
+        offset_x: i32,
+        offset_y: i32,
+    ) -> GameResult {
+        draw_image_anchored(
+            context,
+            &resources.train_images[self.image_index],
+            self.position.x + offset_x,
+            self.position.y + offset_y,
+        )
+    }
+
+    fn x(&self) -> i32 {
+        self.position.x
+    }
+
+    fn width(&self) -> i32 {
+        860
+    }
+}
+
+impl Train {
+    pub fn new(dx: i32, position: Position, rng: &mut RowRng) -> Self {
+        let image_index = if dx < 0 {
+            *rng.choose(&[0, 2, 4]).unwrap()
+        } else {
+            *rng.choose(&[1, 3, 5]).unwrap()
+        };
+        Self {
+            dx,
+            position,
+            image_index,
+        }
+    }
+}
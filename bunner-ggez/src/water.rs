@@ -0,0 +1,109 @@
+use bunner_core::{collision, row_sequence, traffic};
+use ggez::{graphics::Image, Context, GameResult};
+
+use crate::{
+    active_row::ActiveRow,
+    child::Child,
+    log::Log,
+    player_state::PlayerState,
+    position::Position,
+    resources::Resources,
+    row::{build_row, Row, RowSound},
+    row_rng::RowRng,
+    sound::play_sound_once,
+    ROW_HEIGHT,
+};
+
+/// Maximum (absolute) log speed, in pixels per frame.
+const MAX_SPEED: i32 = 2;
+
+pub struct Water {
+    dx: i32,
+    timer: f32,
+    index: i32,
+    y: i32,
+    children: Vec<Child>,
+}
+
+impl Row for Water {
+    fn y(&self) -> i32 {
+        self.y
+    }
+
+    fn children(&self) -> &[Child] {
+        &self.children
+    }
+
+    fn children_mut(&mut self) -> &mut Vec<Child> {
+        self.children.as_mut()
+    }
+
+    fn update(
+        &mut self,
+        _context: &mut Context,
+        _resources: &mut Resources,
+        _scroll_pos: i32,
+        _bunner_pos: Option<Position>,
+        rng: &mut RowRng,
+    ) -> GameResult {
+        self.update_children();
+        self.children.retain(|c| traffic::is_on_row(c.x()));
+        self.timer -= 1.;
+
+        // Create new child objects on a random interval
+        if self.timer < 0. {
+            self.children.push(self.create_random_child(self.dx, rng));
+            self.timer = self.random_interval(self.dx, rng);
+        }
+
+        Ok(())
+    }
+
+    fn image<'a>(&self, resources: &'a Resources) -> &'a Image {
+        &resources.water_images[self.index as usize]
+    }
+
+    fn play_sound(&self, context: &mut Context, resources: &mut Resources) -> GameResult {
+        play_sound_once(context, &mut resources.log_sound)
+    }
+
+    fn next(&self, rng: &mut RowRng) -> Box<dyn Row> {
+        build_row(
+            row_sequence::after_water(self.index, 1., rng),
+            self.dx,
+            self.y - ROW_HEIGHT,
+            rng,
+        )
+    }
+
+    fn check_collision(&self, x: i32) -> PlayerState {
+        collision::water_state(self.collide(x, collision::LOG_MARGIN))
+    }
+
+    fn push(&self) -> i32 {
+        self.dx
+    }
+
+    fn sound(&self) -> Option<RowSound> {
+        Some(RowSound::River)
+    }
+}
+
+impl ActiveRow for Water {
+    fn build_child(dx: i32, position: Position, rng: &mut RowRng) -> Child {
+        Child::Log(Log::new(dx, position, rng))
+    }
+}
+
+impl Water {
+    pub fn new(previous_dx: i32, index: i32, y: i32, rng: &mut RowRng) -> Self {
+        let dx = traffic::water_dx(previous_dx, MAX_SPEED, rng);
+        Self {
+            dx,
+            timer: 0.,
+            index,
+            y,
+            children: Self::build_children(dx, rng),
+        }
+    }
+}
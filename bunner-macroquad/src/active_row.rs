@@ -1,33 +1,22 @@
-use crate::{child::Child, position::Position, WIDTH};
-use macroquad::rand;
+use crate::{child::Child, position::Position, row_rng::RowRng};
+use bunner_core::traffic;
 
 pub trait ActiveRow: Sized {
-    fn build_children(dx: i32) -> Vec<Child> {
-        let mut children = Vec::new();
-        let mut x = -WIDTH / 2 - 70;
-        while x < WIDTH / 2 + 70 {
-            x += rand::gen_range::<i32>(240, 481);
-            let position = if dx > 0 {
-                Position::new(WIDTH / 2 + x, 0)
-            } else {
-                Position::new(WIDTH / 2 - x, 0)
-            };
-            children.push(Self::build_child(dx, position));
-        }
-        children
+    /// Rust: The children are added to the passed vector, so that the row storage is reused. The density is
+    /// defined by the difficulty curve (1 in the original game).
+    fn build_children(dx: i32, traffic_density: f32, children: &mut Vec<Child>, rng: &mut RowRng) {
+        traffic::for_each_initial_child(dx, traffic_density, rng, |x, rng| {
+            children.push(Self::build_child(dx, Position::new(x, 0), rng));
+        });
     }
 
-    fn build_child(dx: i32, position: Position) -> Child;
+    fn build_child(dx: i32, position: Position, rng: &mut RowRng) -> Child;
 
-    fn create_random_child(&self, dx: i32) -> Child {
-        let pos = Position::new(if dx < 0 { WIDTH + 70 } else { -70 }, 0);
-        Self::build_child(dx, pos)
+    fn create_random_child(&self, dx: i32, rng: &mut RowRng) -> Child {
+        Self::build_child(dx, Position::new(traffic::spawn_x(dx), 0), rng)
     }
 
-    fn random_interval(&self, dx: i32) -> f32 {
-        // 240 is minimum distance between the start of one child object and the start of the next, assuming its
-        // speed is 1. If the speed is 2, they can occur twice as frequently without risk of overlapping with
-        // each other. The maximum distance is double the minimum distance (1 + random value of 1)
-        (1. + rand::gen_range::<f32>(0.0, 1.0)) * (240 / dx.abs()) as f32
+    fn random_interval(&self, dx: i32, traffic_density: f32, rng: &mut RowRng) -> f32 {
+        traffic::random_interval(dx, traffic_density, rng)
     }
 }
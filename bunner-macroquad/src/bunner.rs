@@ -1,10 +1,11 @@
 use crate::{
-    child::Child, player_direction::PlayerDirection, player_state::PlayerState, position::Position,
-    resources::Resources, row::Row, splat::Splat, HEIGHT, WIDTH,
+    child::Child, eagle_pressure, effects::Effects, player_direction::PlayerDirection,
+    player_state::PlayerState, position::Position, power_up::ActivePowerUp,
+    power_up_kind::PowerUpKind, reduced_motion, resources::Resources, row::Row,
+    sound::play_sound_once, splat::Splat, HEIGHT, WIDTH,
 };
 use macroquad::{
-    audio::play_sound_once,
-    color::colors::WHITE,
+    color::{colors::WHITE, Color},
     experimental::collections::storage,
     input::KeyCode,
     texture::{draw_texture, Texture2D},
@@ -19,11 +20,27 @@ pub struct Bunner {
     direction: PlayerDirection,
     input_queue: VecDeque<PlayerDirection>,
     image: Texture2D,
+    /// Rust: Not in the original game. Direction and position of the hop started in the last update, if any; used
+    /// to record the ghost.
+    hop_start: Option<(PlayerDirection, Position, bool)>,
+    /// Rust: Not in the original game; see `PowerUp`.
+    power_up: Option<ActivePowerUp>,
+    /// Whether the current hop is a double one.
+    double_hop: bool,
+    /// Rust: Not in the original game; see `Effects`.
+    effects: Effects,
+    /// Rust: Not in the original game. Updates since the bunner last reached a further row; see `eagle_pressure`.
+    idle_time: u32,
 }
 
 impl Bunner {
     const MOVE_DISTANCE: i32 = 10;
 
+    /// Rust: Not in the original game. While invincible, the bunner blinks with this period (in updates); in reduced
+    /// motion mode, it's steadily translucent.
+    const INVINCIBLE_BLINK_PERIOD: i32 = 8;
+    const INVINCIBLE_BLINK_COLOUR: Color = Color::new(1., 1., 1., 0.4);
+
     pub fn new(position: Position) -> Self {
         Self {
             state: PlayerState::Alive,
@@ -33,14 +50,22 @@ impl Bunner {
             direction: PlayerDirection::Down,
             input_queue: VecDeque::new(),
             image: storage::get::<Resources>().blank_texture,
+            hop_start: None,
+            power_up: None,
+            double_hop: false,
+            effects: Effects::default(),
+            idle_time: 0,
         }
     }
 
+    /// Rust: `rows_updated` is false when the rows have not been updated (see `PowerUpKind::SlowTime`), in which
+    /// case the bunner is not pushed by them.
     pub fn update(
         &mut self,
         scroll_pos: i32,
         rows: &mut [Box<dyn Row>],
         input_queue: VecDeque<KeyCode>,
+        rows_updated: bool,
     ) {
         self.input_queue.append(
             &mut input_queue
@@ -55,8 +80,18 @@ impl Bunner {
                 .collect::<VecDeque<PlayerDirection>>(),
         );
 
+        self.hop_start = None;
+        self.effects.update();
+
         match self.state {
             PlayerState::Alive => {
+                // Rust: Not in the original game.
+                if let Some(power_up) = &mut self.power_up {
+                    if !power_up.update() {
+                        self.power_up = None;
+                    }
+                }
+
                 // While the player is alive, the timer variable is used for movement.
                 // If it's zero, the player is on the ground. If it's above zero,
                 // they're currently jumping to a new location.
@@ -78,13 +113,37 @@ impl Bunner {
                     land = self.timer == 0;
                 }
 
-                if let Some(current_row) = rows.iter_mut().find(|row| row.y() == self.position.y) {
-                    self.state = current_row.check_collision(self.position.x);
+                // Rust: In a double hop, the bunner is in the air until landing, so it doesn't interact with the rows.
+                let airborne = self.double_hop && self.timer > 0;
+
+                if let Some(current_row) = rows
+                    .iter_mut()
+                    .find(|row| !airborne && row.y() == self.position.y)
+                {
+                    self.state = match current_row.check_collision(self.position.x) {
+                        PlayerState::Splat(_) if self.has_power_up(PowerUpKind::Invincibility) => {
+                            PlayerState::Alive
+                        }
+                        state => state,
+                    };
                     match self.state {
                         PlayerState::Alive => {
-                            self.position.x += current_row.push();
+                            if rows_updated {
+                                self.position.x += current_row.push();
+                            }
                             if land {
                                 current_row.play_sound();
+                                if current_row.is_water() {
+                                    self.effects.ripple(
+                                        self.position.x,
+                                        self.position.y,
+                                        current_row.push(),
+                                    );
+                                }
+                            }
+                            if let Some(kind) = current_row.take_power_up(self.position.x) {
+                                self.power_up = Some(ActivePowerUp::new(kind));
+                                play_sound_once(storage::get::<Resources>().bell_sound);
                             }
                         }
                         PlayerState::Splat(y_offset) => {
@@ -98,17 +157,35 @@ impl Bunner {
                                 )),
                             );
                             play_sound_once(storage::get::<Resources>().splat_sound);
+                            self.effects.shake();
+                            self.effects.splat(self.position.x, self.position.y - 20);
                         }
                         PlayerState::Splash => {
                             play_sound_once(storage::get::<Resources>().splash_sound);
+                            self.effects.ripple(self.position.x, self.position.y, 0);
                             self.timer = 100;
                         }
                         _ => self.timer = 100,
                     }
-                } else if self.position.y > scroll_pos + HEIGHT + 80 {
-                    self.state = PlayerState::Eagle(self.position.x);
-                    self.timer = 150;
-                    play_sound_once(storage::get::<Resources>().eagle_sound);
+                } else if self.position.y > scroll_pos + HEIGHT + 80
+                    && !self.has_power_up(PowerUpKind::Invincibility)
+                {
+                    // Rust: The eagle doesn't catch an invincible bunner; if the bunner is still off screen once
+                    // the invincibility expires, it's caught.
+                    self.caught_by_eagle();
+                }
+
+                // Rust: Not in the original game; see `eagle_pressure`.
+                if self.position.y < self.min_y {
+                    self.idle_time = 0;
+                } else {
+                    self.idle_time += 1;
+                }
+                if self.state == PlayerState::Alive
+                    && self.idle_time >= eagle_pressure::tuning().dive_time
+                    && !self.has_power_up(PowerUpKind::Invincibility)
+                {
+                    self.caught_by_eagle();
                 }
 
                 // Limit x position
@@ -157,26 +234,98 @@ impl Bunner {
     pub fn draw(&self, offset_x: i32, offset_y: i32) {
         let x = (self.position.x + offset_x) as f32 - self.image.width() / 2.;
         let y = (self.position.y + offset_y) as f32 - self.image.height();
-        draw_texture(self.image, x, y, WHITE);
+        let colour = match self.power_up {
+            Some(ActivePowerUp {
+                kind: PowerUpKind::Invincibility,
+                timer,
+            }) if timer / Self::INVINCIBLE_BLINK_PERIOD % 2 == 0
+                || reduced_motion::is_enabled() =>
+            {
+                Self::INVINCIBLE_BLINK_COLOUR
+            }
+            _ => WHITE,
+        };
+        draw_texture(self.image, x, y, colour);
+
+        self.effects.draw(offset_x, offset_y);
+    }
+
+    fn caught_by_eagle(&mut self) {
+        self.state = PlayerState::Eagle(self.position.x);
+        self.timer = 150;
+        play_sound_once(storage::get::<Resources>().eagle_sound);
+        self.effects.shake();
+    }
+
+    /// Rust: Not in the original game. From 0 to 1; see `eagle_pressure`.
+    pub fn eagle_pressure(&self) -> f32 {
+        if self.is_alive() {
+            eagle_pressure::tuning().pressure(self.idle_time)
+        } else {
+            0.
+        }
+    }
+
+    /// Rust: Not in the original game; see `Effects`.
+    pub fn shake_offset(&self) -> (i32, i32) {
+        self.effects.shake_offset()
     }
 
     pub fn handle_input(&mut self, direction: Option<PlayerDirection>, rows: &[Box<dyn Row>]) {
         if let Some(direction) = direction {
-            for row in rows.iter() {
-                if row.y() == self.position.y + Self::MOVE_DISTANCE * Self::dy(&direction) {
-                    if row.allow_movement(
-                        self.position.x + Self::MOVE_DISTANCE * Self::dx(&direction),
-                    ) {
+            // Rust: With the double hop power-up, the double hop is attempted first, falling back to the regular
+            // one if the landing cell is blocked (or not generated yet).
+            let hop_lengths: &[i32] = if self.has_power_up(PowerUpKind::DoubleHop) {
+                &[2, 1]
+            } else {
+                &[1]
+            };
+
+            for &hop_length in hop_lengths {
+                let distance = Self::MOVE_DISTANCE * hop_length;
+                let target_row = rows
+                    .iter()
+                    .find(|row| row.y() == self.position.y + distance * Self::dy(&direction));
+
+                if let Some(row) = target_row {
+                    if row.allow_movement(self.position.x + distance * Self::dx(&direction)) {
                         self.direction = direction;
-                        self.timer = Bunner::MOVE_DISTANCE;
+                        self.timer = distance;
+                        self.double_hop = hop_length > 1;
+                        self.hop_start = Some((direction, self.position, self.double_hop));
                         play_sound_once(storage::get::<Resources>().jump_sound);
+                        return;
                     }
-                    break;
                 }
             }
         }
     }
 
+    pub fn is_alive(&self) -> bool {
+        self.state == PlayerState::Alive
+    }
+
+    /// Rust: Not in the original game. Where the current hop (if any) lands, not considering the push of the rows.
+    pub fn landing_position(&self) -> Position {
+        Position::new(
+            self.position.x + Self::dx(&self.direction) * self.timer,
+            self.position.y + Self::dy(&self.direction) * self.timer,
+        )
+    }
+
+    /// Direction, position and whether it's a double hop.
+    pub fn hop_start(&self) -> Option<(PlayerDirection, Position, bool)> {
+        self.hop_start
+    }
+
+    pub fn power_up(&self) -> Option<&ActivePowerUp> {
+        self.power_up.as_ref()
+    }
+
+    pub fn has_power_up(&self, kind: PowerUpKind) -> bool {
+        self.power_up.is_some_and(|power_up| power_up.kind == kind)
+    }
+
     fn dx(direction: &PlayerDirection) -> i32 {
         match direction {
             PlayerDirection::Up => 0,
@@ -0,0 +1,90 @@
+use crate::{reduced_motion, HEIGHT};
+
+// Rust: Not in the original game, where the scroll speed depends only on the player distance from the bottom of
+// the screen. The camera eases toward a point above the player, looking further ahead when the player is moving
+// quickly upward, which gives more time to react at higher speeds.
+//
+// As in the original game, the camera never scrolls back, and scrolls at least one pixel per frame, so that the
+// player can't stay behind indefinitely.
+//
+// In reduced motion mode, there is no look-ahead, and the scroll speed ramps up and down gradually, up to the
+// maximum of the original game.
+//
+#[derive(Default)]
+pub struct Camera {
+    /// Kept as float, since the easing produces fractional movements.
+    scroll_pos: f32,
+    /// Smoothed upward speed of the target, in pixels per frame.
+    target_speed: f32,
+    /// Of the last frame.
+    scroll_speed: f32,
+    previous_target_y: Option<i32>,
+}
+
+impl Camera {
+    /// Screen position of the target, without look-ahead; it's the position of the player at the start.
+    const TARGET_SCREEN_Y: f32 = HEIGHT as f32 * 0.6;
+    /// Look-ahead distance, in pixels, per pixel per frame of upward speed.
+    const LOOK_AHEAD_FACTOR: f32 = 60.;
+    const MAX_LOOK_AHEAD: f32 = 160.;
+    /// Fraction of the distance to the target point covered on each frame.
+    const EASING: f32 = 0.05;
+    /// Fraction of the speed change applied on each frame; hops are discrete, so the speed is smoothed.
+    const SPEED_SMOOTHING: f32 = 0.05;
+    const MIN_SPEED: f32 = 1.;
+    const MAX_SPEED: f32 = 4.;
+    const REDUCED_MOTION_MAX_SPEED: f32 = 3.;
+    /// Maximum change of the scroll speed per frame, in reduced motion mode.
+    const REDUCED_MOTION_MAX_RAMP: f32 = 0.05;
+
+    pub fn new(scroll_pos: i32) -> Self {
+        Self {
+            scroll_pos: scroll_pos as f32,
+            ..Self::default()
+        }
+    }
+
+    pub fn scroll_pos(&self) -> i32 {
+        self.scroll_pos as i32
+    }
+
+    /// Moves the camera toward the target (the player y), if present; otherwise, it scrolls at the minimum speed.
+    pub fn update(&mut self, target_y: Option<i32>) {
+        let target_y = match target_y {
+            Some(target_y) => target_y,
+            None => {
+                self.scroll_pos -= Self::MIN_SPEED;
+                return;
+            }
+        };
+
+        let speed = self
+            .previous_target_y
+            .map_or(0, |previous_target_y| previous_target_y - target_y);
+        self.target_speed += (speed as f32 - self.target_speed) * Self::SPEED_SMOOTHING;
+        self.previous_target_y = Some(target_y);
+
+        let reduced_motion = reduced_motion::is_enabled();
+
+        let look_ahead = if reduced_motion {
+            0.
+        } else {
+            (self.target_speed.max(0.) * Self::LOOK_AHEAD_FACTOR).min(Self::MAX_LOOK_AHEAD)
+        };
+        let target_scroll_pos = target_y as f32 - Self::TARGET_SCREEN_Y - look_ahead;
+
+        let mut scroll_speed = (self.scroll_pos - target_scroll_pos) * Self::EASING;
+        if reduced_motion {
+            scroll_speed = scroll_speed
+                .clamp(
+                    self.scroll_speed - Self::REDUCED_MOTION_MAX_RAMP,
+                    self.scroll_speed + Self::REDUCED_MOTION_MAX_RAMP,
+                )
+                .clamp(Self::MIN_SPEED, Self::REDUCED_MOTION_MAX_SPEED);
+        } else {
+            scroll_speed = scroll_speed.clamp(Self::MIN_SPEED, Self::MAX_SPEED);
+        }
+        self.scroll_speed = scroll_speed;
+        self.scroll_pos -= scroll_speed;
+    }
+}
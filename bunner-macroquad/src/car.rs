@@ -1,8 +1,10 @@
-use crate::{actor::Actor, mover::Mover, position::Position, resources::Resources};
+use crate::{
+    actor::Actor, mover::Mover, palette, position::Position, resources::Resources, row_rng::RowRng,
+    sound::play_sound_once,
+};
 use macroquad::{
-    audio::play_sound_once,
     prelude::{collections::storage, draw_texture, WHITE},
-    rand::{self, ChooseRandom},
+    rand,
 };
 use std::collections::HashSet;
 
@@ -36,6 +38,16 @@ impl Actor for Car {
             (self.position.y + offset_y) as f32 - image.height(),
             WHITE,
         );
+
+        if palette::is_high_contrast() {
+            palette::draw_outline(
+                (self.position.x + offset_x) as f32,
+                (self.position.y + offset_y) as f32,
+                self.width() as f32,
+                image.height(),
+                palette::CAR_OUTLINE_COLOR,
+            );
+        }
     }
 
     fn x(&self) -> i32 {
@@ -52,11 +64,11 @@ impl Actor for Car {
 }
 
 impl Car {
-    pub fn new(dx: i32, position: Position) -> Self {
+    pub fn new(dx: i32, position: Position, rng: &mut RowRng) -> Self {
         let image_index = if dx < 0 {
-            *vec![0, 2, 4].choose().unwrap()
+            *rng.choose(&[0, 2, 4]).unwrap()
         } else {
-            *vec![1, 3, 5].choose().unwrap()
+            *rng.choose(&[1, 3, 5]).unwrap()
         };
         Self {
             dx,
@@ -0,0 +1,73 @@
+use crate::{game::Game, position::Position, HEIGHT, ROW_HEIGHT, WIDTH};
+use macroquad::{
+    color::Color,
+    shapes::draw_rectangle,
+    text::{draw_text, measure_text},
+};
+
+// Rust: Not in the original game. Every `INTERVAL` rows, the first safe row (see `Row::is_safe`) on which the bunner
+// stands becomes the checkpoint; after dying, the player can continue from the last one, at a score penalty.
+//
+// Since each row is generated from the seed and its position (see `row_rng`), the world generation state at the
+// checkpoint is just the seed: the rows up to the checkpoint are regenerated, and discarded.
+//
+#[derive(Clone, Copy)]
+pub struct Checkpoint {
+    pub seed: u64,
+    pub position: Position,
+}
+
+impl Checkpoint {
+    /// In rows (i.e. points).
+    pub const INTERVAL: u32 = 50;
+    /// Points subtracted on each continue.
+    pub const PENALTY: u32 = 10;
+
+    const MARKER_COLOR: Color = Color::new(1., 0.85, 0., 0.6);
+    const MARKER_HEIGHT: i32 = 4;
+    const MARKER_FONT_SIZE: u16 = 20;
+
+    pub fn score(&self) -> u32 {
+        Game::distance(self.position.y)
+    }
+
+    /// Y of the row reached with the given score; the bunner starts at y -320.
+    fn row_y(score: u32) -> i32 {
+        -320 - score as i32 * ROW_HEIGHT
+    }
+
+    /// Draws the markers of the checkpoint distances on screen, at the bottom of their rows.
+    pub fn draw_markers(offset_y: i32) {
+        // Scores of the rows at the bottom and top of the screen.
+        let min_score = ((-320 - (HEIGHT - offset_y)) / ROW_HEIGHT).max(0) as u32;
+        let max_score = ((offset_y - 320) / ROW_HEIGHT).max(0) as u32;
+
+        for marker in min_score.div_ceil(Self::INTERVAL).max(1)..=max_score / Self::INTERVAL {
+            let score = marker * Self::INTERVAL;
+            let y = (Self::row_y(score) + offset_y) as f32;
+
+            draw_rectangle(
+                0.,
+                y - Self::MARKER_HEIGHT as f32 / 2.,
+                WIDTH as f32,
+                Self::MARKER_HEIGHT as f32,
+                Self::MARKER_COLOR,
+            );
+
+            let text = format!("CHECKPOINT {}", score);
+            let text_width = measure_text(&text, None, Self::MARKER_FONT_SIZE, 1.).width;
+            draw_text(
+                &text,
+                WIDTH as f32 - text_width - 8.,
+                y - Self::MARKER_HEIGHT as f32,
+                Self::MARKER_FONT_SIZE as f32,
+                Self::MARKER_COLOR,
+            );
+        }
+    }
+
+    /// Whether the score (distance) is past the checkpoint interval, after the given checkpoint.
+    pub fn is_due(score: u32, last_checkpoint_score: u32) -> bool {
+        score / Self::INTERVAL > last_checkpoint_score / Self::INTERVAL
+    }
+}
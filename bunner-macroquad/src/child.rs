@@ -49,4 +49,9 @@ impl Child {
             Child::Log(log) => log.width(),
         }
     }
+
+    /// Rust: Not in the original game; submerged children (see `Log::is_submerged()`) are ignored by the collisions.
+    pub fn is_submerged(&self) -> bool {
+        matches!(self, Child::Log(log) if log.is_submerged())
+    }
 }
@@ -0,0 +1,217 @@
+#[cfg(not(target_arch = "wasm32"))]
+use std::fs;
+
+use macroquad::{
+    color::{
+        colors::{DARKGRAY, WHITE},
+        Color,
+    },
+    miniquad::date,
+    shapes::{draw_rectangle, draw_rectangle_lines},
+    text::{draw_text, measure_text},
+};
+use nanoserde::{DeRon, SerRon};
+
+#[cfg(not(target_arch = "wasm32"))]
+const CALENDAR_FILE: &str = "daily.ron";
+
+// In the browser, the calendar is stored in the local storage, like the records (see `index.html`), as a flat list of
+// (day, score) pairs.
+//
+#[cfg(target_arch = "wasm32")]
+extern "C" {
+    fn bunner_daily_len() -> u32;
+    fn bunner_daily_get(index: u32) -> u32;
+    fn bunner_daily_set(values: *const u32, len: u32);
+}
+
+const SECONDS_PER_DAY: f64 = 86_400.;
+
+// Rust: Not in the original game. The daily challenge is the same for all the players on a given (UTC) day: the
+// seed of the rows and the modifiers (difficulty curve and night mode) are derived from the date. Only one attempt
+// per day is allowed; it's recorded when started, so that quitting doesn't allow a retry.
+//
+#[derive(Clone, Copy)]
+pub struct DailyChallenge {
+    /// Days since the Unix epoch.
+    pub day: u32,
+    pub seed: u64,
+    /// Index of the difficulty curve.
+    pub difficulty_index: usize,
+    pub night_mode: bool,
+}
+
+impl DailyChallenge {
+    pub fn today(difficulty_curves_count: usize) -> Self {
+        Self::for_day(today(), difficulty_curves_count)
+    }
+
+    fn for_day(day: u32, difficulty_curves_count: usize) -> Self {
+        let seed = mix(day as u64);
+        let modifiers = mix(seed);
+
+        Self {
+            day,
+            seed,
+            difficulty_index: (modifiers % difficulty_curves_count as u64) as usize,
+            night_mode: modifiers >> 32 & 1 == 1,
+        }
+    }
+}
+
+#[derive(Clone, Copy, DeRon, SerRon)]
+pub struct DailyResult {
+    pub day: u32,
+    pub score: u32,
+}
+
+/// Results of the daily challenges, persisted across runs.
+#[derive(Default, DeRon, SerRon)]
+pub struct DailyCalendar {
+    /// Sorted by day.
+    results: Vec<DailyResult>,
+}
+
+impl DailyCalendar {
+    /// Days displayed by `draw`, up to today.
+    const DISPLAYED_DAYS: u32 = 7;
+    const CELL_WIDTH: f32 = 56.;
+    const CELL_HEIGHT: f32 = 40.;
+    const CELL_SPACING: f32 = 6.;
+    const FONT_SIZE: u16 = 20;
+    const PLAYED_COLOR: Color = Color::new(0.2, 0.45, 0.8, 1.);
+
+    /// If the file is missing or invalid, the calendar is empty.
+    pub fn load() -> Self {
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            fs::read_to_string(CALENDAR_FILE)
+                .ok()
+                .and_then(|content| Self::deserialize_ron(&content).ok())
+                .unwrap_or_default()
+        }
+
+        #[cfg(target_arch = "wasm32")]
+        {
+            let mut calendar = Self::default();
+            // SAFETY: The functions only access the browser local storage.
+            let values = unsafe {
+                (0..bunner_daily_len())
+                    .map(|i| bunner_daily_get(i))
+                    .collect::<Vec<_>>()
+            };
+            for pair in values.chunks_exact(2) {
+                calendar.set_score(pair[0], pair[1]);
+            }
+            calendar
+        }
+    }
+
+    pub fn save(&self) {
+        #[cfg(not(target_arch = "wasm32"))]
+        if let Err(error) = fs::write(CALENDAR_FILE, self.serialize_ron()) {
+            eprintln!("Couldn't save the daily results: {}", error);
+        }
+
+        #[cfg(target_arch = "wasm32")]
+        {
+            let values = self
+                .results
+                .iter()
+                .flat_map(|result| [result.day, result.score])
+                .collect::<Vec<_>>();
+            // SAFETY: The function copies the values from the Wasm memory, without retaining the pointer.
+            unsafe {
+                bunner_daily_set(values.as_ptr(), values.len() as u32);
+            }
+        }
+    }
+
+    pub fn result(&self, day: u32) -> Option<&DailyResult> {
+        self.results.iter().find(|result| result.day == day)
+    }
+
+    pub fn is_played(&self, day: u32) -> bool {
+        self.result(day).is_some()
+    }
+
+    /// Adds the result of the day, or replaces it.
+    pub fn set_score(&mut self, day: u32, score: u32) {
+        match self.results.binary_search_by_key(&day, |result| result.day) {
+            Ok(i) => self.results[i].score = score,
+            Err(i) => self.results.insert(i, DailyResult { day, score }),
+        }
+    }
+
+    /// Draws the results of the last days (today on the right), centered at the given y.
+    pub fn draw(&self, center_x: f32, y: f32) {
+        let today = today();
+        let total_width = Self::DISPLAYED_DAYS as f32 * (Self::CELL_WIDTH + Self::CELL_SPACING)
+            - Self::CELL_SPACING;
+        let left = center_x - total_width / 2.;
+
+        for i in 0..Self::DISPLAYED_DAYS {
+            let day = (today + 1 + i).saturating_sub(Self::DISPLAYED_DAYS);
+            let x = left + i as f32 * (Self::CELL_WIDTH + Self::CELL_SPACING);
+
+            let (fill_color, score_text) = match self.result(day) {
+                Some(result) => (Self::PLAYED_COLOR, result.score.to_string()),
+                None => (DARKGRAY, "-".to_string()),
+            };
+            draw_rectangle(x, y, Self::CELL_WIDTH, Self::CELL_HEIGHT, fill_color);
+            draw_rectangle_lines(x, y, Self::CELL_WIDTH, Self::CELL_HEIGHT, 2., WHITE);
+
+            let (_, month, day_of_month) = civil_date(day);
+            let date_text = format!("{}/{}", day_of_month, month);
+            for (text, text_y) in [(date_text.as_str(), 16.), (score_text.as_str(), 34.)] {
+                let text_width = measure_text(text, None, Self::FONT_SIZE, 1.).width;
+                draw_text(
+                    text,
+                    x + (Self::CELL_WIDTH - text_width) / 2.,
+                    y + text_y,
+                    Self::FONT_SIZE as f32,
+                    WHITE,
+                );
+            }
+        }
+    }
+}
+
+/// Days since the Unix epoch, in UTC.
+pub fn today() -> u32 {
+    (date::now() / SECONDS_PER_DAY) as u32
+}
+
+/// Formats the day (since the Unix epoch) as ISO date.
+pub fn format_date(day: u32) -> String {
+    let (year, month, day_of_month) = civil_date(day);
+    format!("{:04}-{:02}-{:02}", year, month, day_of_month)
+}
+
+/// Converts the days since the Unix epoch to (year, month, day); see Howard Hinnant's `civil_from_days`.
+fn civil_date(day: u32) -> (i32, u32, u32) {
+    let z = day as i32 + 719_468;
+    let era = z.div_euclid(146_097);
+    let day_of_era = z.rem_euclid(146_097);
+    let year_of_era =
+        (day_of_era - day_of_era / 1_460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month_index = (5 * day_of_year + 2) / 153;
+    let day_of_month = (day_of_year - (153 * month_index + 2) / 5 + 1) as u32;
+    let month = if month_index < 10 {
+        month_index + 3
+    } else {
+        month_index - 9
+    } as u32;
+    let year = year_of_era + era * 400 + if month <= 2 { 1 } else { 0 };
+
+    (year, month, day_of_month)
+}
+
+/// SplitMix64 finalizer; spreads consecutive days over the whole seed range.
+fn mix(value: u64) -> u64 {
+    let mut z = value.wrapping_add(0x9E37_79B9_7F4A_7C15);
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    z ^ (z >> 31)
+}
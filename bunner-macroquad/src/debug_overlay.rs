@@ -0,0 +1,109 @@
+use crate::{
+    bunner::Bunner, player_state::PlayerState, row::Row, HEIGHT, ROW_HEIGHT, TILE_WIDTH, WIDTH,
+};
+use macroquad::{
+    color::Color,
+    shapes::{draw_circle, draw_rectangle, draw_rectangle_lines},
+};
+use std::sync::atomic::{AtomicBool, Ordering};
+
+// Rust: Not in the original game. Debug overlay, toggled with F3, which displays how the rows handle the collisions:
+//
+// - the spans where landing is lethal (e.g. cars, water) or where movement is blocked (e.g. hedges), computed by
+//   probing the rows themselves, so that any row type is covered;
+// - the rectangles of the row children (cars, logs, trains...);
+// - the bunner position, and the cell where the current hop lands.
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+
+const LETHAL_COLOR: Color = Color::new(1., 0., 0., 0.35);
+const BLOCKED_COLOR: Color = Color::new(0.2, 0.2, 0.2, 0.5);
+const SAFE_COLOR: Color = Color::new(0., 1., 0., 0.15);
+const CHILD_COLOR: Color = Color::new(1., 1., 0., 0.9);
+const BUNNER_COLOR: Color = Color::new(0., 1., 1., 0.9);
+
+/// Horizontal distance between the probed positions.
+const PROBE_STEP: i32 = 2;
+
+pub fn toggle() {
+    ENABLED.fetch_xor(true, Ordering::Relaxed);
+}
+
+pub fn is_enabled() -> bool {
+    ENABLED.load(Ordering::Relaxed)
+}
+
+/// The rows outside the screen (given the offset) are skipped.
+pub fn draw(rows: &[Box<dyn Row>], bunner: Option<&Bunner>, offset_y: i32) {
+    for row in rows {
+        let y = row.y() + offset_y;
+        if y > 0 && y - ROW_HEIGHT < HEIGHT {
+            draw_row(row.as_ref(), y);
+        }
+    }
+
+    if let Some(bunner) = bunner.filter(|bunner| bunner.is_alive()) {
+        let landing_position = bunner.landing_position();
+        draw_rectangle_lines(
+            (landing_position.x - TILE_WIDTH / 2) as f32,
+            (landing_position.y + offset_y - ROW_HEIGHT) as f32,
+            TILE_WIDTH as f32,
+            ROW_HEIGHT as f32,
+            2.,
+            BUNNER_COLOR,
+        );
+        draw_circle(
+            bunner.position.x as f32,
+            (bunner.position.y + offset_y) as f32,
+            3.,
+            BUNNER_COLOR,
+        );
+    }
+}
+
+fn draw_row(row: &dyn Row, y: i32) {
+    // Contiguous probes with the same outcome are merged into a single span.
+    let mut span_start = 0;
+    let mut span_color = probe_color(row, 0);
+
+    for x in (PROBE_STEP..WIDTH).step_by(PROBE_STEP as usize) {
+        let color = probe_color(row, x);
+        if color != span_color {
+            draw_span(span_start, x, y, span_color);
+            span_start = x;
+            span_color = color;
+        }
+    }
+    draw_span(span_start, WIDTH, y, span_color);
+
+    for child in row.children() {
+        draw_rectangle_lines(
+            (child.x() - child.width() / 2) as f32,
+            (y - ROW_HEIGHT) as f32,
+            child.width() as f32,
+            ROW_HEIGHT as f32,
+            2.,
+            CHILD_COLOR,
+        );
+    }
+}
+
+fn probe_color(row: &dyn Row, x: i32) -> Color {
+    if row.check_collision(x) != PlayerState::Alive {
+        LETHAL_COLOR
+    } else if !row.allow_movement(x) {
+        BLOCKED_COLOR
+    } else {
+        SAFE_COLOR
+    }
+}
+
+fn draw_span(start_x: i32, end_x: i32, y: i32, color: Color) {
+    draw_rectangle(
+        start_x as f32,
+        (y - ROW_HEIGHT) as f32,
+        (end_x - start_x) as f32,
+        ROW_HEIGHT as f32,
+        color,
+    );
+}
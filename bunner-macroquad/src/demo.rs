@@ -0,0 +1,100 @@
+#[cfg(not(target_arch = "wasm32"))]
+use std::fs;
+
+use crate::player_direction::PlayerDirection;
+use macroquad::{file::load_string, input::KeyCode};
+use nanoserde::{DeRon, SerRon};
+use std::collections::VecDeque;
+
+// Rust: Not in the original game. As in the arcade games, after some time idle on the title screen, a recorded run is
+// played through the regular game loop (attract mode); any input returns to the title screen.
+//
+// A run is the seed, the difficulty curve, and the hops input on each update; since the games are deterministic,
+// given these, replaying the inputs reproduces the run. Runs are recorded by launching the game with
+// `--record-demo`.
+
+pub const DEMO_FILE: &str = "resources/demo.ron";
+/// Where the recorded runs are saved; to be used as demo, the file is moved to `DEMO_FILE`.
+#[cfg(not(target_arch = "wasm32"))]
+const RECORDED_DEMO_FILE: &str = "demo.ron";
+
+/// Updates idle on the title screen, before the demo starts.
+pub const IDLE_DURATION: u32 = 20 * 60;
+
+/// A hop input, with the update (from the start of the game) in which it's queued.
+#[derive(Clone, Copy, DeRon, SerRon)]
+pub struct DemoInput {
+    time: u32,
+    direction: PlayerDirection,
+}
+
+#[derive(Default, DeRon, SerRon)]
+pub struct DemoRun {
+    pub seed: u64,
+    /// Name of the difficulty curve.
+    pub difficulty: String,
+    /// Sorted by time.
+    inputs: Vec<DemoInput>,
+}
+
+impl DemoRun {
+    pub fn new(seed: u64, difficulty: String) -> Self {
+        Self {
+            seed,
+            difficulty,
+            inputs: Vec::new(),
+        }
+    }
+
+    /// None if the file is missing or invalid.
+    pub async fn load() -> Option<Self> {
+        match load_string(DEMO_FILE).await {
+            Ok(content) => match Self::deserialize_ron(&content) {
+                Ok(demo) => Some(demo),
+                Err(error) => {
+                    eprintln!("Invalid demo file: {:?}", error);
+                    None
+                }
+            },
+            Err(_) => None,
+        }
+    }
+
+    /// In the browser, runs can't be recorded.
+    pub fn save(&self) {
+        #[cfg(not(target_arch = "wasm32"))]
+        if let Err(error) = fs::write(RECORDED_DEMO_FILE, self.serialize_ron()) {
+            eprintln!("Couldn't save the demo: {}", error);
+        }
+    }
+
+    /// Records the hops in the input of the given update.
+    pub fn record(&mut self, time: u32, input_queue: &VecDeque<KeyCode>) {
+        for key_code in input_queue {
+            let direction = match key_code {
+                KeyCode::Up => PlayerDirection::Up,
+                KeyCode::Right => PlayerDirection::Right,
+                KeyCode::Down => PlayerDirection::Down,
+                KeyCode::Left => PlayerDirection::Left,
+                _ => continue,
+            };
+            self.inputs.push(DemoInput { time, direction });
+        }
+    }
+
+    /// Input of the given update, as arrow keys.
+    pub fn input_at(&self, time: u32) -> VecDeque<KeyCode> {
+        let start = self.inputs.partition_point(|input| input.time < time);
+
+        self.inputs[start..]
+            .iter()
+            .take_while(|input| input.time == time)
+            .map(|input| match input.direction {
+                PlayerDirection::Up => KeyCode::Up,
+                PlayerDirection::Right => KeyCode::Right,
+                PlayerDirection::Down => KeyCode::Down,
+                PlayerDirection::Left => KeyCode::Left,
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,161 @@
+use macroquad::{experimental::collections::storage, file::load_string};
+use nanoserde::{DeRon, SerRon};
+
+use crate::ROW_HEIGHT;
+
+// Rust: Not in the original game, where these values are hardcoded. The difficulty curves are loaded at startup,
+// and one of them is selected on the title screen; each curve defines the parameters at given distances, which are
+// interpolated in between (and kept constant after the last point).
+//
+// The selected curve is kept in the Macroquad storage (like the resources), since it's read by the rows while
+// generating the next ones.
+
+pub const DIFFICULTY_FILE: &str = "resources/difficulty.ron";
+
+/// Parameters at a given distance; the values of the original game are the defaults (the field attributes must
+/// match `Default`, since Nanoserde doesn't use it for the missing fields).
+#[derive(Clone, Copy, Debug, DeRon, SerRon)]
+pub struct DifficultyPoint {
+    /// In rows, from the start position of the player.
+    #[nserde(default)]
+    pub distance: i32,
+    /// Maximum (absolute) car speed, in pixels per frame.
+    #[nserde(default = 5)]
+    pub max_car_speed: i32,
+    /// Maximum (absolute) log speed, in pixels per frame.
+    #[nserde(default = 2)]
+    pub max_log_speed: i32,
+    /// Multiplies the frequency of the cars and logs.
+    #[nserde(default = 1.)]
+    pub traffic_density: f32,
+    /// Multiplies the probability that a road or river section ends, and is followed by a safe row.
+    #[nserde(default = 1.)]
+    pub safe_row_frequency: f32,
+}
+
+impl Default for DifficultyPoint {
+    fn default() -> Self {
+        Self {
+            distance: 0,
+            max_car_speed: 5,
+            max_log_speed: 2,
+            traffic_density: 1.,
+            safe_row_frequency: 1.,
+        }
+    }
+}
+
+impl DifficultyPoint {
+    fn interpolate(&self, other: &Self, distance: i32) -> Self {
+        let ratio = (distance - self.distance) as f32 / (other.distance - self.distance) as f32;
+        let lerp = |from: f32, to: f32| from + (to - from) * ratio;
+
+        Self {
+            distance,
+            max_car_speed: lerp(self.max_car_speed as f32, other.max_car_speed as f32).round()
+                as i32,
+            max_log_speed: lerp(self.max_log_speed as f32, other.max_log_speed as f32).round()
+                as i32,
+            traffic_density: lerp(self.traffic_density, other.traffic_density),
+            safe_row_frequency: lerp(self.safe_row_frequency, other.safe_row_frequency),
+        }
+    }
+}
+
+#[derive(Clone, DeRon, SerRon)]
+pub struct DifficultyCurve {
+    pub name: String,
+    /// Sorted by distance.
+    pub points: Vec<DifficultyPoint>,
+}
+
+impl Default for DifficultyCurve {
+    fn default() -> Self {
+        Self {
+            name: "Classic".to_string(),
+            points: vec![DifficultyPoint::default()],
+        }
+    }
+}
+
+impl DifficultyCurve {
+    /// Parameters at the given distance (in rows); speeds are at least 1.
+    pub fn point_at(&self, distance: i32) -> DifficultyPoint {
+        let next_i = self
+            .points
+            .iter()
+            .position(|point| point.distance > distance);
+
+        let point = match next_i {
+            Some(0) => self.points[0],
+            Some(next_i) => self.points[next_i - 1].interpolate(&self.points[next_i], distance),
+            None => self.points.last().copied().unwrap_or_default(),
+        };
+
+        DifficultyPoint {
+            distance,
+            max_car_speed: point.max_car_speed.max(1),
+            max_log_speed: point.max_log_speed.max(1),
+            ..point
+        }
+    }
+}
+
+#[derive(DeRon, SerRon)]
+pub struct DifficultyCurves {
+    pub curves: Vec<DifficultyCurve>,
+}
+
+impl Default for DifficultyCurves {
+    fn default() -> Self {
+        Self {
+            curves: vec![DifficultyCurve::default()],
+        }
+    }
+}
+
+impl DifficultyCurves {
+    /// If the file is missing or invalid, only the classic curve (with the original values) is available.
+    pub async fn load() -> Self {
+        match load_string(DIFFICULTY_FILE).await {
+            Ok(content) => match Self::deserialize_ron(&content) {
+                Ok(mut curves) if curves.is_valid() => {
+                    for curve in curves.curves.iter_mut() {
+                        curve.points.sort_by_key(|point| point.distance);
+                    }
+                    curves
+                }
+                Ok(_) => {
+                    eprintln!("Invalid difficulty file (empty curves); using the defaults");
+                    Self::default()
+                }
+                Err(error) => {
+                    eprintln!("Invalid difficulty file; using the defaults: {:?}", error);
+                    Self::default()
+                }
+            },
+            Err(_) => Self::default(),
+        }
+    }
+
+    fn is_valid(&self) -> bool {
+        !self.curves.is_empty() && self.curves.iter().all(|curve| !curve.points.is_empty())
+    }
+}
+
+/// Sets the curve used by the rows generated from now on.
+pub fn select(curve: DifficultyCurve) {
+    storage::store(curve);
+}
+
+pub fn selected_name() -> String {
+    storage::get::<DifficultyCurve>().name.clone()
+}
+
+/// Parameters for the row at the given y.
+pub fn point_at(y: i32) -> DifficultyPoint {
+    // The player starts at y -320.
+    let distance = (-320 - y) / ROW_HEIGHT;
+
+    storage::get::<DifficultyCurve>().point_at(distance)
+}
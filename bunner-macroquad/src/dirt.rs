@@ -1,15 +1,11 @@
 use crate::{
-    child::Child, position::Position, resources::Resources, road::Road, row::Row, water::Water,
-    ROW_HEIGHT,
-};
-use macroquad::{
-    audio::play_sound_once,
-    prelude::collections::storage,
-    rand::{self},
-    texture::Texture2D,
+    child::Child, position::Position, resources::Resources, row::Row, row_pool::RowPool,
+    row_rng::RowRng, sound::play_sound_once, terrain::Terrain, ROW_HEIGHT,
 };
+use bunner_core::row_sequence;
+use macroquad::{prelude::collections::storage, texture::Texture2D};
 
-#[derive(Clone)]
+#[derive(Clone, Default)]
 pub struct Dirt {
     index: i32,
     y: i32,
@@ -29,7 +25,11 @@ impl Row for Dirt {
         self.children.as_mut()
     }
 
-    fn update(&mut self, _scroll_pos: i32, _bunner_pos: Option<Position>) {}
+    fn update(&mut self, _scroll_pos: i32, _bunner_pos: Option<Position>, _rng: &mut RowRng) {}
+
+    fn terrain(&self) -> Terrain {
+        Terrain::Dirt
+    }
 
     fn image(&self) -> Texture2D {
         *storage::get::<Resources>()
@@ -42,30 +42,28 @@ impl Row for Dirt {
         play_sound_once(storage::get::<Resources>().dirt_sound);
     }
 
-    fn next(&self) -> Box<dyn Row> {
-        let y = self.y - ROW_HEIGHT;
-        if self.index <= 5 {
-            Box::new(Dirt::new(self.index + 8, y))
-        } else if self.index == 6 {
-            Box::new(Dirt::new(7, y))
-        } else if self.index == 7 {
-            Box::new(Dirt::new(15, y))
-        } else if self.index >= 8 && self.index <= 14 {
-            Box::new(Dirt::new(self.index + 1, y))
-        } else if rand::gen_range::<u8>(0, 2) == 1 {
-            Box::new(Road::empty(y))
-        } else {
-            Box::new(Water::empty(y))
-        }
+    fn next(&self, pool: &mut RowPool, rng: &mut RowRng) -> Box<dyn Row> {
+        pool.build(
+            row_sequence::after_dirt(self.index, rng),
+            0,
+            self.y - ROW_HEIGHT,
+            rng,
+        )
+    }
+
+    fn recycle(self: Box<Self>, pool: &mut RowPool) {
+        pool.dirts.push(self);
+    }
+
+    fn is_safe(&self) -> bool {
+        true
     }
 }
 
 impl Dirt {
-    pub fn new(index: i32, y: i32) -> Self {
-        Self {
-            index,
-            y,
-            children: Vec::new(),
-        }
+    pub fn new(pool: &mut RowPool, index: i32, y: i32) -> Box<Self> {
+        let (mut row, children) = RowPool::take(&mut pool.dirts);
+        *row = Self { index, y, children };
+        row
     }
 }
@@ -3,6 +3,7 @@ use macroquad::prelude::{collections::storage, draw_texture, WHITE};
 
 pub enum NumberAlign {
     Left,
+    Center,
     Right,
 }
 
@@ -11,7 +12,7 @@ pub enum NumberColor {
     Yellow,
 }
 
-pub fn display_number(number: u32, color: NumberColor, x: i32, align: NumberAlign) {
+pub fn display_number(number: u32, color: NumberColor, x: i32, y: i32, align: NumberAlign) {
     let resources = storage::get::<Resources>();
     let n = number.to_string();
     for (i, digit) in n.chars().enumerate() {
@@ -21,12 +22,13 @@ pub fn display_number(number: u32, color: NumberColor, x: i32, align: NumberAlig
         };
         let x_pos = match align {
             NumberAlign::Right => x + ((i as i32 - n.len() as i32) * 25),
+            NumberAlign::Center => x + ((i as i32 * 2 - n.len() as i32) * 25) / 2,
             NumberAlign::Left => x + (i as i32 * 25),
         };
         draw_texture(
             resources.digit_textures[digit_index],
             x_pos as f32,
-            0.,
+            y as f32,
             WHITE,
         );
     }
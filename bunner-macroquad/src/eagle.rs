@@ -1,13 +1,15 @@
-use crate::{actor::Actor, position::Position, resources::Resources};
+use crate::{actor::Actor, eagle_pressure, position::Position, resources::Resources};
 use macroquad::prelude::{collections::storage, draw_texture, WHITE};
 
 pub struct Eagle {
     position: Position,
+    /// Rust: Not in the original game, where it's hardcoded; see `eagle_pressure`.
+    dive_speed: i32,
 }
 
 impl Actor for Eagle {
     fn update(&mut self) {
-        self.position.y += 12;
+        self.position.y += self.dive_speed;
     }
 
     fn draw(&self, offset_x: i32, offset_y: i32) {
@@ -35,6 +37,9 @@ impl Actor for Eagle {
 
 impl Eagle {
     pub fn new(position: Position) -> Self {
-        Self { position }
+        Self {
+            position,
+            dive_speed: eagle_pressure::tuning().dive_speed,
+        }
     }
 }
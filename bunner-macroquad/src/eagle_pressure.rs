@@ -0,0 +1,125 @@
+use macroquad::{
+    color::{
+        colors::{RED, WHITE, YELLOW},
+        Color,
+    },
+    experimental::collections::storage,
+    file::load_string,
+    shapes::{draw_rectangle, draw_rectangle_lines},
+    text::{draw_text, measure_text},
+};
+use nanoserde::{DeRon, SerRon};
+
+// Rust: Not in the original game, where the eagle catches the bunner only once it's scrolled off the bottom of the
+// screen. The eagle also dives on a bunner that dawdles, i.e. doesn't reach a row further than the previous ones for
+// too long; the pressure builds up after some idle time, and is displayed as a bar filling up.
+//
+// The timings are loaded at startup, and kept in the Macroquad storage (like the resources), since they're read by
+// the bunner and the eagle.
+
+pub const TUNING_FILE: &str = "resources/eagle.ron";
+
+/// The field attributes must match `Default`, since Nanoserde doesn't use it for the missing fields.
+#[derive(Clone, Copy, Debug, DeRon, SerRon)]
+pub struct EagleTuning {
+    /// Updates idle before the pressure starts building up.
+    #[nserde(default = 180)]
+    pub pressure_start: u32,
+    /// Updates idle after which the eagle dives; the pressure is full at this point.
+    #[nserde(default = 600)]
+    pub dive_time: u32,
+    /// In pixels per frame.
+    #[nserde(default = 12)]
+    pub dive_speed: i32,
+}
+
+impl Default for EagleTuning {
+    fn default() -> Self {
+        Self {
+            pressure_start: 180,
+            dive_time: 600,
+            dive_speed: 12,
+        }
+    }
+}
+
+impl EagleTuning {
+    /// If the file is missing or invalid, the defaults are used.
+    pub async fn load() {
+        let tuning = match load_string(TUNING_FILE).await {
+            Ok(content) => match Self::deserialize_ron(&content) {
+                Ok(tuning) if tuning.is_valid() => tuning,
+                Ok(_) => {
+                    eprintln!(
+                        "Invalid eagle tuning file (inconsistent values); using the defaults"
+                    );
+                    Self::default()
+                }
+                Err(error) => {
+                    eprintln!("Invalid eagle tuning file; using the defaults: {:?}", error);
+                    Self::default()
+                }
+            },
+            Err(_) => Self::default(),
+        };
+
+        storage::store(tuning);
+    }
+
+    fn is_valid(&self) -> bool {
+        self.pressure_start < self.dive_time && self.dive_speed > 0
+    }
+
+    /// From 0 (no pressure) to 1 (the eagle dives), for the given updates idle.
+    pub fn pressure(&self, idle_time: u32) -> f32 {
+        let building_time = idle_time.saturating_sub(self.pressure_start);
+        (building_time as f32 / (self.dive_time - self.pressure_start) as f32).min(1.)
+    }
+}
+
+pub fn tuning() -> EagleTuning {
+    *storage::get::<EagleTuning>()
+}
+
+const HUD_FONT_SIZE: u16 = 20;
+const HUD_BAR_WIDTH: f32 = 100.;
+const HUD_BAR_HEIGHT: f32 = 8.;
+const HUD_BACKGROUND_COLOR: Color = Color::new(0., 0., 0., 0.5);
+
+/// Draws the pressure as a filling bar, turning from yellow to red, with the top right at the given coordinates;
+/// nothing is drawn while there's no pressure.
+pub fn draw_hud(pressure: f32, right_x: i32, y: i32) {
+    if pressure <= 0. {
+        return;
+    }
+
+    let x = right_x as f32 - HUD_BAR_WIDTH;
+    let y = y as f32;
+
+    let text = "EAGLE";
+    let text_width = measure_text(text, None, HUD_FONT_SIZE, 1.).width;
+    draw_text(
+        text,
+        right_x as f32 - text_width,
+        y + HUD_FONT_SIZE as f32,
+        HUD_FONT_SIZE as f32,
+        WHITE,
+    );
+
+    let bar_y = y + HUD_FONT_SIZE as f32 + 4.;
+    let color = Color::new(
+        YELLOW.r + (RED.r - YELLOW.r) * pressure,
+        YELLOW.g + (RED.g - YELLOW.g) * pressure,
+        YELLOW.b + (RED.b - YELLOW.b) * pressure,
+        1.,
+    );
+    draw_rectangle(
+        x,
+        bar_y,
+        HUD_BAR_WIDTH,
+        HUD_BAR_HEIGHT,
+        HUD_BACKGROUND_COLOR,
+    );
+    draw_rectangle(x, bar_y, HUD_BAR_WIDTH * pressure, HUD_BAR_HEIGHT, color);
+    draw_rectangle_lines(x, bar_y, HUD_BAR_WIDTH, HUD_BAR_HEIGHT, 2., WHITE);
+}
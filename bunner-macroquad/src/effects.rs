@@ -0,0 +1,154 @@
+use crate::reduced_motion;
+use macroquad::{
+    color::Color,
+    rand::gen_range,
+    shapes::{draw_circle, draw_circle_lines},
+};
+
+// Rust: Not in the original game. Impact feedback: the screen shakes when the bunner is hit by a car (or train) or
+// caught by the eagle, fur particles burst from the splat, and ripples spread where the bunner lands on the water
+// (or sinks). The effects are purely cosmetic, so they use the Macroquad generator (see `row_rng`).
+//
+// The particle positions are in game coordinates, like the bunner one. In reduced motion mode, there are no effects.
+
+const SPLAT_PARTICLES: usize = 24;
+const SPLAT_COLORS: [Color; 2] = [Color::new(1., 1., 1., 1.), Color::new(0.75, 0.6, 0.45, 1.)];
+const RIPPLE_COLOR: Color = Color::new(0.85, 0.95, 1., 1.);
+
+/// Per update; pulls the splat particles down, for a slightly arched trajectory.
+const GRAVITY: f32 = 0.25;
+
+#[derive(Clone, Copy)]
+enum ParticleKind {
+    /// Filled circle, with the given radius.
+    Fur(f32),
+    /// Circle outline, growing from the start radius.
+    Ripple(f32),
+}
+
+#[derive(Clone, Copy)]
+struct Particle {
+    kind: ParticleKind,
+    x: f32,
+    y: f32,
+    dx: f32,
+    dy: f32,
+    color: Color,
+    /// Remaining updates.
+    life: i32,
+    max_life: i32,
+}
+
+#[derive(Default)]
+pub struct Effects {
+    particles: Vec<Particle>,
+    shake_timer: i32,
+    shake_offset: (i32, i32),
+}
+
+impl Effects {
+    const SHAKE_DURATION: i32 = 20;
+    /// Maximum offset, in pixels, at the start of the shake.
+    const SHAKE_INTENSITY: f32 = 8.;
+
+    pub fn shake(&mut self) {
+        if reduced_motion::is_enabled() {
+            return;
+        }
+
+        self.shake_timer = Self::SHAKE_DURATION;
+    }
+
+    pub fn splat(&mut self, x: i32, y: i32) {
+        if reduced_motion::is_enabled() {
+            return;
+        }
+
+        for _ in 0..SPLAT_PARTICLES {
+            let angle = gen_range(0., std::f32::consts::TAU);
+            let speed = gen_range(1., 5.);
+            let life = gen_range(20, 40);
+
+            self.particles.push(Particle {
+                kind: ParticleKind::Fur(gen_range(1.5, 3.5)),
+                x: x as f32,
+                y: y as f32,
+                dx: angle.cos() * speed,
+                dy: angle.sin() * speed - 2.,
+                color: SPLAT_COLORS[gen_range(0, SPLAT_COLORS.len())],
+                life,
+                max_life: life,
+            });
+        }
+    }
+
+    /// The ripples follow the given speed, e.g. of the log the bunner landed on.
+    pub fn ripple(&mut self, x: i32, y: i32, dx: i32) {
+        if reduced_motion::is_enabled() {
+            return;
+        }
+
+        for (i, radius) in [4., 10.].into_iter().enumerate() {
+            let life = 30 + i as i32 * 10;
+
+            self.particles.push(Particle {
+                kind: ParticleKind::Ripple(radius),
+                x: x as f32,
+                y: y as f32,
+                dx: dx as f32,
+                dy: 0.,
+                color: RIPPLE_COLOR,
+                life,
+                max_life: life,
+            });
+        }
+    }
+
+    pub fn update(&mut self) {
+        for particle in self.particles.iter_mut() {
+            particle.x += particle.dx;
+            particle.y += particle.dy;
+            if let ParticleKind::Fur(_) = particle.kind {
+                particle.dy += GRAVITY;
+            }
+            particle.life -= 1;
+        }
+        self.particles.retain(|particle| particle.life > 0);
+
+        self.shake_offset = if self.shake_timer > 0 {
+            self.shake_timer -= 1;
+            let intensity =
+                Self::SHAKE_INTENSITY * self.shake_timer as f32 / Self::SHAKE_DURATION as f32;
+            (
+                gen_range(-intensity, intensity) as i32,
+                gen_range(-intensity, intensity) as i32,
+            )
+        } else {
+            (0, 0)
+        };
+    }
+
+    /// Offset to apply to the whole scene.
+    pub fn shake_offset(&self) -> (i32, i32) {
+        self.shake_offset
+    }
+
+    pub fn draw(&self, offset_x: i32, offset_y: i32) {
+        for particle in self.particles.iter() {
+            let progress = 1. - particle.life as f32 / particle.max_life as f32;
+            let color = Color {
+                a: 1. - progress,
+                ..particle.color
+            };
+            let x = particle.x + offset_x as f32;
+            let y = particle.y + offset_y as f32;
+
+            match particle.kind {
+                ParticleKind::Fur(radius) => draw_circle(x, y, radius, color),
+                ParticleKind::Ripple(radius) => {
+                    draw_circle_lines(x, y, radius + progress * 20., 2., color)
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,157 @@
+use crate::{
+    child::Child, palette, player_state::PlayerState, position::Position, rail::Rail,
+    reduced_motion, resources::Resources, row::Row, row_pool::RowPool, row_rng::RowRng,
+    sound::play_sound_once, terrain::Terrain, train::Train, HEIGHT, ROW_HEIGHT, WIDTH,
+};
+use macroquad::{
+    color::colors::RED, prelude::collections::storage, rand, shapes::draw_circle,
+    texture::Texture2D,
+};
+
+// Rust: Not in the original game. A track used by express trains, which replaces the track of a rail section.
+// Before a train arrives, the bell rings and the warning lights flash; while the train passes, the whole row is
+// lethal, since at its speed, it can't be dodged.
+//
+#[derive(Clone, Default)]
+pub struct ExpressRail {
+    y: i32,
+    /// Counts down the warning before the train arrives; None when there's no warning in progress.
+    warning_timer: Option<i32>,
+    children: Vec<Child>,
+}
+
+impl Row for ExpressRail {
+    fn y(&self) -> i32 {
+        self.y
+    }
+
+    fn children(&self) -> &[Child] {
+        &self.children
+    }
+
+    fn children_mut(&mut self) -> &mut Vec<Child> {
+        self.children.as_mut()
+    }
+
+    fn update(&mut self, scroll_pos: i32, _bunner_pos: Option<Position>, rng: &mut RowRng) {
+        self.update_children();
+        self.children
+            .retain(|c| c.x() > -1000 && c.x() < WIDTH + 1000);
+
+        match self.warning_timer {
+            Some(0) => {
+                self.warning_timer = None;
+
+                let dx = *rng
+                    .choose(&[-Self::TRAIN_SPEED, Self::TRAIN_SPEED])
+                    .unwrap();
+                let position = if dx < 0 {
+                    Position::new(WIDTH + 1000, 47)
+                } else {
+                    Position::new(-1000, 47)
+                };
+                self.children
+                    .push(Child::Train(Train::new(dx, position, rng)));
+
+                let train_sound = *storage::get::<Resources>()
+                    .train_sounds
+                    .get(rand::gen_range::<usize>(0, 2))
+                    .unwrap();
+                play_sound_once(train_sound);
+            }
+            Some(timer) => self.warning_timer = Some(timer - 1),
+            None => {
+                if self.y < scroll_pos + HEIGHT
+                    && self.children.is_empty()
+                    && rng.gen_range::<u8>(0, 100) < 1
+                {
+                    self.warning_timer = Some(Self::WARNING_DURATION);
+                    play_sound_once(storage::get::<Resources>().bell_sound);
+                }
+            }
+        }
+    }
+
+    fn terrain(&self) -> Terrain {
+        Terrain::Rail
+    }
+
+    fn image(&self) -> Texture2D {
+        storage::get::<Resources>().rail_textures[2]
+    }
+
+    fn draw(&self, offset_x: i32, offset_y: i32) {
+        let (x, y) = self.draw_row(offset_x, offset_y);
+
+        // The lights flash while the train approaches, and while it passes; in high contrast and reduced motion
+        // modes, they're steady.
+        let warning = self.warning_timer.is_some() || !self.children.is_empty();
+        let light_on = palette::is_high_contrast()
+            || reduced_motion::is_enabled()
+            || (self.warning_timer.unwrap_or(0) / Self::LIGHT_FLASH_INTERVAL) % 2 == 0;
+
+        if warning && light_on {
+            for light_x in [Self::LIGHT_MARGIN, WIDTH - Self::LIGHT_MARGIN] {
+                draw_circle(
+                    (x + light_x) as f32,
+                    (y - ROW_HEIGHT / 2) as f32,
+                    Self::LIGHT_RADIUS,
+                    RED,
+                );
+            }
+        }
+
+        self.draw_children(x, y);
+    }
+
+    fn play_sound(&self) {
+        play_sound_once(storage::get::<Resources>().grass_sound);
+    }
+
+    fn next(&self, pool: &mut RowPool, _rng: &mut RowRng) -> Box<dyn Row> {
+        Rail::new(pool, 3, self.y - ROW_HEIGHT)
+    }
+
+    fn recycle(self: Box<Self>, pool: &mut RowPool) {
+        pool.express_rails.push(self);
+    }
+
+    fn check_collision(&self, _x: i32) -> PlayerState {
+        if self.children.is_empty() {
+            PlayerState::Alive
+        } else {
+            PlayerState::Splat(8)
+        }
+    }
+}
+
+impl ExpressRail {
+    const TRAIN_SPEED: i32 = 32;
+    const WARNING_DURATION: i32 = 90;
+    const LIGHT_FLASH_INTERVAL: i32 = 10;
+    const LIGHT_MARGIN: i32 = 12;
+    const LIGHT_RADIUS: f32 = 8.;
+
+    /// Express tracks are not generated at the beginning of the game, so that the player has some time to warm up.
+    const MIN_DISTANCE_ROWS: i32 = 30;
+    /// Percentage of the rail sections that have an express track.
+    const PROBABILITY: u8 = 25;
+
+    pub fn new(pool: &mut RowPool, y: i32) -> Box<Self> {
+        let (mut row, children) = RowPool::take(&mut pool.express_rails);
+        *row = Self {
+            y,
+            warning_timer: None,
+            children,
+        };
+        row
+    }
+
+    /// Express tracks replace the track of a rail section (so that there is always a safe row before and after
+    /// them), only after the minimum distance.
+    pub fn generate(y: i32, rng: &mut RowRng) -> bool {
+        let distance_rows = (-320 - y) / ROW_HEIGHT;
+
+        distance_rows >= Self::MIN_DISTANCE_ROWS && rng.gen_range::<u8>(0, 100) < Self::PROBABILITY
+    }
+}
@@ -1,10 +1,13 @@
 use crate::{
-    actor::Actor, bunner::Bunner, eagle::Eagle, grass::Grass, player_state::PlayerState,
-    position::Position, resources::Resources, row::Row, row::RowSound, HEIGHT, ROW_HEIGHT,
+    actor::Actor, bunner::Bunner, camera::Camera, checkpoint::Checkpoint, child::Child,
+    debug_overlay, eagle::Eagle, grass::Grass, mover::Mover, night_mode::Light,
+    player_state::PlayerState, position::Position, power_up_kind::PowerUpKind,
+    resources::Resources, row::Row, row::RowSound, row_pool::RowPool, row_rng::RowRng, sound,
+    stats::RunStats, HEIGHT, ROW_HEIGHT,
 };
 use macroquad::{
     audio::{play_sound, set_sound_volume, stop_sound, PlaySoundParams},
-    prelude::{clear_background, collections::storage, KeyCode, BLACK},
+    prelude::{collections::storage, KeyCode},
     rand::gen_range,
 };
 use std::collections::{HashMap, HashSet, VecDeque};
@@ -13,65 +16,102 @@ use std::collections::{HashMap, HashSet, VecDeque};
 pub struct Game {
     pub bunner: Option<Bunner>,
     pub scroll_pos: i32,
+    camera: Camera,
     eagle: Option<Eagle>,
     rows: Vec<Box<dyn Row>>,
+    /// Rust: Not in the original game; see `RowPool`.
+    row_pool: RowPool,
     looped_sounds: HashSet<RowSound>,
+    /// Rust: Not in the original game. In the race mode, only one of the games plays the rows sounds, since the
+    /// looped sounds are shared.
+    pub play_looped_sounds: bool,
+    /// Rust: Not in the original game. Each game owns its row generator, so that the games of a race generate the
+    /// same rows; the seed is also stored in the checkpoint.
+    row_rng: RowRng,
+    checkpoint: Option<Checkpoint>,
+    /// Subtracted from the score, for each continue from a checkpoint.
+    score_penalty: u32,
+    /// Rust: Not in the original game; see `RunStats`.
+    stats: RunStats,
 }
 
 impl Game {
-    pub fn new(bunner: Option<Bunner>) -> Self {
+    pub fn new(bunner: Option<Bunner>, seed: u64) -> Self {
+        let mut row_pool = RowPool::default();
+        let mut row_rng = RowRng::new(seed);
+        let first_row = Grass::without_hedge(&mut row_pool, 0, 0, &mut row_rng);
+
         Self {
             bunner,
             scroll_pos: -HEIGHT,
+            camera: Camera::new(-HEIGHT),
             eagle: None,
-            rows: vec![Box::new(Grass::without_hedge(0, 0))],
+            rows: vec![first_row],
+            row_pool,
             looped_sounds: HashSet::new(),
+            play_looped_sounds: true,
+            row_rng,
+            checkpoint: None,
+            score_penalty: 0,
+            stats: RunStats::default(),
         }
     }
 
+    /// Rust: Not in the original game. Creates a game from the checkpoint; the bunner is placed at the same screen
+    /// position as at the start of a game.
+    pub fn from_checkpoint(checkpoint: &Checkpoint, score_penalty: u32) -> Self {
+        let mut game = Self::new(Some(Bunner::new(checkpoint.position)), checkpoint.seed);
+        game.scroll_pos = checkpoint.position.y + 320 - HEIGHT;
+        game.camera = Camera::new(game.scroll_pos);
+        game.checkpoint = Some(*checkpoint);
+        game.score_penalty = score_penalty;
+
+        // Generate the rows up to the checkpoint; the ones below the screen are discarded.
+        game.update_rows();
+
+        game
+    }
+
     pub fn update(&mut self, input_queue: VecDeque<KeyCode>) {
-        if let Some(bunner) = &self.bunner {
-            // Scroll faster if the player is close to the top of the screen. Limit scroll speed to
-            // between 1 and 3 pixels per frame.
-            self.scroll_pos -=
-                1.max(3.min(self.scroll_pos + HEIGHT - bunner.position.y) / (HEIGHT / 4));
-        } else {
-            self.scroll_pos -= 1;
-        }
+        self.camera
+            .update(self.bunner.as_ref().map(|bunner| bunner.position.y));
+        self.scroll_pos = self.camera.scroll_pos();
 
-        // Remove rows that have scrolled past the bottom of the screen.
-        let scroll_pos = self.scroll_pos;
-        self.rows
-            .retain(|row| row.y() < (scroll_pos + HEIGHT + ROW_HEIGHT * 2));
+        self.update_rows();
 
-        // Add rows
-        while let Some(last_row) = self.rows.last() {
-            if last_row.y() > self.scroll_pos + ROW_HEIGHT {
-                let new_row = last_row.next();
-                self.rows.push(new_row)
-            } else {
-                break;
-            }
-        }
+        // Rust: Not in the original game. With the slow time power-up, the rows are updated every other update.
+        let rows_updated = match self.bunner.as_ref().and_then(|bunner| bunner.power_up()) {
+            Some(power_up) if power_up.kind == PowerUpKind::SlowTime => power_up.timer % 2 == 0,
+            _ => true,
+        };
 
-        for row in self.rows.iter_mut() {
-            row.update(
-                self.scroll_pos,
-                self.bunner.as_ref().map(|bunner| bunner.position),
-            );
+        if rows_updated {
+            for row in self.rows.iter_mut() {
+                row.update(
+                    self.scroll_pos,
+                    self.bunner.as_ref().map(|bunner| bunner.position),
+                    &mut self.row_rng,
+                );
+            }
         }
         if let Some(bunner) = self.bunner.as_mut() {
-            bunner.update(self.scroll_pos, &mut self.rows, input_queue);
+            bunner.update(self.scroll_pos, &mut self.rows, input_queue, rows_updated);
+            self.stats.update(bunner, &self.rows);
             if let PlayerState::Eagle(x) = bunner.state {
                 self.eagle
                     .get_or_insert_with(|| Eagle::new(Position::new(x, self.scroll_pos)));
             };
         }
+        self.update_checkpoint();
         if let Some(eagle) = self.eagle.as_mut() {
             eagle.update();
         }
 
-        if let Some(bunner) = &self.bunner {
+        if let Some(bunner) = self
+            .bunner
+            .as_ref()
+            .filter(|_| self.play_looped_sounds && !sound::is_muted())
+        {
             let mut sounds: HashMap<RowSound, f32> = HashMap::new();
             for row in self.rows.iter() {
                 if let Some(sound_name) = row.sound() {
@@ -86,18 +126,145 @@ impl Game {
         }
     }
 
+    fn update_rows(&mut self) {
+        // Remove rows that have scrolled past the bottom of the screen.
+        // Rust: The rows are ordered from the bottom, so the removed ones are at the start; they're recycled.
+        let scroll_pos = self.scroll_pos;
+        let removed_count = self
+            .rows
+            .partition_point(|row| row.y() >= (scroll_pos + HEIGHT + ROW_HEIGHT * 2));
+        for row in self.rows.drain(..removed_count) {
+            row.recycle(&mut self.row_pool);
+        }
+
+        // Add rows
+        while let Some(last_row) = self.rows.last() {
+            if last_row.y() > self.scroll_pos + ROW_HEIGHT {
+                self.row_rng.reseed_row(last_row.y() - ROW_HEIGHT);
+                let new_row = last_row.next(&mut self.row_pool, &mut self.row_rng);
+                self.rows.push(new_row);
+
+                // Rust: When starting from a checkpoint, many rows are generated at once; the ones already below
+                // the screen are recycled immediately.
+                if self.rows[0].y() >= scroll_pos + HEIGHT + ROW_HEIGHT * 2 {
+                    self.rows.remove(0).recycle(&mut self.row_pool);
+                }
+            } else {
+                break;
+            }
+        }
+    }
+
+    /// Rust: Not in the original game. The checkpoint is set once the bunner stands on a safe row, past the next
+    /// checkpoint distance.
+    fn update_checkpoint(&mut self) {
+        let bunner = match &self.bunner {
+            Some(bunner) if bunner.is_alive() && bunner.timer == 0 => bunner,
+            _ => return,
+        };
+
+        let distance = Self::distance(bunner.position.y);
+        let last_checkpoint_distance = self.checkpoint.map_or(0, |checkpoint| checkpoint.score());
+
+        if Checkpoint::is_due(distance, last_checkpoint_distance)
+            && self
+                .rows
+                .iter()
+                .any(|row| row.y() == bunner.position.y && row.is_safe())
+        {
+            self.checkpoint = Some(Checkpoint {
+                seed: self.row_rng.seed(),
+                position: bunner.position,
+            });
+        }
+    }
+
+    pub fn checkpoint(&self) -> Option<&Checkpoint> {
+        self.checkpoint.as_ref()
+    }
+
+    pub fn stats(&self) -> &RunStats {
+        &self.stats
+    }
+
+    pub fn seed(&self) -> u64 {
+        self.row_rng.seed()
+    }
+
+    pub fn score_penalty(&self) -> u32 {
+        self.score_penalty
+    }
+
+    pub fn is_continued(&self) -> bool {
+        self.score_penalty > 0
+    }
+
+    /// Rows advanced from the start position, for the given y.
+    pub fn distance(y: i32) -> u32 {
+        0.max((-320 - y) / ROW_HEIGHT) as u32
+    }
+
+    /// Rust: The background is cleared by the caller, since in the race mode, the games are drawn side by side.
     pub fn draw(&self) {
-        clear_background(BLACK);
+        // Rust: The screen shakes on impacts; see `Effects`.
+        let (shake_x, shake_y) = self
+            .bunner
+            .as_ref()
+            .map_or((0, 0), |bunner| bunner.shake_offset());
+        let offset_y = shake_y - self.scroll_pos;
 
         for row in self.rows.iter().rev() {
-            row.draw(0, -self.scroll_pos);
+            row.draw(shake_x, offset_y);
+        }
+        if self.bunner.is_some() {
+            Checkpoint::draw_markers(offset_y);
         }
         if let Some(bunner) = &self.bunner {
-            bunner.draw(0, -self.scroll_pos);
+            bunner.draw(shake_x, offset_y);
         }
         if let Some(eagle) = &self.eagle {
-            eagle.draw(0, -self.scroll_pos);
+            eagle.draw(shake_x, offset_y);
         }
+        if debug_overlay::is_enabled() {
+            debug_overlay::draw(&self.rows, self.bunner.as_ref(), -self.scroll_pos);
+        }
+    }
+
+    /// Rust: Not in the original game. Lights of the night mode (around the bunner, and in front of the cars), with
+    /// the given offset.
+    pub fn lights(&self, offset_x: i32, offset_y: i32) -> Vec<Light> {
+        const BUNNER_LIGHT_SIZE: f32 = 260.;
+        const HEADLIGHT_WIDTH: f32 = 140.;
+        const HEADLIGHT_HEIGHT: f32 = 50.;
+        /// From the center of the car.
+        const HEADLIGHT_DISTANCE: f32 = 100.;
+
+        let mut lights = Vec::new();
+
+        if let Some(bunner) = &self.bunner {
+            lights.push(Light {
+                x: (bunner.position.x + offset_x) as f32,
+                y: (bunner.position.y + offset_y - ROW_HEIGHT / 2) as f32,
+                width: BUNNER_LIGHT_SIZE,
+                height: BUNNER_LIGHT_SIZE,
+            });
+        }
+
+        for row in self.rows.iter() {
+            for child in row.children() {
+                if let Child::Car(car) = child {
+                    lights.push(Light {
+                        x: (car.x() + offset_x) as f32
+                            + car.dx().signum() as f32 * HEADLIGHT_DISTANCE,
+                        y: (row.y() + offset_y - ROW_HEIGHT / 2) as f32,
+                        width: HEADLIGHT_WIDTH,
+                        height: HEADLIGHT_HEIGHT,
+                    });
+                }
+            }
+        }
+
+        lights
     }
 
     pub fn game_over(&self) -> bool {
@@ -108,14 +275,31 @@ impl Game {
         }
     }
 
+    /// Rust: After continuing from a checkpoint, the penalty is subtracted.
     pub fn score(&self) -> u32 {
         if let Some(bunner) = &self.bunner {
-            0.max((-320 - bunner.min_y as i32) / 40) as u32
+            Self::distance(bunner.min_y).saturating_sub(self.score_penalty)
         } else {
             0
         }
     }
 
+    /// Rust: Not in the original game. Stops the looped sounds (all their variants), e.g. when the game is paused;
+    /// they're restarted by the next update.
+    pub fn stop_looped_sounds(&mut self) {
+        let resources = storage::get::<Resources>();
+
+        for row_sound in self.looped_sounds.drain() {
+            let sounds = match row_sound {
+                RowSound::River => &resources.river_sounds,
+                RowSound::Traffic => &resources.traffic_sounds,
+            };
+            for sound in sounds {
+                stop_sound(*sound);
+            }
+        }
+    }
+
     fn loop_sound(&mut self, row_sound: RowSound, volume: f32) {
         let sound = match row_sound {
             RowSound::River => *storage::get::<Resources>()
@@ -0,0 +1,97 @@
+use gilrs::{Axis, Button, Event, EventType, Gilrs};
+use macroquad::input::KeyCode;
+
+/// Beyond this deflection, the stick is considered pushed.
+const STICK_THRESHOLD: f32 = 0.5;
+
+/// Gamepad input, translated to the equivalent keys, so that it's merged with the keyboard input.
+///
+/// Macroquad doesn't support gamepads, so they're handled via Gilrs. As with the keyboard, where only the key
+/// presses are considered (not the repeats), each d-pad press, or stick push, causes a single hop; holding them
+/// doesn't cause further hops.
+pub struct Gamepads {
+    /// None if the gamepad support couldn't be initialized (e.g. no permissions on the devices).
+    gilrs: Option<Gilrs>,
+    /// Direction in which the stick is currently pushed, if any.
+    stick_direction: Option<KeyCode>,
+}
+
+impl Gamepads {
+    pub fn new() -> Self {
+        let gilrs = Gilrs::new()
+            .map_err(|error| eprintln!("Couldn't initialize the gamepads: {}", error))
+            .ok();
+
+        Self {
+            gilrs,
+            stick_direction: None,
+        }
+    }
+
+    /// Processes the gamepad events, and returns the keys pressed since the previous invocation.
+    pub fn pressed_keys(&mut self) -> Vec<KeyCode> {
+        let mut pressed_keys = vec![];
+
+        let gilrs = match &mut self.gilrs {
+            Some(gilrs) => gilrs,
+            None => return pressed_keys,
+        };
+
+        while let Some(Event { id, event, .. }) = gilrs.next_event() {
+            match event {
+                EventType::ButtonPressed(button, _) => {
+                    pressed_keys.extend_from_slice(button_keys(button));
+                }
+                EventType::AxisChanged(Axis::LeftStickX | Axis::LeftStickY, _, _) => {
+                    let gamepad = gilrs.gamepad(id);
+                    let stick_direction = stick_direction(
+                        gamepad.value(Axis::LeftStickX),
+                        gamepad.value(Axis::LeftStickY),
+                    );
+
+                    if stick_direction != self.stick_direction {
+                        pressed_keys.extend(stick_direction);
+                        self.stick_direction = stick_direction;
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        pressed_keys
+    }
+}
+
+impl Default for Gamepads {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// The A button hops forward, and also starts the game (like the space key).
+fn button_keys(button: Button) -> &'static [KeyCode] {
+    match button {
+        Button::DPadUp => &[KeyCode::Up],
+        Button::DPadRight => &[KeyCode::Right],
+        Button::DPadDown => &[KeyCode::Down],
+        Button::DPadLeft => &[KeyCode::Left],
+        Button::South => &[KeyCode::Up, KeyCode::Space],
+        Button::Start => &[KeyCode::Space],
+        _ => &[],
+    }
+}
+
+/// The dominant axis determines the direction; Gilrs' Y axis points upwards.
+fn stick_direction(x: f32, y: f32) -> Option<KeyCode> {
+    if x.abs().max(y.abs()) < STICK_THRESHOLD {
+        None
+    } else if x.abs() > y.abs() {
+        Some(if x > 0. {
+            KeyCode::Right
+        } else {
+            KeyCode::Left
+        })
+    } else {
+        Some(if y > 0. { KeyCode::Up } else { KeyCode::Down })
+    }
+}
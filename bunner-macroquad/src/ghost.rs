@@ -0,0 +1,201 @@
+#[cfg(not(target_arch = "wasm32"))]
+use std::fs;
+
+use macroquad::prelude::{collections::storage, draw_texture, Color};
+use nanoserde::{DeRon, SerRon};
+
+use crate::{bunner::Bunner, player_direction::PlayerDirection, resources::Resources};
+
+#[cfg(not(target_arch = "wasm32"))]
+const GHOSTS_DIR: &str = "ghosts";
+
+const GHOST_COLOUR: Color = Color::new(1., 1., 1., 0.4);
+
+/// Updates taken by a hop; see `Bunner::MOVE_DISTANCE`.
+const HOP_DURATION: u32 = 10;
+/// Distance covered by a hop, in each axis.
+const HOP_DISTANCE: i32 = 40;
+
+/// A hop, with the update (from the start of the game) and the position at which it started.
+#[derive(Clone, Copy, DeRon, SerRon)]
+pub struct GhostHop {
+    time: u32,
+    x: i32,
+    y: i32,
+    direction: PlayerDirection,
+    /// Rust: Not in the original game; see `PowerUpKind::DoubleHop`.
+    #[nserde(default)]
+    double: bool,
+}
+
+impl GhostHop {
+    fn length(&self) -> u32 {
+        if self.double {
+            2
+        } else {
+            1
+        }
+    }
+
+    fn duration(&self) -> u32 {
+        HOP_DURATION * self.length()
+    }
+
+    fn landing_position(&self) -> (i32, i32) {
+        let (dx, dy) = direction_offset(self.direction);
+        let distance = HOP_DISTANCE * self.length() as i32;
+        (self.x + dx * distance, self.y + dy * distance)
+    }
+}
+
+// Rust: Not in the original game. When the games are generated from a seed, the best run (the one reaching the
+// furthest row) is recorded, as the sequence of the hops, and played back as a translucent bunner on the following
+// games with the same seed.
+//
+// Between the hops, the bunner may be carried by a log; since the logs move at constant speed, the ghost is moved
+// linearly to the start of the next hop.
+//
+#[derive(Default, DeRon, SerRon)]
+pub struct GhostRun {
+    score: u32,
+    hops: Vec<GhostHop>,
+    /// Updates from the start of the game, up to the death of the bunner.
+    time: u32,
+    /// Last position of the bunner while alive.
+    end_x: i32,
+    end_y: i32,
+}
+
+impl GhostRun {
+    #[cfg(not(target_arch = "wasm32"))]
+    fn path(seed: u64) -> String {
+        format!("{}/seed_{}.ron", GHOSTS_DIR, seed)
+    }
+
+    /// None if there's no ghost for the seed, or if the file is invalid.
+    ///
+    /// In the browser, the ghosts are not persisted, so they're available only until the page is closed.
+    pub fn load(seed: u64) -> Option<Self> {
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            fs::read_to_string(Self::path(seed))
+                .ok()
+                .and_then(|content| Self::deserialize_ron(&content).ok())
+        }
+
+        #[cfg(target_arch = "wasm32")]
+        {
+            let _ = seed;
+            None
+        }
+    }
+
+    pub fn save(&self, seed: u64) {
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            let result = fs::create_dir_all(GHOSTS_DIR)
+                .and_then(|_| fs::write(Self::path(seed), self.serialize_ron()));
+
+            if let Err(error) = result {
+                eprintln!("Couldn't save the ghost: {}", error);
+            }
+        }
+
+        #[cfg(target_arch = "wasm32")]
+        let _ = seed;
+    }
+
+    pub fn score(&self) -> u32 {
+        self.score
+    }
+
+    /// Invoked on each update of the game.
+    pub fn record(&mut self, bunner: &Bunner, score: u32) {
+        if let Some((direction, position, double)) = bunner.hop_start() {
+            self.hops.push(GhostHop {
+                time: self.time,
+                x: position.x,
+                y: position.y,
+                direction,
+                double,
+            });
+        }
+
+        if bunner.is_alive() {
+            self.time += 1;
+            self.end_x = bunner.position.x;
+            self.end_y = bunner.position.y;
+        }
+
+        self.score = score;
+    }
+
+    /// Draws the ghost after the given number of updates of the game; once the run is over, nothing is drawn.
+    pub fn draw(&self, time: u32, offset_y: i32) {
+        if time > self.time {
+            return;
+        }
+
+        let resources = storage::get::<Resources>();
+
+        // Hops started in the updates performed so far.
+        let current_hop_i = self.hops.partition_point(|hop| hop.time < time);
+
+        let (x, y, texture) = match current_hop_i.checked_sub(1).map(|i| (i, self.hops[i])) {
+            // Before the first hop, the bunner sits at the start position.
+            None => {
+                let (x, y) = self
+                    .hops
+                    .first()
+                    .map_or((self.end_x, self.end_y), |hop| (hop.x, hop.y));
+                (
+                    x,
+                    y,
+                    resources.sit_textures[PlayerDirection::default() as usize],
+                )
+            }
+            // Hopping; as in `Bunner`, the movement is applied also on the update the hop starts.
+            Some((_, hop)) if time - hop.time < hop.duration() => {
+                let (dx, dy) = direction_offset(hop.direction);
+                let progress = ((time - hop.time) * (HOP_DISTANCE as u32 / HOP_DURATION)) as i32;
+                let texture = resources.jump_textures[hop.direction as usize];
+                (hop.x + dx * progress, hop.y + dy * progress, texture)
+            }
+            // Landed; moved toward the start of the next hop (or the end position).
+            Some((hop_i, hop)) => {
+                let (land_x, land_y) = hop.landing_position();
+                let (next_time, next_x) = self
+                    .hops
+                    .get(hop_i + 1)
+                    .map_or((self.time, self.end_x), |next_hop| {
+                        (next_hop.time, next_hop.x)
+                    });
+
+                let land_time = hop.time + hop.duration();
+                let x = if next_time > land_time {
+                    let ratio = (time - land_time) as f32 / (next_time - land_time) as f32;
+                    land_x + ((next_x - land_x) as f32 * ratio) as i32
+                } else {
+                    land_x
+                };
+                (x, land_y, resources.sit_textures[hop.direction as usize])
+            }
+        };
+
+        draw_texture(
+            texture,
+            x as f32 - texture.width() / 2.,
+            (y + offset_y) as f32 - texture.height(),
+            GHOST_COLOUR,
+        );
+    }
+}
+
+fn direction_offset(direction: PlayerDirection) -> (i32, i32) {
+    match direction {
+        PlayerDirection::Up => (0, -1),
+        PlayerDirection::Right => (1, 0),
+        PlayerDirection::Down => (0, 1),
+        PlayerDirection::Left => (-1, 0),
+    }
+}
@@ -1,31 +1,106 @@
+#[cfg(feature = "online_leaderboard")]
+use crate::online_leaderboard::{LeaderboardEntry, OnlineLeaderboard};
 use crate::{
     bunner::Bunner,
+    checkpoint::Checkpoint,
+    daily::{self, DailyCalendar, DailyChallenge},
+    debug_overlay,
+    demo::{self, DemoRun},
+    difficulty::{self, DifficultyCurve},
     drawing::{display_number, NumberAlign, NumberColor},
+    eagle_pressure,
     game::Game,
+    ghost::GhostRun,
+    hop_keys::HopKeys,
+    night_mode::NightMode,
+    palette,
+    pause_menu::{PauseMenu, PauseOption},
     position::Position,
+    power_up::ActivePowerUp,
+    race::Race,
+    records::Records,
+    reduced_motion,
     resources::Resources,
+    settings::Settings,
+    sound,
     state::State,
+    stats::{self, LifetimeStats},
+    touch_controls::TouchControls,
+    virtual_screen::{self, VirtualScreen},
     HEIGHT, WIDTH,
 };
 use macroquad::{
     audio::{play_sound, set_sound_volume, PlaySoundParams, Sound},
-    color::colors::WHITE,
+    color::colors::{BLACK, WHITE},
     experimental::collections::storage,
     input::KeyCode,
+    math::{vec2, Rect},
     miniquad::{self},
     rand::{self},
+    text::{draw_text, measure_text},
     texture::draw_texture,
+    window::clear_background,
 };
-use std::collections::VecDeque;
-#[cfg(not(target_arch = "wasm32"))]
-use std::fs;
+use std::{collections::VecDeque, mem};
+
+/// Vertical spacing of the top scores.
+const RECORDS_SPACING: i32 = 40;
+const DIFFICULTY_FONT_SIZE: u16 = 40;
+const OPTIONS_FONT_SIZE: u16 = 24;
+const CHECKPOINT_FONT_SIZE: u16 = 30;
+const DAILY_FONT_SIZE: u16 = 30;
+const DEMO_FONT_SIZE: u16 = 36;
+#[cfg(feature = "online_leaderboard")]
+const LEADERBOARD_FONT_SIZE: u16 = 30;
+/// Below the top scores.
+const DAILY_CALENDAR_Y: i32 = 220;
 
 pub struct GlobalState {
     state: State,
     game: Game,
-    high_score: u32,
+    /// Rust: Not in the original game; set while in the race mode (in which case, `game` is not used).
+    race: Option<Race>,
+    records: Records,
+    /// Rust: Not in the original game; see `RunStats`.
+    lifetime_stats: LifetimeStats,
+    /// Position of the last game score in the top scores, if it entered them.
+    last_score_position: Option<usize>,
     music: Sound,
+    /// Volume set by the current state; while muted, the music is silenced.
+    music_volume: f32,
+    /// If set, each game is generated from it; otherwise, each game is random.
+    seed: Option<u64>,
     input_queue: VecDeque<KeyCode>,
+    /// Rust: Not in the original game. Updates from the start of the current game; used by the ghosts and the demo.
+    game_timer: u32,
+    touch_controls: TouchControls,
+    /// Rust: Not in the original game. The curve is selected on the title screen; see `difficulty`.
+    difficulty_curves: Vec<DifficultyCurve>,
+    difficulty_index: usize,
+    /// Rust: Not in the original game. Best run with the current seed, if any; see `GhostRun`.
+    ghost: Option<GhostRun>,
+    /// Run of the current game, saved as ghost if it beats the best one.
+    ghost_recording: GhostRun,
+    /// Rust: Not in the original game. State to return to, when resuming from the pause.
+    paused_state: State,
+    pause_menu: PauseMenu,
+    quit_requested: bool,
+    settings: Settings,
+    night_mode: NightMode,
+    /// Rust: Not in the original game. Set while playing the daily challenge; see `DailyChallenge`.
+    daily: Option<DailyChallenge>,
+    daily_calendar: DailyCalendar,
+    /// Rust: Not in the original game; configured in the settings.
+    hop_keys: HopKeys,
+    /// Rust: Not in the original game. Played after some time idle on the title screen; see `DemoRun`.
+    demo: Option<DemoRun>,
+    /// Set when recording the demo runs; the current game inputs.
+    demo_recording: Option<DemoRun>,
+    /// Updates without input on the title screen.
+    idle_timer: u32,
+    /// Rust: Not in the original game. None if there's no leaderboard URL in the settings.
+    #[cfg(feature = "online_leaderboard")]
+    online_leaderboard: Option<OnlineLeaderboard>,
 }
 
 impl miniquad::EventHandler for GlobalState {
@@ -41,25 +116,82 @@ impl miniquad::EventHandler for GlobalState {
         repeat: bool,
     ) {
         if !repeat {
-            self.input_queue.push_back(keycode);
+            // Rust: Only the keyboard keys are rebindable.
+            self.queue_input(self.hop_keys.translate(keycode));
+        }
+    }
+
+    fn touch_event(
+        &mut self,
+        _ctx: &mut miniquad::Context,
+        phase: miniquad::TouchPhase,
+        id: u64,
+        x: f32,
+        y: f32,
+    ) {
+        // Rust: The touches are in window coordinates, while the controls are on the (scaled) canvas.
+        let position = virtual_screen::to_virtual(vec2(x, y));
+        for keycode in self
+            .touch_controls
+            .touch_event(phase, id, position.x, position.y)
+        {
+            self.queue_input(keycode);
         }
     }
 }
 
 impl GlobalState {
-    pub fn new(music: Sound) -> Self {
+    pub fn new(
+        music: Sound,
+        seed: Option<u64>,
+        difficulty_curves: Vec<DifficultyCurve>,
+        night_mode: NightMode,
+        demo: Option<DemoRun>,
+    ) -> Self {
+        // The classic curve is the default one, if present.
+        let difficulty_index = difficulty_curves
+            .iter()
+            .position(|curve| curve.name == DifficultyCurve::default().name)
+            .unwrap_or(0);
+        difficulty::select(difficulty_curves[difficulty_index].clone());
+
         Self {
             // Set the initial game state
             state: State::Menu,
-            game: Game::new(None),
-            high_score: 0,
+            game: Game::new(None, 0),
+            race: None,
+            records: Records::default(),
+            lifetime_stats: LifetimeStats::default(),
+            last_score_position: None,
             music,
+            music_volume: 1.,
+            seed,
             input_queue: VecDeque::new(),
+            game_timer: 0,
+            touch_controls: TouchControls::default(),
+            difficulty_curves,
+            difficulty_index,
+            ghost: None,
+            ghost_recording: GhostRun::default(),
+            paused_state: State::Play,
+            pause_menu: PauseMenu::default(),
+            quit_requested: false,
+            settings: Settings::default(),
+            night_mode,
+            daily: None,
+            daily_calendar: DailyCalendar::default(),
+            hop_keys: HopKeys::default(),
+            demo,
+            demo_recording: None,
+            idle_timer: 0,
+            #[cfg(feature = "online_leaderboard")]
+            online_leaderboard: None,
         }
     }
 
     pub fn init(&mut self) {
         rand::srand(macroquad::miniquad::date::now() as u64);
+        self.game = Game::new(None, rand::rand() as u64);
         play_sound(
             self.music,
             PlaySoundParams {
@@ -67,62 +199,373 @@ impl GlobalState {
                 volume: 1.,
             },
         );
-        #[cfg(not(target_arch = "wasm32"))]
+        self.records = Records::load();
+        self.lifetime_stats = LifetimeStats::load();
+        self.settings = Settings::load();
+        self.hop_keys = HopKeys::new(&self.settings);
+        palette::set_high_contrast(self.settings.high_contrast);
+        reduced_motion::set_enabled(self.settings.reduced_motion);
+        sound::set_muted(self.settings.muted);
+        self.set_music_volume(1.);
+
+        #[cfg(feature = "online_leaderboard")]
         {
-            self.high_score = fs::read_to_string("high.txt")
-                .map_or(Ok(0), |i| i.parse::<u32>())
-                .unwrap_or(0);
+            self.online_leaderboard = (!self.settings.leaderboard_url.is_empty())
+                .then(|| OnlineLeaderboard::new(&self.settings.leaderboard_url));
         }
+        self.daily_calendar = DailyCalendar::load();
+        self.ghost = self.seed.and_then(GhostRun::load);
+    }
+
+    /// Rust: Not in the original game. The inputs of each game (but the continued ones) are recorded, and saved on
+    /// game over; see `DemoRun`.
+    pub fn record_demos(&mut self) {
+        self.demo_recording = Some(DemoRun::default());
+    }
+
+    /// Input from the other devices (e.g. gamepads) is translated to keys, and queued as keyboard input.
+    pub fn queue_input(&mut self, keycode: KeyCode) {
+        self.input_queue.push_back(keycode);
+    }
+
+    /// Rust: Set when the player chooses to quit; in the browser, the page can't be closed, so the title screen is
+    /// displayed instead.
+    pub fn quit_requested(&self) -> bool {
+        self.quit_requested
     }
 
     pub fn update(&mut self) {
+        #[cfg(feature = "online_leaderboard")]
+        if let Some(online_leaderboard) = &mut self.online_leaderboard {
+            online_leaderboard.update();
+        }
+
+        // Removed from the queue, since some states don't drain it.
+        if self.input_queue.contains(&KeyCode::F3) {
+            debug_overlay::toggle();
+            self.input_queue.retain(|key_code| *key_code != KeyCode::F3);
+        }
+
+        let repeated_hop = self.hop_keys.update();
+        if let Some(key_code) =
+            repeated_hop.filter(|_| matches!(self.state, State::Play | State::Race))
+        {
+            self.input_queue.push_back(key_code);
+        }
+
+        if matches!(self.state, State::Play | State::Race)
+            && PauseMenu::PAUSE_KEYS
+                .iter()
+                .any(|key_code| self.input_queue.contains(key_code))
+        {
+            self.pause();
+        }
+
         match self.state {
             State::Menu => {
                 if self.input_queue.contains(&KeyCode::Space) {
-                    // Switch to play state, and create a new Game object, passing it a new Player object to use
-                    self.state = State::Play;
-                    self.game = Game::new(Some(Bunner::new(Position::new(240, -320))));
+                    self.start_game();
+                } else if self.input_queue.contains(&KeyCode::Key2) {
+                    // Rust: Not in the original game. Two players race on the same rows.
+                    self.start_race();
+                } else if self.input_queue.contains(&KeyCode::D) {
+                    // Rust: Not in the original game.
+                    self.start_daily();
+                } else if self.input_queue.contains(&KeyCode::Escape) {
+                    self.quit();
+                } else if self.input_queue.contains(&KeyCode::N) {
+                    // Rust: Not in the original game.
+                    self.settings.night_mode = !self.settings.night_mode;
+                    self.settings.save();
+                    self.input_queue.clear();
+                } else if self.input_queue.contains(&KeyCode::H) {
+                    // Rust: Not in the original game.
+                    self.settings.high_contrast = !self.settings.high_contrast;
+                    palette::set_high_contrast(self.settings.high_contrast);
+                    self.settings.save();
+                    self.input_queue.clear();
+                } else if self.input_queue.contains(&KeyCode::M) {
+                    // Rust: Not in the original game.
+                    self.settings.reduced_motion = !self.settings.reduced_motion;
+                    reduced_motion::set_enabled(self.settings.reduced_motion);
+                    self.settings.save();
                     self.input_queue.clear();
-                    set_sound_volume(self.music, 0.3);
                 } else {
+                    // Rust: Not in the original game. Left/right select the difficulty curve.
+                    if self.input_queue.contains(&KeyCode::Left) {
+                        self.select_difficulty(self.difficulty_curves.len() - 1);
+                    } else if self.input_queue.contains(&KeyCode::Right) {
+                        self.select_difficulty(1);
+                    }
+
+                    // Rust: Not in the original game.
+                    if self.input_queue.is_empty() {
+                        self.idle_timer += 1;
+                    } else {
+                        self.idle_timer = 0;
+                    }
+
                     self.game.update(self.input_queue.drain(..).collect());
+
+                    if self.idle_timer >= demo::IDLE_DURATION {
+                        self.start_demo();
+                    }
                 }
             }
             State::Play => {
                 if self.game.game_over() {
-                    self.high_score = self.high_score.max(self.game.score());
-                    #[cfg(not(target_arch = "wasm32"))]
-                    {
-                        fs::write("high.txt", self.high_score.to_string()).ok();
+                    self.last_score_position = self.records.add_score(self.game.score());
+                    self.records.save();
+                    self.lifetime_stats.add(self.game.stats());
+                    self.lifetime_stats.save();
+                    self.save_ghost();
+                    self.save_daily_result();
+                    self.submit_online_score();
+                    if let Some(demo_recording) = &self.demo_recording {
+                        demo_recording.save();
                     }
 
                     self.state = State::GameOver;
                 } else {
-                    self.game.update(self.input_queue.drain(..).collect());
+                    let input_queue: VecDeque<KeyCode> = self.input_queue.drain(..).collect();
+                    if let Some(demo_recording) = self
+                        .demo_recording
+                        .as_mut()
+                        .filter(|_| !self.game.is_continued())
+                    {
+                        demo_recording.record(self.game_timer, &input_queue);
+                    }
+
+                    self.game.update(input_queue);
+                    self.record_ghost();
+                    self.game_timer += 1;
                 }
             }
             State::GameOver => {
                 if self.input_queue.contains(&KeyCode::Space) {
-                    // Switch to menu state, and create a new game object
-                    self.state = State::Menu;
-                    self.game = Game::new(None);
-                    self.input_queue.clear();
-                    set_sound_volume(self.music, 1.0);
+                    self.return_to_menu();
+                } else if self.input_queue.contains(&KeyCode::C) && self.daily.is_none() {
+                    // Rust: Not in the original game. The daily challenge can't be continued.
+                    if let Some(checkpoint) = self.game.checkpoint().copied() {
+                        self.continue_from_checkpoint(&checkpoint);
+                    }
                 }
             }
+            State::Race => {
+                if let Some(race) = &mut self.race {
+                    if race.is_over() {
+                        self.state = State::RaceOver;
+                    } else {
+                        race.update(self.input_queue.drain(..).collect());
+                    }
+                }
+            }
+            State::RaceOver => {
+                if self.input_queue.contains(&KeyCode::Space) {
+                    self.return_to_menu();
+                }
+            }
+            State::Demo => {
+                // Any input ends the demo, and is discarded.
+                if !self.input_queue.is_empty() || self.game.game_over() {
+                    self.return_to_menu();
+                } else if let Some(demo) = &self.demo {
+                    self.game.update(demo.input_at(self.game_timer));
+                    self.game_timer += 1;
+                }
+            }
+            State::Paused => {
+                let option = self.pause_menu.update(&self.input_queue);
+                self.input_queue.clear();
+
+                match option {
+                    Some(PauseOption::Resume) => self.state = self.paused_state,
+                    Some(PauseOption::Restart) if self.paused_state == State::Race => {
+                        self.start_race()
+                    }
+                    Some(PauseOption::Restart) => self.start_game(),
+                    Some(PauseOption::Mute) => {
+                        self.settings.muted = !self.settings.muted;
+                        self.settings.save();
+                        sound::set_muted(self.settings.muted);
+                        self.set_music_volume(self.music_volume);
+                    }
+                    Some(PauseOption::Quit) => self.quit(),
+                    None => {}
+                }
+            }
+        }
+    }
+
+    fn start_game(&mut self) {
+        self.end_daily();
+        self.start_game_with_seed(self.seed.unwrap_or_else(|| rand::rand() as u64));
+    }
+
+    fn start_game_with_seed(&mut self, seed: u64) {
+        // Switch to play state, and create a new Game object, passing it a new Player object to use
+        self.state = State::Play;
+        self.game = Game::new(Some(Bunner::new(Position::new(240, -320))), seed);
+        self.ghost_recording = GhostRun::default();
+        self.game_timer = 0;
+        if let Some(demo_recording) = &mut self.demo_recording {
+            *demo_recording = DemoRun::new(seed, difficulty::selected_name());
+        }
+        self.input_queue.clear();
+        self.set_music_volume(0.3);
+    }
+
+    /// Rust: Not in the original game. The attempt is recorded immediately (with a zero score); if today's
+    /// challenge has already been played, nothing happens.
+    fn start_daily(&mut self) {
+        let daily = DailyChallenge::today(self.difficulty_curves.len());
+        if self.daily_calendar.is_played(daily.day) {
+            self.input_queue.clear();
+            return;
+        }
+
+        self.daily_calendar.set_score(daily.day, 0);
+        self.daily_calendar.save();
+
+        difficulty::select(self.difficulty_curves[daily.difficulty_index].clone());
+        self.daily = Some(daily);
+        self.start_game_with_seed(daily.seed);
+    }
+
+    /// Rust: Not in the original game. The demo is played with its difficulty curve, if available.
+    fn start_demo(&mut self) {
+        let demo = match &self.demo {
+            Some(demo) => demo,
+            None => return,
+        };
+
+        if let Some(curve) = self
+            .difficulty_curves
+            .iter()
+            .find(|curve| curve.name == demo.difficulty)
+        {
+            difficulty::select(curve.clone());
+        }
+
+        self.state = State::Demo;
+        self.game = Game::new(Some(Bunner::new(Position::new(240, -320))), demo.seed);
+        self.game_timer = 0;
+        self.input_queue.clear();
+    }
+
+    fn save_daily_result(&mut self) {
+        if let Some(daily) = &self.daily {
+            self.daily_calendar.set_score(daily.day, self.game.score());
+            self.daily_calendar.save();
+        }
+    }
+
+    /// Records the result of the daily challenge, if in progress, and restores the difficulty curve selected by the
+    /// player.
+    fn end_daily(&mut self) {
+        if self.daily.is_some() {
+            self.save_daily_result();
+            self.daily = None;
+            self.restore_difficulty();
         }
     }
 
-    pub fn draw(&mut self) {
+    /// Rust: Not in the original game. The score of the game over is replaced by the one of the continued game;
+    /// continued games are not recorded as ghosts, since their hops don't start from the beginning.
+    fn continue_from_checkpoint(&mut self, checkpoint: &Checkpoint) {
+        if let Some(position) = self.last_score_position.take() {
+            self.records.remove_score(position);
+            self.records.save();
+        }
+
+        self.state = State::Play;
+        let score_penalty = self.game.score_penalty() + Checkpoint::PENALTY;
+        self.game = Game::from_checkpoint(checkpoint, score_penalty);
+        self.input_queue.clear();
+    }
+
+    fn start_race(&mut self) {
+        self.state = State::Race;
+        let seed = self.seed.unwrap_or_else(|| rand::rand() as u64);
+        self.race = Some(Race::new(seed));
+        self.input_queue.clear();
+        self.set_music_volume(0.3);
+    }
+
+    fn return_to_menu(&mut self) {
+        self.end_daily();
+        if self.state == State::Demo {
+            self.restore_difficulty();
+        }
+        self.idle_timer = 0;
+        // Switch to menu state, and create a new game object
+        self.state = State::Menu;
+        self.race = None;
+        self.game = Game::new(None, rand::rand() as u64);
+        self.last_score_position = None;
+        self.input_queue.clear();
+        self.set_music_volume(1.0);
+    }
+
+    /// Rust: Not in the original game. The game (or race) is not updated while paused, so everything is frozen; the
+    /// looped sounds are stopped, and restarted on resume.
+    fn pause(&mut self) {
+        self.paused_state = self.state;
+        self.state = State::Paused;
+        self.pause_menu = PauseMenu::default();
+        self.input_queue.clear();
+
+        match &mut self.race {
+            Some(race) => race.stop_looped_sounds(),
+            None => self.game.stop_looped_sounds(),
+        }
+    }
+
+    fn quit(&mut self) {
+        self.end_daily();
+        if cfg!(target_arch = "wasm32") {
+            self.return_to_menu();
+        } else {
+            self.quit_requested = true;
+        }
+    }
+
+    fn set_music_volume(&mut self, volume: f32) {
+        self.music_volume = volume;
+        let volume = if sound::is_muted() { 0. } else { volume };
+        set_sound_volume(self.music, volume);
+    }
+
+    pub fn draw(&mut self, screen: &VirtualScreen) {
         let resources = storage::get::<Resources>();
 
-        self.game.draw();
+        clear_background(BLACK);
+        // Rust: The daily challenge overrides the night mode setting.
+        let night_mode_enabled = self
+            .daily
+            .map_or(self.settings.night_mode, |daily| daily.night_mode);
+        let night_mode = Some(&self.night_mode).filter(|_| night_mode_enabled);
+        match &self.race {
+            Some(race) => race.draw(self.state == State::RaceOver, night_mode, screen),
+            None => {
+                self.game.draw();
+                if let Some(night_mode) = night_mode {
+                    let region = Rect::new(0., 0., WIDTH as f32, HEIGHT as f32);
+                    night_mode.draw(region, &self.game.lights(0, -self.game.scroll_pos), screen);
+                }
+            }
+        }
 
         match self.state {
             State::Menu => {
                 // Draw title screen
                 draw_texture(resources.title_texture, 0., 0., WHITE);
-                let index: usize = ((self.game.scroll_pos.abs() / 6) % 4) as usize;
+                // Rust: In reduced motion mode, the prompt is not animated.
+                let index: usize = if reduced_motion::is_enabled() {
+                    0
+                } else {
+                    ((self.game.scroll_pos.abs() / 6) % 4) as usize
+                };
                 if let Some(start_index) = [0, 1, 2, 1].get(index) {
                     draw_texture(
                         resources.start_textures[*start_index],
@@ -131,21 +574,278 @@ impl GlobalState {
                         WHITE,
                     );
                 };
+                self.draw_records();
+                self.draw_difficulty();
+                self.draw_daily();
             }
             State::Play => {
+                if let Some(ghost) = self.ghost.as_ref().filter(|_| self.ghost_enabled()) {
+                    ghost.draw(self.game_timer, -self.game.scroll_pos);
+                }
+
                 // Display score and high score
-                display_number(self.game.score(), NumberColor::Blue, 0, NumberAlign::Left);
                 display_number(
-                    self.high_score,
+                    self.game.score(),
+                    NumberColor::Blue,
+                    0,
+                    0,
+                    NumberAlign::Left,
+                );
+                display_number(
+                    self.records.high_score(),
                     NumberColor::Yellow,
                     WIDTH - 10,
+                    0,
                     NumberAlign::Right,
                 );
+                // Rust: Not in the original game.
+                if let Some(power_up) = self.game.bunner.as_ref().and_then(|b| b.power_up()) {
+                    power_up.draw_hud(0, ActivePowerUp::HUD_Y);
+                }
+                if let Some(bunner) = &self.game.bunner {
+                    eagle_pressure::draw_hud(
+                        bunner.eagle_pressure(),
+                        WIDTH - 10,
+                        ActivePowerUp::HUD_Y,
+                    );
+                }
             }
             State::GameOver => {
                 // Display "Game Over" image
                 draw_texture(resources.gameover_texture, 0., 0., WHITE);
+                if !self.draw_online_top_scores() {
+                    self.draw_records();
+                }
+                stats::draw_summary(self.game.stats(), &self.lifetime_stats);
+                self.draw_checkpoint_prompt();
+            }
+            State::Race | State::RaceOver => {}
+            State::Demo => {
+                // Rust: Not in the original game.
+                display_number(
+                    self.game.score(),
+                    NumberColor::Blue,
+                    0,
+                    0,
+                    NumberAlign::Left,
+                );
+
+                let text = "DEMO - PRESS ANY KEY";
+                let text_width = measure_text(text, None, DEMO_FONT_SIZE, 1.).width;
+                draw_text(
+                    text,
+                    (WIDTH as f32 - text_width) / 2.,
+                    (HEIGHT - 40) as f32,
+                    DEMO_FONT_SIZE as f32,
+                    WHITE,
+                );
+            }
+            State::Paused => {
+                if let Some(ghost) = self
+                    .ghost
+                    .as_ref()
+                    .filter(|_| self.race.is_none() && self.ghost_enabled())
+                {
+                    ghost.draw(self.game_timer, -self.game.scroll_pos);
+                }
+                self.pause_menu.draw(sound::is_muted());
             }
         }
+
+        self.touch_controls.draw();
+    }
+
+    /// Ghosts are recorded only when the games are generated from a seed, since otherwise, each game is different;
+    /// continued games and daily challenges (which have their own seed) are not recorded.
+    fn ghost_enabled(&self) -> bool {
+        self.seed.is_some() && !self.game.is_continued() && self.daily.is_none()
+    }
+
+    fn record_ghost(&mut self) {
+        if !self.ghost_enabled() {
+            return;
+        }
+
+        if let Some(bunner) = &self.game.bunner {
+            self.ghost_recording.record(bunner, self.game.score());
+        }
+    }
+
+    fn save_ghost(&mut self) {
+        let seed = match self.seed.filter(|_| self.ghost_enabled()) {
+            Some(seed) => seed,
+            None => return,
+        };
+
+        let is_best = self
+            .ghost
+            .as_ref()
+            .is_none_or(|ghost| self.ghost_recording.score() > ghost.score());
+
+        if is_best && self.ghost_recording.score() > 0 {
+            self.ghost_recording.save(seed);
+            self.ghost = Some(mem::take(&mut self.ghost_recording));
+        }
+    }
+
+    /// Selects again the difficulty curve chosen by the player, e.g. after the daily challenge.
+    fn restore_difficulty(&self) {
+        difficulty::select(self.difficulty_curves[self.difficulty_index].clone());
+    }
+
+    /// Moves the difficulty selection forward by the given number of curves, wrapping around.
+    fn select_difficulty(&mut self, offset: usize) {
+        self.difficulty_index = (self.difficulty_index + offset) % self.difficulty_curves.len();
+        difficulty::select(self.difficulty_curves[self.difficulty_index].clone());
+    }
+
+    /// Rust: Not in the original game. Displays the selected difficulty curve, below the start prompt, along with
+    /// the night mode, high contrast and reduced motion settings.
+    fn draw_difficulty(&self) {
+        let on_off = |enabled: bool| if enabled { "on" } else { "off" };
+        let options_lines = [
+            format!(
+                "Night (N): {}   High contrast (H): {}",
+                on_off(self.settings.night_mode),
+                on_off(self.settings.high_contrast)
+            ),
+            format!(
+                "Reduced motion (M): {}",
+                on_off(self.settings.reduced_motion)
+            ),
+        ];
+        for (i, options_text) in options_lines.iter().enumerate() {
+            let text_width = measure_text(options_text, None, OPTIONS_FONT_SIZE, 1.).width;
+            draw_text(
+                options_text,
+                (WIDTH as f32 - text_width) / 2.,
+                (HEIGHT - 100 + i as i32 * OPTIONS_FONT_SIZE as i32) as f32,
+                OPTIONS_FONT_SIZE as f32,
+                WHITE,
+            );
+        }
+
+        let text = format!("< {} >", self.difficulty_curves[self.difficulty_index].name);
+        let text_width = measure_text(&text, None, DIFFICULTY_FONT_SIZE, 1.).width;
+        draw_text(
+            &text,
+            (WIDTH as f32 - text_width) / 2.,
+            (HEIGHT - 40) as f32,
+            DIFFICULTY_FONT_SIZE as f32,
+            WHITE,
+        );
+    }
+
+    /// Rust: Not in the original game.
+    fn draw_checkpoint_prompt(&self) {
+        if let Some(checkpoint) = self.game.checkpoint().filter(|_| self.daily.is_none()) {
+            let text = format!(
+                "C: continue from {} (-{} points)",
+                checkpoint.score(),
+                Checkpoint::PENALTY
+            );
+            let text_width = measure_text(&text, None, CHECKPOINT_FONT_SIZE, 1.).width;
+            draw_text(
+                &text,
+                (WIDTH as f32 - text_width) / 2.,
+                (HEIGHT - 40) as f32,
+                CHECKPOINT_FONT_SIZE as f32,
+                WHITE,
+            );
+        }
+    }
+
+    /// Rust: Not in the original game. Displays today's challenge (or its result), and the results of the last
+    /// days.
+    fn draw_daily(&self) {
+        let today = daily::today();
+        let text = match self.daily_calendar.result(today) {
+            Some(result) => format!("Daily {}: {}", daily::format_date(today), result.score),
+            None => format!("Daily (D): {}", daily::format_date(today)),
+        };
+        let text_width = measure_text(&text, None, DAILY_FONT_SIZE, 1.).width;
+        draw_text(
+            &text,
+            (WIDTH as f32 - text_width) / 2.,
+            (HEIGHT - 130) as f32,
+            DAILY_FONT_SIZE as f32,
+            WHITE,
+        );
+
+        self.daily_calendar
+            .draw(WIDTH as f32 / 2., DAILY_CALENDAR_Y as f32);
+    }
+
+    /// Rust: Only the new personal bests are submitted; otherwise, the online table is just refreshed. The table is
+    /// specific to the difficulty curve.
+    #[cfg(feature = "online_leaderboard")]
+    fn submit_online_score(&mut self) {
+        if let Some(online_leaderboard) = &mut self.online_leaderboard {
+            let entry = (self.last_score_position == Some(0) && self.game.score() > 0).then(|| {
+                LeaderboardEntry {
+                    score: self.game.score(),
+                    seed: self.game.seed(),
+                }
+            });
+            online_leaderboard.submit_and_fetch(&difficulty::selected_name(), entry);
+        }
+    }
+
+    #[cfg(not(feature = "online_leaderboard"))]
+    fn submit_online_score(&mut self) {}
+
+    /// Rust: Not in the original game. Displays the online top scores, in place of the local ones, highlighting
+    /// the one of the last game; returns false if the online table is not available (or not used).
+    #[cfg(feature = "online_leaderboard")]
+    fn draw_online_top_scores(&self) -> bool {
+        let top_scores = match self
+            .online_leaderboard
+            .as_ref()
+            .and_then(|online_leaderboard| online_leaderboard.top_scores())
+        {
+            Some(top_scores) => top_scores,
+            None => return false,
+        };
+
+        for (i, entry) in top_scores.iter().enumerate() {
+            let color = if entry.score == self.game.score() && entry.seed == self.game.seed() {
+                NumberColor::Blue
+            } else {
+                NumberColor::Yellow
+            };
+            let y = 10 + i as i32 * RECORDS_SPACING;
+            display_number(entry.score, color, WIDTH / 2, y, NumberAlign::Center);
+        }
+
+        let text = "GLOBAL TOP SCORES";
+        let text_width = measure_text(text, None, LEADERBOARD_FONT_SIZE, 1.).width;
+        draw_text(
+            text,
+            (WIDTH as f32 - text_width) / 2.,
+            (10 + top_scores.len() as i32 * RECORDS_SPACING + 30) as f32,
+            LEADERBOARD_FONT_SIZE as f32,
+            WHITE,
+        );
+
+        true
+    }
+
+    #[cfg(not(feature = "online_leaderboard"))]
+    fn draw_online_top_scores(&self) -> bool {
+        false
+    }
+
+    /// Rust: Not in the original game. Displays the top scores at the top of the screen; the one of the last game,
+    /// if present, is highlighted.
+    fn draw_records(&self) {
+        for (i, score) in self.records.top_scores().iter().enumerate() {
+            let color = if self.last_score_position == Some(i) {
+                NumberColor::Blue
+            } else {
+                NumberColor::Yellow
+            };
+            let y = 10 + i as i32 * RECORDS_SPACING;
+            display_number(*score, color, WIDTH / 2, y, NumberAlign::Center);
+        }
     }
 }
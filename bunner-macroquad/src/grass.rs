@@ -1,22 +1,24 @@
 use crate::{
-    child::Child, hedge::Hedge, hedge_mask::HedgeMask, hedge_row::HedgeRow, hedge_tile::HedgeTile,
-    position::Position, resources::Resources, road::Road, row::Row, water::Water, ROW_HEIGHT,
-    WIDTH,
+    child::Child, hedge::Hedge, hedge_mask::HedgeMask, hedge_row::HedgeRow, position::Position,
+    power_up::PowerUp, power_up_kind::PowerUpKind, resources::Resources, row::Row,
+    row_pool::RowPool, row_rng::RowRng, sound::play_sound_once, terrain::Terrain, ROW_HEIGHT,
 };
-use macroquad::{
-    audio::play_sound_once,
-    prelude::collections::storage,
-    rand::{self},
-    texture::Texture2D,
+use bunner_core::{
+    collision, hedges,
+    row_sequence::{self, NextRow},
 };
+use macroquad::{prelude::collections::storage, texture::Texture2D};
+use std::mem;
 
-#[derive(Clone)]
+#[derive(Clone, Default)]
 pub struct Grass {
     index: i32,
     y: i32,
     hedge_row: HedgeRow,
     hedge_mask: Vec<HedgeMask>,
     children: Vec<Child>,
+    /// Rust: Not in the original game; see `PowerUp`.
+    power_up: Option<PowerUp>,
 }
 
 impl Row for Grass {
@@ -32,6 +34,18 @@ impl Row for Grass {
         self.children.as_mut()
     }
 
+    fn draw(&self, offset_x: i32, offset_y: i32) {
+        let (x, y) = self.draw_row(offset_x, offset_y);
+        self.draw_children(x, y);
+        if let Some(power_up) = &self.power_up {
+            power_up.draw(x, y);
+        }
+    }
+
+    fn terrain(&self) -> Terrain {
+        Terrain::Grass
+    }
+
     fn image(&self) -> Texture2D {
         *storage::get::<Resources>()
             .grass_textures
@@ -43,157 +57,109 @@ impl Row for Grass {
         play_sound_once(storage::get::<Resources>().grass_sound);
     }
 
-    fn next(&self) -> Box<dyn Row> {
+    fn next(&self, pool: &mut RowPool, rng: &mut RowRng) -> Box<dyn Row> {
         let y = self.y - ROW_HEIGHT;
-        if self.index <= 5 {
-            self.grass_row(self.index + 8, y)
-        } else if self.index == 6 {
-            self.grass_row(7, y)
-        } else if self.index == 7 {
-            self.grass_row(15, y)
-        } else if self.index >= 8 && self.index <= 14 {
-            self.grass_row(self.index + 1, y)
-        } else if rand::gen_range::<u8>(0, 2) == 0 {
-            Box::new(Road::empty(y))
-        } else {
-            Box::new(Water::empty(y))
+        match row_sequence::after_grass(self.index, rng) {
+            NextRow::Grass(index) => {
+                Grass::new(pool, Some(&self.hedge_mask), self.hedge_row, index, y, rng)
+            }
+            next => pool.build(next, 0, y, rng),
         }
     }
 
+    fn recycle(self: Box<Self>, pool: &mut RowPool) {
+        pool.grasses.push(self);
+    }
+
+    fn is_safe(&self) -> bool {
+        true
+    }
+
     fn allow_movement(&self, x: i32) -> bool {
-        (16..=WIDTH - 16).contains(&x) && !self.collide(x, 8)
+        collision::is_within_bounds(x) && !self.collide(x, collision::HEDGE_MARGIN)
     }
 
     fn push(&self) -> i32 {
         0
     }
+
+    fn take_power_up(&mut self, x: i32) -> Option<PowerUpKind> {
+        self.power_up
+            .filter(|power_up| power_up.is_reached(x))
+            .and_then(|_| self.power_up.take())
+            .map(|power_up| power_up.kind)
+    }
 }
 
 impl Grass {
+    /// Percentage of the grass rows with a pickup.
+    const POWER_UP_CHANCE: u8 = 4;
+
     pub fn new(
-        previous_hedge_mask: Option<Vec<HedgeMask>>,
+        pool: &mut RowPool,
+        previous_hedge_mask: Option<&[HedgeMask]>,
         previous_hedge_row: HedgeRow,
         index: i32,
         y: i32,
-    ) -> Self {
-        let (hedge_mask, hedge_row) = match previous_hedge_mask {
-            Some(_) if previous_hedge_row == HedgeRow::None => Self::first_hedge_row(index),
-            Some(mask) if previous_hedge_row == HedgeRow::First => (mask, HedgeRow::Second),
-            Some(_) => (Vec::new(), HedgeRow::None),
-            None => Self::first_hedge_row(index),
-        };
+        rng: &mut RowRng,
+    ) -> Box<Self> {
+        let (mut row, mut children) = RowPool::take(&mut pool.grasses);
+        let mut hedge_mask = mem::take(&mut row.hedge_mask);
+        hedge_mask.clear();
+
+        let hedge_row = hedges::next_hedge_row(
+            previous_hedge_mask,
+            previous_hedge_row,
+            index,
+            &mut hedge_mask,
+            rng,
+        );
+        hedges::for_each_hedge(&hedge_mask, |x, hedge_tile| {
+            children.push(Child::Hedge(Hedge::new(
+                hedge_tile,
+                hedge_row,
+                Position::new(x, 0),
+            )));
+        });
 
-        let mut children: Vec<Child> = Vec::new();
-        if hedge_row != HedgeRow::None {
-            // See comments in classify_hedge_segment for explanation of previous_mid_segment
-            #[allow(unused_assignments)]
-            let mut hedge_tile = HedgeTile::Grass;
-            let mut previous_mid_segment = None;
-            for i in 1..13 {
-                (hedge_tile, previous_mid_segment) =
-                    Self::classify_hedge_segment(&hedge_mask[i - 1..i + 2], previous_mid_segment);
-                if hedge_tile != HedgeTile::Grass {
-                    children.push(Child::Hedge(Hedge::new(
-                        hedge_tile,
-                        hedge_row,
-                        Position::new(i as i32 * 40 - 20, 0),
-                    )));
-                }
-            }
-        }
+        let power_up = Self::spawn_power_up(&hedge_mask, y, rng);
 
-        Self {
+        *row = Self {
             y,
             index,
             hedge_row,
             hedge_mask,
             children,
-        }
+            power_up,
+        };
+        row
     }
 
-    pub fn without_hedge(index: i32, y: i32) -> Self {
-        Self::new(None, HedgeRow::None, index, y)
-    }
-
-    pub fn classify_hedge_segment(
-        mask_window: &[HedgeMask],
-        previous_mid_segment: Option<HedgeTile>,
-    ) -> (HedgeTile, Option<HedgeTile>) {
-        if mask_window[1] == HedgeMask::Empty {
-            (HedgeTile::Grass, None)
-        } else if mask_window[0] == HedgeMask::Empty && mask_window[2] == HedgeMask::Empty {
-            (HedgeTile::SingleWidth, None)
-        } else if mask_window[0] == HedgeMask::Empty {
-            (HedgeTile::LeftMost, None)
-        } else if mask_window[2] == HedgeMask::Empty {
-            (HedgeTile::RightMost, None)
-        } else {
-            match previous_mid_segment {
-                Some(HedgeTile::Middle4) if mask_window[2] == HedgeMask::Empty => {
-                    (HedgeTile::Middle5, None)
-                }
-                Some(HedgeTile::Middle4) if mask_window[2] == HedgeMask::Hedge => {
-                    (HedgeTile::Middle3, Some(HedgeTile::Middle3))
-                }
-                Some(HedgeTile::Middle3) => (HedgeTile::Middle3, Some(HedgeTile::Middle3)),
-                _ => (HedgeTile::Middle3, Some(HedgeTile::Middle3)),
-            }
-        }
+    pub fn without_hedge(pool: &mut RowPool, index: i32, y: i32, rng: &mut RowRng) -> Box<Self> {
+        Self::new(pool, None, HedgeRow::None, index, y, rng)
     }
 
-    pub fn first_hedge_row(index: i32) -> (Vec<HedgeMask>, HedgeRow) {
-        if rand::gen_range::<u8>(0, 1) == 0 && index > 7 && index < 14 {
-            (Self::generate_hedge_mask(), HedgeRow::First)
-        } else {
-            (Vec::new(), HedgeRow::None)
+    /// Rust: Not in the original game. Pickups are not spawned on the start rows, nor on the hedges.
+    fn spawn_power_up(hedge_mask: &[HedgeMask], y: i32, rng: &mut RowRng) -> Option<PowerUp> {
+        if y >= -320 || rng.gen_range::<u8>(0, 100) >= Self::POWER_UP_CHANCE {
+            return None;
         }
-    }
 
-    pub fn generate_hedge_mask() -> Vec<HedgeMask> {
-        let mut mask = Vec::new();
-        mask.resize_with(12, || {
-            if rand::gen_range::<u8>(0, 100) >= 1 {
-                HedgeMask::Hedge
-            } else {
-                HedgeMask::Empty
+        let mut free_columns = [0; 12];
+        let mut free_count = 0;
+        for i in 1..13 {
+            if hedge_mask
+                .get(i)
+                .is_none_or(|mask| *mask == HedgeMask::Empty)
+            {
+                free_columns[free_count] = i as i32;
+                free_count += 1;
             }
-        });
-        // Ensure there is at least one gap
-        mask[rand::gen_range(0, 11)] = HedgeMask::Empty;
-
-        let mut new_mask = Vec::with_capacity(12);
-        for i in 0..12 {
-            let low_index = 0.max(i as i32 - 1) as usize;
-            let high_index = 11.min(i + 1);
-            new_mask.push(
-                if mask[low_index..=high_index]
-                    .iter()
-                    .filter(|&&item| item == HedgeMask::Empty)
-                    .count()
-                    > 0
-                {
-                    HedgeMask::Empty
-                } else {
-                    HedgeMask::Hedge
-                },
-            );
         }
 
-        // Duplicate first and last elements
-        let mut mask = Vec::new();
-        mask.push(*new_mask.get(0).unwrap());
-        mask.extend(new_mask.clone());
-        mask.push(new_mask.pop().unwrap());
+        let column = *rng.choose(&free_columns[..free_count])?;
+        let kind = *rng.choose(&PowerUpKind::ALL)?;
 
-        mask
-    }
-
-    fn grass_row(&self, index: i32, y: i32) -> Box<dyn Row> {
-        Box::new(Grass::new(
-            Some(self.hedge_mask.clone()),
-            self.hedge_row,
-            index,
-            y,
-        ))
+        Some(PowerUp::new(kind, Position::new(column * 40 - 20, 0)))
     }
 }
@@ -1,6 +0,0 @@
-#[derive(Copy, Clone, Eq, PartialEq)]
-pub enum HedgeRow {
-    First,
-    Second,
-    None,
-}
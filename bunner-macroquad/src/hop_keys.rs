@@ -0,0 +1,143 @@
+use crate::settings::Settings;
+use macroquad::input::{is_key_down, KeyCode};
+
+/// Keys that can be bound to the hops; they're referenced in the settings by their name (e.g. `I`, `Key8`). The bound
+/// keys are translated in every state, so the keys that the game uses elsewhere are excluded: the second player's keys
+/// in the race (W, A, S, D), the pause (P), and the title screen shortcuts (C, D, H, M, N, 2).
+const BINDABLE_KEYS: [KeyCode; 34] = [
+    KeyCode::Up,
+    KeyCode::Right,
+    KeyCode::Down,
+    KeyCode::Left,
+    KeyCode::B,
+    KeyCode::E,
+    KeyCode::F,
+    KeyCode::G,
+    KeyCode::I,
+    KeyCode::J,
+    KeyCode::K,
+    KeyCode::L,
+    KeyCode::O,
+    KeyCode::Q,
+    KeyCode::R,
+    KeyCode::T,
+    KeyCode::U,
+    KeyCode::V,
+    KeyCode::X,
+    KeyCode::Y,
+    KeyCode::Z,
+    KeyCode::Key0,
+    KeyCode::Key1,
+    KeyCode::Key3,
+    KeyCode::Key4,
+    KeyCode::Key5,
+    KeyCode::Key6,
+    KeyCode::Key7,
+    KeyCode::Key8,
+    KeyCode::Key9,
+    KeyCode::Kp2,
+    KeyCode::Kp4,
+    KeyCode::Kp6,
+    KeyCode::Kp8,
+];
+
+/// Arrow keys, which the game uses for the hops, in the order of the settings.
+const ARROW_KEYS: [KeyCode; 4] = [KeyCode::Up, KeyCode::Right, KeyCode::Down, KeyCode::Left];
+
+// Rust: Not in the original game. The keys bound to the hops (in the settings) are translated to the arrow keys;
+// the arrows keep working, since they're also used by the menus.
+//
+// With hold-to-repeat, holding a hop key repeats the hop after the configured delay, and then again at the same
+// interval. Only the keyboard is considered.
+//
+pub struct HopKeys {
+    /// (bound key, arrow key), for each direction.
+    bindings: [(KeyCode, KeyCode); 4],
+    hold_to_repeat: bool,
+    /// In updates.
+    repeat_delay: u32,
+    /// Arrow key of the held direction, and for how many updates it's been held.
+    held: Option<(KeyCode, u32)>,
+}
+
+impl Default for HopKeys {
+    fn default() -> Self {
+        Self::new(&Settings::default())
+    }
+}
+
+impl HopKeys {
+    pub fn new(settings: &Settings) -> Self {
+        let key_names = [
+            &settings.hop_up_key,
+            &settings.hop_right_key,
+            &settings.hop_down_key,
+            &settings.hop_left_key,
+        ];
+
+        let bindings = [0, 1, 2, 3].map(|direction_i| {
+            let (key_name, arrow_key) = (key_names[direction_i], ARROW_KEYS[direction_i]);
+
+            match parse_key(key_name) {
+                Some(key_code) => (key_code, arrow_key),
+                None => {
+                    eprintln!(
+                        "Invalid or reserved hop key: {:?}; using {:?}",
+                        key_name, arrow_key
+                    );
+                    (arrow_key, arrow_key)
+                }
+            }
+        });
+
+        Self {
+            bindings,
+            hold_to_repeat: settings.hold_to_repeat,
+            repeat_delay: settings.repeat_delay.max(1),
+            held: None,
+        }
+    }
+
+    /// Translates a bound key to the arrow key of its direction; other keys are returned unchanged.
+    pub fn translate(&self, key_code: KeyCode) -> KeyCode {
+        self.bindings
+            .iter()
+            .find(|(bound_key, _)| *bound_key == key_code)
+            .map_or(key_code, |(_, arrow_key)| *arrow_key)
+    }
+
+    /// Invoked on each update of the game; returns the arrow key to queue, if the hop is repeated.
+    pub fn update(&mut self) -> Option<KeyCode> {
+        if !self.hold_to_repeat {
+            return None;
+        }
+
+        let held_key = self
+            .bindings
+            .iter()
+            .find(|(bound_key, arrow_key)| is_key_down(*bound_key) || is_key_down(*arrow_key))
+            .map(|(_, arrow_key)| *arrow_key);
+
+        self.held = match (held_key, self.held) {
+            (Some(key), Some((previous_key, held_time))) if key == previous_key => {
+                Some((key, held_time + 1))
+            }
+            (Some(key), _) => Some((key, 0)),
+            (None, _) => None,
+        };
+
+        match self.held {
+            Some((key, held_time)) if held_time > 0 && held_time % self.repeat_delay == 0 => {
+                Some(key)
+            }
+            _ => None,
+        }
+    }
+}
+
+fn parse_key(name: &str) -> Option<KeyCode> {
+    BINDABLE_KEYS
+        .iter()
+        .find(|key_code| format!("{:?}", key_code) == name)
+        .copied()
+}
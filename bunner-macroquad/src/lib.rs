@@ -1,36 +1,62 @@
 pub mod active_row;
 pub mod actor;
 pub mod bunner;
+pub mod camera;
 pub mod car;
+pub mod checkpoint;
 pub mod child;
+pub mod daily;
+pub mod debug_overlay;
+pub mod demo;
+pub mod difficulty;
 pub mod dirt;
 pub mod drawing;
 pub mod eagle;
+pub mod eagle_pressure;
+pub mod effects;
+pub mod express_rail;
 pub mod game;
+#[cfg(feature = "gamepad")]
+pub mod gamepad;
+pub mod ghost;
 pub mod global_state;
 pub mod grass;
 pub mod hedge;
-pub mod hedge_mask;
-pub mod hedge_row;
-pub mod hedge_tile;
+pub mod hop_keys;
 pub mod log;
 pub mod mover;
+pub mod night_mode;
+#[cfg(feature = "online_leaderboard")]
+pub mod online_leaderboard;
+pub mod palette;
+pub mod pause_menu;
 pub mod pavement;
 pub mod player_direction;
-pub mod player_state;
 pub mod position;
+pub mod power_up;
+pub mod power_up_kind;
+pub mod race;
 pub mod rail;
+pub mod records;
+pub mod reduced_motion;
 pub mod resources;
 pub mod road;
 pub mod row;
+pub mod row_pool;
+pub mod settings;
+pub mod sound;
 pub mod splat;
 pub mod state;
+pub mod stats;
+pub mod terrain;
+pub mod touch_controls;
 pub mod train;
+pub mod virtual_screen;
 pub mod water;
 
-pub const WIDTH: i32 = 480;
-pub const HEIGHT: i32 = 800;
+pub use bunner_core::{
+    hedge_mask, hedge_row, hedge_tile, player_state, row_rng, HEIGHT, ROW_HEIGHT, TILE_WIDTH, WIDTH,
+};
+
 pub const TITLE: &str = "Bunner Macroquad";
-pub const ROW_HEIGHT: i32 = 40;
-pub const TILE_WIDTH: i32 = 40;
 pub const TIME_PER_FRAME: f32 = 1. / 60.;
@@ -1,14 +1,52 @@
-use crate::{actor::Actor, mover::Mover, position::Position, resources::Resources};
-use macroquad::{
-    prelude::{collections::storage, draw_texture, WHITE},
-    rand::gen_range,
+use crate::{
+    actor::Actor, mover::Mover, palette, position::Position, reduced_motion, resources::Resources,
+    row_rng::RowRng,
 };
+use macroquad::prelude::{collections::storage, draw_texture, Color, WHITE};
+
+/// Rust: Not in the original game; probability that a log is a diving one.
+const DIVING_CHANCE: f32 = 0.15;
+
+/// Updates spent in each phase of the dive cycle.
+const FLOATING_DURATION: i32 = 180;
+const WARNING_DURATION: i32 = 60;
+const SUBMERGED_DURATION: i32 = 90;
+
+/// While warning, the log bobs up and down by this many pixels, every `BOB_PERIOD` updates.
+const BOB_DISTANCE: i32 = 2;
+const BOB_PERIOD: i32 = 4;
+
+const WARNING_COLOUR: Color = Color::new(1., 0.7, 0.7, 1.);
+const SUBMERGED_COLOUR: Color = Color::new(0.4, 0.6, 1., 0.35);
+
+/// Rust: Not in the original game. Phase of a diving log; the timer counts down the updates left in the phase.
+#[derive(Clone, Copy)]
+enum Dive {
+    Floating(i32),
+    Warning(i32),
+    Submerged(i32),
+}
+
+impl Dive {
+    fn next(self) -> Self {
+        match self {
+            Dive::Floating(timer) if timer > 1 => Dive::Floating(timer - 1),
+            Dive::Floating(_) => Dive::Warning(WARNING_DURATION),
+            Dive::Warning(timer) if timer > 1 => Dive::Warning(timer - 1),
+            Dive::Warning(_) => Dive::Submerged(SUBMERGED_DURATION),
+            Dive::Submerged(timer) if timer > 1 => Dive::Submerged(timer - 1),
+            Dive::Submerged(_) => Dive::Floating(FLOATING_DURATION),
+        }
+    }
+}
 
 #[derive(Clone)]
 pub struct Log {
     dx: i32,
     position: Position,
     image_index: usize,
+    /// Rust: Not in the original game; None for the logs that never dive.
+    dive: Option<Dive>,
 }
 
 impl Mover for Log {
@@ -20,6 +58,7 @@ impl Mover for Log {
 impl Actor for Log {
     fn update(&mut self) {
         self.position.x += self.dx;
+        self.dive = self.dive.map(Dive::next);
     }
 
     fn draw(&self, offset_x: i32, offset_y: i32) {
@@ -27,12 +66,37 @@ impl Actor for Log {
             .log_textures
             .get(self.image_index)
             .unwrap();
+
+        let (bob_y, colour) = match self.dive {
+            Some(Dive::Warning(timer)) => {
+                // In reduced motion mode, only the tint warns of the dive.
+                let bob_y = if (timer / BOB_PERIOD) % 2 == 0 && !reduced_motion::is_enabled() {
+                    BOB_DISTANCE
+                } else {
+                    0
+                };
+                (bob_y, WARNING_COLOUR)
+            }
+            Some(Dive::Submerged(_)) => (0, SUBMERGED_COLOUR),
+            _ => (0, WHITE),
+        };
+
         draw_texture(
             image,
             (self.position.x + offset_x) as f32 - image.width() / 2.,
-            (self.position.y + offset_y) as f32 - image.height(),
-            WHITE,
+            (self.position.y + offset_y + bob_y) as f32 - image.height(),
+            colour,
         );
+
+        if palette::is_high_contrast() && !self.is_submerged() {
+            palette::draw_outline(
+                (self.position.x + offset_x) as f32,
+                (self.position.y + offset_y) as f32,
+                self.width() as f32,
+                image.height(),
+                palette::LOG_OUTLINE_COLOR,
+            );
+        }
     }
 
     fn x(&self) -> i32 {
@@ -53,12 +117,21 @@ impl Actor for Log {
 }
 
 impl Log {
-    pub fn new(dx: i32, position: Position) -> Self {
-        let image_index = gen_range::<usize>(0, 2);
+    pub fn new(dx: i32, position: Position, rng: &mut RowRng) -> Self {
+        let image_index = rng.gen_range::<usize>(0, 2);
+        // Rust: The diving logs start at a random point of the floating phase, so that they don't dive in unison.
+        let dive = (rng.gen_range::<f32>(0., 1.) < DIVING_CHANCE)
+            .then(|| Dive::Floating(rng.gen_range(1, FLOATING_DURATION + 1)));
         Self {
             dx,
             position,
             image_index,
+            dive,
         }
     }
+
+    /// Rust: Not in the original game. A submerged log doesn't hold the bunner, who falls in the water.
+    pub fn is_submerged(&self) -> bool {
+        matches!(self.dive, Some(Dive::Submerged(_)))
+    }
 }
@@ -9,17 +9,27 @@ use macroquad::{
 };
 
 use bunner_macroquad::{
-    global_state::GlobalState, resources::Resources, HEIGHT, TIME_PER_FRAME, TITLE, WIDTH,
+    demo::DemoRun, difficulty::DifficultyCurves, eagle_pressure::EagleTuning,
+    global_state::GlobalState, night_mode::NightMode, resources::Resources,
+    virtual_screen::VirtualScreen, HEIGHT, TIME_PER_FRAME, TITLE, WIDTH,
 };
 
-use std::error;
+#[cfg(feature = "gamepad")]
+use bunner_macroquad::gamepad::Gamepads;
+
+use std::{env, error};
+
+/// Generates the games from the given seed (unsigned integer), e.g. for challenges between players.
+const SEED_ARG: &str = "--seed";
+/// Saves the inputs of each game into `demo.ron`, so that it can be used as demo.
+const RECORD_DEMO_ARG: &str = "--record-demo";
 
 fn window_conf() -> Conf {
     Conf {
         window_title: TITLE.into(),
         window_width: WIDTH as i32,
         window_height: HEIGHT as i32,
-        window_resizable: false,
+        window_resizable: true,
         ..Default::default()
     }
 }
@@ -28,24 +38,58 @@ fn window_conf() -> Conf {
 async fn main() -> Result<(), Box<dyn error::Error>> {
     Resources::load().await?;
 
+    // In the browser, there are no arguments
+    let args = env::args().collect::<Vec<_>>();
+    let seed = match args.iter().position(|arg| arg == SEED_ARG) {
+        Some(arg_i) => Some(args.get(arg_i + 1).ok_or("Missing seed")?.parse()?),
+        None => None,
+    };
+
+    let difficulty_curves = DifficultyCurves::load().await;
+    EagleTuning::load().await;
+    let demo = DemoRun::load().await;
+
     // Start music
     let music = audio::load_sound("resources/music/theme.ogg").await?;
-    let mut global_state = GlobalState::new(music);
+    let night_mode = NightMode::new()?;
+    let mut global_state =
+        GlobalState::new(music, seed, difficulty_curves.curves, night_mode, demo);
     global_state.init();
+    if args.iter().any(|arg| arg == RECORD_DEMO_ARG) {
+        global_state.record_demos();
+    }
+
+    #[cfg(feature = "gamepad")]
+    let mut gamepads = Gamepads::new();
+
+    let mut screen = VirtualScreen::new();
 
     let input_subscriber = register_input_subscriber();
     let mut frame_time: f32 = 0.;
     loop {
-        if is_key_pressed(KeyCode::Escape) || is_key_pressed(KeyCode::Q) {
+        if global_state.quit_requested() {
             std::process::exit(0);
         }
         repeat_all_miniquad_input(&mut global_state, input_subscriber);
+        #[cfg(feature = "gamepad")]
+        for keycode in gamepads.pressed_keys() {
+            global_state.queue_input(keycode);
+        }
+        // The game logic (scrolling, traffic, timers) runs at a fixed 60 Hz, regardless of the display refresh rate,
+        // so that the games (and the recorded ghosts) are the same on any display. The elapsed time is capped, so
+        // that after a stall, the game slows down instead of running many updates at once.
         frame_time += get_frame_time().min(0.25);
         while frame_time >= TIME_PER_FRAME {
             global_state.update();
             frame_time -= TIME_PER_FRAME;
         }
-        global_state.draw();
+        if is_key_pressed(KeyCode::F11) {
+            screen.toggle_fullscreen();
+        }
+
+        screen.begin();
+        global_state.draw(&screen);
+        screen.end();
 
         next_frame().await
     }
@@ -0,0 +1,151 @@
+use crate::{virtual_screen::VirtualScreen, HEIGHT, WIDTH};
+use macroquad::{
+    camera::{set_camera, Camera2D},
+    color::{colors::WHITE, Color},
+    material::{gl_use_default_material, gl_use_material, load_material, Material, MaterialParams},
+    math::{vec2, Rect},
+    miniquad::{BlendFactor, BlendState, BlendValue, Equation, PipelineParams, ShaderError},
+    texture::{draw_texture_ex, render_target, DrawTextureParams, Image, RenderTarget, Texture2D},
+    window::clear_background,
+};
+
+/// A light, as an ellipse, in screen coordinates.
+pub struct Light {
+    pub x: f32,
+    pub y: f32,
+    pub width: f32,
+    pub height: f32,
+}
+
+// Rust: Not in the original game. Night mode modifier: after the scene is drawn, it's darkened, except inside the
+// lights (around the bunner, and in front of the cars).
+//
+// The lights are drawn into a light map (a render target cleared to the ambient color), which is then multiplied
+// with the scene, via a material with multiplicative blending.
+//
+pub struct NightMode {
+    light_map: RenderTarget,
+    light_texture: Texture2D,
+    multiply_material: Material,
+}
+
+impl NightMode {
+    const AMBIENT_COLOR: Color = Color::new(0.08, 0.08, 0.2, 1.);
+    const LIGHT_TEXTURE_SIZE: u16 = 64;
+
+    const VERTEX_SHADER: &'static str = r#"#version 100
+attribute vec3 position;
+attribute vec2 texcoord;
+attribute vec4 color0;
+
+varying lowp vec2 uv;
+varying lowp vec4 color;
+
+uniform mat4 Model;
+uniform mat4 Projection;
+
+void main() {
+    gl_Position = Projection * Model * vec4(position, 1);
+    color = color0 / 255.0;
+    uv = texcoord;
+}
+"#;
+
+    const FRAGMENT_SHADER: &'static str = r#"#version 100
+varying lowp vec2 uv;
+varying lowp vec4 color;
+
+uniform sampler2D Texture;
+
+void main() {
+    gl_FragColor = texture2D(Texture, uv) * color;
+}
+"#;
+
+    pub fn new() -> Result<Self, ShaderError> {
+        let multiply_material = load_material(
+            Self::VERTEX_SHADER,
+            Self::FRAGMENT_SHADER,
+            MaterialParams {
+                pipeline_params: PipelineParams {
+                    color_blend: Some(BlendState::new(
+                        Equation::Add,
+                        BlendFactor::Value(BlendValue::DestinationColor),
+                        BlendFactor::Zero,
+                    )),
+                    ..Default::default()
+                },
+                ..Default::default()
+            },
+        )?;
+
+        Ok(Self {
+            light_map: render_target(WIDTH as u32, HEIGHT as u32),
+            light_texture: Self::light_texture(),
+            multiply_material,
+        })
+    }
+
+    /// White disc, fading toward the border.
+    fn light_texture() -> Texture2D {
+        let size = Self::LIGHT_TEXTURE_SIZE;
+        let mut image = Image::gen_image_color(size, size, Color::new(1., 1., 1., 0.));
+        let radius = size as f32 / 2.;
+
+        for y in 0..size as u32 {
+            for x in 0..size as u32 {
+                let distance =
+                    vec2(x as f32 + 0.5 - radius, y as f32 + 0.5 - radius).length() / radius;
+                let alpha = (1. - distance).clamp(0., 1.);
+                image.set_pixel(x, y, Color::new(1., 1., 1., alpha * (2. - alpha)));
+            }
+        }
+
+        Texture2D::from_image(&image)
+    }
+
+    /// Darkens the given screen region, except inside the lights.
+    pub fn draw(&self, region: Rect, lights: &[Light], screen: &VirtualScreen) {
+        let mut camera =
+            Camera2D::from_display_rect(Rect::new(0., 0., WIDTH as f32, HEIGHT as f32));
+        camera.render_target = Some(self.light_map);
+        set_camera(&camera);
+
+        clear_background(Self::AMBIENT_COLOR);
+        for light in lights {
+            draw_texture_ex(
+                self.light_texture,
+                light.x - light.width / 2.,
+                light.y - light.height / 2.,
+                WHITE,
+                DrawTextureParams {
+                    dest_size: Some(vec2(light.width, light.height)),
+                    ..Default::default()
+                },
+            );
+        }
+
+        screen.begin();
+
+        // The render target is stored upside down, so it's flipped, and the source region is mirrored.
+        gl_use_material(self.multiply_material);
+        draw_texture_ex(
+            self.light_map.texture,
+            region.x,
+            region.y,
+            WHITE,
+            DrawTextureParams {
+                dest_size: Some(region.size()),
+                source: Some(Rect::new(
+                    region.x,
+                    HEIGHT as f32 - region.y - region.h,
+                    region.w,
+                    region.h,
+                )),
+                flip_y: true,
+                ..Default::default()
+            },
+        );
+        gl_use_default_material();
+    }
+}
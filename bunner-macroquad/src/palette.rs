@@ -0,0 +1,50 @@
+use crate::ROW_HEIGHT;
+use macroquad::{
+    color::Color,
+    shapes::{draw_rectangle, draw_rectangle_lines},
+};
+use std::sync::atomic::{AtomicBool, Ordering};
+
+// Rust: Not in the original game. High contrast mode, an accessibility option toggled on the title screen: the cars
+// and logs are outlined (the cars with their lethal extent), the water is darkened so that the logs stand out, and
+// the flashing effects (the warning lights of the express rails) are steady.
+//
+// The game has no screen flash on death (the splat and splash are sprites), so there's nothing else to disable.
+
+static HIGH_CONTRAST: AtomicBool = AtomicBool::new(false);
+
+pub const CAR_OUTLINE_COLOR: Color = Color::new(1., 0., 1., 1.);
+pub const LOG_OUTLINE_COLOR: Color = Color::new(1., 1., 0., 1.);
+const WATER_OVERLAY_COLOR: Color = Color::new(0., 0., 0.15, 0.6);
+const OUTLINE_THICKNESS: f32 = 3.;
+
+pub fn set_high_contrast(high_contrast: bool) {
+    HIGH_CONTRAST.store(high_contrast, Ordering::Relaxed);
+}
+
+pub fn is_high_contrast() -> bool {
+    HIGH_CONTRAST.load(Ordering::Relaxed)
+}
+
+/// Draws the outline of a child, given its center x and bottom y (as the sprites are positioned).
+pub fn draw_outline(x: f32, bottom_y: f32, width: f32, height: f32, color: Color) {
+    draw_rectangle_lines(
+        x - width / 2.,
+        bottom_y - height,
+        width,
+        height,
+        OUTLINE_THICKNESS,
+        color,
+    );
+}
+
+/// Darkens the water row with the given bottom y.
+pub fn draw_water_overlay(x: f32, bottom_y: f32, width: f32) {
+    draw_rectangle(
+        x,
+        bottom_y - ROW_HEIGHT as f32,
+        width,
+        ROW_HEIGHT as f32,
+        WATER_OVERLAY_COLOR,
+    );
+}
@@ -0,0 +1,97 @@
+use crate::{HEIGHT, WIDTH};
+use macroquad::{
+    color::{
+        colors::{WHITE, YELLOW},
+        Color,
+    },
+    input::KeyCode,
+    shapes::draw_rectangle,
+    text::{draw_text, measure_text},
+};
+use std::collections::VecDeque;
+
+#[derive(Clone, Copy, Eq, PartialEq)]
+pub enum PauseOption {
+    Resume,
+    Restart,
+    Mute,
+    Quit,
+}
+
+// Rust: Not in the original game. Overlay displayed while the game is paused; the options are selected with the
+// up/down arrows, and confirmed with space/enter. Escape and P resume the game.
+//
+#[derive(Default)]
+pub struct PauseMenu {
+    selected: usize,
+}
+
+impl PauseMenu {
+    const OPTIONS: [PauseOption; 4] = [
+        PauseOption::Resume,
+        PauseOption::Restart,
+        PauseOption::Mute,
+        PauseOption::Quit,
+    ];
+
+    const BACKGROUND_COLOR: Color = Color::new(0., 0., 0., 0.6);
+    const TITLE_FONT_SIZE: u16 = 60;
+    const OPTION_FONT_SIZE: u16 = 40;
+    const OPTION_SPACING: f32 = 60.;
+
+    /// Keys that pause the game, and resume it from the overlay.
+    pub const PAUSE_KEYS: [KeyCode; 2] = [KeyCode::Escape, KeyCode::P];
+
+    /// Returns the chosen option, if any.
+    pub fn update(&mut self, input_queue: &VecDeque<KeyCode>) -> Option<PauseOption> {
+        for key_code in input_queue {
+            match key_code {
+                KeyCode::Up => {
+                    self.selected = (self.selected + Self::OPTIONS.len() - 1) % Self::OPTIONS.len();
+                }
+                KeyCode::Down => {
+                    self.selected = (self.selected + 1) % Self::OPTIONS.len();
+                }
+                KeyCode::Space | KeyCode::Enter => return Some(Self::OPTIONS[self.selected]),
+                key_code if Self::PAUSE_KEYS.contains(key_code) => {
+                    return Some(PauseOption::Resume)
+                }
+                _ => {}
+            }
+        }
+
+        None
+    }
+
+    pub fn draw(&self, muted: bool) {
+        draw_rectangle(0., 0., WIDTH as f32, HEIGHT as f32, Self::BACKGROUND_COLOR);
+
+        let top = HEIGHT as f32 / 2. - Self::OPTION_SPACING * 2.;
+        Self::draw_centered_text("PAUSED", top, Self::TITLE_FONT_SIZE, WHITE);
+
+        for (i, option) in Self::OPTIONS.iter().enumerate() {
+            let label = match option {
+                PauseOption::Resume => "Resume",
+                PauseOption::Restart => "Restart",
+                PauseOption::Mute if muted => "Unmute",
+                PauseOption::Mute => "Mute",
+                PauseOption::Quit => "Quit",
+            };
+            let color = if i == self.selected { YELLOW } else { WHITE };
+            let y = top + Self::OPTION_SPACING * (i + 1) as f32;
+
+            Self::draw_centered_text(label, y, Self::OPTION_FONT_SIZE, color);
+        }
+    }
+
+    fn draw_centered_text(text: &str, y: f32, font_size: u16, color: Color) {
+        let text_width = measure_text(text, None, font_size, 1.).width;
+        draw_text(
+            text,
+            (WIDTH as f32 - text_width) / 2.,
+            y,
+            font_size as f32,
+            color,
+        );
+    }
+}
@@ -1,7 +1,11 @@
-use crate::{child::Child, resources::Resources, road::Road, row::Row, ROW_HEIGHT};
-use macroquad::{audio::play_sound_once, prelude::collections::storage, texture::Texture2D};
+use crate::{
+    child::Child, resources::Resources, row::Row, row_pool::RowPool, row_rng::RowRng,
+    sound::play_sound_once, terrain::Terrain, ROW_HEIGHT,
+};
+use bunner_core::row_sequence;
+use macroquad::{prelude::collections::storage, texture::Texture2D};
 
-#[derive(Clone)]
+#[derive(Clone, Default)]
 pub struct Pavement {
     index: i32,
     y: i32,
@@ -21,6 +25,10 @@ impl Row for Pavement {
         self.children.as_mut()
     }
 
+    fn terrain(&self) -> Terrain {
+        Terrain::Pavement
+    }
+
     fn image(&self) -> Texture2D {
         *storage::get::<Resources>()
             .side_textures
@@ -32,26 +40,32 @@ impl Row for Pavement {
         play_sound_once(storage::get::<Resources>().sidewalk_sound);
     }
 
-    fn next(&self) -> Box<dyn Row> {
-        let y = self.y - ROW_HEIGHT;
-        if self.index < 2 {
-            Box::new(Pavement::new(self.index + 1, y))
-        } else {
-            Box::new(Road::empty(y))
-        }
+    fn next(&self, pool: &mut RowPool, rng: &mut RowRng) -> Box<dyn Row> {
+        pool.build(
+            row_sequence::after_pavement(self.index),
+            0,
+            self.y - ROW_HEIGHT,
+            rng,
+        )
+    }
+
+    fn recycle(self: Box<Self>, pool: &mut RowPool) {
+        pool.pavements.push(self);
+    }
+
+    fn is_safe(&self) -> bool {
+        true
     }
 }
 
 impl Pavement {
-    pub fn new(index: i32, y: i32) -> Self {
-        Self {
-            index,
-            y,
-            children: Vec::new(),
-        }
+    pub fn new(pool: &mut RowPool, index: i32, y: i32) -> Box<Self> {
+        let (mut row, children) = RowPool::take(&mut pool.pavements);
+        *row = Self { index, y, children };
+        row
     }
 
-    pub fn empty(y: i32) -> Self {
-        Self::new(0, y)
+    pub fn empty(pool: &mut RowPool, y: i32) -> Box<Self> {
+        Self::new(pool, 0, y)
     }
 }
@@ -1,13 +1,10 @@
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+use nanoserde::{DeRon, SerRon};
+
+#[derive(Clone, Copy, Default, Debug, Eq, PartialEq, DeRon, SerRon)]
 pub enum PlayerDirection {
     Up,
     Right,
+    #[default]
     Down,
     Left,
 }
-
-impl Default for PlayerDirection {
-    fn default() -> Self {
-        PlayerDirection::Down
-    }
-}
@@ -0,0 +1,110 @@
+use crate::{position::Position, power_up_kind::PowerUpKind, TILE_WIDTH};
+use macroquad::{
+    color::colors::{BLACK, WHITE},
+    shapes::{draw_circle, draw_circle_lines, draw_rectangle, draw_rectangle_lines},
+    text::{draw_text, measure_text},
+};
+
+// Rust: Not in the original game. Pickups are occasionally spawned on the grass rows; when the bunner reaches one,
+// the power-up is active for a limited time (replacing the previous one, if any).
+//
+
+/// A pickup, lying on a grass row; the position is relative to the row.
+#[derive(Clone, Copy)]
+pub struct PowerUp {
+    pub kind: PowerUpKind,
+    pub position: Position,
+}
+
+impl PowerUp {
+    const RADIUS: f32 = 12.;
+    const FONT_SIZE: u16 = 24;
+
+    pub fn new(kind: PowerUpKind, position: Position) -> Self {
+        Self { kind, position }
+    }
+
+    /// Whether the bunner, at the given x, is on the pickup cell.
+    pub fn is_reached(&self, x: i32) -> bool {
+        (x - self.position.x).abs() < TILE_WIDTH / 2
+    }
+
+    pub fn draw(&self, offset_x: i32, offset_y: i32) {
+        let x = (self.position.x + offset_x) as f32;
+        let y = (self.position.y + offset_y - TILE_WIDTH / 2) as f32;
+
+        draw_circle(x, y, Self::RADIUS, self.kind.color());
+        draw_circle_lines(x, y, Self::RADIUS, 2., BLACK);
+
+        let symbol = self.kind.symbol();
+        let text_size = measure_text(symbol, None, Self::FONT_SIZE, 1.);
+        draw_text(
+            symbol,
+            x - text_size.width / 2.,
+            y + text_size.height / 2.,
+            Self::FONT_SIZE as f32,
+            BLACK,
+        );
+    }
+}
+
+/// The power-up of the bunner, with the remaining updates.
+#[derive(Clone, Copy)]
+pub struct ActivePowerUp {
+    pub kind: PowerUpKind,
+    pub timer: i32,
+}
+
+impl ActivePowerUp {
+    /// Below the score.
+    pub const HUD_Y: i32 = 50;
+
+    const HUD_FONT_SIZE: u16 = 20;
+    const HUD_BAR_WIDTH: f32 = 100.;
+    const HUD_BAR_HEIGHT: f32 = 8.;
+
+    pub fn new(kind: PowerUpKind) -> Self {
+        Self {
+            kind,
+            timer: kind.duration(),
+        }
+    }
+
+    /// Returns false once expired.
+    pub fn update(&mut self) -> bool {
+        self.timer -= 1;
+        self.timer > 0
+    }
+
+    /// Draws the name and the remaining time, as a shrinking bar, with the top left at the given coordinates.
+    pub fn draw_hud(&self, x: i32, y: i32) {
+        let (x, y) = (x as f32, y as f32);
+        let color = self.kind.color();
+
+        draw_text(
+            self.kind.name(),
+            x,
+            y + Self::HUD_FONT_SIZE as f32,
+            Self::HUD_FONT_SIZE as f32,
+            WHITE,
+        );
+
+        let bar_y = y + Self::HUD_FONT_SIZE as f32 + 4.;
+        let ratio = self.timer as f32 / self.kind.duration() as f32;
+        draw_rectangle(
+            x,
+            bar_y,
+            Self::HUD_BAR_WIDTH * ratio,
+            Self::HUD_BAR_HEIGHT,
+            color,
+        );
+        draw_rectangle_lines(
+            x,
+            bar_y,
+            Self::HUD_BAR_WIDTH,
+            Self::HUD_BAR_HEIGHT,
+            2.,
+            WHITE,
+        );
+    }
+}
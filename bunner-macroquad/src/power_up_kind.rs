@@ -0,0 +1,53 @@
+use macroquad::color::Color;
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum PowerUpKind {
+    /// Cars and trains don't splat the bunner, and the eagle doesn't catch it.
+    Invincibility,
+    /// Hops cover two cells, jumping over the row (or cell) in between.
+    DoubleHop,
+    /// The rows move at half speed.
+    SlowTime,
+}
+
+impl PowerUpKind {
+    pub const ALL: [PowerUpKind; 3] = [
+        PowerUpKind::Invincibility,
+        PowerUpKind::DoubleHop,
+        PowerUpKind::SlowTime,
+    ];
+
+    /// In updates.
+    pub fn duration(&self) -> i32 {
+        match self {
+            PowerUpKind::Invincibility => 300,
+            PowerUpKind::DoubleHop => 360,
+            PowerUpKind::SlowTime => 300,
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            PowerUpKind::Invincibility => "INVINCIBLE",
+            PowerUpKind::DoubleHop => "DOUBLE HOP",
+            PowerUpKind::SlowTime => "SLOW TIME",
+        }
+    }
+
+    /// Letter displayed on the pickup.
+    pub fn symbol(&self) -> &'static str {
+        match self {
+            PowerUpKind::Invincibility => "I",
+            PowerUpKind::DoubleHop => "D",
+            PowerUpKind::SlowTime => "S",
+        }
+    }
+
+    pub fn color(&self) -> Color {
+        match self {
+            PowerUpKind::Invincibility => Color::new(1., 0.85, 0., 1.),
+            PowerUpKind::DoubleHop => Color::new(0.3, 0.9, 0.3, 1.),
+            PowerUpKind::SlowTime => Color::new(0.3, 0.6, 1., 1.),
+        }
+    }
+}
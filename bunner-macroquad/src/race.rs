@@ -0,0 +1,190 @@
+use crate::{
+    bunner::Bunner,
+    drawing::{display_number, NumberAlign, NumberColor},
+    eagle_pressure,
+    game::Game,
+    night_mode::NightMode,
+    position::Position,
+    power_up::ActivePowerUp,
+    resources::Resources,
+    virtual_screen::VirtualScreen,
+    HEIGHT, WIDTH,
+};
+use macroquad::{
+    camera::set_camera,
+    color::colors::{BLACK, WHITE},
+    experimental::collections::storage,
+    input::KeyCode,
+    math::{vec2, Rect},
+    shapes::draw_rectangle,
+    texture::{draw_texture_ex, DrawTextureParams},
+};
+use std::collections::VecDeque;
+
+// Rust: Not in the original game. Two players race on the same rows, each in their own game, displayed side by
+// side; the first player reaching the target row wins. If a player dies, the other one wins.
+//
+pub struct Race {
+    games: [Game; 2],
+}
+
+impl Race {
+    pub const TARGET_SCORE: u32 = 100;
+
+    const VIEWPORT_WIDTH: i32 = WIDTH / 2;
+    const DIVIDER_WIDTH: i32 = 2;
+
+    /// Keys of the second player; they're translated to the arrow keys, used by the first player.
+    const PLAYER_2_KEYS: [(KeyCode, KeyCode); 4] = [
+        (KeyCode::W, KeyCode::Up),
+        (KeyCode::D, KeyCode::Right),
+        (KeyCode::S, KeyCode::Down),
+        (KeyCode::A, KeyCode::Left),
+    ];
+
+    pub fn new(seed: u64) -> Self {
+        let mut games = [Self::new_game(seed), Self::new_game(seed)];
+        games[1].play_looped_sounds = false;
+
+        Self { games }
+    }
+
+    fn new_game(seed: u64) -> Game {
+        Game::new(Some(Bunner::new(Position::new(240, -320))), seed)
+    }
+
+    pub fn update(&mut self, input_queue: VecDeque<KeyCode>) {
+        let player_2_queue = input_queue
+            .iter()
+            .filter_map(|key_code| {
+                Self::PLAYER_2_KEYS
+                    .iter()
+                    .find(|(player_2_key, _)| player_2_key == key_code)
+                    .map(|(_, arrow_key)| *arrow_key)
+            })
+            .collect();
+
+        self.games[0].update(input_queue);
+        self.games[1].update(player_2_queue);
+    }
+
+    pub fn stop_looped_sounds(&mut self) {
+        for game in self.games.iter_mut() {
+            game.stop_looped_sounds();
+        }
+    }
+
+    pub fn is_over(&self) -> bool {
+        self.games
+            .iter()
+            .any(|game| game.score() >= Self::TARGET_SCORE || game.game_over())
+    }
+
+    /// Index of the winning player, if the race is over and it isn't a draw.
+    pub fn winner(&self) -> Option<usize> {
+        let finished = [0, 1].map(|i| self.games[i].score() >= Self::TARGET_SCORE);
+        let dead = [0, 1].map(|i| self.games[i].game_over());
+
+        match (finished[0], finished[1], dead[0], dead[1]) {
+            (true, false, _, _) => Some(0),
+            (false, true, _, _) => Some(1),
+            (false, false, false, true) => Some(0),
+            (false, false, true, false) => Some(1),
+            _ => None,
+        }
+    }
+
+    /// Each viewport shows the half of the game around the player.
+    fn view_x(game: &Game) -> i32 {
+        let player_x = game.bunner.as_ref().map_or(WIDTH / 2, |b| b.position.x);
+        (player_x - Self::VIEWPORT_WIDTH / 2).clamp(0, WIDTH - Self::VIEWPORT_WIDTH)
+    }
+
+    pub fn draw(&self, is_over: bool, night_mode: Option<&NightMode>, screen: &VirtualScreen) {
+        let resources = storage::get::<Resources>();
+        let winner = self.winner();
+
+        for (i, game) in self.games.iter().enumerate() {
+            let viewport_x = i as i32 * Self::VIEWPORT_WIDTH;
+            let view_x = Self::view_x(game);
+
+            let mut camera = screen.camera(Rect::new(
+                view_x as f32,
+                0.,
+                Self::VIEWPORT_WIDTH as f32,
+                HEIGHT as f32,
+            ));
+            camera.viewport = Some((viewport_x, 0, Self::VIEWPORT_WIDTH, HEIGHT));
+            set_camera(&camera);
+
+            game.draw();
+        }
+
+        // Restore the full screen viewport
+        screen.begin();
+
+        if let Some(night_mode) = night_mode {
+            for (i, game) in self.games.iter().enumerate() {
+                let viewport_x = i as i32 * Self::VIEWPORT_WIDTH;
+                let lights = game.lights(viewport_x - Self::view_x(game), -game.scroll_pos);
+                let region = Rect::new(
+                    viewport_x as f32,
+                    0.,
+                    Self::VIEWPORT_WIDTH as f32,
+                    HEIGHT as f32,
+                );
+                night_mode.draw(region, &lights, screen);
+            }
+        }
+
+        draw_rectangle(
+            (Self::VIEWPORT_WIDTH - Self::DIVIDER_WIDTH / 2) as f32,
+            0.,
+            Self::DIVIDER_WIDTH as f32,
+            HEIGHT as f32,
+            BLACK,
+        );
+
+        for (i, game) in self.games.iter().enumerate() {
+            let viewport_x = i as i32 * Self::VIEWPORT_WIDTH;
+
+            display_number(
+                game.score(),
+                NumberColor::Blue,
+                viewport_x,
+                0,
+                NumberAlign::Left,
+            );
+            display_number(
+                Self::TARGET_SCORE,
+                NumberColor::Yellow,
+                viewport_x + Self::VIEWPORT_WIDTH - 10,
+                0,
+                NumberAlign::Right,
+            );
+            if let Some(power_up) = game.bunner.as_ref().and_then(|b| b.power_up()) {
+                power_up.draw_hud(viewport_x, ActivePowerUp::HUD_Y);
+            }
+            if let Some(bunner) = &game.bunner {
+                eagle_pressure::draw_hud(
+                    bunner.eagle_pressure(),
+                    viewport_x + Self::VIEWPORT_WIDTH - 10,
+                    ActivePowerUp::HUD_Y,
+                );
+            }
+
+            if is_over && winner != Some(i) {
+                draw_texture_ex(
+                    resources.gameover_texture,
+                    viewport_x as f32,
+                    (HEIGHT / 4) as f32,
+                    WHITE,
+                    DrawTextureParams {
+                        dest_size: Some(vec2(Self::VIEWPORT_WIDTH as f32, (HEIGHT / 2) as f32)),
+                        ..Default::default()
+                    },
+                );
+            }
+        }
+    }
+}
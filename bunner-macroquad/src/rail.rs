@@ -1,16 +1,13 @@
 use crate::{
-    child::Child, player_state::PlayerState, position::Position, resources::Resources, road::Road,
-    row::Row, train::Train, water::Water, HEIGHT, ROW_HEIGHT, WIDTH,
+    child::Child, express_rail::ExpressRail, player_state::PlayerState, position::Position,
+    resources::Resources, row::Row, row_pool::RowPool, row_rng::RowRng, sound::play_sound_once,
+    terrain::Terrain, train::Train, HEIGHT, ROW_HEIGHT, WIDTH,
 };
+use bunner_core::{collision, row_sequence};
 
-use macroquad::{
-    audio::play_sound_once,
-    prelude::collections::storage,
-    rand::{self, ChooseRandom},
-    texture::Texture2D,
-};
+use macroquad::{prelude::collections::storage, rand, texture::Texture2D};
 
-#[derive(Clone)]
+#[derive(Clone, Default)]
 pub struct Rail {
     index: i32,
     y: i32,
@@ -30,22 +27,23 @@ impl Row for Rail {
         self.children.as_mut()
     }
 
-    fn update(&mut self, scroll_pos: i32, _bunner_pos: Option<Position>) {
+    fn update(&mut self, scroll_pos: i32, _bunner_pos: Option<Position>, rng: &mut RowRng) {
         self.update_children();
         if self.index == 2 {
             self.children
                 .retain(|c| c.x() > -1000 && c.x() < WIDTH + 1000);
             if self.y < scroll_pos + HEIGHT
                 && self.children.is_empty()
-                && rand::gen_range::<u8>(0, 100) < 1
+                && rng.gen_range::<u8>(0, 100) < 1
             {
-                let dx = *vec![-20, 20].choose().unwrap();
+                let dx = *rng.choose(&[-20, 20]).unwrap();
                 let position = if dx < 0 {
                     Position::new(WIDTH + 1000, 47)
                 } else {
                     Position::new(WIDTH - 1000, 47)
                 };
-                self.children.push(Child::Train(Train::new(dx, position)));
+                self.children
+                    .push(Child::Train(Train::new(dx, position, rng)));
                 play_sound_once(storage::get::<Resources>().bell_sound);
                 let train_sound = *storage::get::<Resources>()
                     .train_sounds
@@ -56,6 +54,10 @@ impl Row for Rail {
         }
     }
 
+    fn terrain(&self) -> Terrain {
+        Terrain::Rail
+    }
+
     fn image(&self) -> Texture2D {
         *storage::get::<Resources>()
             .rail_textures
@@ -67,35 +69,32 @@ impl Row for Rail {
         play_sound_once(storage::get::<Resources>().grass_sound);
     }
 
-    fn next(&self) -> Box<dyn Row> {
+    fn next(&self, pool: &mut RowPool, rng: &mut RowRng) -> Box<dyn Row> {
         let y = self.y - ROW_HEIGHT;
-        if self.index < 3 {
-            Box::new(Rail::new(self.index + 1, y))
-        } else if rand::gen_range::<u8>(0, 2) == 0 {
-            Box::new(Road::empty(y))
+        if self.index == 1 && ExpressRail::generate(y, rng) {
+            ExpressRail::new(pool, y)
         } else {
-            Box::new(Water::empty(y))
+            pool.build(row_sequence::after_rail(self.index, rng), 0, y, rng)
         }
     }
 
+    fn recycle(self: Box<Self>, pool: &mut RowPool) {
+        pool.rails.push(self);
+    }
+
     fn check_collision(&self, x: i32) -> PlayerState {
-        if self.index == 2 && self.collide(x, 0) {
-            return PlayerState::Splat(8);
-        }
-        PlayerState::Alive
+        collision::rail_state(self.index, self.collide(x, 0))
     }
 }
 
 impl Rail {
-    pub fn new(index: i32, y: i32) -> Self {
-        Self {
-            index,
-            y,
-            children: Vec::new(),
-        }
+    pub fn new(pool: &mut RowPool, index: i32, y: i32) -> Box<Self> {
+        let (mut row, children) = RowPool::take(&mut pool.rails);
+        *row = Self { index, y, children };
+        row
     }
 
-    pub fn empty(y: i32) -> Self {
-        Self::new(0, y)
+    pub fn empty(pool: &mut RowPool, y: i32) -> Box<Self> {
+        Self::new(pool, 0, y)
     }
 }
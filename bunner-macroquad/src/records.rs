@@ -0,0 +1,108 @@
+#[cfg(not(target_arch = "wasm32"))]
+use std::fs;
+
+// In the browser, the records are stored in the local storage, via the functions defined in `index.html`.
+//
+#[cfg(target_arch = "wasm32")]
+extern "C" {
+    fn bunner_records_len() -> u32;
+    fn bunner_records_get(index: u32) -> u32;
+    fn bunner_records_set(scores: *const u32, len: u32);
+}
+
+/// Top scores, persisted across launches.
+///
+/// The score is the number of rows the player advanced, so the best distance is the top score. The file format is
+/// one score per line, which is compatible with the previous file, storing only the high score.
+#[derive(Default)]
+pub struct Records {
+    /// Sorted in descending order.
+    top_scores: Vec<u32>,
+}
+
+impl Records {
+    #[cfg(not(target_arch = "wasm32"))]
+    const FILENAME: &'static str = "high.txt";
+    const MAX_SCORES: usize = 5;
+
+    pub fn load() -> Self {
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            if let Ok(content) = fs::read_to_string(Self::FILENAME) {
+                let mut records = Self::default();
+                for score in content.lines().filter_map(|line| line.trim().parse().ok()) {
+                    records.add_score(score);
+                }
+                return records;
+            }
+        }
+
+        #[cfg(target_arch = "wasm32")]
+        {
+            let mut records = Self::default();
+            // SAFETY: The functions only access the browser local storage.
+            unsafe {
+                for i in 0..bunner_records_len() {
+                    records.add_score(bunner_records_get(i));
+                }
+            }
+            return records;
+        }
+
+        #[allow(unreachable_code)]
+        Self::default()
+    }
+
+    pub fn save(&self) {
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            let content: String = self
+                .top_scores
+                .iter()
+                .map(|score| format!("{}\n", score))
+                .collect();
+            fs::write(Self::FILENAME, content).ok();
+        }
+
+        #[cfg(target_arch = "wasm32")]
+        // SAFETY: The function copies the scores from the Wasm memory, without retaining the pointer.
+        unsafe {
+            bunner_records_set(self.top_scores.as_ptr(), self.top_scores.len() as u32);
+        }
+    }
+
+    /// Returns the position of the score in the top scores, if it entered them; zero scores are not recorded.
+    pub fn add_score(&mut self, score: u32) -> Option<usize> {
+        if score == 0 {
+            return None;
+        }
+
+        let position = self
+            .top_scores
+            .partition_point(|top_score| *top_score >= score);
+
+        if position < Self::MAX_SCORES {
+            self.top_scores.insert(position, score);
+            self.top_scores.truncate(Self::MAX_SCORES);
+            Some(position)
+        } else {
+            None
+        }
+    }
+
+    /// Removes the score at the given position, e.g. when the game is continued.
+    pub fn remove_score(&mut self, position: usize) {
+        if position < self.top_scores.len() {
+            self.top_scores.remove(position);
+        }
+    }
+
+    pub fn top_scores(&self) -> &[u32] {
+        &self.top_scores
+    }
+
+    /// Since the score is the distance, this is also the furthest row reached.
+    pub fn high_score(&self) -> u32 {
+        self.top_scores.first().copied().unwrap_or(0)
+    }
+}
@@ -0,0 +1,19 @@
+use std::sync::atomic::{AtomicBool, Ordering};
+
+// Rust: Not in the original game. Reduced motion mode, an accessibility option toggled on the title screen, which is
+// checked by each visual effect:
+//
+// - on impacts, the screen doesn't shake, and no particles burst (nor ripples spread); see `Effects`;
+// - the camera doesn't look ahead, and its scroll speed changes gradually, up to a lower maximum; see `Camera`;
+// - the flashing and blinking elements are steady: the express rail lights, the invincible bunner, the warning of
+//   the diving logs, and the start prompt of the title screen.
+
+static REDUCED_MOTION: AtomicBool = AtomicBool::new(false);
+
+pub fn set_enabled(enabled: bool) {
+    REDUCED_MOTION.store(enabled, Ordering::Relaxed);
+}
+
+pub fn is_enabled() -> bool {
+    REDUCED_MOTION.load(Ordering::Relaxed)
+}
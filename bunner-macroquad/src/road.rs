@@ -1,19 +1,18 @@
 use crate::{
     active_row::ActiveRow, actor::Actor, car::Car, car::CarSound, car::TrafficSound, child::Child,
-    grass::Grass, mover::Mover, pavement::Pavement, player_state::PlayerState, position::Position,
-    rail::Rail, resources::Resources, row::Row, row::RowSound, ROW_HEIGHT, WIDTH,
+    difficulty, mover::Mover, player_state::PlayerState, position::Position, resources::Resources,
+    row::Row, row::RowSound, row_pool::RowPool, row_rng::RowRng, sound::play_sound_once,
+    terrain::Terrain, ROW_HEIGHT,
 };
+use bunner_core::{collision, row_sequence, traffic};
 
-use macroquad::{
-    audio::play_sound_once,
-    prelude::collections::storage,
-    rand::{self, ChooseRandom},
-    texture::Texture2D,
-};
+use macroquad::{prelude::collections::storage, texture::Texture2D};
 
-#[derive(Clone)]
+#[derive(Clone, Default)]
 pub struct Road {
     dx: i32,
+    /// Rust: Defined by the difficulty curve, at the row position.
+    traffic_density: f32,
     timer: f32,
     index: i32,
     y: i32,
@@ -33,15 +32,15 @@ impl Row for Road {
         self.children.as_mut()
     }
 
-    fn update(&mut self, _scroll_pos: i32, bunner_pos: Option<Position>) {
+    fn update(&mut self, _scroll_pos: i32, bunner_pos: Option<Position>, rng: &mut RowRng) {
         self.update_children();
-        self.children.retain(|c| c.x() > -70 && c.x() < WIDTH + 70);
+        self.children.retain(|c| traffic::is_on_row(c.x()));
         self.timer -= 1.;
 
         // Create new child objects on a random interval
         if self.timer < 0. {
-            self.children.push(self.create_random_child(self.dx));
-            self.timer = self.random_interval(self.dx);
+            self.children.push(self.create_random_child(self.dx, rng));
+            self.timer = self.random_interval(self.dx, self.traffic_density, rng);
         }
 
         if let Some(bunner_pos) = bunner_pos {
@@ -69,6 +68,10 @@ impl Row for Road {
         }
     }
 
+    fn terrain(&self) -> Terrain {
+        Terrain::Road
+    }
+
     fn image(&self) -> Texture2D {
         *storage::get::<Resources>()
             .road_textures
@@ -80,43 +83,24 @@ impl Row for Road {
         play_sound_once(storage::get::<Resources>().road_sound);
     }
 
-    fn next(&self) -> Box<dyn Row> {
+    fn next(&self, pool: &mut RowPool, rng: &mut RowRng) -> Box<dyn Row> {
         let y = self.y - ROW_HEIGHT;
-        if self.index == 0 {
-            Box::new(Road::new(self.dx, 1, y))
-        } else if self.index < 5 {
-            let random = rand::gen_range::<u8>(0, 100);
-            if random < 80 {
-                Box::new(Road::new(self.dx, self.index + 1, y))
-            } else if random < 88 {
-                Box::new(Grass::without_hedge(rand::gen_range(0, 7), y))
-            } else if random < 94 {
-                Box::new(Rail::empty(y))
-            } else {
-                Box::new(Pavement::empty(y))
-            }
-        } else {
-            let random = rand::gen_range::<u8>(0, 100);
-            if random < 60 {
-                Box::new(Grass::without_hedge(rand::gen_range(0, 7), y))
-            } else if random < 90 {
-                Box::new(Rail::empty(y))
-            } else {
-                Box::new(Pavement::empty(y))
-            }
-        }
+        // Rust: The probability that the section ends is scaled by the difficulty curve.
+        let safe_row_frequency = difficulty::point_at(y).safe_row_frequency;
+        pool.build(
+            row_sequence::after_road(self.index, safe_row_frequency, rng),
+            self.dx,
+            y,
+            rng,
+        )
     }
 
-    fn allow_movement(&self, x: i32) -> bool {
-        (16..=WIDTH - 16).contains(&x)
+    fn recycle(self: Box<Self>, pool: &mut RowPool) {
+        pool.roads.push(self);
     }
 
     fn check_collision(&self, x: i32) -> PlayerState {
-        if self.collide(x, 0) {
-            PlayerState::Splat(0)
-        } else {
-            PlayerState::Alive
-        }
+        collision::road_state(self.collide(x, 0))
     }
 
     fn push(&self) -> i32 {
@@ -129,8 +113,8 @@ impl Row for Road {
 }
 
 impl ActiveRow for Road {
-    fn build_child(dx: i32, position: Position) -> Child {
-        Child::Car(Car::new(dx, position))
+    fn build_child(dx: i32, position: Position, rng: &mut RowRng) -> Child {
+        Child::Car(Car::new(dx, position, rng))
     }
 }
 
@@ -149,26 +133,33 @@ impl Road {
             sound: CarSound::Zoom,
         },
     ];
-    const DXS: &'static [i32] = &[-5, -4, -3, -2, -1, 1, 2, 3, 4, 5];
 
-    pub fn new(previous_dx: i32, index: i32, y: i32) -> Self {
+    pub fn new(
+        pool: &mut RowPool,
+        previous_dx: i32,
+        index: i32,
+        y: i32,
+        rng: &mut RowRng,
+    ) -> Box<Self> {
         // Populate the row with child objects (cars or logs). Without this, the row would initially be empty.
-        let dx = **Self::DXS
-            .iter()
-            .filter(|&dx| *dx != previous_dx)
-            .collect::<Vec<&i32>>()
-            .choose()
-            .unwrap();
-        Self {
+        // Rust: The maximum speed is defined by the difficulty curve.
+        let difficulty_point = difficulty::point_at(y);
+        let dx = traffic::road_dx(previous_dx, difficulty_point.max_car_speed, rng);
+
+        let (mut row, mut children) = RowPool::take(&mut pool.roads);
+        Self::build_children(dx, difficulty_point.traffic_density, &mut children, rng);
+        *row = Self {
             dx,
+            traffic_density: difficulty_point.traffic_density,
             timer: 0.,
             index,
             y,
-            children: Self::build_children(dx),
-        }
+            children,
+        };
+        row
     }
 
-    pub fn empty(y: i32) -> Self {
-        Self::new(0, 0, y)
+    pub fn empty(pool: &mut RowPool, y: i32, rng: &mut RowRng) -> Box<Self> {
+        Self::new(pool, 0, 0, y, rng)
     }
 }
@@ -1,4 +1,8 @@
-use crate::{child::Child, player_state::PlayerState, position::Position, WIDTH};
+use crate::{
+    child::Child, player_state::PlayerState, position::Position, power_up_kind::PowerUpKind,
+    row_pool::RowPool, row_rng::RowRng, terrain::Terrain,
+};
+use bunner_core::collision;
 use macroquad::{
     color::colors::WHITE,
     texture::{draw_texture, Texture2D},
@@ -9,7 +13,7 @@ pub trait Row {
     fn children(&self) -> &[Child];
     fn children_mut(&mut self) -> &mut Vec<Child>;
 
-    fn update(&mut self, _scroll_pos: i32, _bunner_pos: Option<Position>) {
+    fn update(&mut self, _scroll_pos: i32, _bunner_pos: Option<Position>, _rng: &mut RowRng) {
         self.update_children();
     }
 
@@ -21,6 +25,9 @@ pub trait Row {
 
     fn image(&self) -> Texture2D;
 
+    /// Rust: Not in the original game; used by the run statistics.
+    fn terrain(&self) -> Terrain;
+
     fn draw(&self, offset_x: i32, offset_y: i32) {
         let (x, y) = self.draw_row(offset_x, offset_y);
         self.draw_children(x, y);
@@ -42,31 +49,47 @@ pub trait Row {
 
     fn play_sound(&self);
 
-    fn next(&self) -> Box<dyn Row>;
+    fn next(&self, pool: &mut RowPool, rng: &mut RowRng) -> Box<dyn Row>;
+
+    /// Returns the row to the pool, so that it can be reused; see `RowPool`.
+    fn recycle(self: Box<Self>, pool: &mut RowPool);
 
     fn check_collision(&self, _x: i32) -> PlayerState {
         PlayerState::Alive
     }
 
     fn allow_movement(&self, x: i32) -> bool {
-        (16..=WIDTH - 16).contains(&x)
+        collision::is_within_bounds(x)
     }
 
     fn collide(&self, x: i32, margin: i32) -> bool {
-        for child in self.children().iter() {
-            if x >= child.x() - (child.width() / 2) - margin
-                && x < child.x() + (child.width() / 2) + margin
-            {
-                return true;
-            }
-        }
-        false
+        let children = self
+            .children()
+            .iter()
+            .filter(|child| !child.is_submerged())
+            .map(|child| (child.x(), child.width()));
+        collision::collide(x, margin, children)
     }
 
     fn push(&self) -> i32 {
         0
     }
 
+    /// Rust: Not in the original game. Whether the row can't become lethal, so that it can be used as checkpoint.
+    fn is_safe(&self) -> bool {
+        false
+    }
+
+    /// Rust: Not in the original game. Removes the pickup at the given x, if any, returning its power-up.
+    fn take_power_up(&mut self, _x: i32) -> Option<PowerUpKind> {
+        None
+    }
+
+    /// Rust: Not in the original game; used by the landing effects.
+    fn is_water(&self) -> bool {
+        false
+    }
+
     fn sound(&self) -> Option<RowSound> {
         None
     }
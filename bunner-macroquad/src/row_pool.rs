@@ -0,0 +1,53 @@
+use crate::{
+    child::Child, dirt::Dirt, express_rail::ExpressRail, grass::Grass, pavement::Pavement,
+    rail::Rail, road::Road, row::Row, row_rng::RowRng, water::Water,
+};
+use bunner_core::row_sequence::NextRow;
+use std::mem;
+
+// Rust: Not in the original game. Rows are continuously created and removed while the game scrolls; the removed
+// ones are kept in the pool, and reused (along with their children storage) for the new rows of the same type, so
+// that in the steady state, no heap allocations are performed. This matters in particular for the web build.
+//
+#[derive(Default)]
+pub struct RowPool {
+    pub dirts: Vec<Box<Dirt>>,
+    pub express_rails: Vec<Box<ExpressRail>>,
+    pub grasses: Vec<Box<Grass>>,
+    pub pavements: Vec<Box<Pavement>>,
+    pub rails: Vec<Box<Rail>>,
+    pub roads: Vec<Box<Road>>,
+    pub waters: Vec<Box<Water>>,
+}
+
+impl RowPool {
+    /// Returns a row from the given pool (or a new one, if empty), along with its (emptied) children storage; the
+    /// caller is expected to overwrite the row.
+    pub fn take<T: Row + Default>(rows: &mut Vec<Box<T>>) -> (Box<T>, Vec<Child>) {
+        let mut row = rows.pop().unwrap_or_default();
+        let mut children = mem::take(row.children_mut());
+        children.clear();
+
+        (row, children)
+    }
+
+    /// Builds the next row chosen by the generation rules; the speed of roads and rivers alternates direction
+    /// relative to the previous one. A grass row is built without hedges, since the grass rows continuing the
+    /// hedges of the previous one are built by `Grass` itself.
+    pub fn build(
+        &mut self,
+        next: NextRow,
+        previous_dx: i32,
+        y: i32,
+        rng: &mut RowRng,
+    ) -> Box<dyn Row> {
+        match next {
+            NextRow::Grass(index) => Grass::without_hedge(self, index, y, rng),
+            NextRow::Dirt(index) => Dirt::new(self, index, y),
+            NextRow::Road(index) => Road::new(self, previous_dx, index, y, rng),
+            NextRow::Pavement(index) => Pavement::new(self, index, y),
+            NextRow::Rail(index) => Rail::new(self, index, y),
+            NextRow::Water(index) => Water::new(self, previous_dx, index, y, rng),
+        }
+    }
+}
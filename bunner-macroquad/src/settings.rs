@@ -0,0 +1,80 @@
+#[cfg(not(target_arch = "wasm32"))]
+use std::fs;
+
+use nanoserde::{DeRon, SerRon};
+
+#[cfg(not(target_arch = "wasm32"))]
+const SETTINGS_FILE: &str = "settings.ron";
+
+/// Rust: Not in the original game. User settings, persisted across runs; fields missing from the file take the
+/// default value (see `Default`). In the browser, the settings are not persisted.
+#[derive(Clone, DeRon, SerRon)]
+#[nserde(default)]
+pub struct Settings {
+    /// The scene is dark, except around the bunner and in front of the cars; see `NightMode`.
+    pub night_mode: bool,
+    /// Accessibility option; see `palette`.
+    pub high_contrast: bool,
+    /// Accessibility option; see `reduced_motion`.
+    pub reduced_motion: bool,
+    /// Sounds and music are silenced; toggled from the pause menu.
+    pub muted: bool,
+    /// Names of the keys bound to the hops (see `HopKeys`).
+    #[nserde(default = "Up")]
+    pub hop_up_key: String,
+    #[nserde(default = "Right")]
+    pub hop_right_key: String,
+    #[nserde(default = "Down")]
+    pub hop_down_key: String,
+    #[nserde(default = "Left")]
+    pub hop_left_key: String,
+    /// Holding a hop key repeats the hop.
+    pub hold_to_repeat: bool,
+    /// Updates before a held hop key repeats the hop.
+    #[nserde(default = 15)]
+    pub repeat_delay: u32,
+    /// Base URL of the online leaderboard; empty if not used. Only read when built with the `online_leaderboard`
+    /// feature.
+    pub leaderboard_url: String,
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Self {
+            night_mode: false,
+            high_contrast: false,
+            reduced_motion: false,
+            muted: false,
+            hop_up_key: "Up".to_string(),
+            hop_right_key: "Right".to_string(),
+            hop_down_key: "Down".to_string(),
+            hop_left_key: "Left".to_string(),
+            hold_to_repeat: false,
+            repeat_delay: 15,
+            leaderboard_url: String::new(),
+        }
+    }
+}
+
+impl Settings {
+    /// If the file is missing or invalid, the default settings are used.
+    pub fn load() -> Self {
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            fs::read_to_string(SETTINGS_FILE)
+                .ok()
+                .and_then(|content| Self::deserialize_ron(&content).ok())
+                .unwrap_or_default()
+        }
+
+        #[cfg(target_arch = "wasm32")]
+        Self::default()
+    }
+
+    pub fn save(&self) {
+        #[cfg(not(target_arch = "wasm32"))]
+        if let Err(error) = fs::write(SETTINGS_FILE, self.serialize_ron()) {
+            eprintln!("Couldn't save the settings: {}", error);
+        }
+    }
+}
@@ -0,0 +1,22 @@
+use macroquad::audio::{self, Sound};
+use std::sync::atomic::{AtomicBool, Ordering};
+
+// Rust: Not in the original game. The sounds can be muted from the pause overlay; since Macroquad has no master
+// volume, the sound effects are played via this function, which skips them while muted. The music and the looped
+// sounds are silenced by their owners.
+
+static MUTED: AtomicBool = AtomicBool::new(false);
+
+pub fn is_muted() -> bool {
+    MUTED.load(Ordering::Relaxed)
+}
+
+pub fn set_muted(muted: bool) {
+    MUTED.store(muted, Ordering::Relaxed);
+}
+
+pub fn play_sound_once(sound: Sound) {
+    if !is_muted() {
+        audio::play_sound_once(sound);
+    }
+}
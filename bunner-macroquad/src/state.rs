@@ -3,4 +3,10 @@ pub enum State {
     Menu,
     Play,
     GameOver,
+    Race,
+    RaceOver,
+    /// Rust: Not in the original game; the game (or race) is frozen, and the pause overlay is displayed.
+    Paused,
+    /// Rust: Not in the original game; a recorded run is played back, see `DemoRun`.
+    Demo,
 }
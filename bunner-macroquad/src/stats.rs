@@ -0,0 +1,213 @@
+#[cfg(not(target_arch = "wasm32"))]
+use std::fs;
+
+use crate::{
+    bunner::Bunner, player_state::PlayerState, row::Row, terrain::Terrain, HEIGHT, ROW_HEIGHT,
+    TIME_PER_FRAME, WIDTH,
+};
+use macroquad::{
+    color::{colors::WHITE, Color},
+    shapes::draw_rectangle,
+    text::{draw_text, measure_text},
+};
+use nanoserde::{DeRon, SerRon};
+
+#[cfg(not(target_arch = "wasm32"))]
+const LIFETIME_STATS_FILE: &str = "stats.ron";
+
+const PANEL_COLOR: Color = Color::new(0., 0., 0., 0.7);
+const PANEL_TOP: f32 = (HEIGHT - 330) as f32;
+const PANEL_MARGIN: f32 = 20.;
+const LINE_SPACING: f32 = 26.;
+const FONT_SIZE: u16 = 22;
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum CauseOfDeath {
+    /// Car or train.
+    Vehicle,
+    Water,
+    Eagle,
+}
+
+impl CauseOfDeath {
+    fn from_state(state: PlayerState) -> Option<Self> {
+        match state {
+            PlayerState::Alive => None,
+            PlayerState::Splat(_) => Some(CauseOfDeath::Vehicle),
+            PlayerState::Splash => Some(CauseOfDeath::Water),
+            PlayerState::Eagle(_) => Some(CauseOfDeath::Eagle),
+        }
+    }
+
+    fn description(&self) -> &'static str {
+        match self {
+            CauseOfDeath::Vehicle => "Hit by a vehicle",
+            CauseOfDeath::Water => "Drowned",
+            CauseOfDeath::Eagle => "Caught by the eagle",
+        }
+    }
+}
+
+// Rust: Not in the original game. Statistics of the current run, displayed on the game over screen, and added to
+// the lifetime ones.
+//
+// A near miss is a vehicle passing within `NEAR_MISS_MARGIN` of the bunner, without hitting it; it's counted when
+// the vehicle moves away.
+//
+#[derive(Default)]
+pub struct RunStats {
+    pub hops: u32,
+    pub near_misses: u32,
+    /// Updates spent on each terrain, in the order of `Terrain::ALL`.
+    terrain_time: [u32; 6],
+    pub cause_of_death: Option<CauseOfDeath>,
+    /// Whether a vehicle is currently within the near miss margin.
+    near_miss: bool,
+}
+
+impl RunStats {
+    const NEAR_MISS_MARGIN: i32 = 20;
+
+    /// Invoked after each update of the bunner.
+    pub fn update(&mut self, bunner: &Bunner, rows: &[Box<dyn Row>]) {
+        if !bunner.is_alive() {
+            self.cause_of_death = self
+                .cause_of_death
+                .or_else(|| CauseOfDeath::from_state(bunner.state));
+            return;
+        }
+
+        if bunner.hop_start().is_some() {
+            self.hops += 1;
+        }
+
+        // While hopping, the bunner is between two rows; the time is assigned to the one it's over.
+        let y = bunner.position.y;
+        if let Some(row) = rows
+            .iter()
+            .find(|row| row.y() >= y && row.y() - ROW_HEIGHT < y)
+        {
+            self.terrain_time[row.terrain() as usize] += 1;
+        }
+
+        let near_miss = rows.iter().any(|row| {
+            row.y() == y
+                && matches!(row.terrain(), Terrain::Road | Terrain::Rail)
+                && row.collide(bunner.position.x, Self::NEAR_MISS_MARGIN)
+        });
+        if self.near_miss && !near_miss {
+            self.near_misses += 1;
+        }
+        self.near_miss = near_miss;
+    }
+
+    pub fn terrain_time(&self, terrain: Terrain) -> u32 {
+        self.terrain_time[terrain as usize]
+    }
+}
+
+/// Rust: Not in the original game. Aggregates of all the runs, persisted across launches, along with the records.
+/// In the browser, they're not persisted.
+#[derive(Default, DeRon, SerRon)]
+#[nserde(default)]
+pub struct LifetimeStats {
+    pub runs: u32,
+    pub hops: u32,
+    pub near_misses: u32,
+    pub vehicle_deaths: u32,
+    pub water_deaths: u32,
+    pub eagle_deaths: u32,
+    /// Updates spent on each terrain, in the order of `Terrain::ALL`.
+    pub terrain_time: Vec<u32>,
+}
+
+impl LifetimeStats {
+    /// If the file is missing or invalid, the statistics start from zero.
+    pub fn load() -> Self {
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            fs::read_to_string(LIFETIME_STATS_FILE)
+                .ok()
+                .and_then(|content| Self::deserialize_ron(&content).ok())
+                .unwrap_or_default()
+        }
+
+        #[cfg(target_arch = "wasm32")]
+        Self::default()
+    }
+
+    pub fn save(&self) {
+        #[cfg(not(target_arch = "wasm32"))]
+        if let Err(error) = fs::write(LIFETIME_STATS_FILE, self.serialize_ron()) {
+            eprintln!("Couldn't save the statistics: {}", error);
+        }
+    }
+
+    pub fn add(&mut self, run: &RunStats) {
+        self.runs += 1;
+        self.hops += run.hops;
+        self.near_misses += run.near_misses;
+
+        match run.cause_of_death {
+            Some(CauseOfDeath::Vehicle) => self.vehicle_deaths += 1,
+            Some(CauseOfDeath::Water) => self.water_deaths += 1,
+            Some(CauseOfDeath::Eagle) => self.eagle_deaths += 1,
+            None => {}
+        }
+
+        self.terrain_time.resize(Terrain::ALL.len(), 0);
+        for terrain in Terrain::ALL {
+            self.terrain_time[terrain as usize] += run.terrain_time(terrain);
+        }
+    }
+}
+
+/// Draws the panel with the statistics of the run, and the lifetime ones.
+pub fn draw_summary(run: &RunStats, lifetime: &LifetimeStats) {
+    let terrain_times = Terrain::ALL
+        .iter()
+        .filter(|terrain| run.terrain_time(**terrain) > 0)
+        .map(|terrain| {
+            let seconds = run.terrain_time(*terrain) as f32 * TIME_PER_FRAME;
+            format!("{} {:.0}s", terrain.name(), seconds)
+        })
+        .collect::<Vec<_>>();
+
+    let mut lines = vec![
+        format!("Hops: {}   Near misses: {}", run.hops, run.near_misses),
+        run.cause_of_death
+            .map_or("", |cause| cause.description())
+            .to_string(),
+    ];
+    // Three terrains per line, so that they fit the screen.
+    lines.extend(terrain_times.chunks(3).map(|chunk| chunk.join("  ")));
+    lines.push(String::new());
+    lines.push(format!(
+        "Lifetime: {} runs, {} hops, {} near misses",
+        lifetime.runs, lifetime.hops, lifetime.near_misses
+    ));
+    lines.push(format!(
+        "Vehicles {}  Water {}  Eagle {}",
+        lifetime.vehicle_deaths, lifetime.water_deaths, lifetime.eagle_deaths
+    ));
+
+    let panel_height = lines.len() as f32 * LINE_SPACING + PANEL_MARGIN;
+    draw_rectangle(
+        PANEL_MARGIN,
+        PANEL_TOP,
+        WIDTH as f32 - PANEL_MARGIN * 2.,
+        panel_height,
+        PANEL_COLOR,
+    );
+
+    for (i, line) in lines.iter().enumerate() {
+        let text_width = measure_text(line, None, FONT_SIZE, 1.).width;
+        draw_text(
+            line,
+            (WIDTH as f32 - text_width) / 2.,
+            PANEL_TOP + PANEL_MARGIN + i as f32 * LINE_SPACING + FONT_SIZE as f32 / 2.,
+            FONT_SIZE as f32,
+            WHITE,
+        );
+    }
+}
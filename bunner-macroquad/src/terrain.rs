@@ -0,0 +1,32 @@
+/// Rust: Not in the original game. Type of a row, as tracked by the run statistics.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Terrain {
+    Grass,
+    Road,
+    Pavement,
+    Rail,
+    Water,
+    Dirt,
+}
+
+impl Terrain {
+    pub const ALL: [Terrain; 6] = [
+        Terrain::Grass,
+        Terrain::Road,
+        Terrain::Pavement,
+        Terrain::Rail,
+        Terrain::Water,
+        Terrain::Dirt,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Terrain::Grass => "Grass",
+            Terrain::Road => "Road",
+            Terrain::Pavement => "Pavement",
+            Terrain::Rail => "Rail",
+            Terrain::Water => "Water",
+            Terrain::Dirt => "Dirt",
+        }
+    }
+}
@@ -0,0 +1,136 @@
+use crate::{HEIGHT, WIDTH};
+use macroquad::{
+    color::Color,
+    input::KeyCode,
+    math::{vec2, Vec2},
+    miniquad::TouchPhase,
+    shapes::{draw_circle, draw_triangle},
+};
+use std::collections::HashMap;
+
+// Rust: Not in the original game. Touch input, for playing on phones (typically, the web build); as with the
+// gamepads, it's translated to the equivalent keys, so that it's merged with the keyboard input.
+//
+// A swipe hops in its direction, and a tap hops forward (and starts the game, like the space key); optionally,
+// on-screen arrows are displayed, which can be tapped to hop in their direction. The arrows display is toggled by
+// tapping with two fingers.
+//
+#[derive(Default)]
+pub struct TouchControls {
+    /// Start position of the touches in progress.
+    touch_starts: HashMap<u64, Vec2>,
+    show_arrows: bool,
+}
+
+impl TouchControls {
+    /// Shorter movements are considered taps.
+    const SWIPE_MIN_DISTANCE: f32 = 30.;
+
+    const ARROWS_CENTER: (f32, f32) = (WIDTH as f32 / 2., HEIGHT as f32 - 120.);
+    const ARROW_DISTANCE: f32 = 64.;
+    const ARROW_RADIUS: f32 = 28.;
+    const ARROW_BACKGROUND_COLOR: Color = Color::new(0., 0., 0., 0.3);
+    const ARROW_COLOR: Color = Color::new(1., 1., 1., 0.7);
+
+    const ARROWS: [(KeyCode, (f32, f32)); 4] = [
+        (KeyCode::Up, (0., -1.)),
+        (KeyCode::Right, (1., 0.)),
+        (KeyCode::Down, (0., 1.)),
+        (KeyCode::Left, (-1., 0.)),
+    ];
+
+    /// Returns the keys corresponding to the touch event, if any.
+    pub fn touch_event(&mut self, phase: TouchPhase, id: u64, x: f32, y: f32) -> Vec<KeyCode> {
+        let position = vec2(x, y);
+
+        match phase {
+            TouchPhase::Started => {
+                self.touch_starts.insert(id, position);
+
+                // Two fingers tap; the touches are forgotten, so that their end is ignored.
+                if self.touch_starts.len() == 2 {
+                    self.show_arrows = !self.show_arrows;
+                    self.touch_starts.clear();
+                }
+
+                vec![]
+            }
+            TouchPhase::Ended => match self.touch_starts.remove(&id) {
+                Some(start) => self.gesture_keys(start, position),
+                None => vec![],
+            },
+            TouchPhase::Cancelled => {
+                self.touch_starts.remove(&id);
+                vec![]
+            }
+            TouchPhase::Moved => vec![],
+        }
+    }
+
+    fn gesture_keys(&self, start: Vec2, end: Vec2) -> Vec<KeyCode> {
+        let movement = end - start;
+
+        if movement.length() >= Self::SWIPE_MIN_DISTANCE {
+            let key = if movement.x.abs() > movement.y.abs() {
+                if movement.x > 0. {
+                    KeyCode::Right
+                } else {
+                    KeyCode::Left
+                }
+            } else if movement.y > 0. {
+                KeyCode::Down
+            } else {
+                KeyCode::Up
+            };
+
+            return vec![key];
+        }
+
+        if self.show_arrows {
+            let tapped_arrow = Self::ARROWS.iter().find(|(_, direction)| {
+                end.distance(Self::arrow_position(*direction)) <= Self::ARROW_RADIUS
+            });
+
+            if let Some((key, _)) = tapped_arrow {
+                return vec![*key];
+            }
+        }
+
+        vec![KeyCode::Up, KeyCode::Space]
+    }
+
+    fn arrow_position((direction_x, direction_y): (f32, f32)) -> Vec2 {
+        let (center_x, center_y) = Self::ARROWS_CENTER;
+        vec2(
+            center_x + direction_x * Self::ARROW_DISTANCE,
+            center_y + direction_y * Self::ARROW_DISTANCE,
+        )
+    }
+
+    pub fn draw(&self) {
+        if !self.show_arrows {
+            return;
+        }
+
+        for (_, direction) in Self::ARROWS {
+            let center = Self::arrow_position(direction);
+            let (direction_x, direction_y) = direction;
+            let forward = vec2(direction_x, direction_y);
+            let side = vec2(-direction_y, direction_x);
+            let size = Self::ARROW_RADIUS / 2.;
+
+            draw_circle(
+                center.x,
+                center.y,
+                Self::ARROW_RADIUS,
+                Self::ARROW_BACKGROUND_COLOR,
+            );
+            draw_triangle(
+                center + forward * size,
+                center - forward * size + side * size,
+                center - forward * size - side * size,
+                Self::ARROW_COLOR,
+            );
+        }
+    }
+}
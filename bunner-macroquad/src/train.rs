@@ -1,8 +1,5 @@
-use crate::{actor::Actor, position::Position, resources::Resources};
-use macroquad::{
-    prelude::{collections::storage, draw_texture, WHITE},
-    rand::ChooseRandom,
-};
+use crate::{actor::Actor, position::Position, resources::Resources, row_rng::RowRng};
+use macroquad::prelude::{collections::storage, draw_texture, WHITE};
 
 #[derive(Clone)]
 pub struct Train {
@@ -43,11 +40,11 @@ impl Actor for Train {
 }
 
 impl Train {
-    pub fn new(dx: i32, position: Position) -> Self {
+    pub fn new(dx: i32, position: Position, rng: &mut RowRng) -> Self {
         let image_index = if dx < 0 {
-            *vec![0, 2, 4].choose().unwrap()
+            *rng.choose(&[0, 2, 4]).unwrap()
         } else {
-            *vec![1, 3, 5].choose().unwrap()
+            *rng.choose(&[1, 3, 5]).unwrap()
         };
         Self {
             dx,
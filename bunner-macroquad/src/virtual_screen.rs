@@ -0,0 +1,101 @@
+use macroquad::prelude::{
+    clear_background, draw_texture_ex, render_target, screen_height, screen_width, set_camera,
+    set_default_camera, set_fullscreen, vec2, Camera2D, DrawTextureParams, FilterMode, Rect,
+    RenderTarget, Vec2, BLACK, WHITE,
+};
+
+use crate::{HEIGHT, WIDTH};
+
+// Rust: Not in the original code (Pygame Zero windows are not resizable). The game is rendered to a canvas of
+// fixed size, which is then scaled to the window, preserving the aspect ratio (the remaining area is filled
+// with black bars).
+//
+pub struct VirtualScreen {
+    render_target: RenderTarget,
+    camera: Camera2D,
+    fullscreen: bool,
+}
+
+impl VirtualScreen {
+    pub fn new() -> Self {
+        let render_target = render_target(WIDTH as u32, HEIGHT as u32);
+        render_target.texture.set_filter(FilterMode::Nearest);
+
+        let camera = Self::canvas_camera(
+            render_target,
+            Rect::new(0., 0., WIDTH as f32, HEIGHT as f32),
+        );
+
+        Self {
+            render_target,
+            camera,
+            fullscreen: false,
+        }
+    }
+
+    fn canvas_camera(render_target: RenderTarget, display_rect: Rect) -> Camera2D {
+        let mut camera = Camera2D::from_display_rect(display_rect);
+        camera.render_target = Some(render_target);
+        camera
+    }
+
+    pub fn toggle_fullscreen(&mut self) {
+        self.fullscreen = !self.fullscreen;
+        set_fullscreen(self.fullscreen);
+    }
+
+    /// Camera drawing the given region of the game on the canvas; used for the split screen of the races.
+    pub fn camera(&self, display_rect: Rect) -> Camera2D {
+        Self::canvas_camera(self.render_target, display_rect)
+    }
+
+    /// Sets the canvas as drawing target; must be invoked before drawing the game, and after drawing with another
+    /// camera (which also resets the viewport).
+    pub fn begin(&self) {
+        set_camera(&self.camera);
+    }
+
+    /// Draws the canvas on the window.
+    pub fn end(&self) {
+        set_default_camera();
+        clear_background(BLACK);
+
+        let (scale, offset) = scale_and_offset();
+
+        draw_texture_ex(
+            self.render_target.texture,
+            offset.x,
+            offset.y,
+            WHITE,
+            DrawTextureParams {
+                dest_size: Some(vec2(WIDTH as f32 * scale, HEIGHT as f32 * scale)),
+                // Render targets are upside down.
+                flip_y: true,
+                ..Default::default()
+            },
+        )
+    }
+}
+
+impl Default for VirtualScreen {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+fn scale_and_offset() -> (f32, Vec2) {
+    let scale = (screen_width() / WIDTH as f32).min(screen_height() / HEIGHT as f32);
+    let offset = vec2(
+        (screen_width() - WIDTH as f32 * scale) / 2.,
+        (screen_height() - HEIGHT as f32 * scale) / 2.,
+    );
+
+    (scale, offset)
+}
+
+/// Converts a window position (e.g. a touch) to a position on the canvas.
+pub fn to_virtual(position: Vec2) -> Vec2 {
+    let (scale, offset) = scale_and_offset();
+
+    (position - offset) / scale
+}
@@ -1,17 +1,16 @@
 use crate::{
-    active_row::ActiveRow, child::Child, dirt::Dirt, log::Log, player_state::PlayerState,
-    position::Position, resources::Resources, row::Row, row::RowSound, ROW_HEIGHT, WIDTH,
-};
-use macroquad::{
-    audio::play_sound_once,
-    prelude::collections::storage,
-    rand::{self},
-    texture::Texture2D,
+    active_row::ActiveRow, child::Child, difficulty, log::Log, palette, player_state::PlayerState,
+    position::Position, resources::Resources, row::Row, row::RowSound, row_pool::RowPool,
+    row_rng::RowRng, sound::play_sound_once, terrain::Terrain, ROW_HEIGHT, WIDTH,
 };
+use bunner_core::{collision, row_sequence, traffic};
+use macroquad::{prelude::collections::storage, texture::Texture2D};
 
-#[derive(Clone)]
+#[derive(Clone, Default)]
 pub struct Water {
     dx: i32,
+    /// Rust: Defined by the difficulty curve, at the row position.
+    traffic_density: f32,
     timer: f32,
     index: i32,
     y: i32,
@@ -31,18 +30,30 @@ impl Row for Water {
         self.children.as_mut()
     }
 
-    fn update(&mut self, _scroll_pos: i32, _bunner_pos: Option<Position>) {
+    fn update(&mut self, _scroll_pos: i32, _bunner_pos: Option<Position>, rng: &mut RowRng) {
         self.update_children();
-        self.children.retain(|c| c.x() > -70 && c.x() < WIDTH + 70);
+        self.children.retain(|c| traffic::is_on_row(c.x()));
         self.timer -= 1.;
 
         // Create new child objects on a random interval
         if self.timer < 0. {
-            self.children.push(self.create_random_child(self.dx));
-            self.timer = self.random_interval(self.dx);
+            self.children.push(self.create_random_child(self.dx, rng));
+            self.timer = self.random_interval(self.dx, self.traffic_density, rng);
         }
     }
 
+    fn draw(&self, offset_x: i32, offset_y: i32) {
+        let (x, y) = self.draw_row(offset_x, offset_y);
+        if palette::is_high_contrast() {
+            palette::draw_water_overlay(x as f32, y as f32, WIDTH as f32);
+        }
+        self.draw_children(x, y);
+    }
+
+    fn terrain(&self) -> Terrain {
+        Terrain::Water
+    }
+
     fn image(&self) -> Texture2D {
         *storage::get::<Resources>()
             .water_textures
@@ -54,54 +65,71 @@ impl Row for Water {
         play_sound_once(storage::get::<Resources>().log_sound);
     }
 
-    fn next(&self) -> Box<dyn Row> {
+    fn next(&self, pool: &mut RowPool, rng: &mut RowRng) -> Box<dyn Row> {
         let y = self.y - ROW_HEIGHT;
-        if self.index == 7 || (self.index >= 1 && rand::gen_range(0, 2) == 0) {
-            Box::new(Dirt::new(rand::gen_range(4, 7), y))
-        } else {
-            Box::new(Water::new(self.dx, self.index + 1, y))
-        }
+        // Rust: The probability that the section ends is scaled by the difficulty curve.
+        let safe_row_frequency = difficulty::point_at(y).safe_row_frequency;
+        pool.build(
+            row_sequence::after_water(self.index, safe_row_frequency, rng),
+            self.dx,
+            y,
+            rng,
+        )
+    }
+
+    fn recycle(self: Box<Self>, pool: &mut RowPool) {
+        pool.waters.push(self);
     }
 
     fn check_collision(&self, x: i32) -> PlayerState {
-        if self.collide(x, -4) {
-            return PlayerState::Alive;
-        }
-        PlayerState::Splash
+        collision::water_state(self.collide(x, collision::LOG_MARGIN))
     }
 
     fn push(&self) -> i32 {
         self.dx
     }
 
+    fn is_water(&self) -> bool {
+        true
+    }
+
     fn sound(&self) -> Option<RowSound> {
         Some(RowSound::River)
     }
 }
 
 impl ActiveRow for Water {
-    fn build_child(dx: i32, position: Position) -> Child {
-        Child::Log(Log::new(dx, position))
+    fn build_child(dx: i32, position: Position, rng: &mut RowRng) -> Child {
+        Child::Log(Log::new(dx, position, rng))
     }
 }
 
 impl Water {
-    pub fn new(previous_dx: i32, index: i32, y: i32) -> Self {
-        let dx = if previous_dx >= 0 {
-            -rand::gen_range(1, 3)
-        } else {
-            rand::gen_range(1, 3)
-        };
-        Self {
+    pub fn new(
+        pool: &mut RowPool,
+        previous_dx: i32,
+        index: i32,
+        y: i32,
+        rng: &mut RowRng,
+    ) -> Box<Self> {
+        // Rust: The maximum speed is defined by the difficulty curve.
+        let difficulty_point = difficulty::point_at(y);
+        let dx = traffic::water_dx(previous_dx, difficulty_point.max_log_speed, rng);
+
+        let (mut row, mut children) = RowPool::take(&mut pool.waters);
+        Self::build_children(dx, difficulty_point.traffic_density, &mut children, rng);
+        *row = Self {
             dx,
+            traffic_density: difficulty_point.traffic_density,
             timer: 0.,
             index,
             y,
-            children: Self::build_children(dx),
-        }
+            children,
+        };
+        row
     }
 
-    pub fn empty(y: i32) -> Self {
-        Self::new(0, 0, y)
+    pub fn empty(pool: &mut RowPool, y: i32, rng: &mut RowRng) -> Box<Self> {
+        Self::new(pool, 0, 0, y, rng)
     }
 }
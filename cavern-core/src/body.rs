@@ -0,0 +1,64 @@
+#[derive(Clone, Copy)]
+pub enum Anchor {
+    Centre,
+    CentreBottom,
+}
+
+impl Anchor {
+    /// Offset of the top left corner from the anchor position, for the given size.
+    pub fn offset(&self, (width, height): (i32, i32)) -> (i32, i32) {
+        match self {
+            Anchor::Centre => (width / 2, height / 2),
+            Anchor::CentreBottom => (width / 2, height),
+        }
+    }
+}
+
+/// Geometry of an actor, which in the original game is provided by Pygame Zero. The size is the one of the
+/// current image, so the engines provide it.
+pub trait Body {
+    // x/y are separate, rather than a tuple, because mutable access to a single coordinate is needed.
+    fn x(&self) -> i32;
+    fn x_mut(&mut self) -> &mut i32;
+    fn y(&self) -> i32;
+    fn y_mut(&mut self) -> &mut i32;
+    fn size(&self) -> (i32, i32);
+    fn anchor(&self) -> Anchor;
+
+    // All the geometry methods below are meant to be conveniently implemented, not fast.
+
+    fn top_left(&self) -> (i32, i32) {
+        let (diff_x, diff_y) = self.anchor().offset(self.size());
+
+        (self.x() - diff_x, self.y() - diff_y)
+    }
+
+    fn top(&self) -> i32 {
+        self.top_left().1
+    }
+
+    fn bottom(&self) -> i32 {
+        self.top() + self.size().1
+    }
+
+    fn left(&self) -> i32 {
+        self.top_left().0
+    }
+
+    fn right(&self) -> i32 {
+        self.left() + self.size().0
+    }
+
+    fn center(&self) -> (i32, i32) {
+        let (width, height) = self.size();
+
+        (self.left() + width / 2, self.top() + height / 2)
+    }
+
+    fn collidepoint(&self, pos: (i32, i32)) -> bool {
+        self.left() <= pos.0
+            && pos.0 <= self.right()
+            && self.top() <= pos.1
+            && pos.1 <= self.bottom()
+    }
+}
@@ -1,16 +1,13 @@
-use crate::{
-    actor::{Actor, Anchor},
-    GRID_BLOCK_SIZE, LEVEL_X_OFFSET, NUM_COLUMNS, NUM_ROWS,
-};
+use crate::{Anchor, Body, GRID_BLOCK_SIZE, LEVEL_X_OFFSET, NUM_COLUMNS, NUM_ROWS};
 
-pub const COLLIDE_ACTOR_DEFAULT_ANCHOR: Anchor = Anchor::Centre;
+pub const COLLIDE_BODY_DEFAULT_ANCHOR: Anchor = Anchor::Centre;
 
-fn block(x: i32, y: i32, grid: &[&str]) -> bool {
-    // Is there a level grid block at these coordinates?
+/// Is there a level grid block at these coordinates? Public, since it's also used by the robots AI.
+pub fn block<S: AsRef<str>>(x: i32, y: i32, grid: &[S]) -> bool {
     let grid_x = (x - LEVEL_X_OFFSET) / GRID_BLOCK_SIZE;
     let grid_y = y / GRID_BLOCK_SIZE;
     if grid_y > 0 && grid_y < NUM_ROWS {
-        let row = grid[grid_y as usize];
+        let row = grid[grid_y as usize].as_ref();
         grid_x >= 0
             && grid_x < NUM_COLUMNS
             && row.len() > 0
@@ -20,8 +17,8 @@ fn block(x: i32, y: i32, grid: &[&str]) -> bool {
     }
 }
 
-pub trait CollideActor: Actor {
-    fn move_(&mut self, dx: i32, dy: i32, speed: i32, grid: &[&str]) -> bool {
+pub trait CollideBody: Body {
+    fn move_<S: AsRef<str>>(&mut self, dx: i32, dy: i32, speed: i32, grid: &[S]) -> bool {
         let (mut new_x, mut new_y) = (self.x(), self.y());
 
         // Movement is done 1 pixel at a time, which ensures we don't get embedded into a wall we're moving towards
@@ -0,0 +1,26 @@
+#![allow(clippy::all)]
+#![deny(clippy::correctness)]
+
+//! Game logic of Cavern which doesn't depend on the engine: the actors geometry, the grid collision,
+//! the gravity, and the orbs behavior. The ports provide the drawing, input and audio layers.
+//!
+//! Positions and sizes are in pixels, and times in frames (updates).
+
+mod body;
+pub mod collide;
+pub mod gravity;
+pub mod orb;
+
+pub use body::{Anchor, Body};
+pub use collide::CollideBody;
+pub use gravity::GravityBody;
+pub use orb::OrbBody;
+
+pub const WIDTH: i32 = 800;
+pub const HEIGHT: i32 = 480;
+
+pub const NUM_ROWS: i32 = 18;
+pub const NUM_COLUMNS: i32 = 28;
+
+pub const LEVEL_X_OFFSET: i32 = 50;
+pub const GRID_BLOCK_SIZE: i32 = 25;
@@ -0,0 +1,64 @@
+use crate::CollideBody;
+
+/// Lifetime of an orb.
+pub const MAX_TIMER: i32 = 250;
+/// Time an orb is blown for, if the blow button is released straight away.
+pub const INITIAL_BLOWN_FRAMES: i32 = 6;
+/// The orb grows to full size over this time.
+pub const GROW_TIME: i32 = 9;
+/// Horizontal speed while blown.
+pub const DEFAULT_SPEED: i32 = 4;
+
+/// Image of an orb, as index of the "orb" or "trap" images.
+pub enum OrbImage {
+    Orb(usize),
+    Trap(usize),
+}
+
+/// Pop if our lifetime has run out or if we have gone off the top of the screen.
+pub fn is_popped(timer: i32, y: i32) -> bool {
+    timer >= MAX_TIMER || y <= -40
+}
+
+/// The trapped enemy type is the index of its row in the "trap" images.
+pub fn image(timer: i32, trapped_enemy_type: Option<i32>) -> OrbImage {
+    if timer < GROW_TIME {
+        // Orb grows to full size over the course of 9 frames - the animation frame updating every 3 frames
+        OrbImage::Orb((timer / 3) as usize)
+    } else if let Some(trapped_enemy_type) = trapped_enemy_type {
+        OrbImage::Trap((trapped_enemy_type * 8 + (timer / 4) % 8) as usize)
+    } else {
+        OrbImage::Orb((3 + ((timer - GROW_TIME) / 8) % 4) as usize)
+    }
+}
+
+pub trait OrbBody: CollideBody {
+    fn direction_x(&self) -> i32;
+    fn floating(&self) -> bool;
+    fn floating_mut(&mut self) -> &mut bool;
+
+    /// Orbs are initially blown horizontally, then start floating upwards. Returns whether a block has been hit
+    /// while moving horizontally.
+    ///
+    /// The rise speed is random in the original game; it's a closure, so that the engine random number generator
+    /// is invoked only when the orb is floating.
+    fn step<S: AsRef<str>>(
+        &mut self,
+        rise_speed: impl FnOnce() -> i32,
+        speed: i32,
+        grid: &[S],
+    ) -> bool {
+        if self.floating() {
+            // Float upwards
+            self.move_(0, -1, rise_speed(), grid);
+            false
+        } else {
+            // Move horizontally; if we hit a block, start floating
+            let hit_block = self.move_(self.direction_x(), 0, speed, grid);
+            if hit_block {
+                *self.floating_mut() = true;
+            }
+            hit_block
+        }
+    }
+}
@@ -0,0 +1,7 @@
+#[derive(Clone, Copy)]
+pub enum Anchor {
+    Centre,
+    CentreBottom,
+    // Not in the source project, which draws the non-actor images by their top left corner.
+    TopLeft,
+}
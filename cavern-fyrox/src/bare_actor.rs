@@ -0,0 +1,29 @@
+use crate::prelude::*;
+
+// Used for the images that are not game objects (status, title screen...), which in the source project
+// are drawn directly on the screen.
+//
+#[my_actor_based]
+pub struct BareActor {}
+
+impl BareActor {
+    pub fn new(
+        img_base: &'static str,
+        img_indexes: Vec<u8>,
+        x: i32,
+        y: i32,
+        anchor: Anchor,
+        graph: &mut Graph,
+    ) -> Self {
+        let rectangle_h = RectangleBuilder::new(BaseBuilder::new()).build(graph);
+
+        Self {
+            img_base,
+            img_indexes,
+            x,
+            y,
+            anchor,
+            rectangle_h,
+        }
+    }
+}
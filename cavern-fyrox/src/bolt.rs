@@ -0,0 +1,65 @@
+use crate::prelude::*;
+
+const SPEED: i32 = 7;
+
+#[my_actor_based]
+pub struct Bolt {
+    pub direction_x: i32,
+    pub active: bool,
+}
+
+impl Bolt {
+    pub fn new(x: i32, y: i32, direction_x: i32, graph: &mut Graph) -> Self {
+        let rectangle_h = RectangleBuilder::new(BaseBuilder::new()).build(graph);
+
+        Self {
+            direction_x,
+            active: true,
+            img_base: BLANK_IMAGE,
+            img_indexes: vec![],
+            x,
+            y,
+            anchor: Anchor::Centre,
+            rectangle_h,
+        }
+    }
+
+    pub fn update(
+        &mut self,
+        orbs: &mut Pool<Orb>,
+        player: Option<&mut Player>,
+        game_timer: i32,
+        grid: &[&str],
+        scene: &mut Scene,
+        media: &Media,
+    ) {
+        //# Move horizontally and check to see if we've collided with a block
+        if self.move_(self.direction_x, 0, SPEED, grid) {
+            //# Collided
+            self.active = false;
+        } else {
+            //# We didn't collide with a block - check to see if we collided with an orb or the player
+            for orb in orbs.iter_mut() {
+                if orb.hit_test(self, media) {
+                    self.active = false;
+                    break;
+                }
+            }
+
+            if self.active {
+                if let Some(player) = player {
+                    if player.hit_test(self, scene, media) {
+                        self.active = false;
+                    }
+                }
+            }
+        }
+
+        let direction_idx = if self.direction_x > 0 { 1 } else { 0 };
+        let anim_frame = ((game_timer / 4) % 2) as u8;
+        self.img_base = "bolt";
+        self.img_indexes = vec![direction_idx, anim_frame];
+    }
+}
+
+impl CollideActor for Bolt {}
@@ -0,0 +1,88 @@
+use fyrox::resource::texture::{Texture, TextureKind};
+
+use crate::prelude::*;
+
+// Draws the image (loads the texture, adds the node to the scene, and links it to the root), and returns
+// the node handle, so that the image can be removed.
+//
+// The coordinates ("std" = "standard") are the typical orientation used for 2d libraries (center
+// at top left, x -> right, y -> down).
+//
+pub fn add_image_node(
+    media: &Media,
+    scene: &mut Scene,
+    base: &str,
+    indexes: &[u8],
+    std_x: i32,
+    std_y: i32,
+    z: f32,
+    anchor: Anchor,
+) -> Handle<Node> {
+    let texture = media.image(base, indexes);
+    let (fyrox_coords, texture_dims) = to_fyrox_coordinates(std_x, std_y, z, anchor, &texture);
+
+    RectangleBuilder::new(
+        BaseBuilder::new().with_local_transform(
+            TransformBuilder::new()
+                .with_local_position(fyrox_coords)
+                .with_local_scale(Vector3::new(texture_dims.x, texture_dims.y, f32::EPSILON))
+                .build(),
+        ),
+    )
+    .with_texture(texture)
+    .build(&mut scene.graph)
+}
+
+pub fn to_fyrox_coordinates(
+    std_x: i32,
+    std_y: i32,
+    z: f32,
+    anchor: Anchor,
+    texture: &Texture,
+) -> (Vector3<f32>, Vector2<f32>) {
+    let texture_kind = texture.data_ref().kind();
+
+    if let TextureKind::Rectangle {
+        width: texture_width,
+        height: texture_height,
+    } = texture_kind
+    {
+        use Anchor::*;
+        let (texture_width, texture_height) = (texture_width as f32, texture_height as f32);
+
+        // As a base, we start with the top left corner of the screen, and we subtract the "standard"
+        // coordinates, since they go to the opposite direction to the Fyrox ones.
+        //
+        // Fyrox positions the rectangles by their center, so the coordinates are shifted according to
+        // the anchor.
+        //
+        let (mut fyrox_x, mut fyrox_y) = (
+            WIDTH as f32 / 2. - std_x as f32,
+            HEIGHT as f32 / 2. - std_y as f32,
+        );
+
+        match anchor {
+            Centre => {
+                // Do nothing
+            }
+            CentreBottom => {
+                // Shift the texture up, of half texture.
+                //
+                fyrox_y = fyrox_y + texture_height / 2.;
+            }
+            TopLeft => {
+                // Shift the texture, to the bottom right, of half texture.
+                //
+                fyrox_x = fyrox_x - texture_width / 2.;
+                fyrox_y = fyrox_y - texture_height / 2.;
+            }
+        };
+
+        (
+            Vector3::new(fyrox_x, fyrox_y, z),
+            Vector2::new(texture_width, texture_height),
+        )
+    } else {
+        panic!("Texture is not a rectangle!")
+    }
+}
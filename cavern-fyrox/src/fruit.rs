@@ -0,0 +1,135 @@
+use crate::{game_playback::play_game_sound, prelude::*};
+
+#[derive(Clone, Copy)]
+pub enum FruitType {
+    Apple,
+    Raspberry,
+    Lemon,
+    ExtraHealth,
+    ExtraLife,
+}
+
+impl FruitType {
+    fn val(&self) -> u8 {
+        match self {
+            FruitType::Apple => 0,
+            FruitType::Raspberry => 1,
+            FruitType::Lemon => 2,
+            FruitType::ExtraHealth => 3,
+            FruitType::ExtraLife => 4,
+        }
+    }
+}
+
+#[my_actor_based]
+pub struct Fruit {
+    pub time_to_live: i32,
+    pub trapped_enemy_type: Option<RobotType>,
+    pub type_: FruitType,
+
+    // GravityActor trait
+    pub vel_y: i32,
+    pub landed: bool,
+}
+
+impl Fruit {
+    pub fn new(x: i32, y: i32, trapped_enemy_type: Option<RobotType>, graph: &mut Graph) -> Self {
+        use FruitType::*;
+
+        //# Choose which type of fruit we're going to be.
+        let type_ = if let Some(RobotType::Normal) = trapped_enemy_type {
+            *[Apple, Raspberry, Lemon].choose(&mut thread_rng()).unwrap()
+        } else {
+            //# If trapped_enemy_type is 1, it means this fruit came from bursting an orb containing the more dangerous type
+            //# of enemy. In this case there is a chance of getting an extra help or extra life power up
+            //# We create a list containing the possible types of fruit, in proportions based on the probability we want
+            //# each type of fruit to be chosen
+            let mut types = [Apple, Raspberry, Lemon].repeat(10); //# Each of these appear in the list 10 times
+            types.extend([ExtraHealth].repeat(9)); //# This appears 9 times
+            types.push(ExtraLife); //# This only appears once
+            *types.choose(&mut thread_rng()).unwrap() //# Randomly choose one from the list
+        };
+
+        let rectangle_h = RectangleBuilder::new(BaseBuilder::new()).build(graph);
+
+        Self {
+            time_to_live: 500, //# Counts down to zero
+            trapped_enemy_type,
+            type_,
+            img_base: BLANK_IMAGE,
+            img_indexes: vec![],
+            x,
+            y,
+            anchor: Anchor::CentreBottom,
+            rectangle_h,
+            vel_y: 0,
+            landed: false,
+        }
+    }
+
+    pub fn update(
+        &mut self,
+        pops: &mut Vec<Pop>,
+        player: Option<&mut Player>,
+        game_timer: i32,
+        grid: &[&str],
+        scene: &mut Scene,
+        media: &Media,
+    ) {
+        GravityActor::update(self, true, grid, media);
+
+        //# Does the player exist, and are they colliding with us?
+        match player {
+            Some(player) if player.collidepoint(self.center(media), media) => {
+                match self.type_ {
+                    FruitType::ExtraHealth => {
+                        player.health = 3.min(player.health + 1);
+                        play_game_sound(Some(&*player), "bonus", 1, scene, media);
+                    }
+                    FruitType::ExtraLife => {
+                        player.lives += 1;
+                        play_game_sound(Some(&*player), "bonus", 1, scene, media);
+                    }
+                    _ => {
+                        player.score += (self.type_.val() as i32 + 1) * 100;
+                        play_game_sound(Some(&*player), "score", 1, scene, media);
+                    }
+                }
+
+                self.time_to_live = 0; //# Disappear
+            }
+            _ => {
+                self.time_to_live -= 1;
+            }
+        }
+
+        if self.time_to_live <= 0 {
+            //# Create 'pop' animation
+            pops.push(Pop::new(self.x, self.y - 27, 0, &mut scene.graph));
+        }
+
+        let anim_frame = [0, 1, 2, 1][((game_timer / 6) % 4) as usize];
+        self.img_base = "fruit";
+        self.img_indexes = vec![self.type_.val(), anim_frame];
+    }
+}
+
+impl CollideActor for Fruit {}
+
+impl GravityActor for Fruit {
+    fn vel_y(&self) -> i32 {
+        self.vel_y
+    }
+
+    fn vel_y_mut(&mut self) -> &mut i32 {
+        &mut self.vel_y
+    }
+
+    fn landed(&self) -> bool {
+        self.landed
+    }
+
+    fn landed_mut(&mut self) -> &mut bool {
+        &mut self.landed
+    }
+}
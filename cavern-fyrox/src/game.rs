@@ -0,0 +1,338 @@
+use crate::{game_playback::play_game_sound, prelude::*};
+
+pub struct Game {
+    pub player: Option<Player>,
+    pub level_colour: i8,
+    pub level: i8,
+    pub timer: i32,
+    pub grid: Vec<&'static str>,
+
+    pub fruits: Vec<Fruit>,
+    pub bolts: Vec<Bolt>,
+    pub enemies: Vec<Robot>,
+    pub pending_enemies: Vec<RobotType>,
+    pub pops: Vec<Pop>,
+    // The orbs are referenced by the player (the orb being blown), so they're stored in a pool. Differently
+    // from a list, a pool doesn't preserve the insertion order, but this doesn't make any practical difference.
+    pub orbs: Pool<Orb>,
+
+    // Not in the source project; the background and blocks don't change during a level, so they're added
+    // to the scene once, and removed on the next level.
+    level_nodes: Vec<Handle<Node>>,
+}
+
+impl Game {
+    pub fn new(player: Option<Player>, scene: &mut Scene, media: &Media) -> Self {
+        let mut instance = Self {
+            player,
+            level_colour: -1,
+            level: -1,
+            timer: -1,
+            grid: vec![],
+            fruits: vec![],
+            bolts: vec![],
+            enemies: vec![],
+            pending_enemies: vec![],
+            pops: vec![],
+            orbs: Pool::new(),
+            level_nodes: vec![],
+        };
+
+        instance.next_level(scene, media);
+
+        instance
+    }
+
+    pub fn fire_probability(&self) -> f32 {
+        //# Likelihood per frame of each robot firing a bolt - they fire more often on higher levels
+        0.001 + (0.0001 * 100.min(self.level) as f32)
+    }
+
+    pub fn max_enemies(&self) -> usize {
+        //# Maximum number of enemies on-screen at once – increases as you progress through the levels
+        ((self.level as usize + 6) / 2).min(8)
+    }
+
+    pub fn next_level(&mut self, scene: &mut Scene, media: &Media) {
+        self.level_colour = (self.level_colour + 1) % 4;
+        self.level += 1;
+
+        //# Set up grid
+        self.grid = LEVELS[(self.level as usize) % LEVELS.len()].to_vec();
+
+        //# The last row is a copy of the first row
+        self.grid.push(self.grid[0]);
+
+        self.timer = -1;
+
+        if let Some(player) = &mut self.player {
+            player.reset();
+        }
+
+        self.clear_objects(&mut scene.graph);
+        self.add_level_nodes(scene, media);
+
+        //# At the start of each level we create a list of pending enemies - enemies to be created as the level plays out.
+        //# When this list is empty, we have no more enemies left to create, and the level will end once we have destroyed
+        //# all enemies currently on-screen. Each element of the list will be either 0 or 1, where 0 corresponds to
+        //# a standard enemy, and 1 is a more powerful enemy.
+        //# First we work out how many total enemies and how many of each type to create
+        let num_enemies = 10 + self.level as usize;
+        let num_strong_enemies = 1 + (self.level as f32 / 1.5) as usize;
+        let num_weak_enemies = num_enemies - num_strong_enemies;
+
+        //# Then we create the list of pending enemies. The resulting list will consist of a series of copies of
+        //# the number 1 (the number depending on the value of num_strong_enemies), followed by a series of copies of
+        //# the number zero, based on num_weak_enemies.
+        self.pending_enemies = [RobotType::Aggressive].repeat(num_strong_enemies);
+        self.pending_enemies
+            .extend([RobotType::Normal].repeat(num_weak_enemies));
+
+        //# Finally we shuffle the list so that the order is randomised
+        self.pending_enemies.shuffle(&mut thread_rng());
+
+        play_game_sound(self.player.as_ref(), "level", 1, scene, media);
+    }
+
+    pub fn get_robot_spawn_x(&self) -> i32 {
+        //# Find a spawn location for a robot, by checking the top row of the grid for empty spots
+        //# Start by choosing a random grid column
+        let r = thread_rng().gen_range(0..NUM_COLUMNS);
+
+        for i in 0..NUM_COLUMNS {
+            //# Keep looking at successive columns (wrapping round if we go off the right-hand side) until
+            //# we find one where the top grid column is unoccupied
+            let grid_x = (r + i) % NUM_COLUMNS;
+            if self.grid[0].as_bytes()[grid_x as usize] == b' ' {
+                return GRID_BLOCK_SIZE * grid_x + LEVEL_X_OFFSET + 12;
+            }
+        }
+
+        //# If we failed to find an opening in the top grid row (shouldn't ever happen), just spawn the enemy
+        //# in the centre of the screen
+        WIDTH / 2
+    }
+
+    pub fn update(&mut self, input: &InputController, scene: &mut Scene, media: &Media) {
+        self.timer += 1;
+
+        // Precomputed, since it can't be done while the enemies are mutably borrowed.
+        let fire_probability = self.fire_probability();
+
+        //# Update all objects
+        for fruit in &mut self.fruits {
+            fruit.update(
+                &mut self.pops,
+                self.player.as_mut(),
+                self.timer,
+                &self.grid,
+                scene,
+                media,
+            );
+        }
+        for bolt in &mut self.bolts {
+            bolt.update(
+                &mut self.orbs,
+                self.player.as_mut(),
+                self.timer,
+                &self.grid,
+                scene,
+                media,
+            );
+        }
+        for enemy in &mut self.enemies {
+            enemy.update(
+                &mut self.bolts,
+                &mut self.orbs,
+                self.player.as_ref(),
+                fire_probability,
+                self.timer,
+                &self.grid,
+                scene,
+                media,
+            );
+        }
+        for pop in &mut self.pops {
+            pop.update();
+        }
+        if let Some(player) = &mut self.player {
+            player.update(&mut self.orbs, &self.grid, self.timer, input, scene, media);
+        }
+        for orb in self.orbs.iter_mut() {
+            orb.update(
+                &mut self.fruits,
+                &mut self.pops,
+                self.player.as_ref(),
+                &self.grid,
+                scene,
+                media,
+            );
+        }
+
+        //# Remove objects which are no longer wanted from the lists. For example, we recreate
+        //# self.fruits such that it contains all existing fruits except those whose time_to_live counter has reached zero
+        let graph = &mut scene.graph;
+
+        retain_actors(&mut self.fruits, graph, |f| f.time_to_live > 0);
+        retain_actors(&mut self.bolts, graph, |b| b.active);
+        retain_actors(&mut self.enemies, graph, |e| e.alive);
+        retain_actors(&mut self.pops, graph, |p| p.timer < 12);
+
+        let popped_orb_hs = self
+            .orbs
+            .pair_iter()
+            .filter_map(|(orb_h, orb)| orb.is_popped().then_some(orb_h))
+            .collect::<Vec<_>>();
+
+        for orb_h in popped_orb_hs {
+            self.orbs.free(orb_h).remove_node(graph);
+        }
+
+        //# Every 100 frames, create a random fruit (unless there are no remaining enemies on this level)
+        if self.timer % 100 == 0 && self.pending_enemies.len() + self.enemies.len() > 0 {
+            //# Create fruit at random position
+            self.fruits.push(Fruit::new(
+                thread_rng().gen_range(70..=730),
+                thread_rng().gen_range(75..=400),
+                None,
+                graph,
+            ));
+        }
+
+        //# Every 81 frames, if there is at least 1 pending enemy, and the number of active enemies is below the current
+        //# level's maximum enemies, create a robot
+        if self.timer % 81 == 0
+            && self.pending_enemies.len() > 0
+            && self.enemies.len() < self.max_enemies()
+        {
+            //# Retrieve and remove the last element from the pending enemies list
+            let robot_type = self.pending_enemies.pop().unwrap();
+            let (x, y) = (self.get_robot_spawn_x(), -30);
+            self.enemies.push(Robot::new(x, y, robot_type, graph));
+        }
+
+        //# End level if there are no enemies remaining to be created, no existing enemies, no fruit, no popping orbs,
+        //# and no orbs containing trapped enemies. (We don't want to include orbs which don't contain trapped enemies,
+        //# as the level would never end if the player kept firing new orbs)
+        if self.pending_enemies.len() + self.fruits.len() + self.enemies.len() + self.pops.len()
+            == 0
+        {
+            if self.orbs.iter().all(|orb| orb.trapped_enemy_type.is_none()) {
+                self.next_level(scene, media);
+            }
+        }
+    }
+
+    // The background and the blocks are prepared once per level (see add_level_nodes()).
+    //
+    pub fn prepare_draw(&self, scene: &mut Scene, media: &Media) {
+        //# Draw all objects
+        for fruit in &self.fruits {
+            fruit.prepare_draw(scene, media, DRAW_FRUITS_Z);
+        }
+        for bolt in &self.bolts {
+            bolt.prepare_draw(scene, media, DRAW_BOLTS_Z);
+        }
+        for enemy in &self.enemies {
+            enemy.prepare_draw(scene, media, DRAW_ENEMIES_Z);
+        }
+        for pop in &self.pops {
+            pop.prepare_draw(scene, media, DRAW_POPS_Z);
+        }
+        for orb in self.orbs.iter() {
+            orb.prepare_draw(scene, media, DRAW_ORBS_Z);
+        }
+        if let Some(player) = &self.player {
+            player.prepare_draw(scene, media, DRAW_PLAYER_Z);
+        }
+    }
+
+    // Not in the source project, where the game is simply replaced; here, its nodes must be removed
+    // from the scene.
+    //
+    pub fn clear(&mut self, graph: &mut Graph) {
+        self.clear_objects(graph);
+
+        for node_h in self.level_nodes.drain(..) {
+            graph.remove_node(node_h);
+        }
+
+        if let Some(player) = self.player.take() {
+            player.remove_node(graph);
+        }
+    }
+
+    fn clear_objects(&mut self, graph: &mut Graph) {
+        retain_actors(&mut self.fruits, graph, |_| false);
+        retain_actors(&mut self.bolts, graph, |_| false);
+        retain_actors(&mut self.enemies, graph, |_| false);
+        retain_actors(&mut self.pops, graph, |_| false);
+
+        let orb_hs = self
+            .orbs
+            .pair_iter()
+            .map(|(orb_h, _)| orb_h)
+            .collect::<Vec<_>>();
+
+        for orb_h in orb_hs {
+            self.orbs.free(orb_h).remove_node(graph);
+        }
+    }
+
+    fn add_level_nodes(&mut self, scene: &mut Scene, media: &Media) {
+        for node_h in self.level_nodes.drain(..) {
+            scene.graph.remove_node(node_h);
+        }
+
+        let background_h = add_image_node(
+            media,
+            scene,
+            "bg",
+            &[self.level_colour as u8],
+            0,
+            0,
+            DRAW_BACKGROUND_Z,
+            Anchor::TopLeft,
+        );
+        self.level_nodes.push(background_h);
+
+        let block_sprite_index = (self.level % 4) as u8;
+
+        //# Display blocks
+        for (row_y, row) in self.grid.iter().enumerate().take(NUM_ROWS as usize) {
+            //# Initial offset - large blocks at edge of level are 50 pixels wide
+            let mut x = LEVEL_X_OFFSET;
+            for block in row.chars() {
+                if block != ' ' {
+                    let block_h = add_image_node(
+                        media,
+                        scene,
+                        "block",
+                        &[block_sprite_index],
+                        x,
+                        row_y as i32 * GRID_BLOCK_SIZE,
+                        DRAW_BLOCKS_Z,
+                        Anchor::TopLeft,
+                    );
+                    self.level_nodes.push(block_h);
+                }
+                x += GRID_BLOCK_SIZE;
+            }
+        }
+    }
+}
+
+// Equivalent of the source project list comprehensions, which additionally removes the nodes of the
+// discarded objects.
+//
+fn retain_actors<T: MyActor>(actors: &mut Vec<T>, graph: &mut Graph, keep: impl Fn(&T) -> bool) {
+    actors.retain(|actor| {
+        let retained = keep(actor);
+
+        if !retained {
+            actor.remove_node(graph);
+        }
+
+        retained
+    });
+}
@@ -0,0 +1,182 @@
+use fyrox::{
+    dpi::PhysicalSize,
+    engine::framework::prelude::GameState,
+    engine::Engine,
+    event::{ElementState, WindowEvent},
+    event_loop::ControlFlow,
+    scene::camera::{CameraBuilder, OrthographicProjection, Projection},
+};
+
+use crate::{game_playback::play_game_sound, prelude::*};
+
+pub struct GameGlobal {
+    media: Media,
+    scene: Handle<Scene>,
+    input: InputController,
+    game: Game,
+    game_hud: GameHud,
+    state: State,
+    menu_screen: MenuScreen,
+    game_over_screen: GameOverScreen,
+}
+
+impl GameState for GameGlobal {
+    fn init(engine: &mut Engine) -> Self {
+        Self::preset_window(engine);
+
+        let mut scene = Scene::new();
+
+        Self::add_camera(&mut scene);
+
+        let media = Media::new(&engine.resource_manager);
+
+        media.play_looping_sound(&mut scene, "theme");
+
+        let input = InputController::new();
+
+        //# Set the initial game state
+        let state = State::Menu;
+
+        //# Create a new Game object, without a Player object
+        let game = Game::new(None, &mut scene, &media);
+        let game_hud = GameHud::new();
+
+        let menu_screen = MenuScreen::new(&mut scene.graph);
+        let game_over_screen = GameOverScreen::new();
+
+        let scene_h = engine.scenes.add(scene);
+
+        Self {
+            media,
+            scene: scene_h,
+            input,
+            game,
+            game_hud,
+            state,
+            menu_screen,
+            game_over_screen,
+        }
+    }
+
+    fn on_tick(&mut self, engine: &mut Engine, _dt: f32, _control_flow: &mut ControlFlow) {
+        self.update(engine);
+
+        self.prepare_draw(engine);
+
+        self.input.flush_event_received_state();
+    }
+
+    fn on_window_event(&mut self, _engine: &mut Engine, event: WindowEvent) {
+        if let WindowEvent::KeyboardInput { input, .. } = event {
+            if let Some(key_code) = input.virtual_keycode {
+                use ElementState::*;
+
+                match input.state {
+                    Pressed => self.input.key_down(key_code),
+                    Released => self.input.key_up(key_code),
+                }
+            }
+        }
+    }
+}
+
+// update() and prepare_draw() don't have the Fyrox semantics, but they're added to make the comparison
+// with the source code simpler.
+//
+impl GameGlobal {
+    fn preset_window(engine: &Engine) {
+        let window = engine.get_window();
+
+        // WATCH OUT! Don't invert this and the following, otherwise, resize won't work.
+        // See https://#github.com/rust-windowing/winit/issues/2306.
+        //
+        window.set_resizable(false);
+
+        window.set_inner_size(PhysicalSize {
+            width: WIDTH,
+            height: HEIGHT,
+        });
+    }
+
+    fn update(&mut self, engine: &mut Engine) {
+        use State::*;
+
+        let scene = &mut engine.scenes[self.scene];
+
+        match &self.state {
+            Menu => {
+                if self.input.is_key_just_pressed(VirtualKeyCode::Space) {
+                    //# Switch to play state, and create a new Game object, passing it a new Player object to use
+                    self.menu_screen.clear(&mut scene.graph);
+                    self.game.clear(&mut scene.graph);
+
+                    self.state = State::Play;
+                    let player = Player::new(&mut scene.graph);
+                    self.game = Game::new(Some(player), scene, &self.media);
+                } else {
+                    self.game.update(&self.input, scene, &self.media);
+                }
+            }
+            Play => {
+                //# Has player lost all their lives?
+                if self.game.player.as_ref().unwrap().lives < 0 {
+                    play_game_sound(self.game.player.as_ref(), "over", 1, scene, &self.media);
+
+                    self.state = State::GameOver;
+
+                    self.game_over_screen.display(scene, &self.media);
+                } else {
+                    self.game.update(&self.input, scene, &self.media);
+                }
+            }
+            GameOver => {
+                if self.input.is_key_just_pressed(VirtualKeyCode::Space) {
+                    self.game_over_screen.clear(&mut scene.graph);
+                    self.game_hud.clear(&mut scene.graph);
+                    self.game.clear(&mut scene.graph);
+
+                    //# Switch to menu state, and create a new game object without a player
+                    self.state = State::Menu;
+                    self.game = Game::new(None, scene, &self.media);
+
+                    self.menu_screen.display(&mut scene.graph);
+                }
+            }
+        }
+    }
+
+    // This stage sets the animation frames and the sprite Z depths. In the GameOver state, the game
+    // and the HUD are left as they were, behind the game over screen.
+    //
+    fn prepare_draw(&mut self, engine: &mut Engine) {
+        let scene = &mut engine.scenes[self.scene];
+
+        match &self.state {
+            State::Menu => {
+                self.game.prepare_draw(scene, &self.media);
+                self.menu_screen
+                    .prepare_draw(self.game.timer, scene, &self.media);
+            }
+            State::Play => {
+                self.game.prepare_draw(scene, &self.media);
+                self.game_hud.update(
+                    self.game.player.as_ref().unwrap(),
+                    self.game.level,
+                    scene,
+                    &self.media,
+                );
+            }
+            State::GameOver => {}
+        }
+    }
+
+    fn add_camera(scene: &mut Scene) -> Handle<Node> {
+        CameraBuilder::new(BaseBuilder::new())
+            .with_projection(Projection::Orthographic(OrthographicProjection {
+                z_near: CAMERA_NEAR_Z,
+                z_far: CAMERA_FAR_Z,
+                vertical_size: (HEIGHT as f32 / 2.),
+            }))
+            .build(&mut scene.graph)
+    }
+}
@@ -0,0 +1,131 @@
+use crate::prelude::*;
+
+//# Widths of the letters A to Z in the font images
+const CHAR_WIDTH: [i32; 26] = [
+    27, 26, 25, 26, 25, 25, 26, 25, 12, 26, 26, 25, 33, 25, 26, 25, 27, 26, 26, 25, 26, 26, 38, 25,
+    25, 25,
+];
+
+const FONT_IMG_BASE: &str = "font";
+
+fn char_width(chr: u8) -> i32 {
+    //# Return width of given character. For characters other than the letters A to Z (i.e. space, and the digits 0 to 9),
+    //# the width of the letter A is returned. ord gives the ASCII/Unicode code for the given character.
+    let index = if chr < 65 { 0 } else { chr - 65 } as usize;
+    CHAR_WIDTH[index]
+}
+
+fn image_width(image: &str) -> i32 {
+    match image {
+        "life" => 44,
+        "plus" => 40,
+        "health" => 40,
+        _ => unreachable!(),
+    }
+}
+
+// The source project draws the status directly on the screen; here, the sprites are rebuilt only when
+// the displayed values change.
+//
+pub struct GameHud {
+    // Score, level, lives and health.
+    displayed_values: Option<(i32, i8, i32, i32)>,
+    sprites: Vec<BareActor>,
+}
+
+impl GameHud {
+    // Doesn't display anything.
+    //
+    pub fn new() -> Self {
+        Self {
+            displayed_values: None,
+            sprites: vec![],
+        }
+    }
+
+    pub fn update(&mut self, player: &Player, level: i8, scene: &mut Scene, media: &Media) {
+        let values = (player.score, level, player.lives, player.health);
+
+        if self.displayed_values == Some(values) {
+            return;
+        }
+
+        self.clear(&mut scene.graph);
+
+        //# Display score, right-justified at edge of screen
+        let number_width = CHAR_WIDTH[0];
+        let s = player.score.to_string();
+        self.add_text(
+            &s,
+            451,
+            Some(WIDTH - 2 - (number_width * s.len() as i32)),
+            &mut scene.graph,
+        );
+
+        //# Display level number
+        self.add_text(&format!("LEVEL {}", level + 1), 451, None, &mut scene.graph);
+
+        //# Display lives and health
+        //# We only display a maximum of two lives - if there are more than two, a plus symbol is displayed
+        let mut lives_health = ["life"].repeat(2.min(player.lives.max(0) as usize));
+        if player.lives > 2 {
+            lives_health.push("plus");
+        }
+        if player.lives >= 0 {
+            lives_health.extend(["health"].repeat(player.health.max(0) as usize));
+        }
+
+        let mut x = 0;
+        for image in lives_health {
+            self.sprites.push(BareActor::new(
+                image,
+                vec![],
+                x,
+                450,
+                Anchor::TopLeft,
+                &mut scene.graph,
+            ));
+            x += image_width(image);
+        }
+
+        for sprite in &self.sprites {
+            sprite.prepare_draw(scene, media, DRAW_GAME_HUD_Z);
+        }
+
+        self.displayed_values = Some(values);
+    }
+
+    pub fn clear(&mut self, graph: &mut Graph) {
+        for sprite in self.sprites.drain(..) {
+            sprite.remove_node(graph);
+        }
+
+        self.displayed_values = None;
+    }
+
+    // Equivalent of the source project `draw_text()`.
+    //
+    fn add_text(&mut self, text: &str, y: i32, x: Option<i32>, graph: &mut Graph) {
+        let text = text.as_bytes();
+
+        let mut x = x.unwrap_or_else(|| {
+            //# If no X pos specified, draw text in centre of the screen - must first work out total width of text
+            (WIDTH - text.iter().map(|chr| char_width(*chr)).sum::<i32>()) / 2
+        });
+
+        for chr in text {
+            //# Characters are drawn using images named "font0xx", where xx is the ASCII code of the character
+            let img_indexes = vec![chr / 100, (chr / 10) % 10, chr % 10];
+
+            self.sprites.push(BareActor::new(
+                FONT_IMG_BASE,
+                img_indexes,
+                x,
+                y,
+                Anchor::TopLeft,
+                graph,
+            ));
+            x += char_width(*chr);
+        }
+    }
+}
@@ -0,0 +1,37 @@
+use crate::prelude::*;
+
+const BACKGROUND_IMG_BASE: &str = "over";
+
+pub struct GameOverScreen {
+    background: Option<BareActor>,
+}
+
+impl GameOverScreen {
+    // Doesn't display the screen or perform any instantiation.
+    //
+    pub fn new() -> Self {
+        Self { background: None }
+    }
+
+    // The image is static, so it's prepared only once.
+    //
+    pub fn display(&mut self, scene: &mut Scene, media: &Media) {
+        let background = BareActor::new(
+            BACKGROUND_IMG_BASE,
+            vec![],
+            0,
+            0,
+            Anchor::TopLeft,
+            &mut scene.graph,
+        );
+        background.prepare_draw(scene, media, DRAW_GAME_OVER_Z);
+
+        self.background = Some(background);
+    }
+
+    pub fn clear(&mut self, graph: &mut Graph) {
+        if let Some(background) = self.background.take() {
+            background.remove_node(graph);
+        }
+    }
+}
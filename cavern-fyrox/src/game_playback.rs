@@ -0,0 +1,22 @@
+use crate::prelude::*;
+
+// Equivalent of the source project `Game.play_sound()`; sounds are played only if there's a player, i.e.
+// not while the title screen is displayed.
+//
+// Each sound has `count` variants (numbered from 0), one of which is randomly chosen.
+//
+// In the source project, this is a Game method, but since the objects are updated while Game is borrowed,
+// it's simpler to have it as a function.
+//
+pub fn play_game_sound(
+    player: Option<&Player>,
+    name: &str,
+    count: u8,
+    scene: &mut Scene,
+    media: &Media,
+) {
+    if player.is_some() {
+        let index = thread_rng().gen_range(0..count);
+        media.play_sound(scene, name, &[index]);
+    }
+}
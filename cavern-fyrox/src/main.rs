@@ -0,0 +1,105 @@
+#![allow(clippy::all)]
+#![deny(clippy::correctness)]
+
+mod anchor;
+mod bare_actor;
+mod bolt;
+mod collide_actor;
+mod draw_utils;
+mod fruit;
+mod game;
+mod game_global;
+mod game_hud;
+mod game_over_screen;
+mod game_playback;
+mod gravity_actor;
+mod input_controller;
+mod levels;
+mod media;
+mod menu_screen;
+mod my_actor;
+mod orb;
+mod player;
+mod pop;
+mod robot;
+mod state;
+
+pub mod prelude {
+    pub use fyrox::{
+        core::{
+            algebra::{Vector2, Vector3},
+            pool::{Handle, Pool},
+        },
+        event::VirtualKeyCode,
+        scene::{
+            base::BaseBuilder,
+            dim2::rectangle::{Rectangle, RectangleBuilder},
+            graph::Graph,
+            node::Node,
+            transform::TransformBuilder,
+            Scene,
+        },
+    };
+    pub use rand::{seq::SliceRandom, thread_rng, Rng};
+
+    pub use crate::anchor::Anchor;
+    pub use crate::bare_actor::BareActor;
+    pub use crate::bolt::Bolt;
+    pub use crate::collide_actor::CollideActor;
+    pub use crate::draw_utils::*;
+    pub use crate::fruit::Fruit;
+    pub use crate::game::Game;
+    pub use crate::game_hud::GameHud;
+    pub use crate::game_over_screen::GameOverScreen;
+    pub use crate::gravity_actor::GravityActor;
+    pub use crate::input_controller::InputController;
+    pub use crate::levels::LEVELS;
+    pub use crate::media::{Media, BLANK_IMAGE};
+    pub use crate::menu_screen::MenuScreen;
+    pub use crate::my_actor::MyActor;
+    pub use crate::orb::Orb;
+    pub use crate::player::Player;
+    pub use crate::pop::Pop;
+    pub use crate::robot::{Robot, RobotType};
+    pub use crate::state::State;
+    pub use cavern_macros_fyrox::my_actor_based;
+
+    pub const WIDTH: i32 = 800;
+    pub const HEIGHT: i32 = 480;
+
+    pub const NUM_ROWS: i32 = 18;
+    pub const NUM_COLUMNS: i32 = 28;
+
+    pub const LEVEL_X_OFFSET: i32 = 50;
+    pub const GRID_BLOCK_SIZE: i32 = 25;
+
+    // As in the Soccer port, the drawing sequence is replaced by the z-depth (lower = nearer); the
+    // order is the same as the source project.
+
+    pub const CAMERA_NEAR_Z: f32 = -1.0;
+    pub const CAMERA_FAR_Z: f32 = 16.0;
+
+    pub const DRAW_BACKGROUND_Z: f32 = 16.0;
+    pub const DRAW_BLOCKS_Z: f32 = 15.0;
+    pub const DRAW_FRUITS_Z: f32 = 14.0;
+    pub const DRAW_BOLTS_Z: f32 = 13.0;
+    pub const DRAW_ENEMIES_Z: f32 = 12.0;
+    pub const DRAW_POPS_Z: f32 = 11.0;
+    pub const DRAW_ORBS_Z: f32 = 10.0;
+    pub const DRAW_PLAYER_Z: f32 = 9.0;
+
+    pub const DRAW_GAME_HUD_Z: f32 = 2.0;
+    pub const DRAW_MENU_Z: f32 = 1.0;
+    pub const DRAW_MENU_SPACE_Z: f32 = 0.0;
+    pub const DRAW_GAME_OVER_Z: f32 = 1.0;
+}
+
+use fyrox::engine::framework::Framework;
+
+use game_global::GameGlobal;
+
+const TITLE: &str = "Cavern";
+
+fn main() {
+    Framework::<GameGlobal>::new().unwrap().title(TITLE).run();
+}
@@ -0,0 +1,63 @@
+use crate::prelude::*;
+
+const TITLE_IMG_BASE: &str = "title";
+const SPACE_IMG_BASE: &str = "space";
+
+pub struct MenuScreen {
+    title: Option<BareActor>,
+    space: Option<BareActor>,
+}
+
+impl MenuScreen {
+    pub fn new(graph: &mut Graph) -> Self {
+        let mut instance = Self {
+            title: None,
+            space: None,
+        };
+
+        instance.display(graph);
+
+        instance
+    }
+
+    pub fn display(&mut self, graph: &mut Graph) {
+        self.title = Some(BareActor::new(
+            TITLE_IMG_BASE,
+            vec![],
+            0,
+            0,
+            Anchor::TopLeft,
+            graph,
+        ));
+        self.space = Some(BareActor::new(
+            SPACE_IMG_BASE,
+            vec![0],
+            130,
+            280,
+            Anchor::TopLeft,
+            graph,
+        ));
+    }
+
+    pub fn prepare_draw(&mut self, game_timer: i32, scene: &mut Scene, media: &Media) {
+        if let (Some(title), Some(space)) = (&self.title, &mut self.space) {
+            title.prepare_draw(scene, media, DRAW_MENU_Z);
+
+            //# Draw "Press SPACE" animation, which has 10 frames numbered 0 to 9
+            //# The first part gives us a number between 0 and 159, based on the game timer
+            //# Dividing by 4 means we go to a new animation frame every 4 frames
+            //# We enclose this calculation in the min function, with the other argument being 9, which results in the
+            //# animation staying on frame 9 for three quarters of the time. Adding 40 to the game timer is done to alter
+            //# which stage the animation is at when the game first starts
+            let anim_frame = (((game_timer + 40) % 160) / 4).min(9);
+            space.img_indexes = vec![anim_frame as u8];
+            space.prepare_draw(scene, media, DRAW_MENU_SPACE_Z);
+        }
+    }
+
+    pub fn clear(&mut self, graph: &mut Graph) {
+        for actor in [self.title.take(), self.space.take()].into_iter().flatten() {
+            actor.remove_node(graph);
+        }
+    }
+}
@@ -0,0 +1,86 @@
+use crate::prelude::*;
+
+// Equivalent of the Actor class of Pygame Zero, which is the base of all the game objects; the image
+// is stored as base name and indexes (e.g. `run` and `[1, 2]` for `run12`), as in the Soccer port.
+//
+// The geometry is computed from the image size, so it requires the media.
+pub trait MyActor {
+    fn x(&self) -> i32;
+    fn x_mut(&mut self) -> &mut i32;
+    fn y(&self) -> i32;
+    fn y_mut(&mut self) -> &mut i32;
+    fn img_base(&self) -> &'static str;
+    fn img_indexes(&self) -> &[u8];
+    fn anchor(&self) -> Anchor;
+    fn rectangle_h(&self) -> Handle<Node>;
+
+    // All the geometry methods below are meant to be conveniently implemented, not fast.
+
+    fn image_size(&self, media: &Media) -> (i32, i32) {
+        media.image_size(self.img_base(), self.img_indexes())
+    }
+
+    fn top_left(&self, media: &Media) -> (i32, i32) {
+        let (image_width, image_height) = self.image_size(media);
+
+        let (diff_x, diff_y) = match self.anchor() {
+            Anchor::Centre => (image_width / 2, image_height / 2),
+            Anchor::CentreBottom => (image_width / 2, image_height),
+            Anchor::TopLeft => (0, 0),
+        };
+
+        (self.x() - diff_x, self.y() - diff_y)
+    }
+
+    fn top(&self, media: &Media) -> i32 {
+        self.top_left(media).1
+    }
+
+    fn bottom(&self, media: &Media) -> i32 {
+        self.top(media) + self.image_size(media).1
+    }
+
+    fn left(&self, media: &Media) -> i32 {
+        self.top_left(media).0
+    }
+
+    fn right(&self, media: &Media) -> i32 {
+        self.left(media) + self.image_size(media).0
+    }
+
+    fn center(&self, media: &Media) -> (i32, i32) {
+        let (left, top) = self.top_left(media);
+        let (image_width, image_height) = self.image_size(media);
+
+        (left + image_width / 2, top + image_height / 2)
+    }
+
+    fn collidepoint(&self, pos: (i32, i32), media: &Media) -> bool {
+        let (left, top) = self.top_left(media);
+        let (image_width, image_height) = self.image_size(media);
+
+        left <= pos.0 && pos.0 <= left + image_width && top <= pos.1 && pos.1 <= top + image_height
+    }
+
+    fn prepare_draw(&self, scene: &mut Scene, media: &Media, z: f32) {
+        let texture = media.image(self.img_base(), self.img_indexes());
+        let (fyrox_coords, texture_dims) =
+            to_fyrox_coordinates(self.x(), self.y(), z, self.anchor(), &texture);
+
+        let frame = scene.graph[self.rectangle_h()].as_rectangle_mut();
+
+        frame.set_texture(Some(texture));
+        frame.set_local_transform(
+            TransformBuilder::new()
+                .with_local_position(fyrox_coords)
+                .with_local_scale(Vector3::new(texture_dims.x, texture_dims.y, f32::EPSILON))
+                .build(),
+        );
+    }
+
+    // Not in the source project; in a scene graph, the objects must be removed once they're not wanted.
+    //
+    fn remove_node(&self, graph: &mut Graph) {
+        graph.remove_node(self.rectangle_h());
+    }
+}
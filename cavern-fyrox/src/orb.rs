@@ -0,0 +1,107 @@
+use crate::{game_playback::play_game_sound, prelude::*};
+
+const MAX_TIMER: i32 = 250;
+
+#[my_actor_based]
+pub struct Orb {
+    pub direction_x: i32,
+    pub timer: i32,
+    pub floating: bool,
+    //# Number of frames during which we will be pushed horizontally
+    pub blown_frames: i32,
+    //# Type of enemy trapped in this bubble
+    pub trapped_enemy_type: Option<RobotType>,
+}
+
+impl Orb {
+    pub fn new(x: i32, y: i32, direction_x: i32, graph: &mut Graph) -> Self {
+        let rectangle_h = RectangleBuilder::new(BaseBuilder::new()).build(graph);
+
+        Self {
+            //# Orbs are initially blown horizontally, then start floating upwards
+            direction_x,
+            timer: -1,
+            floating: false,
+            blown_frames: 6,
+            trapped_enemy_type: None,
+            img_base: BLANK_IMAGE,
+            img_indexes: vec![],
+            x,
+            y,
+            anchor: Anchor::Centre,
+            rectangle_h,
+        }
+    }
+
+    pub fn hit_test(&mut self, bolt: &Bolt, media: &Media) -> bool {
+        //# Check for collision with a bolt
+        let collided = self.collidepoint((bolt.x, bolt.y), media);
+        if collided {
+            self.timer = MAX_TIMER - 1;
+        }
+        collided
+    }
+
+    // Not in the source project, where the condition is inlined in the update and the removal.
+    //
+    pub fn is_popped(&self) -> bool {
+        self.timer >= MAX_TIMER || self.y <= -40
+    }
+
+    pub fn update(
+        &mut self,
+        fruits: &mut Vec<Fruit>,
+        pops: &mut Vec<Pop>,
+        player: Option<&Player>,
+        grid: &[&str],
+        scene: &mut Scene,
+        media: &Media,
+    ) {
+        self.timer += 1;
+
+        if self.floating {
+            //# Float upwards
+            self.move_(0, -1, thread_rng().gen_range(1..3), grid);
+        } else {
+            //# Move horizontally
+            if self.move_(self.direction_x, 0, 4, grid) {
+                //# If we hit a block, start floating
+                self.floating = true;
+            }
+        }
+
+        if self.timer == self.blown_frames {
+            self.floating = true;
+        } else if self.is_popped() {
+            //# Pop if our lifetime has run out or if we have gone off the top of the screen
+            pops.push(Pop::new(self.x, self.y, 1, &mut scene.graph));
+            if let Some(trapped_enemy_type) = self.trapped_enemy_type {
+                //# trapped_enemy_type is either zero or one. A value of one means there's a chance of creating a
+                //# powerup such as an extra life or extra health
+                fruits.push(Fruit::new(
+                    self.x,
+                    self.y,
+                    Some(trapped_enemy_type),
+                    &mut scene.graph,
+                ));
+            }
+            play_game_sound(player, "pop", 4, scene, media);
+        }
+
+        if self.timer < 9 {
+            //# Orb grows to full size over the course of 9 frames - the animation frame updating every 3 frames
+            self.img_base = "orb";
+            self.img_indexes = vec![(self.timer / 3) as u8];
+        } else {
+            if let Some(trapped_enemy_type) = self.trapped_enemy_type {
+                self.img_base = "trap";
+                self.img_indexes = vec![trapped_enemy_type.val(), ((self.timer / 4) % 8) as u8];
+            } else {
+                self.img_base = "orb";
+                self.img_indexes = vec![(3 + (((self.timer - 9) / 8) % 4)) as u8];
+            }
+        }
+    }
+}
+
+impl CollideActor for Orb {}
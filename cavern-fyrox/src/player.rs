@@ -0,0 +1,214 @@
+use crate::{game_playback::play_game_sound, prelude::*};
+
+#[my_actor_based]
+pub struct Player {
+    pub lives: i32,
+    pub score: i32,
+    pub direction_x: i32, //# -1 = left, 1 = right
+    pub fire_timer: i32,
+    pub hurt_timer: i32,
+    pub health: i32,
+    // In a scene graph engine, the pool handle is the natural way to reference another object; once the
+    // orb is removed, the handle is invalidated.
+    pub blowing_orb: Handle<Orb>,
+
+    // GravityActor trait
+    pub vel_y: i32,
+    pub landed: bool,
+}
+
+impl Player {
+    pub fn new(graph: &mut Graph) -> Self {
+        let rectangle_h = RectangleBuilder::new(BaseBuilder::new()).build(graph);
+
+        Self {
+            lives: 2,
+            score: 0,
+            direction_x: 0,
+            fire_timer: 0,
+            hurt_timer: 0,
+            health: 0,
+            blowing_orb: Handle::NONE,
+            img_base: BLANK_IMAGE,
+            img_indexes: vec![],
+            x: 0,
+            y: 0,
+            anchor: Anchor::CentreBottom,
+            rectangle_h,
+            vel_y: 0,
+            landed: false,
+        }
+    }
+
+    pub fn reset(&mut self) {
+        self.x = WIDTH / 2;
+        self.y = 100;
+        self.vel_y = 0;
+        self.direction_x = 1; //# -1 = left, 1 = right
+        self.fire_timer = 0;
+        self.hurt_timer = 100; //# Invulnerable for this many frames
+        self.health = 3;
+        self.blowing_orb = Handle::NONE;
+    }
+
+    pub fn hit_test(&mut self, other: &Bolt, scene: &mut Scene, media: &Media) -> bool {
+        //# Check for collision between player and bolt - called from Bolt.update. Also check hurt_timer - after being hurt,
+        //# there is a period during which the player cannot be hurt again
+        if self.collidepoint((other.x, other.y), media) && self.hurt_timer < 0 {
+            //# Player loses 1 health, is knocked in the direction the bolt had been moving, and can't be hurt again
+            //# for a while
+            self.hurt_timer = 200;
+            self.health -= 1;
+            self.vel_y = -12;
+            self.landed = false;
+            self.direction_x = other.direction_x;
+            if self.health > 0 {
+                play_game_sound(Some(self), "ouch", 4, scene, media);
+            } else {
+                play_game_sound(Some(self), "die", 1, scene, media);
+            }
+            true
+        } else {
+            false
+        }
+    }
+
+    pub fn update(
+        &mut self,
+        orbs: &mut Pool<Orb>,
+        grid: &[&str],
+        game_timer: i32,
+        input: &InputController,
+        scene: &mut Scene,
+        media: &Media,
+    ) {
+        use VirtualKeyCode::*;
+
+        //# Call GravityActor.update - parameter is whether we want to perform collision detection as we fall. If health
+        //# is zero, we want the player to just fall out of the level
+        GravityActor::update(self, self.health > 0, grid, media);
+
+        self.fire_timer -= 1;
+        self.hurt_timer -= 1;
+
+        // In the source project, this is declared inside the else block, and read outside of it; in Rust,
+        // it must be declared here.
+        let mut dx = 0;
+
+        if self.landed {
+            //# Hurt timer starts at 200, but drops to 100 once the player has landed
+            self.hurt_timer = self.hurt_timer.min(100);
+        }
+
+        if self.hurt_timer > 100 {
+            //# We've just been hurt. Either carry out the sideways motion from being knocked by a bolt, or if health is
+            //# zero, we're dropping out of the level, so check for our sprite reaching a certain Y coordinate before
+            //# reducing our lives count and responding the player. We check for the Y coordinate being the screen height
+            //# plus 50%, rather than simply the screen height, because the former effectively gives us a short delay
+            //# before the player respawns.
+            if self.health > 0 {
+                self.move_(self.direction_x, 0, 4, grid);
+            } else if self.top(media) >= (HEIGHT as f32 * 1.5) as i32 {
+                self.lives -= 1;
+                self.reset();
+            }
+        } else {
+            //# We're not hurt
+            //# Get keyboard input. dx represents the direction the player is facing
+            if input.is_key_pressed(Left) {
+                dx = -1;
+            } else if input.is_key_pressed(Right) {
+                dx = 1;
+            }
+
+            if dx != 0 {
+                self.direction_x = dx;
+
+                //# If we haven't just fired an orb, carry out horizontal movement
+                if self.fire_timer < 10 {
+                    self.move_(dx, 0, 4, grid);
+                }
+            }
+
+            //# Do we need to create a new orb? Space must have been pressed and released, the minimum time between
+            //# orbs must have passed, and there is a limit of 5 orbs.
+            if input.is_key_just_pressed(Space) && self.fire_timer <= 0 && orbs.iter().count() < 5 {
+                //# x position will be 38 pixels in front of the player position, while ensuring it is within the
+                //# bounds of the level
+                let x = (self.x + self.direction_x * 38).clamp(70, 730);
+                let y = self.y - 35;
+                self.blowing_orb = orbs.spawn(Orb::new(x, y, self.direction_x, &mut scene.graph));
+                play_game_sound(Some(self), "blow", 4, scene, media);
+                self.fire_timer = 20;
+            }
+
+            if input.is_key_pressed(Up) && self.vel_y == 0 && self.landed {
+                //# Jump
+                self.vel_y = -16;
+                self.landed = false;
+                play_game_sound(Some(self), "jump", 1, scene, media);
+            }
+        }
+
+        //# Holding down space causes the current orb (if there is one) to be blown further
+        if input.is_key_pressed(Space) {
+            if let Some(blowing_orb) = orbs.try_borrow_mut(self.blowing_orb) {
+                //# Increase blown distance up to a maximum of 120
+                blowing_orb.blown_frames += 4;
+                if blowing_orb.blown_frames >= 120 {
+                    //# Can't be blown any further
+                    self.blowing_orb = Handle::NONE;
+                }
+            }
+        } else {
+            //# If we let go of space, we relinquish control over the current orb - it can't be blown any further
+            self.blowing_orb = Handle::NONE;
+        }
+
+        //# Set sprite image. If we're currently hurt, the sprite will flash on and off on alternate frames.
+        self.img_base = BLANK_IMAGE;
+        self.img_indexes = vec![];
+
+        if self.hurt_timer <= 0 || self.hurt_timer % 2 == 1 {
+            let dir_index = if self.direction_x > 0 { 1 } else { 0 };
+
+            if self.hurt_timer > 100 {
+                if self.health > 0 {
+                    self.img_base = "recoil";
+                    self.img_indexes = vec![dir_index];
+                } else {
+                    self.img_base = "fall";
+                    self.img_indexes = vec![((game_timer / 4) % 2) as u8];
+                }
+            } else if self.fire_timer > 0 {
+                self.img_base = "blow";
+                self.img_indexes = vec![dir_index];
+            } else if dx == 0 {
+                self.img_base = "still";
+            } else {
+                self.img_base = "run";
+                self.img_indexes = vec![dir_index, ((game_timer / 8) % 4) as u8];
+            }
+        }
+    }
+}
+
+impl CollideActor for Player {}
+
+impl GravityActor for Player {
+    fn vel_y(&self) -> i32 {
+        self.vel_y
+    }
+
+    fn vel_y_mut(&mut self) -> &mut i32 {
+        &mut self.vel_y
+    }
+
+    fn landed(&self) -> bool {
+        self.landed
+    }
+
+    fn landed_mut(&mut self) -> &mut bool {
+        &mut self.landed
+    }
+}
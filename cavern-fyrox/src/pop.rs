@@ -0,0 +1,31 @@
+use crate::prelude::*;
+
+#[my_actor_based]
+pub struct Pop {
+    pub timer: i32,
+    pub type_: u8,
+}
+
+impl Pop {
+    pub fn new(x: i32, y: i32, type_: u8, graph: &mut Graph) -> Self {
+        let rectangle_h = RectangleBuilder::new(BaseBuilder::new()).build(graph);
+
+        Self {
+            type_,
+            timer: -1,
+            img_base: BLANK_IMAGE,
+            img_indexes: vec![],
+            x,
+            y,
+            anchor: Anchor::Centre,
+            rectangle_h,
+        }
+    }
+
+    pub fn update(&mut self) {
+        self.timer += 1;
+
+        self.img_base = "pop";
+        self.img_indexes = vec![self.type_, (self.timer / 2) as u8];
+    }
+}
@@ -0,0 +1,167 @@
+use crate::{game_playback::play_game_sound, prelude::*};
+
+#[derive(Clone, Copy)]
+pub enum RobotType {
+    Normal,
+    Aggressive,
+}
+
+impl RobotType {
+    pub fn val(&self) -> u8 {
+        match self {
+            RobotType::Normal => 0,
+            RobotType::Aggressive => 1,
+        }
+    }
+}
+
+#[my_actor_based]
+pub struct Robot {
+    pub type_: RobotType,
+    pub speed: i32,
+    pub direction_x: i32,
+    pub alive: bool,
+    pub change_dir_timer: i32,
+    pub fire_timer: i32,
+
+    // GravityActor trait
+    pub vel_y: i32,
+    pub landed: bool,
+}
+
+impl Robot {
+    pub fn new(x: i32, y: i32, type_: RobotType, graph: &mut Graph) -> Self {
+        let rectangle_h = RectangleBuilder::new(BaseBuilder::new()).build(graph);
+
+        Self {
+            type_,
+            speed: thread_rng().gen_range(1..4),
+            direction_x: 1,
+            alive: true,
+            change_dir_timer: 0,
+            fire_timer: 100,
+            img_base: BLANK_IMAGE,
+            img_indexes: vec![],
+            x,
+            y,
+            anchor: Anchor::CentreBottom,
+            rectangle_h,
+            vel_y: 0,
+            landed: false,
+        }
+    }
+
+    pub fn update(
+        &mut self,
+        bolts: &mut Vec<Bolt>,
+        orbs: &mut Pool<Orb>,
+        player: Option<&Player>,
+        mut fire_probability: f32,
+        game_timer: i32,
+        grid: &[&str],
+        scene: &mut Scene,
+        media: &Media,
+    ) {
+        GravityActor::update(self, true, grid, media);
+
+        self.change_dir_timer -= 1;
+        self.fire_timer += 1;
+
+        //# Move in current direction - turn around if we hit a wall
+        if self.move_(self.direction_x, 0, self.speed, grid) {
+            self.change_dir_timer = 0;
+        }
+
+        if self.change_dir_timer <= 0 {
+            //# Randomly choose a direction to move in
+            //# If there's a player, there's a two thirds chance that we'll move towards them
+            let mut directions = vec![-1, 1];
+            if let Some(player) = player {
+                directions.push((player.x - self.x).signum());
+            }
+            self.direction_x = *directions.choose(&mut thread_rng()).unwrap();
+            // Python's randint() is inclusive on both ends.
+            self.change_dir_timer = thread_rng().gen_range(100..=250);
+        }
+
+        //# The more powerful type of robot can deliberately shoot at orbs - turning to face them if necessary
+        if matches!(self.type_, RobotType::Aggressive) && self.fire_timer >= 24 {
+            //# Go through all orbs to see if any can be shot at
+            for orb in orbs.iter() {
+                //# The orb must be at our height, and within 200 pixels on the x axis
+                if orb.y >= self.top(media)
+                    && orb.y < self.bottom(media)
+                    && (orb.x - self.x).abs() < 200
+                {
+                    self.direction_x = (orb.x - self.x).signum();
+                    self.fire_timer = 0;
+                    break;
+                }
+            }
+        }
+
+        //# Check to see if we can fire at player
+        if self.fire_timer >= 12 {
+            //# Random chance of firing each frame. Likelihood increases 10 times if player is at the same height as us
+            if let Some(player) = player {
+                if self.top(media) < player.bottom(media) && self.bottom(media) > player.top(media)
+                {
+                    fire_probability *= 10.;
+                }
+            }
+            if thread_rng().gen::<f32>() < fire_probability {
+                self.fire_timer = 0;
+                play_game_sound(player, "laser", 4, scene, media);
+            }
+        } else if self.fire_timer == 8 {
+            //# Once the fire timer has been set to 0, it will count up - frame 8 of the animation is when the actual bolt is fired
+            bolts.push(Bolt::new(
+                self.x + self.direction_x * 20,
+                self.y - 38,
+                self.direction_x,
+                &mut scene.graph,
+            ));
+        }
+
+        //# Am I colliding with an orb? If so, become trapped by it
+        for orb in orbs.iter_mut() {
+            if orb.trapped_enemy_type.is_none() && self.collidepoint(orb.center(media), media) {
+                self.alive = false;
+                orb.floating = true;
+                orb.trapped_enemy_type = Some(self.type_);
+                play_game_sound(player, "trap", 4, scene, media);
+                break;
+            }
+        }
+
+        //# Choose and set sprite image
+        let direction_idx = if self.direction_x > 0 { 1 } else { 0 };
+        let anim_frame = if self.fire_timer < 12 {
+            5 + (self.fire_timer / 4)
+        } else {
+            1 + ((game_timer / 4) % 4)
+        };
+        self.img_base = "robot";
+        self.img_indexes = vec![self.type_.val(), direction_idx, anim_frame as u8];
+    }
+}
+
+impl CollideActor for Robot {}
+
+impl GravityActor for Robot {
+    fn vel_y(&self) -> i32 {
+        self.vel_y
+    }
+
+    fn vel_y_mut(&mut self) -> &mut i32 {
+        &mut self.vel_y
+    }
+
+    fn landed(&self) -> bool {
+        self.landed
+    }
+
+    fn landed_mut(&mut self) -> &mut bool {
+        &mut self.landed
+    }
+}
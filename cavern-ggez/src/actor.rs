@@ -0,0 +1,26 @@
+use ggez::{
+    graphics::{self, DrawParam, Image},
+    Context, GameResult,
+};
+use glam::Vec2;
+
+// The geometry is shared with the other ports, via the engine-agnostic core crate; the size of an actor is
+// the size of its image.
+pub use cavern_core::{Anchor, Body};
+
+/// Drawing part of an actor.
+pub trait Actor: Body {
+    fn image(&self) -> &Image;
+
+    /// ggez doesn't support anchoring, so the image is drawn at the top left position.
+    fn draw(&self, context: &mut Context) -> GameResult {
+        let (left, top) = self.top_left();
+        let dest = Vec2::new(left as f32, top as f32);
+
+        graphics::draw(context, self.image(), DrawParam::new().dest(dest))
+    }
+}
+
+pub fn image_size(image: &Image) -> (i32, i32) {
+    (image.width() as i32, image.height() as i32)
+}
@@ -0,0 +1,109 @@
+use cavern_core::{collide::COLLIDE_BODY_DEFAULT_ANCHOR, CollideBody};
+use ggez::{graphics::Image, Context, GameResult};
+
+use crate::{
+    actor::{image_size, Actor, Anchor, Body},
+    orb::RcOrb,
+    player::Player,
+    resources::Resources,
+};
+
+const BOLT_SPEED: i32 = 7;
+
+pub struct Bolt {
+    pub direction_x: i32,
+    pub active: bool,
+
+    // Actor trait
+    pub x: i32,
+    pub y: i32,
+    pub image: Image,
+    pub anchor: Anchor,
+}
+
+impl Bolt {
+    pub fn new(resources: &Resources, x: i32, y: i32, direction_x: i32) -> Self {
+        Self {
+            direction_x,
+            active: true,
+
+            x,
+            y,
+            image: resources.blank_image.clone(),
+            anchor: COLLIDE_BODY_DEFAULT_ANCHOR,
+        }
+    }
+
+    pub fn update(
+        &mut self,
+        context: &mut Context,
+        resources: &mut Resources,
+        orbs: &mut [RcOrb],
+        player: Option<&mut Player>,
+        game_timer: i32,
+        grid: &[&str],
+    ) -> GameResult {
+        // Move horizontally and check to see if we've collided with a block
+        if self.move_(self.direction_x, 0, BOLT_SPEED, grid) {
+            // Collided
+            self.active = false;
+        } else {
+            // We didn't collide with a block - check to see if we collided with an orb or the player
+            for orb in orbs {
+                if orb.borrow_mut().hit_test(self) {
+                    self.active = false;
+                    break;
+                }
+            }
+
+            if self.active {
+                if let Some(player) = player {
+                    if player.hit_test(context, resources, self)? {
+                        self.active = false;
+                    }
+                }
+            }
+        }
+
+        let direction_factor = if self.direction_x > 0 { 2 } else { 0 };
+        let timer_factor = (game_timer / 4) % 2;
+        let image_i = (direction_factor + timer_factor) as usize;
+        self.image = resources.bolt_images[image_i].clone();
+
+        Ok(())
+    }
+}
+
+impl Body for Bolt {
+    fn x(&self) -> i32 {
+        self.x
+    }
+
+    fn x_mut(&mut self) -> &mut i32 {
+        &mut self.x
+    }
+
+    fn y(&self) -> i32 {
+        self.y
+    }
+
+    fn y_mut(&mut self) -> &mut i32 {
+        &mut self.y
+    }
+
+    fn size(&self) -> (i32, i32) {
+        image_size(&self.image)
+    }
+
+    fn anchor(&self) -> Anchor {
+        self.anchor
+    }
+}
+
+impl Actor for Bolt {
+    fn image(&self) -> &Image {
+        &self.image
+    }
+}
+
+impl CollideBody for Bolt {}
@@ -0,0 +1,56 @@
+use ggez::{
+    graphics::{self, DrawParam},
+    Context, GameResult,
+};
+use glam::Vec2;
+
+use crate::{resources::Resources, WIDTH};
+
+// Widths of the letters A to Z in the font images
+pub const CHAR_WIDTH: [i32; 26] = [
+    27, 26, 25, 26, 25, 25, 26, 25, 12, 26, 26, 25, 33, 25, 26, 25, 27, 26, 26, 25, 26, 26, 38, 25,
+    25, 25,
+];
+
+pub fn image_width(image: &str) -> i32 {
+    match image {
+        "life" => 44,
+        "plus" => 40,
+        "health" => 40,
+        _ => unreachable!(),
+    }
+}
+
+fn char_width(chr: &u8) -> i32 {
+    // Return width of given character. For characters other than the letters A to Z (i.e. space, and the digits 0 to 9),
+    // the width of the letter A is returned. ord gives the ASCII/Unicode code for the given character.
+    let index = if *chr < 65 { 0 } else { chr - 65 } as usize;
+    CHAR_WIDTH[index]
+}
+
+pub fn draw_image(context: &mut Context, image: &graphics::Image, x: i32, y: i32) -> GameResult {
+    let dest = Vec2::new(x as f32, y as f32);
+    graphics::draw(context, image, DrawParam::new().dest(dest))
+}
+
+pub fn draw_text(
+    context: &mut Context,
+    resources: &Resources,
+    text: &str,
+    y: i32,
+    x: Option<i32>,
+) -> GameResult {
+    let text = text.as_bytes();
+
+    let mut x = x.unwrap_or_else(|| {
+        // If no X pos specified, draw text in centre of the screen - must first work out total width of text
+        (WIDTH - text.iter().map(char_width).sum::<i32>()) / 2
+    });
+
+    for chr in text {
+        draw_image(context, &resources.fonts[chr], x, y)?;
+        x += char_width(chr);
+    }
+
+    Ok(())
+}
@@ -0,0 +1,290 @@
+use ggez::{Context, GameResult};
+
+use crate::{
+    actor::Actor,
+    bolt::Bolt,
+    drawing::draw_image,
+    fruit::Fruit,
+    game_playback::play_game_sound,
+    levels::LEVELS,
+    orb::RcOrb,
+    player::Player,
+    pop::Pop,
+    resources::Resources,
+    robot::{Robot, RobotType},
+    GRID_BLOCK_SIZE, LEVEL_X_OFFSET, NUM_COLUMNS, NUM_ROWS, WIDTH,
+};
+
+#[derive(Default)]
+pub struct Game {
+    pub player: Option<Player>,
+    pub level_colour: i8,
+    pub level: i8,
+    pub timer: i32,
+    pub grid: Vec<&'static str>,
+
+    pub fruits: Vec<Fruit>,
+    pub bolts: Vec<Bolt>,
+    pub enemies: Vec<Robot>,
+    pub pending_enemies: Vec<RobotType>,
+    pub pops: Vec<Pop>,
+    pub orbs: Vec<RcOrb>,
+}
+
+impl Game {
+    pub fn new(
+        context: &mut Context,
+        resources: &mut Resources,
+        player: Option<Player>,
+    ) -> GameResult<Self> {
+        let mut game = Self {
+            player,
+            level_colour: -1,
+            level: -1,
+            timer: -1,
+            ..Default::default()
+        };
+
+        game.next_level(context, resources)?;
+
+        Ok(game)
+    }
+
+    pub fn fire_probability(&self) -> f32 {
+        // Likelihood per frame of each robot firing a bolt - they fire more often on higher levels
+        0.001 + (0.0001 * 100.min(self.level) as f32)
+    }
+
+    pub fn max_enemies(&self) -> usize {
+        // Maximum number of enemies on-screen at once – increases as you progress through the levels
+        ((self.level as usize + 6) / 2).min(8)
+    }
+
+    pub fn get_robot_spawn_x(&self) -> i32 {
+        // Find a spawn location for a robot, by checking the top row of the grid for empty spots
+        // Start by choosing a random grid column
+        let r = fastrand::i32(0..NUM_COLUMNS);
+
+        for i in 0..NUM_COLUMNS {
+            // Keep looking at successive columns (wrapping round if we go off the right-hand side) until
+            // we find one where the top grid column is unoccupied
+            let grid_x = (r + i) % NUM_COLUMNS;
+            if self.grid[0].as_bytes()[grid_x as usize] == b' ' {
+                return GRID_BLOCK_SIZE * grid_x + LEVEL_X_OFFSET + 12;
+            }
+        }
+
+        // If we failed to find an opening in the top grid row (shouldn't ever happen), just spawn the enemy
+        // in the centre of the screen
+        WIDTH / 2
+    }
+
+    pub fn update(
+        &mut self,
+        context: &mut Context,
+        resources: &mut Resources,
+        space_pressed: bool,
+    ) -> GameResult {
+        self.timer += 1;
+
+        // We precompute this here, since it can't be done inside the enemies cycle, due to borrowing rules.
+        let fire_probability = self.fire_probability();
+
+        // Update all objects
+        for fruit in &mut self.fruits {
+            fruit.update(
+                context,
+                resources,
+                &mut self.pops,
+                self.player.as_mut(),
+                self.timer,
+                &self.grid,
+            )?;
+        }
+        for bolt in &mut self.bolts {
+            bolt.update(
+                context,
+                resources,
+                &mut self.orbs,
+                self.player.as_mut(),
+                self.timer,
+                &self.grid,
+            )?;
+        }
+        for enemy in &mut self.enemies {
+            enemy.update(
+                context,
+                resources,
+                &mut self.bolts,
+                &mut self.orbs,
+                self.player.as_ref(),
+                fire_probability,
+                self.timer,
+                &self.grid,
+            )?;
+        }
+        for pop in &mut self.pops {
+            pop.update(resources);
+        }
+        if let Some(player) = &mut self.player {
+            player.update(
+                context,
+                resources,
+                &mut self.orbs,
+                &self.grid,
+                self.timer,
+                space_pressed,
+            )?;
+        }
+        for orb in &self.orbs {
+            orb.borrow_mut().update(
+                context,
+                resources,
+                &mut self.fruits,
+                &mut self.pops,
+                self.player.as_ref(),
+                &self.grid,
+            )?;
+        }
+
+        // Remove objects which are no longer wanted from the lists. For example, we recreate
+        // self.fruits such that it contains all existing fruits except those whose time_to_live counter has reached zero
+        self.fruits.retain(|f| f.time_to_live > 0);
+        self.bolts.retain(|b| b.active);
+        self.enemies.retain(|e| e.alive);
+        self.pops.retain(|p| p.timer < 12);
+        self.orbs.retain(|o| !o.borrow().is_popped());
+
+        // Every 100 frames, create a random fruit (unless there are no remaining enemies on this level)
+        if self.timer % 100 == 0 && (self.pending_enemies.len() + self.enemies.len()) > 0 {
+            // Create fruit at random position
+            self.fruits.push(Fruit::new(
+                resources,
+                fastrand::i32(70..=730),
+                fastrand::i32(75..=400),
+                None,
+            ));
+        }
+
+        // Every 81 frames, if there is at least 1 pending enemy, and the number of active enemies is below the current
+        // level's maximum enemies, create a robot
+        if self.timer % 81 == 0
+            && self.pending_enemies.len() > 0
+            && self.enemies.len() < self.max_enemies()
+        {
+            // Retrieve and remove the last element from the pending enemies list
+            let robot_type = self.pending_enemies.pop().unwrap();
+            let (x, y) = (self.get_robot_spawn_x(), -30);
+            self.enemies.push(Robot::new(resources, x, y, robot_type));
+        }
+
+        // End level if there are no enemies remaining to be created, no existing enemies, no fruit, no popping orbs,
+        // and no orbs containing trapped enemies. (We don't want to include orbs which don't contain trapped enemies,
+        // as the level would never end if the player kept firing new orbs)
+        if self.pending_enemies.len() + self.fruits.len() + self.enemies.len() + self.pops.len()
+            == 0
+        {
+            if self
+                .orbs
+                .iter()
+                .all(|orb| orb.borrow().trapped_enemy_type.is_none())
+            {
+                self.next_level(context, resources)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    pub fn draw(&self, context: &mut Context, resources: &Resources) -> GameResult {
+        draw_image(
+            context,
+            &resources.background_images[self.level_colour as usize],
+            0,
+            0,
+        )?;
+
+        let block_sprite = &resources.block_images[(self.level % 4) as usize];
+
+        // Display blocks
+        for row_y in 0..NUM_ROWS {
+            let row = self.grid[row_y as usize];
+            if row.len() > 0 {
+                // Initial offset - large blocks at edge of level are 50 pixels wide
+                let mut x = LEVEL_X_OFFSET;
+                for block in row.chars() {
+                    if block != ' ' {
+                        draw_image(context, block_sprite, x, row_y * GRID_BLOCK_SIZE)?;
+                    }
+                    x += GRID_BLOCK_SIZE;
+                }
+            }
+        }
+
+        // Draw all objects
+        for fruit in &self.fruits {
+            fruit.draw(context)?;
+        }
+        for bolt in &self.bolts {
+            bolt.draw(context)?;
+        }
+        for enemy in &self.enemies {
+            enemy.draw(context)?;
+        }
+        for pop in &self.pops {
+            pop.draw(context)?;
+        }
+        for orb in &self.orbs {
+            orb.borrow().draw(context)?;
+        }
+        if let Some(player) = &self.player {
+            player.draw(context)?;
+        }
+
+        Ok(())
+    }
+
+    fn next_level(&mut self, context: &mut Context, resources: &mut Resources) -> GameResult {
+        self.level_colour = (self.level_colour + 1) % 4;
+        self.level += 1;
+
+        // Set up grid
+        self.grid = LEVELS[(self.level as usize) % LEVELS.len()].to_vec();
+
+        // The last row is a copy of the first row
+        self.grid.push(self.grid[0]);
+
+        self.timer = -1;
+
+        if let Some(player) = &mut self.player {
+            player.reset();
+        }
+
+        self.fruits = vec![];
+        self.bolts = vec![];
+        self.enemies = vec![];
+        self.pops = vec![];
+        self.orbs = vec![];
+
+        // At the start of each level we create a list of pending enemies - enemies to be created as the level plays out.
+        // When this list is empty, we have no more enemies left to create, and the level will end once we have destroyed
+        // all enemies currently on-screen. Each element of the list will be either 0 or 1, where 0 corresponds to
+        // a standard enemy, and 1 is a more powerful enemy.
+        // First we work out how many total enemies and how many of each type to create
+        let num_enemies = 10 + self.level as usize;
+        let num_strong_enemies = 1 + (self.level as f32 / 1.5) as usize;
+        let num_weak_enemies = num_enemies - num_strong_enemies;
+
+        // Then we create the list of pending enemies. The resulting list will consist of a series of copies of
+        // RobotType::Aggressive (the number depending on the value of num_strong_enemies), followed by a
+        // series of copies of RobotType::Normal, based on num_weak_enemies.
+        self.pending_enemies = [RobotType::Aggressive].repeat(num_strong_enemies);
+        self.pending_enemies
+            .extend([RobotType::Normal].repeat(num_weak_enemies));
+
+        // Finally we shuffle the list so that the order is randomised
+        fastrand::shuffle(&mut self.pending_enemies);
+
+        play_game_sound(context, self.player.as_ref(), &mut resources.level_sound)
+    }
+}
@@ -0,0 +1,32 @@
+use ggez::{
+    audio::{self, SoundSource},
+    Context, GameResult,
+};
+
+use crate::player::Player;
+
+// Utility functions for game audio playback; sounds are played only if there's a player, i.e. not while
+// the title screen is displayed.
+//
+// Detached playback is used, so that the same sound can overlap itself, like in Pygame Zero.
+
+pub fn play_game_sound(
+    context: &mut Context,
+    player: Option<&Player>,
+    sound: &mut audio::Source,
+) -> GameResult {
+    if player.is_some() {
+        sound.play_detached(context)
+    } else {
+        Ok(())
+    }
+}
+
+pub fn play_game_random_sound(
+    context: &mut Context,
+    player: Option<&Player>,
+    sounds: &mut [audio::Source],
+) -> GameResult {
+    let sound_i = fastrand::usize(..sounds.len());
+    play_game_sound(context, player, &mut sounds[sound_i])
+}
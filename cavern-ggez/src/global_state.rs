@@ -0,0 +1,180 @@
+use ggez::{
+    audio::SoundSource,
+    event::{EventHandler, KeyCode},
+    graphics,
+    input::keyboard::is_key_pressed,
+    timer, Context, GameResult,
+};
+
+use crate::{
+    drawing::{draw_image, draw_text, image_width, CHAR_WIDTH},
+    game::Game,
+    game_playback::play_game_sound,
+    player::Player,
+    resources::Resources,
+    state::State,
+    WIDTH,
+};
+
+/// Pygame Zero runs the update at 60 Hz.
+const UPDATES_PER_SECOND: u32 = 60;
+
+/// Global state, not to be confused with the game state (which is a part of it).
+pub struct GlobalState {
+    state: State,
+    game: Game,
+    space_down: bool,
+
+    resources: Resources,
+}
+
+impl GlobalState {
+    pub fn new(context: &mut Context) -> GameResult<Self> {
+        let mut resources = Resources::new(context)?;
+
+        // Set the initial game state
+        let game = Game::new(context, &mut resources, None)?;
+
+        Ok(Self {
+            state: State::Menu,
+            game,
+            space_down: false,
+            resources,
+        })
+    }
+
+    pub fn play_music(&mut self, context: &mut Context) -> GameResult {
+        let music = &mut self.resources.music;
+
+        music.set_repeat(true);
+        music.set_volume(0.3);
+        music.play(context)
+    }
+
+    fn draw_status(&self, context: &mut Context) -> GameResult {
+        // For Rust convenience
+        let player = self.game.player.as_ref().unwrap();
+        let resources = &self.resources;
+
+        // Display score, right-justified at edge of screen
+        let number_width = CHAR_WIDTH[0];
+        let s = player.score.to_string();
+        draw_text(
+            context,
+            resources,
+            &s,
+            451,
+            Some(WIDTH - 2 - (number_width * s.len() as i32)),
+        )?;
+
+        // Display level number
+        draw_text(
+            context,
+            resources,
+            &format!("LEVEL {}", self.game.level + 1),
+            451,
+            None,
+        )?;
+
+        // Display lives and health
+        // We only display a maximum of two lives - if there are more than two, a plus symbol is displayed
+        let mut lives_health = ["life"].repeat(2.min(player.lives.max(0) as usize));
+        if player.lives > 2 {
+            lives_health.push("plus");
+        }
+        if player.lives >= 0 {
+            lives_health.extend(["health"].repeat(player.health.max(0) as usize));
+        }
+
+        let mut x = 0;
+        for image in lives_health {
+            draw_image(context, &resources.status_images[image], x, 450)?;
+            x += image_width(image);
+        }
+
+        Ok(())
+    }
+}
+
+impl EventHandler for GlobalState {
+    /// The logic is run at a fixed rate, independently of the display refresh rate.
+    fn update(&mut self, context: &mut Context) -> GameResult {
+        while timer::check_update_time(context, UPDATES_PER_SECOND) {
+            // Work out whether the space key has just been pressed - i.e. in the previous frame it wasn't
+            // down, and in this frame it is.
+            let space_pressed = is_key_pressed(context, KeyCode::Space) && !self.space_down;
+            self.space_down = is_key_pressed(context, KeyCode::Space);
+
+            let resources = &mut self.resources;
+
+            match self.state {
+                State::Menu => {
+                    if space_pressed {
+                        // Switch to play state, and create a new Game object, passing it a new Player object to use
+                        self.state = State::Play;
+                        let player = Player::new(resources);
+                        self.game = Game::new(context, resources, Some(player))?;
+                    } else {
+                        self.game.update(context, resources, space_pressed)?;
+                    }
+                }
+                State::Play => {
+                    // Has player lost all their lives?
+                    if self.game.player.as_ref().unwrap().lives < 0 {
+                        play_game_sound(
+                            context,
+                            self.game.player.as_ref(),
+                            &mut resources.over_sound,
+                        )?;
+                        self.state = State::GameOver;
+                    } else {
+                        self.game.update(context, resources, space_pressed)?;
+                    }
+                }
+                State::GameOver => {
+                    if space_pressed {
+                        // Switch to menu state, and create a new game object without a player
+                        self.state = State::Menu;
+                        self.game = Game::new(context, resources, None)?;
+                    }
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    fn draw(&mut self, context: &mut Context) -> GameResult {
+        self.game.draw(context, &self.resources)?;
+
+        match self.state {
+            State::Menu => {
+                // Draw title screen
+                draw_image(context, &self.resources.title_image, 0, 0)?;
+
+                // Draw "Press SPACE" animation, which has 10 frames numbered 0 to 9
+                // The first part gives us a number between 0 and 159, based on the game timer
+                // Dividing by 4 means we go to a new animation frame every 4 frames
+                // We enclose this calculation in the min function, with the other argument being 9, which results in the
+                // animation staying on frame 9 for three quarters of the time. Adding 40 to the game timer is done to alter
+                // which stage the animation is at when the game first starts
+                let anim_frame = (((self.game.timer + 40) % 160) / 4).min(9) as usize;
+                draw_image(context, &self.resources.space_images[anim_frame], 130, 280)?;
+            }
+            State::Play => {
+                self.draw_status(context)?;
+            }
+            State::GameOver => {
+                self.draw_status(context)?;
+                // Display "Game Over" image
+                draw_image(context, &self.resources.over_image, 0, 0)?;
+            }
+        }
+
+        graphics::present(context)?;
+
+        timer::yield_now();
+
+        Ok(())
+    }
+}
@@ -0,0 +1,70 @@
+#![allow(clippy::all)]
+#![deny(clippy::correctness)]
+
+mod actor;
+mod bolt;
+mod drawing;
+mod fruit;
+mod game;
+mod game_playback;
+mod global_state;
+mod levels;
+mod orb;
+mod player;
+mod pop;
+mod resources;
+mod robot;
+mod state;
+
+use std::env;
+use std::path::PathBuf;
+
+use ggez::{event, GameResult};
+
+use global_state::GlobalState;
+
+// The geometry, grid collision, gravity and orbs behavior are shared with the Macroquad port, via the
+// engine-agnostic core crate.
+pub use cavern_core::{GRID_BLOCK_SIZE, HEIGHT, LEVEL_X_OFFSET, NUM_COLUMNS, NUM_ROWS, WIDTH};
+
+const RESOURCES_DIR_NAME: &str = "resources";
+const RESOURCE_SUBDIRS: [&str; 3] = ["images", "music", "sounds"];
+
+const GAME_ID: &str = "Cavern";
+const AUTHOR: &str = "Saverio Miroddi";
+
+const WINDOW_TITLE: &str = GAME_ID;
+
+fn get_resource_dirs() -> Vec<PathBuf> {
+    let resources_root_dir = if let Ok(manifest_dir) = env::var("CARGO_MANIFEST_DIR") {
+        let mut path = PathBuf::from(manifest_dir);
+        path.push(RESOURCES_DIR_NAME);
+        path
+    } else {
+        PathBuf::from(RESOURCES_DIR_NAME)
+    };
+
+    RESOURCE_SUBDIRS
+        .iter()
+        .map(|subdir| resources_root_dir.join(subdir).canonicalize().unwrap())
+        .collect()
+}
+
+fn main() -> GameResult {
+    let resource_dirs = get_resource_dirs();
+
+    let mut context_builder = ggez::ContextBuilder::new(GAME_ID, AUTHOR)
+        .window_setup(ggez::conf::WindowSetup::default().title(WINDOW_TITLE))
+        .window_mode(ggez::conf::WindowMode::default().dimensions(WIDTH as f32, HEIGHT as f32));
+
+    for dir in resource_dirs {
+        context_builder = context_builder.add_resource_path(dir);
+    }
+
+    let (mut context, event_loop) = context_builder.build()?;
+    let mut state = GlobalState::new(&mut context)?;
+
+    state.play_music(&mut context)?;
+
+    event::run(context, event_loop, state)
+}
@@ -0,0 +1,158 @@
+use std::{
+    cell::RefCell,
+    rc::{Rc, Weak},
+};
+
+use cavern_core::{
+    orb::{self, OrbImage},
+    CollideBody, OrbBody,
+};
+use ggez::{graphics::Image, Context, GameResult};
+
+use crate::{
+    actor::{image_size, Actor, Anchor, Body},
+    bolt::Bolt,
+    fruit::Fruit,
+    game_playback::play_game_random_sound,
+    player::Player,
+    pop::Pop,
+    resources::Resources,
+    robot::RobotType,
+};
+
+pub type RcOrb = Rc<RefCell<Orb>>;
+pub type WkOrb = Weak<RefCell<Orb>>;
+
+pub struct Orb {
+    pub direction_x: i32,
+    pub timer: i32,
+    pub floating: bool,
+    /// Number of frames during which we will be pushed horizontally
+    pub blown_frames: i32,
+    /// Type of enemy trapped in this bubble
+    pub trapped_enemy_type: Option<RobotType>,
+
+    // Actor trait
+    pub x: i32,
+    pub y: i32,
+    pub image: Image,
+    pub anchor: Anchor,
+}
+
+impl Orb {
+    pub fn new(resources: &Resources, x: i32, y: i32, direction_x: i32) -> Self {
+        Self {
+            direction_x, // Orbs are initially blown horizontally, then start floating upwards
+            timer: -1,
+            floating: false,
+            blown_frames: orb::INITIAL_BLOWN_FRAMES,
+            trapped_enemy_type: None,
+            x,
+            y,
+            image: resources.blank_image.clone(),
+            anchor: Anchor::Centre,
+        }
+    }
+
+    pub fn hit_test(&mut self, bolt: &Bolt) -> bool {
+        // Check for collision with a bolt
+        let collided = self.collidepoint((bolt.x, bolt.y));
+        if collided {
+            self.timer = orb::MAX_TIMER - 1;
+        }
+        collided
+    }
+
+    pub fn is_popped(&self) -> bool {
+        orb::is_popped(self.timer, self.y)
+    }
+
+    pub fn update(
+        &mut self,
+        context: &mut Context,
+        resources: &mut Resources,
+        fruits: &mut Vec<Fruit>,
+        pops: &mut Vec<Pop>,
+        player: Option<&Player>,
+        grid: &[&str],
+    ) -> GameResult {
+        self.timer += 1;
+
+        self.step(|| fastrand::i32(1..3), orb::DEFAULT_SPEED, grid);
+
+        if self.timer == self.blown_frames {
+            self.floating = true;
+        } else if self.is_popped() {
+            // Pop if our lifetime has run out or if we have gone off the top of the screen
+            pops.push(Pop::new(resources, self.x, self.y, 1));
+            if let Some(trapped_enemy_type) = self.trapped_enemy_type {
+                // trapped_enemy_type is either zero or one. A value of one means there's a chance of creating a
+                // powerup such as an extra life or extra health
+                fruits.push(Fruit::new(
+                    resources,
+                    self.x,
+                    self.y,
+                    Some(trapped_enemy_type),
+                ));
+            }
+            play_game_random_sound(context, player, &mut resources.pop_sounds)?;
+        }
+
+        let trapped_enemy_type = self.trapped_enemy_type.map(|enemy_type| enemy_type.val());
+
+        self.image = match orb::image(self.timer, trapped_enemy_type) {
+            OrbImage::Orb(image_i) => resources.orb_images[image_i].clone(),
+            OrbImage::Trap(image_i) => resources.trap_images[image_i].clone(),
+        };
+
+        Ok(())
+    }
+}
+
+impl Body for Orb {
+    fn x(&self) -> i32 {
+        self.x
+    }
+
+    fn x_mut(&mut self) -> &mut i32 {
+        &mut self.x
+    }
+
+    fn y(&self) -> i32 {
+        self.y
+    }
+
+    fn y_mut(&mut self) -> &mut i32 {
+        &mut self.y
+    }
+
+    fn size(&self) -> (i32, i32) {
+        image_size(&self.image)
+    }
+
+    fn anchor(&self) -> Anchor {
+        self.anchor
+    }
+}
+
+impl Actor for Orb {
+    fn image(&self) -> &Image {
+        &self.image
+    }
+}
+
+impl CollideBody for Orb {}
+
+impl OrbBody for Orb {
+    fn direction_x(&self) -> i32 {
+        self.direction_x
+    }
+
+    fn floating(&self) -> bool {
+        self.floating
+    }
+
+    fn floating_mut(&mut self) -> &mut bool {
+        &mut self.floating
+    }
+}
@@ -0,0 +1,278 @@
+use std::{
+    cell::RefCell,
+    rc::{Rc, Weak},
+};
+
+use cavern_core::{
+    gravity::{DEFAULT_GRAVITY, DEFAULT_MAX_FALL_SPEED, GRAVITY_BODY_DEFAULT_ANCHOR},
+    CollideBody, GravityBody,
+};
+use ggez::{event::KeyCode, graphics::Image, input::keyboard::is_key_pressed, Context, GameResult};
+
+use crate::{
+    actor::{image_size, Actor, Anchor, Body},
+    bolt::Bolt,
+    game_playback::{play_game_random_sound, play_game_sound},
+    orb::{Orb, RcOrb, WkOrb},
+    resources::Resources,
+    HEIGHT, WIDTH,
+};
+
+pub struct Player {
+    pub lives: i32,
+    pub score: i32,
+    pub direction_x: i32, // -1 = left, 1 = right
+    pub fire_timer: i32,
+    pub hurt_timer: i32,
+    pub health: i32,
+    /// Same approach as the Macroquad port: the orb is shared with the orbs list, and the weak reference
+    /// is invalidated once the orb is removed.
+    pub blowing_orb: WkOrb,
+
+    // Actor trait
+    pub x: i32,
+    pub y: i32,
+    pub image: Image,
+    pub anchor: Anchor,
+
+    // GravityActor trait
+    pub vel_y: i32,
+    pub landed: bool,
+}
+
+impl Player {
+    pub fn new(resources: &Resources) -> Self {
+        Self {
+            lives: 2,
+            score: 0,
+            direction_x: 0,
+            fire_timer: 0,
+            hurt_timer: 0,
+            health: 0,
+            blowing_orb: Weak::new(),
+
+            x: 0,
+            y: 0,
+            image: resources.blank_image.clone(),
+            anchor: GRAVITY_BODY_DEFAULT_ANCHOR,
+
+            vel_y: 0,
+            landed: false,
+        }
+    }
+
+    pub fn reset(&mut self) {
+        self.x = WIDTH / 2;
+        self.y = 100;
+        self.vel_y = 0;
+        self.direction_x = 1; // -1 = left, 1 = right
+        self.fire_timer = 0;
+        self.hurt_timer = 100; // Invulnerable for this many frames
+        self.health = 3;
+        self.blowing_orb = Weak::new();
+    }
+
+    pub fn hit_test(
+        &mut self,
+        context: &mut Context,
+        resources: &mut Resources,
+        other: &Bolt,
+    ) -> GameResult<bool> {
+        // Check for collision between player and bolt - called from Bolt.update. Also check hurt_timer - after being hurt,
+        // there is a period during which the player cannot be hurt again
+        if self.collidepoint((other.x, other.y)) && self.hurt_timer < 0 {
+            // Player loses 1 health, is knocked in the direction the bolt had been moving, and can't be hurt again
+            // for a while
+            self.hurt_timer = 200;
+            self.health -= 1;
+            self.vel_y = -12;
+            self.landed = false;
+            self.direction_x = other.direction_x;
+            if self.health > 0 {
+                play_game_random_sound(context, Some(&*self), &mut resources.ouch_sounds)?;
+            } else {
+                play_game_sound(context, Some(&*self), &mut resources.die_sound)?;
+            }
+            Ok(true)
+        } else {
+            Ok(false)
+        }
+    }
+
+    // Input is read here, as in the original code; the "just pressed" state of the space key is computed
+    // by the global state, since ggez doesn't provide it.
+    //
+    pub fn update(
+        &mut self,
+        context: &mut Context,
+        resources: &mut Resources,
+        orbs: &mut Vec<RcOrb>,
+        grid: &[&str],
+        game_timer: i32,
+        space_pressed: bool,
+    ) -> GameResult {
+        // Call GravityActor.update - parameter is whether we want to perform collision detection as we fall. If health
+        // is zero, we want the player to just fall out of the level
+        self.fall(
+            self.health > 0,
+            DEFAULT_GRAVITY,
+            DEFAULT_MAX_FALL_SPEED,
+            grid,
+        );
+
+        self.fire_timer -= 1;
+        self.hurt_timer -= 1;
+
+        // Get keyboard input. dx represents the direction the player is facing
+        // In the original code, this is inside the else block, which, in static languages, is out of scope.
+        let mut dx = 0;
+
+        if self.landed {
+            // Hurt timer starts at 200, but drops to 100 once the player has landed
+            self.hurt_timer = self.hurt_timer.min(100);
+        }
+
+        if self.hurt_timer > 100 {
+            // We've just been hurt. Either carry out the sideways motion from being knocked by a bolt, or if health is
+            // zero, we're dropping out of the level, so check for our sprite reaching a certain Y coordinate before
+            // reducing our lives count and responding the player. We check for the Y coordinate being the screen height
+            // plus 50%, rather than simply the screen height, because the former effectively gives us a short delay
+            // before the player respawns.
+            if self.health > 0 {
+                self.move_(self.direction_x, 0, 4, grid);
+            } else if self.top() >= (HEIGHT as f32 * 1.5) as i32 {
+                self.lives -= 1;
+                self.reset();
+            }
+        } else {
+            // We're not hurt
+            if is_key_pressed(context, KeyCode::Left) {
+                dx = -1;
+            } else if is_key_pressed(context, KeyCode::Right) {
+                dx = 1;
+            }
+
+            if dx != 0 {
+                self.direction_x = dx;
+
+                // If we haven't just fired an orb, carry out horizontal movement
+                if self.fire_timer < 10 {
+                    self.move_(dx, 0, 4, grid);
+                }
+            }
+
+            // Do we need to create a new orb? Space must have been pressed and released, the minimum time between
+            // orbs must have passed, and there is a limit of 5 orbs.
+            if space_pressed && self.fire_timer <= 0 && orbs.len() < 5 {
+                // x position will be 38 pixels in front of the player position, while ensuring it is within the
+                // bounds of the level
+                let x = (self.x() + self.direction_x * 38).clamp(70, 730);
+                let y = self.y() - 35;
+                let new_orb = Rc::new(RefCell::new(Orb::new(resources, x, y, self.direction_x)));
+                self.blowing_orb = Rc::downgrade(&new_orb);
+                orbs.push(new_orb);
+                play_game_random_sound(context, Some(&*self), &mut resources.blow_sounds)?;
+                self.fire_timer = 20;
+            }
+
+            if is_key_pressed(context, KeyCode::Up) && self.vel_y == 0 && self.landed {
+                // Jump
+                self.vel_y = -16;
+                self.landed = false;
+                play_game_sound(context, Some(&*self), &mut resources.jump_sound)?;
+            }
+        }
+
+        // Holding down space causes the current orb (if there is one) to be blown further
+        if is_key_pressed(context, KeyCode::Space) {
+            if let Some(blowing_orb) = Weak::upgrade(&self.blowing_orb) {
+                let mut blowing_orb = blowing_orb.borrow_mut();
+                // Increase blown distance up to a maximum of 120
+                blowing_orb.blown_frames += 4;
+                if blowing_orb.blown_frames >= 120 {
+                    // Can't be blown any further
+                    self.blowing_orb = Weak::new();
+                }
+            }
+        } else {
+            // If we let go of space, we relinquish control over the current orb - it can't be blown any further
+            self.blowing_orb = Weak::new();
+        }
+
+        // Set sprite image. If we're currently hurt, the sprite will flash on and off on alternate frames.
+        self.image = resources.blank_image.clone();
+        if self.hurt_timer <= 0 || self.hurt_timer % 2 == 1 {
+            let dir_index = if self.direction_x > 0 { 1 } else { 0 };
+            self.image = if self.hurt_timer > 100 {
+                if self.health > 0 {
+                    resources.recoil_images[dir_index].clone()
+                } else {
+                    let image_i = (game_timer / 4) % 2;
+                    resources.fall_images[image_i as usize].clone()
+                }
+            } else if self.fire_timer > 0 {
+                resources.blow_images[dir_index].clone()
+            } else if dx == 0 {
+                resources.still_image.clone()
+            } else {
+                let direction_factor = dir_index * 4;
+                let image_i = direction_factor + ((game_timer / 8) % 4) as usize;
+                resources.run_images[image_i].clone()
+            };
+        }
+
+        Ok(())
+    }
+}
+
+impl Body for Player {
+    fn x(&self) -> i32 {
+        self.x
+    }
+
+    fn x_mut(&mut self) -> &mut i32 {
+        &mut self.x
+    }
+
+    fn y(&self) -> i32 {
+        self.y
+    }
+
+    fn y_mut(&mut self) -> &mut i32 {
+        &mut self.y
+    }
+
+    fn size(&self) -> (i32, i32) {
+        image_size(&self.image)
+    }
+
+    fn anchor(&self) -> Anchor {
+        self.anchor
+    }
+}
+
+impl Actor for Player {
+    fn image(&self) -> &Image {
+        &self.image
+    }
+}
+
+impl CollideBody for Player {}
+
+impl GravityBody for Player {
+    fn vel_y(&self) -> i32 {
+        self.vel_y
+    }
+
+    fn vel_y_mut(&mut self) -> &mut i32 {
+        &mut self.vel_y
+    }
+
+    fn landed(&self) -> bool {
+        self.landed
+    }
+
+    fn landed_mut(&mut self) -> &mut bool {
+        &mut self.landed
+    }
+}
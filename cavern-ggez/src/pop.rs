@@ -0,0 +1,71 @@
+use ggez::graphics::Image;
+
+use crate::{
+    actor::{image_size, Actor, Anchor, Body},
+    resources::Resources,
+};
+
+pub struct Pop {
+    pub timer: i32,
+    pub type_: i32,
+
+    // Actor trait
+    pub x: i32,
+    pub y: i32,
+    pub image: Image,
+    pub anchor: Anchor,
+}
+
+impl Pop {
+    pub fn new(resources: &Resources, x: i32, y: i32, type_: i32) -> Self {
+        Self {
+            type_,
+            timer: -1,
+            x,
+            y,
+            image: resources.blank_image.clone(),
+            anchor: Anchor::Centre,
+        }
+    }
+
+    pub fn update(&mut self, resources: &Resources) {
+        self.timer += 1;
+
+        let type_factor = self.type_ * 7;
+        let timer_factor = self.timer / 2;
+        let image_i = (type_factor + timer_factor) as usize;
+        self.image = resources.pop_images[image_i].clone();
+    }
+}
+
+impl Body for Pop {
+    fn x(&self) -> i32 {
+        self.x
+    }
+
+    fn x_mut(&mut self) -> &mut i32 {
+        &mut self.x
+    }
+
+    fn y(&self) -> i32 {
+        self.y
+    }
+
+    fn y_mut(&mut self) -> &mut i32 {
+        &mut self.y
+    }
+
+    fn size(&self) -> (i32, i32) {
+        image_size(&self.image)
+    }
+
+    fn anchor(&self) -> Anchor {
+        self.anchor
+    }
+}
+
+impl Actor for Pop {
+    fn image(&self) -> &Image {
+        &self.image
+    }
+}
@@ -0,0 +1,179 @@
+use std::collections::HashMap;
+
+use ggez::{audio, graphics::Image, Context, GameResult};
+
+const AVAILABLE_FONTS: [u8; 37] = [
+    32, 48, 49, 50, 51, 52, 53, 54, 55, 56, 57, 65, 66, 67, 68, 69, 70, 71, 72, 73, 74, 75, 76, 77,
+    78, 79, 80, 81, 82, 83, 84, 85, 86, 87, 88, 89, 90,
+];
+
+fn load_images_list(
+    context: &mut Context,
+    name_prefix: &str,
+    number: u8,
+) -> GameResult<Vec<Image>> {
+    (0..number)
+        .map(|i| Image::new(context, format!("/{}{}.png", name_prefix, i)))
+        .collect()
+}
+
+fn load_images_map(
+    context: &mut Context,
+    names: &[&'static str],
+) -> GameResult<HashMap<&'static str, Image>> {
+    names
+        .iter()
+        .map(|name| Ok((*name, Image::new(context, format!("/{}.png", name))?)))
+        .collect()
+}
+
+/// Load images that have multiple states, e.g. Robots of two types, with two directions each.
+/// The images are stored in a contiguous array, respecting the order of the states passed.
+fn load_multi_state_images(
+    context: &mut Context,
+    name_prefix: &str,
+    states: &[&str],
+    state_number: u8,
+) -> GameResult<Vec<Image>> {
+    let mut images = vec![];
+
+    for state in states {
+        let prefix = format!("{}{}", name_prefix, state);
+        images.extend(load_images_list(context, &prefix, state_number)?);
+    }
+
+    Ok(images)
+}
+
+fn load_sounds_list(
+    context: &mut Context,
+    name_prefix: &str,
+    number: u8,
+) -> GameResult<Vec<audio::Source>> {
+    (0..number)
+        .map(|i| audio::Source::new(context, format!("/{}{}.ogg", name_prefix, i)))
+        .collect()
+}
+
+// Differently from Macroquad, ggez has no global storage, so the resources are passed around explicitly.
+// Sounds need to be mutable in order to be played.
+//
+pub struct Resources {
+    pub title_image: Image,
+    pub over_image: Image,
+    pub space_images: Vec<Image>,
+    pub status_images: HashMap<&'static str, Image>,
+    pub background_images: Vec<Image>,
+    pub block_images: Vec<Image>,
+    pub blank_image: Image,
+    /// Stored contiguously ("00..." -> "01..." -> "10..." -> "11...")
+    pub robot_images: Vec<Image>,
+    pub recoil_images: Vec<Image>,
+    pub fall_images: Vec<Image>,
+    pub blow_images: Vec<Image>,
+    pub still_image: Image,
+    /// Stored contiguously ("0..." -> "1...")
+    pub run_images: Vec<Image>,
+    pub orb_images: Vec<Image>,
+    pub trap_images: Vec<Image>,
+    pub bolt_images: Vec<Image>,
+    pub pop_images: Vec<Image>,
+    pub fruit_images: Vec<Image>,
+
+    pub over_sound: audio::Source,
+    pub level_sound: audio::Source,
+    pub pop_sounds: Vec<audio::Source>,
+    pub ouch_sounds: Vec<audio::Source>,
+    pub die_sound: audio::Source,
+    pub laser_sounds: Vec<audio::Source>,
+    pub trap_sounds: Vec<audio::Source>,
+    pub blow_sounds: Vec<audio::Source>,
+    pub jump_sound: audio::Source,
+    pub bonus_sound: audio::Source,
+    pub score_sound: audio::Source,
+
+    pub music: audio::Source,
+
+    pub fonts: HashMap<u8, Image>,
+}
+
+impl Resources {
+    pub fn new(context: &mut Context) -> GameResult<Self> {
+        let title_image = Image::new(context, "/title.png")?;
+        let over_image = Image::new(context, "/over.png")?;
+        let space_images = load_images_list(context, "space", 10)?;
+        let status_images = load_images_map(context, &["life", "plus", "health"])?;
+        let background_images = load_images_list(context, "bg", 4)?;
+        let block_images = load_images_list(context, "block", 4)?;
+        let blank_image = Image::new(context, "/blank.png")?;
+        let robot_images = load_multi_state_images(context, "robot", &["00", "01", "10", "11"], 8)?;
+        let recoil_images = load_images_list(context, "recoil", 2)?;
+        let fall_images = load_images_list(context, "fall", 2)?;
+        let blow_images = load_images_list(context, "blow", 2)?;
+        let still_image = Image::new(context, "/still.png")?;
+        let run_images = load_multi_state_images(context, "run", &["0", "1"], 4)?;
+        let orb_images = load_images_list(context, "orb", 7)?;
+        let trap_images = load_multi_state_images(context, "trap", &["0", "1"], 8)?;
+        let bolt_images = load_multi_state_images(context, "bolt", &["0", "1"], 2)?;
+        let pop_images = load_multi_state_images(context, "pop", &["0", "1"], 7)?;
+        let fruit_images =
+            load_multi_state_images(context, "fruit", &["0", "1", "2", "3", "4"], 3)?;
+
+        let over_sound = audio::Source::new(context, "/over0.ogg")?;
+        let level_sound = audio::Source::new(context, "/level0.ogg")?;
+        let pop_sounds = load_sounds_list(context, "pop", 4)?;
+        let ouch_sounds = load_sounds_list(context, "ouch", 4)?;
+        let die_sound = audio::Source::new(context, "/die0.ogg")?;
+        let laser_sounds = load_sounds_list(context, "laser", 4)?;
+        let trap_sounds = load_sounds_list(context, "trap", 4)?;
+        let blow_sounds = load_sounds_list(context, "blow", 4)?;
+        let jump_sound = audio::Source::new(context, "/jump0.ogg")?;
+        let bonus_sound = audio::Source::new(context, "/bonus0.ogg")?;
+        let score_sound = audio::Source::new(context, "/score0.ogg")?;
+
+        let music = audio::Source::new(context, "/theme.ogg")?;
+
+        let mut fonts = HashMap::new();
+        for chr in AVAILABLE_FONTS {
+            let font = Image::new(context, format!("/font0{:02}.png", chr))?;
+            fonts.insert(chr, font);
+        }
+
+        Ok(Self {
+            title_image,
+            over_image,
+            space_images,
+            status_images,
+            background_images,
+            block_images,
+            blank_image,
+            robot_images,
+            recoil_images,
+            fall_images,
+            blow_images,
+            still_image,
+            run_images,
+            orb_images,
+            trap_images,
+            bolt_images,
+            pop_images,
+            fruit_images,
+
+            over_sound,
+            level_sound,
+            pop_sounds,
+            ouch_sounds,
+            die_sound,
+            laser_sounds,
+            trap_sounds,
+            blow_sounds,
+            jump_sound,
+            bonus_sound,
+            score_sound,
+
+            music,
+
+            fonts,
+        })
+    }
+}
@@ -0,0 +1,214 @@
+use cavern_core::{
+    gravity::{DEFAULT_GRAVITY, DEFAULT_MAX_FALL_SPEED, GRAVITY_BODY_DEFAULT_ANCHOR},
+    CollideBody, GravityBody,
+};
+use ggez::{graphics::Image, Context, GameResult};
+
+use crate::{
+    actor::{image_size, Actor, Anchor, Body},
+    bolt::Bolt,
+    game_playback::play_game_random_sound,
+    orb::RcOrb,
+    player::Player,
+    resources::Resources,
+};
+
+#[derive(Clone, Copy)]
+pub enum RobotType {
+    Normal,
+    Aggressive,
+}
+
+impl RobotType {
+    pub fn val(&self) -> i32 {
+        match self {
+            RobotType::Normal => 0,
+            RobotType::Aggressive => 1,
+        }
+    }
+}
+
+pub struct Robot {
+    pub type_: RobotType,
+    pub speed: i32,
+    pub direction_x: i32,
+    pub alive: bool,
+    pub change_dir_timer: i32,
+    pub fire_timer: i32,
+
+    // Actor trait
+    pub x: i32,
+    pub y: i32,
+    pub image: Image,
+    pub anchor: Anchor,
+
+    // GravityActor trait
+    pub vel_y: i32,
+    pub landed: bool,
+}
+
+impl Robot {
+    pub fn new(resources: &Resources, x: i32, y: i32, type_: RobotType) -> Self {
+        Self {
+            x,
+            y,
+            image: resources.blank_image.clone(),
+            anchor: GRAVITY_BODY_DEFAULT_ANCHOR,
+            type_,
+            speed: fastrand::i32(1..4),
+            direction_x: 1,
+            alive: true,
+            change_dir_timer: 0,
+            fire_timer: 100,
+            vel_y: 0,
+            landed: false,
+        }
+    }
+
+    pub fn update(
+        &mut self,
+        context: &mut Context,
+        resources: &mut Resources,
+        bolts: &mut Vec<Bolt>,
+        orbs: &mut [RcOrb],
+        player: Option<&Player>,
+        mut fire_probability: f32,
+        game_timer: i32,
+        grid: &[&str],
+    ) -> GameResult {
+        self.fall(true, DEFAULT_GRAVITY, DEFAULT_MAX_FALL_SPEED, grid);
+
+        self.change_dir_timer -= 1;
+        self.fire_timer += 1;
+
+        // Move in current direction - turn around if we hit a wall
+        if self.move_(self.direction_x, 0, self.speed, grid) {
+            self.change_dir_timer = 0;
+        }
+
+        if self.change_dir_timer <= 0 {
+            // Randomly choose a direction to move in
+            // If there's a player, there's a two thirds chance that we'll move towards them
+            let mut directions = vec![-1, 1];
+            if let Some(player) = player {
+                directions.push((player.x() - self.x()).signum());
+            }
+            self.direction_x = directions[fastrand::usize(..directions.len())];
+            self.change_dir_timer = fastrand::i32(100..=250);
+        }
+
+        // The more powerful type of robot can deliberately shoot at orbs - turning to face them if necessary
+        if matches!(self.type_, RobotType::Aggressive) && self.fire_timer >= 24 {
+            // Go through all orbs to see if any can be shot at
+            for orb in orbs.iter() {
+                let orb = orb.borrow();
+                // The orb must be at our height, and within 200 pixels on the x axis
+                if orb.y >= self.top() && orb.y < self.bottom() && (orb.x() - self.x()).abs() < 200
+                {
+                    self.direction_x = (orb.x() - self.x()).signum();
+                    self.fire_timer = 0;
+                    break;
+                }
+            }
+        }
+
+        // Check to see if we can fire at player
+        if self.fire_timer >= 12 {
+            // Random chance of firing each frame. Likelihood increases 10 times if player is at the same height as us
+            if let Some(player) = player {
+                if self.top() < player.bottom() && self.bottom() > player.top() {
+                    fire_probability *= 10.;
+                }
+            }
+            if fastrand::f32() < fire_probability {
+                self.fire_timer = 0;
+                play_game_random_sound(context, player, &mut resources.laser_sounds)?;
+            }
+        } else if self.fire_timer == 8 {
+            //  Once the fire timer has been set to 0, it will count up - frame 8 of the animation is when the actual bolt is fired
+            bolts.push(Bolt::new(
+                resources,
+                self.x() + self.direction_x * 20,
+                self.y() - 38,
+                self.direction_x,
+            ));
+        }
+
+        // Am I colliding with an orb? If so, become trapped by it
+        for orb in orbs.iter() {
+            let mut orb = orb.borrow_mut();
+            if orb.trapped_enemy_type.is_none() && self.collidepoint(orb.center()) {
+                self.alive = false;
+                orb.floating = true;
+                orb.trapped_enemy_type = Some(self.type_);
+                play_game_random_sound(context, player, &mut resources.trap_sounds)?;
+                break;
+            }
+        }
+
+        // Choose and set sprite image
+        let type_factor = 16 * self.type_.val();
+        let direction_factor = if self.direction_x > 0 { 8 } else { 0 };
+        let fire_factor = if self.fire_timer < 12 {
+            5 + (self.fire_timer / 4)
+        } else {
+            1 + ((game_timer / 4) % 4)
+        };
+        let image_i = (type_factor + direction_factor + fire_factor) as usize;
+        self.image = resources.robot_images[image_i].clone();
+
+        Ok(())
+    }
+}
+
+impl Body for Robot {
+    fn x(&self) -> i32 {
+        self.x
+    }
+
+    fn x_mut(&mut self) -> &mut i32 {
+        &mut self.x
+    }
+
+    fn y(&self) -> i32 {
+        self.y
+    }
+
+    fn y_mut(&mut self) -> &mut i32 {
+        &mut self.y
+    }
+
+    fn size(&self) -> (i32, i32) {
+        image_size(&self.image)
+    }
+
+    fn anchor(&self) -> Anchor {
+        self.anchor
+    }
+}
+
+impl Actor for Robot {
+    fn image(&self) -> &Image {
+        &self.image
+    }
+}
+
+impl CollideBody for Robot {}
+
+impl GravityBody for Robot {
+    fn vel_y(&self) -> i32 {
+        self.vel_y
+    }
+
+    fn vel_y_mut(&mut self) -> &mut i32 {
+        &mut self.vel_y
+    }
+
+    fn landed(&self) -> bool {
+        self.landed
+    }
+
+    fn landed_mut(&mut self) -> &mut bool {
+        &mut self.landed
+    }
+}
@@ -0,0 +1,6 @@
+#[derive(Clone, Copy, PartialEq)]
+pub enum State {
+    Menu,
+    Play,
+    GameOver,
+}
@@ -0,0 +1,69 @@
+use cavern_core::{collide::COLLIDE_BODY_DEFAULT_ANCHOR, CollideBody};
+use macroquad::prelude::Texture2D;
+
+use crate::{
+    entity::{impl_body, Anchor, Entity, UpdateContext},
+    orb::Orb,
+    player::Player,
+    resources::Resources,
+};
+
+const SPEED: i32 = 7;
+
+/// Shot fired by the robots.
+pub struct Bolt {
+    pub direction_x: i32,
+    active: bool,
+
+    pub x: i32,
+    pub y: i32,
+    image: Texture2D,
+    anchor: Anchor,
+}
+
+impl Bolt {
+    pub fn new(x: i32, y: i32, direction_x: i32, resources: &Resources) -> Self {
+        Self {
+            direction_x,
+            active: true,
+            x,
+            y,
+            image: resources.blank_texture,
+            anchor: COLLIDE_BODY_DEFAULT_ANCHOR,
+        }
+    }
+
+    pub fn update(
+        &mut self,
+        orbs: &mut [Orb],
+        player: Option<&mut Player>,
+        ctx: &mut UpdateContext,
+    ) {
+        // Move horizontally, and check if we've collided with a block, then with an orb or the player
+        let collided = self.move_(self.direction_x, 0, SPEED, ctx.grid)
+            || orbs.iter_mut().any(|orb| orb.hit_test(self))
+            || player.is_some_and(|player| player.hit_test(self, ctx.sounds));
+
+        if collided {
+            self.active = false;
+        }
+
+        let direction_factor = if self.direction_x > 0 { 2 } else { 0 };
+        let anim_frame = (ctx.timer / 4) % 2;
+        self.image = ctx.resources.bolt_textures[(direction_factor + anim_frame) as usize];
+    }
+}
+
+impl_body!(Bolt);
+
+impl CollideBody for Bolt {}
+
+impl Entity for Bolt {
+    fn image(&self) -> Texture2D {
+        self.image
+    }
+
+    fn is_alive(&self) -> bool {
+        self.active
+    }
+}
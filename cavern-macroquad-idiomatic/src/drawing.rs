@@ -0,0 +1,35 @@
+use macroquad::prelude::{draw_texture, Texture2D, WHITE};
+
+use crate::{resources::Resources, WIDTH};
+
+/// Widths of the letters A to Z in the font images; the other characters (space and digits) have the width of A.
+const CHAR_WIDTH: [i32; 26] = [
+    27, 26, 25, 26, 25, 25, 26, 25, 12, 26, 26, 25, 33, 25, 26, 25, 27, 26, 26, 25, 26, 26, 38, 25,
+    25, 25,
+];
+
+/// Width of the digits.
+pub const NUMBER_WIDTH: i32 = CHAR_WIDTH[0];
+
+fn char_width(chr: u8) -> i32 {
+    let index = chr.saturating_sub(b'A') as usize;
+    CHAR_WIDTH[index]
+}
+
+pub fn text_width(text: &str) -> i32 {
+    text.bytes().map(char_width).sum()
+}
+
+pub fn draw_image(texture: Texture2D, x: i32, y: i32) {
+    draw_texture(texture, x as f32, y as f32, WHITE);
+}
+
+/// Draws the text with the game font; if no x is specified, the text is centered.
+pub fn draw_text(resources: &Resources, text: &str, y: i32, x: Option<i32>) {
+    let mut x = x.unwrap_or_else(|| (WIDTH - text_width(text)) / 2);
+
+    for chr in text.bytes() {
+        draw_image(resources.fonts[&chr], x, y);
+        x += char_width(chr);
+    }
+}
@@ -0,0 +1,95 @@
+use macroquad::prelude::{draw_texture, Texture2D, WHITE};
+
+use crate::{resources::Resources, sound_effect::SoundEffect};
+
+// The geometry is shared with the other Cavern ports, via the engine-agnostic core crate; the size of an entity is
+// the size of its current image.
+pub use cavern_core::{Anchor, Body};
+
+/// Common interface of the entities of a level. The game handles them as trait objects wherever it doesn't need
+/// the concrete type, i.e. when drawing them and when discarding the expired ones.
+pub trait Entity: Body {
+    /// The image is chosen on update, since the collisions depend on its size.
+    fn image(&self) -> Texture2D;
+
+    /// Entities that aren't alive anymore are removed at the end of the game update.
+    fn is_alive(&self) -> bool;
+
+    fn draw(&self) {
+        let (left, top) = self.top_left();
+
+        draw_texture(self.image(), left as f32, top as f32, WHITE);
+    }
+}
+
+/// Data shared by the updates of all the entities.
+pub struct UpdateContext<'a> {
+    pub resources: &'a Resources,
+    pub grid: &'a [&'static str],
+    /// Game timer; the animations are based on it.
+    pub timer: i32,
+    /// Sounds to play once the update is completed.
+    pub sounds: &'a mut Vec<SoundEffect>,
+}
+
+pub fn image_size(image: Texture2D) -> (i32, i32) {
+    (image.width() as i32, image.height() as i32)
+}
+
+/// Implements `Body` for a struct with `x`, `y`, `image` and `anchor` fields.
+macro_rules! impl_body {
+    ($type:ty) => {
+        impl crate::entity::Body for $type {
+            fn x(&self) -> i32 {
+                self.x
+            }
+
+            fn x_mut(&mut self) -> &mut i32 {
+                &mut self.x
+            }
+
+            fn y(&self) -> i32 {
+                self.y
+            }
+
+            fn y_mut(&mut self) -> &mut i32 {
+                &mut self.y
+            }
+
+            fn size(&self) -> (i32, i32) {
+                crate::entity::image_size(self.image)
+            }
+
+            fn anchor(&self) -> crate::entity::Anchor {
+                self.anchor
+            }
+        }
+    };
+}
+
+/// Implements `GravityBody` (and `CollideBody`) for a struct with `vel_y` and `landed` fields.
+macro_rules! impl_gravity_body {
+    ($type:ty) => {
+        impl cavern_core::CollideBody for $type {}
+
+        impl cavern_core::GravityBody for $type {
+            fn vel_y(&self) -> i32 {
+                self.vel_y
+            }
+
+            fn vel_y_mut(&mut self) -> &mut i32 {
+                &mut self.vel_y
+            }
+
+            fn landed(&self) -> bool {
+                self.landed
+            }
+
+            fn landed_mut(&mut self) -> &mut bool {
+                &mut self.landed
+            }
+        }
+    };
+}
+
+pub(crate) use {impl_body, impl_gravity_body};
@@ -0,0 +1,129 @@
+use cavern_core::{
+    gravity::{DEFAULT_GRAVITY, DEFAULT_MAX_FALL_SPEED, GRAVITY_BODY_DEFAULT_ANCHOR},
+    GravityBody,
+};
+use macroquad::{prelude::Texture2D, rand::ChooseRandom};
+
+use crate::{
+    entity::{impl_body, impl_gravity_body, Anchor, Body, Entity, UpdateContext},
+    player::Player,
+    pop::{Pop, PopType},
+    resources::Resources,
+    robot::RobotType,
+    sound_effect::SoundEffect,
+};
+
+const TIME_TO_LIVE: i32 = 500;
+
+/// The value is the index of the images row.
+#[derive(Clone, Copy)]
+enum FruitType {
+    Apple = 0,
+    Raspberry = 1,
+    Lemon = 2,
+    ExtraHealth = 3,
+    ExtraLife = 4,
+}
+
+pub struct Fruit {
+    type_: FruitType,
+    /// Counts down to zero.
+    time_to_live: i32,
+
+    x: i32,
+    y: i32,
+    image: Texture2D,
+    anchor: Anchor,
+    vel_y: i32,
+    landed: bool,
+}
+
+impl Fruit {
+    /// Fruits released by orbs carry the type of the enemy that was trapped.
+    pub fn new(
+        x: i32,
+        y: i32,
+        trapped_enemy_type: Option<RobotType>,
+        resources: &Resources,
+    ) -> Self {
+        use FruitType::*;
+
+        let type_ = match trapped_enemy_type {
+            Some(RobotType::Normal) => *[Apple, Raspberry, Lemon].choose().unwrap(),
+            _ => {
+                // Random fruits, and fruits from orbs containing the more dangerous type of enemy, have a chance of
+                // being an extra health or extra life power up. The list contains the types in proportion to their
+                // probability.
+                let mut types = [Apple, Raspberry, Lemon].repeat(10);
+                types.extend([ExtraHealth].repeat(9));
+                types.push(ExtraLife);
+                *types.choose().unwrap()
+            }
+        };
+
+        Self {
+            type_,
+            time_to_live: TIME_TO_LIVE,
+            x,
+            y,
+            image: resources.blank_texture,
+            anchor: GRAVITY_BODY_DEFAULT_ANCHOR,
+            vel_y: 0,
+            landed: false,
+        }
+    }
+
+    pub fn update(
+        &mut self,
+        player: Option<&mut Player>,
+        pops: &mut Vec<Pop>,
+        ctx: &mut UpdateContext,
+    ) {
+        self.fall(true, DEFAULT_GRAVITY, DEFAULT_MAX_FALL_SPEED, ctx.grid);
+
+        match player {
+            Some(player) if player.collidepoint(self.center()) => {
+                let sound = match self.type_ {
+                    FruitType::ExtraHealth => {
+                        player.gain_health();
+                        SoundEffect::Bonus
+                    }
+                    FruitType::ExtraLife => {
+                        player.lives += 1;
+                        SoundEffect::Bonus
+                    }
+                    _ => {
+                        player.score += (self.type_ as i32 + 1) * 100;
+                        SoundEffect::Score
+                    }
+                };
+                ctx.sounds.push(sound);
+
+                // Disappear
+                self.time_to_live = 0;
+            }
+            _ => self.time_to_live -= 1,
+        }
+
+        if !self.is_alive() {
+            pops.push(Pop::new(self.x, self.y - 27, PopType::Fruit, ctx.resources));
+        }
+
+        let anim_frame = [0, 1, 2, 1][((ctx.timer / 6) % 4) as usize];
+        let image_i = self.type_ as usize * 3 + anim_frame;
+        self.image = ctx.resources.fruit_textures[image_i];
+    }
+}
+
+impl_body!(Fruit);
+impl_gravity_body!(Fruit);
+
+impl Entity for Fruit {
+    fn image(&self) -> Texture2D {
+        self.image
+    }
+
+    fn is_alive(&self) -> bool {
+        self.time_to_live > 0
+    }
+}
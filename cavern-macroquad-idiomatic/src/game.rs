@@ -0,0 +1,252 @@
+use std::mem;
+
+use macroquad::rand::{gen_range, ChooseRandom};
+
+use crate::{
+    bolt::Bolt,
+    drawing::draw_image,
+    entity::{Entity, UpdateContext},
+    fruit::Fruit,
+    input::Input,
+    levels::LEVELS,
+    orb::Orb,
+    player::Player,
+    pop::Pop,
+    resources::Resources,
+    robot::{Robot, RobotType},
+    sound_effect::SoundEffect,
+    GRID_BLOCK_SIZE, LEVEL_X_OFFSET, NUM_COLUMNS, NUM_ROWS, WIDTH,
+};
+
+const FRUIT_INTERVAL: i32 = 100;
+const ROBOT_INTERVAL: i32 = 81;
+
+pub struct Game {
+    /// None while the title screen is showing the game in the background.
+    pub player: Option<Player>,
+    pub level: i32,
+    timer: i32,
+    grid: Vec<&'static str>,
+
+    fruits: Vec<Fruit>,
+    bolts: Vec<Bolt>,
+    enemies: Vec<Robot>,
+    /// Enemies still to be created in the current level.
+    pending_enemies: Vec<RobotType>,
+    pops: Vec<Pop>,
+    orbs: Vec<Orb>,
+
+    /// Emitted during the update; see `take_sounds()`.
+    sounds: Vec<SoundEffect>,
+}
+
+impl Game {
+    pub fn new(player: Option<Player>) -> Self {
+        let mut game = Self {
+            player,
+            level: -1,
+            timer: -1,
+            grid: vec![],
+            fruits: vec![],
+            bolts: vec![],
+            enemies: vec![],
+            pending_enemies: vec![],
+            pops: vec![],
+            orbs: vec![],
+            sounds: vec![],
+        };
+
+        game.next_level();
+
+        game
+    }
+
+    pub fn timer(&self) -> i32 {
+        self.timer
+    }
+
+    /// Returns the sounds emitted since the last invocation. Sounds are played only if there's a player, i.e. not
+    /// while the title screen is displayed.
+    pub fn take_sounds(&mut self) -> Vec<SoundEffect> {
+        let sounds = mem::take(&mut self.sounds);
+
+        if self.player.is_some() {
+            sounds
+        } else {
+            vec![]
+        }
+    }
+
+    pub fn update(&mut self, input: &Input, resources: &Resources) {
+        self.timer += 1;
+
+        let fire_probability = self.fire_probability();
+
+        let mut ctx = UpdateContext {
+            resources,
+            grid: &self.grid,
+            timer: self.timer,
+            sounds: &mut self.sounds,
+        };
+
+        // The update order is the same as the original game's, since the entities interact
+        for fruit in &mut self.fruits {
+            fruit.update(self.player.as_mut(), &mut self.pops, &mut ctx);
+        }
+        for bolt in &mut self.bolts {
+            bolt.update(&mut self.orbs, self.player.as_mut(), &mut ctx);
+        }
+        for enemy in &mut self.enemies {
+            enemy.update(
+                &mut self.bolts,
+                &mut self.orbs,
+                self.player.as_ref(),
+                fire_probability,
+                &mut ctx,
+            );
+        }
+        for pop in &mut self.pops {
+            pop.update(resources);
+        }
+        if let Some(player) = &mut self.player {
+            player.update(input, &mut self.orbs, &mut ctx);
+        }
+        for orb in &mut self.orbs {
+            orb.update(&mut self.fruits, &mut self.pops, &mut ctx);
+        }
+
+        retain_alive(&mut self.fruits);
+        retain_alive(&mut self.bolts);
+        retain_alive(&mut self.enemies);
+        retain_alive(&mut self.pops);
+        retain_alive(&mut self.orbs);
+
+        // Random fruits appear periodically, as long as there are enemies left
+        if self.timer % FRUIT_INTERVAL == 0 && !self.is_cleared_of_enemies() {
+            let (x, y) = (gen_range(70, 730 + 1), gen_range(75, 400 + 1));
+            self.fruits.push(Fruit::new(x, y, None, resources));
+        }
+
+        // Robots are released periodically, up to the maximum for the level
+        if self.timer % ROBOT_INTERVAL == 0 && self.enemies.len() < self.max_enemies() {
+            if let Some(robot_type) = self.pending_enemies.pop() {
+                let x = self.robot_spawn_x();
+                self.enemies.push(Robot::new(x, -30, robot_type, resources));
+            }
+        }
+
+        // The level ends when everything is cleared, except the orbs without enemies (otherwise the level would
+        // never end, if the player kept blowing orbs)
+        if self.is_cleared_of_enemies()
+            && self.fruits.is_empty()
+            && self.pops.is_empty()
+            && self.orbs.iter().all(|orb| !orb.is_trapping())
+        {
+            self.next_level();
+        }
+    }
+
+    pub fn draw(&self, resources: &Resources) {
+        // The background colour and the blocks change on each level
+        let level_style = (self.level % 4) as usize;
+
+        draw_image(resources.background_textures[level_style], 0, 0);
+
+        // The large blocks at the edge of the level are part of the background
+        let block_texture = resources.block_textures[level_style];
+
+        for (row_y, row) in self.grid.iter().enumerate().take(NUM_ROWS as usize) {
+            for (column_x, block) in row.bytes().enumerate() {
+                if block != b' ' {
+                    let x = LEVEL_X_OFFSET + column_x as i32 * GRID_BLOCK_SIZE;
+                    draw_image(block_texture, x, row_y as i32 * GRID_BLOCK_SIZE);
+                }
+            }
+        }
+
+        for entity in self.entities() {
+            entity.draw();
+        }
+    }
+
+    /// All the entities, in drawing order.
+    fn entities(&self) -> impl Iterator<Item = &dyn Entity> {
+        let fruits = self.fruits.iter().map(|e| e as &dyn Entity);
+        let bolts = self.bolts.iter().map(|e| e as &dyn Entity);
+        let enemies = self.enemies.iter().map(|e| e as &dyn Entity);
+        let pops = self.pops.iter().map(|e| e as &dyn Entity);
+        let orbs = self.orbs.iter().map(|e| e as &dyn Entity);
+        let player = self.player.iter().map(|e| e as &dyn Entity);
+
+        fruits
+            .chain(bolts)
+            .chain(enemies)
+            .chain(pops)
+            .chain(orbs)
+            .chain(player)
+    }
+
+    /// Likelihood per frame of each robot firing a bolt; they fire more often on higher levels.
+    fn fire_probability(&self) -> f32 {
+        0.001 + 0.0001 * self.level.min(100) as f32
+    }
+
+    /// Maximum number of enemies on screen at once; it increases as the player progresses through the levels.
+    fn max_enemies(&self) -> usize {
+        ((self.level as usize + 6) / 2).min(8)
+    }
+
+    fn is_cleared_of_enemies(&self) -> bool {
+        self.pending_enemies.is_empty() && self.enemies.is_empty()
+    }
+
+    /// Robots spawn in a random empty spot of the top row, or, if there isn't any (which shouldn't happen), in the
+    /// centre of the screen.
+    fn robot_spawn_x(&self) -> i32 {
+        let start = gen_range(0, NUM_COLUMNS);
+
+        (0..NUM_COLUMNS)
+            .map(|i| (start + i) % NUM_COLUMNS)
+            .find(|grid_x| self.grid[0].as_bytes()[*grid_x as usize] == b' ')
+            .map_or(WIDTH / 2, |grid_x| {
+                GRID_BLOCK_SIZE * grid_x + LEVEL_X_OFFSET + 12
+            })
+    }
+
+    fn next_level(&mut self) {
+        self.level += 1;
+
+        // The last row is a copy of the first one
+        self.grid = LEVELS[self.level as usize % LEVELS.len()].to_vec();
+        self.grid.push(self.grid[0]);
+
+        self.timer = -1;
+
+        if let Some(player) = &mut self.player {
+            player.reset();
+        }
+
+        self.fruits.clear();
+        self.bolts.clear();
+        self.enemies.clear();
+        self.pops.clear();
+        self.orbs.clear();
+
+        // The enemies of the level are created as it plays out; the number, and the proportion of the more powerful
+        // type, increase with the level. They're released in random order.
+        let num_enemies = 10 + self.level as usize;
+        let num_strong_enemies = 1 + (self.level as f32 / 1.5) as usize;
+        let num_weak_enemies = num_enemies - num_strong_enemies;
+
+        self.pending_enemies = [RobotType::Aggressive].repeat(num_strong_enemies);
+        self.pending_enemies
+            .extend([RobotType::Normal].repeat(num_weak_enemies));
+        self.pending_enemies.shuffle();
+
+        self.sounds.push(SoundEffect::Level);
+    }
+}
+
+fn retain_alive<T: Entity>(entities: &mut Vec<T>) {
+    entities.retain(|entity| entity.is_alive());
+}
@@ -0,0 +1,136 @@
+use crate::{
+    drawing::{draw_image, draw_text, NUMBER_WIDTH},
+    game::Game,
+    input::Input,
+    player::Player,
+    resources::Resources,
+    sound_effect::SoundEffect,
+    WIDTH,
+};
+
+/// Maximum number of lives displayed; beyond it, a plus symbol is displayed.
+const MAX_LIVES_DISPLAYED: i32 = 2;
+
+// Horizontal spacing of the status icons.
+const LIFE_SPACING: i32 = 44;
+const PLUS_SPACING: i32 = 40;
+const HEALTH_SPACING: i32 = 40;
+
+/// The game is shown in the background of the title screen, and under the game over screen.
+#[derive(Clone, Copy, PartialEq)]
+enum State {
+    Menu,
+    Play,
+    GameOver,
+}
+
+/// Global state, not to be confused with the game state (which is a part of it).
+pub struct GlobalState {
+    state: State,
+    game: Game,
+    resources: Resources,
+}
+
+impl GlobalState {
+    pub fn new(resources: Resources) -> Self {
+        Self {
+            state: State::Menu,
+            game: Game::new(None),
+            resources,
+        }
+    }
+
+    pub fn update(&mut self, input: &Input) {
+        let resources = &self.resources;
+
+        match self.state {
+            State::Menu => {
+                if input.blow_pressed {
+                    self.state = State::Play;
+                    self.game = Game::new(Some(Player::new(resources)));
+                } else {
+                    self.game.update(input, resources);
+                }
+            }
+            State::Play => {
+                let game_over = self
+                    .game
+                    .player
+                    .as_ref()
+                    .is_none_or(|player| player.is_game_over());
+
+                if game_over {
+                    self.state = State::GameOver;
+                    resources.play(SoundEffect::Over);
+                } else {
+                    self.game.update(input, resources);
+                }
+            }
+            State::GameOver => {
+                if input.blow_pressed {
+                    self.state = State::Menu;
+                    self.game = Game::new(None);
+                }
+            }
+        }
+
+        for sound in self.game.take_sounds() {
+            resources.play(sound);
+        }
+    }
+
+    pub fn draw(&self) {
+        let resources = &self.resources;
+
+        self.game.draw(resources);
+
+        match self.state {
+            State::Menu => {
+                draw_image(resources.title_texture, 0, 0);
+
+                // The "Press SPACE" animation has 10 frames, each displayed for 4 frames; it then stays on the last
+                // one, for three quarters of the cycle. The offset makes the game start at a nicer point.
+                let anim_frame = (((self.game.timer() + 40) % 160) / 4).min(9);
+                draw_image(resources.space_textures[anim_frame as usize], 130, 280);
+            }
+            State::Play => self.draw_status(),
+            State::GameOver => {
+                self.draw_status();
+                draw_image(resources.over_texture, 0, 0);
+            }
+        }
+    }
+
+    fn draw_status(&self) {
+        let resources = &self.resources;
+        let player = match &self.game.player {
+            Some(player) => player,
+            None => return,
+        };
+
+        // Score, right-justified at the edge of the screen
+        let score = player.score.to_string();
+        let score_x = WIDTH - 2 - NUMBER_WIDTH * score.len() as i32;
+        draw_text(resources, &score, 451, Some(score_x));
+
+        let level = format!("LEVEL {}", self.game.level + 1);
+        draw_text(resources, &level, 451, None);
+
+        // Lives, then health. The images are spaced as in the original game, where they slightly overlap.
+        let lives_displayed = player.lives.clamp(0, MAX_LIVES_DISPLAYED) as usize;
+        let mut icons = vec![(resources.life_texture, LIFE_SPACING); lives_displayed];
+        if player.lives > MAX_LIVES_DISPLAYED {
+            icons.push((resources.plus_texture, PLUS_SPACING));
+        }
+        if player.lives >= 0 {
+            let health = player.health.max(0) as usize;
+            icons.extend(vec![(resources.health_texture, HEALTH_SPACING); health]);
+        }
+
+        let mut x = 0;
+        for (texture, spacing) in icons {
+            draw_image(texture, x, 450);
+            x += spacing;
+        }
+    }
+}
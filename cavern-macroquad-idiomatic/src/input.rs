@@ -0,0 +1,24 @@
+use macroquad::prelude::{is_key_down, is_key_pressed, KeyCode};
+
+/// The keyboard state, sampled once per frame, before the update; the game logic doesn't read the keyboard.
+#[derive(Clone, Copy, Default)]
+pub struct Input {
+    pub left: bool,
+    pub right: bool,
+    pub jump: bool,
+    pub blow: bool,
+    /// Space has been pressed in this frame (as opposed to being held). It's also used to move across the screens.
+    pub blow_pressed: bool,
+}
+
+impl Input {
+    pub fn read() -> Self {
+        Self {
+            left: is_key_down(KeyCode::Left),
+            right: is_key_down(KeyCode::Right),
+            jump: is_key_down(KeyCode::Up),
+            blow: is_key_down(KeyCode::Space),
+            blow_pressed: is_key_pressed(KeyCode::Space),
+        }
+    }
+}
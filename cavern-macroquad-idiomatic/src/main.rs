@@ -0,0 +1,72 @@
+#![deny(clippy::correctness)]
+
+mod bolt;
+mod drawing;
+mod entity;
+mod fruit;
+mod game;
+mod global_state;
+mod input;
+mod levels;
+mod orb;
+mod player;
+mod pop;
+mod resources;
+mod robot;
+mod sound_effect;
+
+use macroquad::{
+    audio::{self, PlaySoundParams},
+    file::FileError,
+    miniquad::date,
+    prelude::{next_frame, Conf},
+    rand::srand,
+};
+
+use global_state::GlobalState;
+use input::Input;
+use resources::Resources;
+
+// Differently from the naive port, which is a line-by-line translation of the original code, this port is
+// structured as an idiomatic Rust program: there are no globals (the resources are passed explicitly), the input
+// is sampled before the update, and the update and draw phases are separated; the game logic is shared with
+// the other ports, via the engine-agnostic core crate.
+pub use cavern_core::{GRID_BLOCK_SIZE, HEIGHT, LEVEL_X_OFFSET, NUM_COLUMNS, NUM_ROWS, WIDTH};
+
+const TITLE: &str = "Cavern Macroquad";
+
+fn window_conf() -> Conf {
+    Conf {
+        window_title: TITLE.into(),
+        window_width: WIDTH,
+        window_height: HEIGHT,
+        window_resizable: false,
+        ..Default::default()
+    }
+}
+
+#[macroquad::main(window_conf())]
+async fn main() -> Result<(), FileError> {
+    srand(date::now() as u64);
+
+    let resources = Resources::load().await?;
+
+    audio::play_sound(
+        resources.music,
+        PlaySoundParams {
+            looped: true,
+            volume: 0.3,
+        },
+    );
+
+    let mut state = GlobalState::new(resources);
+
+    loop {
+        let input = Input::read();
+
+        state.update(&input);
+        state.draw();
+
+        next_frame().await
+    }
+}
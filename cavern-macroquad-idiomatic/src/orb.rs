@@ -0,0 +1,157 @@
+use cavern_core::{
+    orb::{self, OrbImage},
+    CollideBody, OrbBody,
+};
+use macroquad::{prelude::Texture2D, rand::gen_range};
+
+use crate::{
+    bolt::Bolt,
+    entity::{impl_body, Anchor, Body, Entity, UpdateContext},
+    fruit::Fruit,
+    pop::{Pop, PopType},
+    resources::Resources,
+    robot::RobotType,
+    sound_effect::SoundEffect,
+};
+
+/// Frames added to the orb travel, for each frame blow is held.
+const BLOW_INCREMENT: i32 = 4;
+const MAX_BLOWN_FRAMES: i32 = 120;
+
+pub struct Orb {
+    direction_x: i32,
+    timer: i32,
+    floating: bool,
+    /// Time during which the orb is pushed horizontally.
+    blown_frames: i32,
+    trapped_enemy_type: Option<RobotType>,
+    /// Set while the player is blowing the orb further. Since space must be released before blowing a new orb,
+    /// only one orb can be in this state, so the player doesn't need to keep a reference to it.
+    blowing: bool,
+
+    pub x: i32,
+    pub y: i32,
+    image: Texture2D,
+    anchor: Anchor,
+}
+
+impl Orb {
+    /// Orbs are initially blown horizontally, then start floating upwards.
+    pub fn new(x: i32, y: i32, direction_x: i32, resources: &Resources) -> Self {
+        Self {
+            direction_x,
+            timer: -1,
+            floating: false,
+            blown_frames: orb::INITIAL_BLOWN_FRAMES,
+            trapped_enemy_type: None,
+            blowing: true,
+            x,
+            y,
+            image: resources.blank_texture,
+            anchor: Anchor::Centre,
+        }
+    }
+
+    pub fn is_blowing(&self) -> bool {
+        self.blowing
+    }
+
+    /// Increases the blown distance, up to a maximum; after that, the orb can't be blown any further.
+    pub fn blow(&mut self) {
+        self.blown_frames += BLOW_INCREMENT;
+
+        if self.blown_frames >= MAX_BLOWN_FRAMES {
+            self.blowing = false;
+        }
+    }
+
+    /// Once the player stops blowing, the orb can't be blown any further.
+    pub fn stop_blowing(&mut self) {
+        self.blowing = false;
+    }
+
+    pub fn is_trapping(&self) -> bool {
+        self.trapped_enemy_type.is_some()
+    }
+
+    pub fn trap(&mut self, enemy_type: RobotType) {
+        self.floating = true;
+        self.trapped_enemy_type = Some(enemy_type);
+    }
+
+    /// If the bolt collides, the orb pops on the next update.
+    pub fn hit_test(&mut self, bolt: &Bolt) -> bool {
+        let collided = self.collidepoint((bolt.x, bolt.y));
+
+        if collided {
+            self.timer = orb::MAX_TIMER - 1;
+        }
+
+        collided
+    }
+
+    pub fn update(
+        &mut self,
+        fruits: &mut Vec<Fruit>,
+        pops: &mut Vec<Pop>,
+        ctx: &mut UpdateContext,
+    ) {
+        self.timer += 1;
+
+        self.step(|| gen_range(1, 3), orb::DEFAULT_SPEED, ctx.grid);
+
+        if self.timer == self.blown_frames {
+            self.floating = true;
+        } else if !self.is_alive() {
+            // Pop if our lifetime has run out, or if we have gone off the top of the screen; orbs containing an
+            // enemy release a fruit (or, for the more dangerous enemies, possibly a power up)
+            pops.push(Pop::new(self.x, self.y, PopType::Orb, ctx.resources));
+
+            if let Some(trapped_enemy_type) = self.trapped_enemy_type {
+                fruits.push(Fruit::new(
+                    self.x,
+                    self.y,
+                    Some(trapped_enemy_type),
+                    ctx.resources,
+                ));
+            }
+
+            ctx.sounds.push(SoundEffect::Pop);
+        }
+
+        let trapped_enemy_type = self.trapped_enemy_type.map(|enemy_type| enemy_type as i32);
+
+        self.image = match orb::image(self.timer, trapped_enemy_type) {
+            OrbImage::Orb(image_i) => ctx.resources.orb_textures[image_i],
+            OrbImage::Trap(image_i) => ctx.resources.trap_textures[image_i],
+        };
+    }
+}
+
+impl_body!(Orb);
+
+impl CollideBody for Orb {}
+
+impl OrbBody for Orb {
+    fn direction_x(&self) -> i32 {
+        self.direction_x
+    }
+
+    fn floating(&self) -> bool {
+        self.floating
+    }
+
+    fn floating_mut(&mut self) -> &mut bool {
+        &mut self.floating
+    }
+}
+
+impl Entity for Orb {
+    fn image(&self) -> Texture2D {
+        self.image
+    }
+
+    fn is_alive(&self) -> bool {
+        !orb::is_popped(self.timer, self.y)
+    }
+}
@@ -0,0 +1,227 @@
+use cavern_core::{
+    gravity::{DEFAULT_GRAVITY, DEFAULT_MAX_FALL_SPEED, GRAVITY_BODY_DEFAULT_ANCHOR},
+    CollideBody, GravityBody,
+};
+use macroquad::prelude::Texture2D;
+
+use crate::{
+    bolt::Bolt,
+    entity::{impl_body, impl_gravity_body, Anchor, Body, Entity, UpdateContext},
+    input::Input,
+    orb::Orb,
+    resources::Resources,
+    sound_effect::SoundEffect,
+    HEIGHT, WIDTH,
+};
+
+const MAX_HEALTH: i32 = 3;
+const MAX_ORBS: usize = 5;
+const SPEED: i32 = 4;
+const JUMP_VELOCITY: i32 = -16;
+/// Minimum time between orbs.
+const FIRE_INTERVAL: i32 = 20;
+/// After blowing an orb, the player can't move until the fire timer goes below this.
+const FIRE_MOVE_HOLD_OFF: i32 = 10;
+/// After being hurt, the player is knocked back while the hurt timer is above this, then stays invulnerable until
+/// it runs out.
+const INVULNERABLE_TIME: i32 = 100;
+const HURT_TIME: i32 = 200;
+const KNOCKBACK_VELOCITY: i32 = -12;
+/// Distance of a new orb from the player.
+const ORB_DISTANCE_X: i32 = 38;
+const ORB_DISTANCE_Y: i32 = 35;
+
+pub struct Player {
+    pub lives: i32,
+    pub score: i32,
+    pub health: i32,
+    /// -1 = left, 1 = right
+    direction_x: i32,
+    fire_timer: i32,
+    hurt_timer: i32,
+
+    x: i32,
+    y: i32,
+    image: Texture2D,
+    anchor: Anchor,
+    vel_y: i32,
+    landed: bool,
+}
+
+impl Player {
+    pub fn new(resources: &Resources) -> Self {
+        Self {
+            lives: 2,
+            score: 0,
+            health: 0,
+            direction_x: 0,
+            fire_timer: 0,
+            hurt_timer: 0,
+            x: 0,
+            y: 0,
+            image: resources.blank_texture,
+            anchor: GRAVITY_BODY_DEFAULT_ANCHOR,
+            vel_y: 0,
+            landed: false,
+        }
+    }
+
+    pub fn is_game_over(&self) -> bool {
+        self.lives < 0
+    }
+
+    pub fn reset(&mut self) {
+        self.x = WIDTH / 2;
+        self.y = 100;
+        self.vel_y = 0;
+        self.direction_x = 1;
+        self.fire_timer = 0;
+        self.hurt_timer = INVULNERABLE_TIME;
+        self.health = MAX_HEALTH;
+    }
+
+    pub fn gain_health(&mut self) {
+        self.health = (self.health + 1).min(MAX_HEALTH);
+    }
+
+    /// If the bolt collides, the player loses one health, is knocked in the direction the bolt was moving, and can't
+    /// be hurt again for a while.
+    pub fn hit_test(&mut self, bolt: &Bolt, sounds: &mut Vec<SoundEffect>) -> bool {
+        if !self.collidepoint((bolt.x, bolt.y)) || self.hurt_timer >= 0 {
+            return false;
+        }
+
+        self.hurt_timer = HURT_TIME;
+        self.health -= 1;
+        self.vel_y = KNOCKBACK_VELOCITY;
+        self.landed = false;
+        self.direction_x = bolt.direction_x;
+
+        sounds.push(if self.health > 0 {
+            SoundEffect::Ouch
+        } else {
+            SoundEffect::Die
+        });
+
+        true
+    }
+
+    pub fn update(&mut self, input: &Input, orbs: &mut Vec<Orb>, ctx: &mut UpdateContext) {
+        // Without health left, the player just falls out of the level
+        self.fall(
+            self.health > 0,
+            DEFAULT_GRAVITY,
+            DEFAULT_MAX_FALL_SPEED,
+            ctx.grid,
+        );
+
+        self.fire_timer -= 1;
+        self.hurt_timer -= 1;
+
+        if self.landed {
+            // The knockback ends once the player lands
+            self.hurt_timer = self.hurt_timer.min(INVULNERABLE_TIME);
+        }
+
+        let mut dx = 0;
+
+        if self.is_knocked_back() {
+            // Either carry out the knockback, or, without health left, wait until the player has dropped out of the
+            // level (a little beyond the bottom of the screen, which gives a short delay) before respawning.
+            if self.health > 0 {
+                self.move_(self.direction_x, 0, SPEED, ctx.grid);
+            } else if self.top() >= HEIGHT * 3 / 2 {
+                self.lives -= 1;
+                self.reset();
+            }
+        } else {
+            if input.left {
+                dx = -1;
+            } else if input.right {
+                dx = 1;
+            }
+
+            if dx != 0 {
+                self.direction_x = dx;
+
+                if self.fire_timer < FIRE_MOVE_HOLD_OFF {
+                    self.move_(dx, 0, SPEED, ctx.grid);
+                }
+            }
+
+            if input.blow_pressed && self.fire_timer <= 0 && orbs.len() < MAX_ORBS {
+                self.blow_orb(orbs, ctx);
+            }
+
+            if input.jump && self.vel_y == 0 && self.landed {
+                self.vel_y = JUMP_VELOCITY;
+                self.landed = false;
+                ctx.sounds.push(SoundEffect::Jump);
+            }
+        }
+
+        // Holding space blows the last orb further; releasing it relinquishes control over the orb
+        if let Some(orb) = orbs.iter_mut().find(|orb| orb.is_blowing()) {
+            if input.blow {
+                orb.blow();
+            } else {
+                orb.stop_blowing();
+            }
+        }
+
+        self.image = self.choose_image(dx, ctx);
+    }
+
+    fn is_knocked_back(&self) -> bool {
+        self.hurt_timer > INVULNERABLE_TIME
+    }
+
+    /// The new orb is created in front of the player, within the bounds of the level.
+    fn blow_orb(&mut self, orbs: &mut Vec<Orb>, ctx: &mut UpdateContext) {
+        let x = (self.x + self.direction_x * ORB_DISTANCE_X).clamp(70, 730);
+        let y = self.y - ORB_DISTANCE_Y;
+        orbs.push(Orb::new(x, y, self.direction_x, ctx.resources));
+
+        ctx.sounds.push(SoundEffect::Blow);
+        self.fire_timer = FIRE_INTERVAL;
+    }
+
+    /// While hurt, the sprite flashes on and off on alternate frames.
+    fn choose_image(&self, dx: i32, ctx: &UpdateContext) -> Texture2D {
+        let resources = ctx.resources;
+
+        if self.hurt_timer > 0 && self.hurt_timer % 2 == 0 {
+            return resources.blank_texture;
+        }
+
+        let dir_index = if self.direction_x > 0 { 1 } else { 0 };
+
+        if self.is_knocked_back() {
+            if self.health > 0 {
+                resources.recoil_textures[dir_index]
+            } else {
+                resources.fall_textures[((ctx.timer / 4) % 2) as usize]
+            }
+        } else if self.fire_timer > 0 {
+            resources.blow_textures[dir_index]
+        } else if dx == 0 {
+            resources.still_texture
+        } else {
+            let anim_frame = ((ctx.timer / 8) % 4) as usize;
+            resources.run_textures[dir_index * 4 + anim_frame]
+        }
+    }
+}
+
+impl_body!(Player);
+impl_gravity_body!(Player);
+
+impl Entity for Player {
+    fn image(&self) -> Texture2D {
+        self.image
+    }
+
+    fn is_alive(&self) -> bool {
+        !self.is_game_over()
+    }
+}
@@ -0,0 +1,63 @@
+use macroquad::prelude::Texture2D;
+
+use crate::{
+    entity::{impl_body, Anchor, Entity},
+    resources::Resources,
+};
+
+/// Number of images of the animation, for each type.
+const IMAGES: i32 = 7;
+/// Each image is displayed for two frames; the last one is not displayed.
+const DURATION: i32 = 12;
+
+/// The value is the index of the images row.
+#[derive(Clone, Copy)]
+pub enum PopType {
+    Fruit = 0,
+    Orb = 1,
+}
+
+/// Animation displayed when a fruit disappears, or an orb pops.
+pub struct Pop {
+    type_: PopType,
+    timer: i32,
+
+    x: i32,
+    y: i32,
+    image: Texture2D,
+    anchor: Anchor,
+}
+
+impl Pop {
+    pub fn new(x: i32, y: i32, type_: PopType, resources: &Resources) -> Self {
+        Self {
+            type_,
+            timer: -1,
+            x,
+            y,
+            image: resources.blank_texture,
+            anchor: Anchor::Centre,
+        }
+    }
+
+    pub fn update(&mut self, resources: &Resources) {
+        self.timer += 1;
+
+        if self.is_alive() {
+            let image_i = self.type_ as i32 * IMAGES + self.timer / 2;
+            self.image = resources.pop_textures[image_i as usize];
+        }
+    }
+}
+
+impl_body!(Pop);
+
+impl Entity for Pop {
+    fn image(&self) -> Texture2D {
+        self.image
+    }
+
+    fn is_alive(&self) -> bool {
+        self.timer < DURATION
+    }
+}
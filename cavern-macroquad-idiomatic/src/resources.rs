@@ -0,0 +1,135 @@
+use std::collections::HashMap;
+
+use macroquad::{
+    audio::{self, load_sound, Sound},
+    file::FileError,
+    prelude::{load_texture, Texture2D},
+    rand::ChooseRandom,
+};
+
+use crate::sound_effect::SoundEffect;
+
+const AVAILABLE_FONTS: [u8; 37] = [
+    32, 48, 49, 50, 51, 52, 53, 54, 55, 56, 57, 65, 66, 67, 68, 69, 70, 71, 72, 73, 74, 75, 76, 77,
+    78, 79, 80, 81, 82, 83, 84, 85, 86, 87, 88, 89, 90,
+];
+
+async fn load_image(name: &str) -> Result<Texture2D, FileError> {
+    load_texture(&format!("resources/images/{}.png", name)).await
+}
+
+async fn load_images_list(name_prefix: &str, number: u8) -> Result<Vec<Texture2D>, FileError> {
+    let mut textures = vec![];
+
+    for i in 0..number {
+        textures.push(load_image(&format!("{}{}", name_prefix, i)).await?);
+    }
+
+    Ok(textures)
+}
+
+/// Loads the images that have multiple states, e.g. robots of two types, with two directions each. The images are
+/// stored contiguously, in the order of the states passed.
+async fn load_multi_state_images(
+    name_prefix: &str,
+    states: &[&str],
+    state_number: u8,
+) -> Result<Vec<Texture2D>, FileError> {
+    let mut textures = vec![];
+
+    for state in states {
+        let prefix = format!("{}{}", name_prefix, state);
+        textures.extend(load_images_list(&prefix, state_number).await?);
+    }
+
+    Ok(textures)
+}
+
+async fn load_sounds_list(name_prefix: &str, number: u8) -> Result<Vec<Sound>, FileError> {
+    let mut sounds = vec![];
+
+    for i in 0..number {
+        sounds.push(load_sound(&format!("resources/sounds/{}{}.ogg", name_prefix, i)).await?);
+    }
+
+    Ok(sounds)
+}
+
+/// All the assets, loaded once at startup, and passed explicitly to whatever needs them.
+pub struct Resources {
+    pub title_texture: Texture2D,
+    pub over_texture: Texture2D,
+    pub space_textures: Vec<Texture2D>,
+    pub life_texture: Texture2D,
+    pub plus_texture: Texture2D,
+    pub health_texture: Texture2D,
+    pub background_textures: Vec<Texture2D>,
+    pub block_textures: Vec<Texture2D>,
+    pub blank_texture: Texture2D,
+    /// Stored contiguously ("00..." -> "01..." -> "10..." -> "11...")
+    pub robot_textures: Vec<Texture2D>,
+    pub recoil_textures: Vec<Texture2D>,
+    pub fall_textures: Vec<Texture2D>,
+    pub blow_textures: Vec<Texture2D>,
+    pub still_texture: Texture2D,
+    /// Stored contiguously ("0..." -> "1...")
+    pub run_textures: Vec<Texture2D>,
+    pub orb_textures: Vec<Texture2D>,
+    pub trap_textures: Vec<Texture2D>,
+    pub bolt_textures: Vec<Texture2D>,
+    pub pop_textures: Vec<Texture2D>,
+    pub fruit_textures: Vec<Texture2D>,
+    pub fonts: HashMap<u8, Texture2D>,
+
+    pub music: Sound,
+    sounds: HashMap<SoundEffect, Vec<Sound>>,
+}
+
+impl Resources {
+    pub async fn load() -> Result<Self, FileError> {
+        let mut fonts = HashMap::new();
+        for chr in AVAILABLE_FONTS {
+            fonts.insert(chr, load_image(&format!("font0{:02}", chr)).await?);
+        }
+
+        let mut sounds = HashMap::new();
+        for effect in SoundEffect::ALL {
+            sounds.insert(
+                effect,
+                load_sounds_list(effect.name(), effect.variants()).await?,
+            );
+        }
+
+        Ok(Self {
+            title_texture: load_image("title").await?,
+            over_texture: load_image("over").await?,
+            space_textures: load_images_list("space", 10).await?,
+            life_texture: load_image("life").await?,
+            plus_texture: load_image("plus").await?,
+            health_texture: load_image("health").await?,
+            background_textures: load_images_list("bg", 4).await?,
+            block_textures: load_images_list("block", 4).await?,
+            blank_texture: load_image("blank").await?,
+            robot_textures: load_multi_state_images("robot", &["00", "01", "10", "11"], 8).await?,
+            recoil_textures: load_images_list("recoil", 2).await?,
+            fall_textures: load_images_list("fall", 2).await?,
+            blow_textures: load_images_list("blow", 2).await?,
+            still_texture: load_image("still").await?,
+            run_textures: load_multi_state_images("run", &["0", "1"], 4).await?,
+            orb_textures: load_images_list("orb", 7).await?,
+            trap_textures: load_multi_state_images("trap", &["0", "1"], 8).await?,
+            bolt_textures: load_multi_state_images("bolt", &["0", "1"], 2).await?,
+            pop_textures: load_multi_state_images("pop", &["0", "1"], 7).await?,
+            fruit_textures: load_multi_state_images("fruit", &["0", "1", "2", "3", "4"], 3).await?,
+            fonts,
+
+            music: audio::load_sound("resources/music/theme.ogg").await?,
+            sounds,
+        })
+    }
+
+    pub fn play(&self, effect: SoundEffect) {
+        let sound = self.sounds[&effect].choose().unwrap();
+        audio::play_sound_once(*sound);
+    }
+}
@@ -0,0 +1,170 @@
+use cavern_core::{
+    gravity::{DEFAULT_GRAVITY, DEFAULT_MAX_FALL_SPEED, GRAVITY_BODY_DEFAULT_ANCHOR},
+    CollideBody, GravityBody,
+};
+use macroquad::{
+    prelude::Texture2D,
+    rand::{gen_range, ChooseRandom},
+};
+
+use crate::{
+    bolt::Bolt,
+    entity::{impl_body, impl_gravity_body, Anchor, Body, Entity, UpdateContext},
+    orb::Orb,
+    player::Player,
+    resources::Resources,
+    sound_effect::SoundEffect,
+};
+
+/// Minimum time between shots.
+const FIRE_INTERVAL: i32 = 12;
+/// Minimum time between shots at the orbs, for the aggressive robots.
+const ORB_FIRE_INTERVAL: i32 = 24;
+/// Frame of the firing animation at which the bolt is released.
+const BOLT_RELEASE_FRAME: i32 = 8;
+/// Maximum horizontal distance of the orbs the aggressive robots fire at.
+const ORB_FIRE_DISTANCE: i32 = 200;
+
+/// The value is the index of the images row.
+#[derive(Clone, Copy)]
+pub enum RobotType {
+    Normal = 0,
+    Aggressive = 1,
+}
+
+pub struct Robot {
+    type_: RobotType,
+    speed: i32,
+    direction_x: i32,
+    alive: bool,
+    change_dir_timer: i32,
+    fire_timer: i32,
+
+    x: i32,
+    y: i32,
+    image: Texture2D,
+    anchor: Anchor,
+    vel_y: i32,
+    landed: bool,
+}
+
+impl Robot {
+    pub fn new(x: i32, y: i32, type_: RobotType, resources: &Resources) -> Self {
+        Self {
+            type_,
+            speed: gen_range(1, 4),
+            direction_x: 1,
+            alive: true,
+            change_dir_timer: 0,
+            fire_timer: 100,
+            x,
+            y,
+            image: resources.blank_texture,
+            anchor: GRAVITY_BODY_DEFAULT_ANCHOR,
+            vel_y: 0,
+            landed: false,
+        }
+    }
+
+    pub fn update(
+        &mut self,
+        bolts: &mut Vec<Bolt>,
+        orbs: &mut [Orb],
+        player: Option<&Player>,
+        fire_probability: f32,
+        ctx: &mut UpdateContext,
+    ) {
+        self.fall(true, DEFAULT_GRAVITY, DEFAULT_MAX_FALL_SPEED, ctx.grid);
+
+        self.change_dir_timer -= 1;
+        self.fire_timer += 1;
+
+        // Move in the current direction; turn around when hitting a wall
+        if self.move_(self.direction_x, 0, self.speed, ctx.grid) {
+            self.change_dir_timer = 0;
+        }
+
+        if self.change_dir_timer <= 0 {
+            // Randomly choose a direction to move in; if there's a player, there's a two thirds chance of moving
+            // towards them
+            let mut directions = vec![-1, 1];
+            if let Some(player) = player {
+                directions.push((player.x() - self.x).signum());
+            }
+            self.direction_x = *directions.choose().unwrap();
+            self.change_dir_timer = gen_range(100, 250 + 1);
+        }
+
+        // The more powerful type of robot can deliberately shoot at orbs at its height, turning to face them if
+        // necessary
+        if matches!(self.type_, RobotType::Aggressive) && self.fire_timer >= ORB_FIRE_INTERVAL {
+            let target = orbs.iter().find(|orb| {
+                orb.y >= self.top()
+                    && orb.y < self.bottom()
+                    && (orb.x - self.x).abs() < ORB_FIRE_DISTANCE
+            });
+
+            if let Some(orb) = target {
+                self.direction_x = (orb.x - self.x).signum();
+                self.fire_timer = 0;
+            }
+        }
+
+        if self.fire_timer >= FIRE_INTERVAL {
+            // Random chance of firing each frame; it increases 10 times if the player is at the same height
+            let at_player_height = player
+                .is_some_and(|player| self.top() < player.bottom() && self.bottom() > player.top());
+            let fire_probability = if at_player_height {
+                fire_probability * 10.
+            } else {
+                fire_probability
+            };
+
+            if gen_range(0., 1.) < fire_probability {
+                self.fire_timer = 0;
+                ctx.sounds.push(SoundEffect::Laser);
+            }
+        } else if self.fire_timer == BOLT_RELEASE_FRAME {
+            bolts.push(Bolt::new(
+                self.x + self.direction_x * 20,
+                self.y - 38,
+                self.direction_x,
+                ctx.resources,
+            ));
+        }
+
+        // Colliding with an orb that doesn't contain an enemy already traps the robot
+        let trapping_orb = orbs
+            .iter_mut()
+            .find(|orb| !orb.is_trapping() && self.collidepoint(orb.center()));
+
+        if let Some(orb) = trapping_orb {
+            self.alive = false;
+            orb.trap(self.type_);
+            ctx.sounds.push(SoundEffect::Trap);
+        }
+
+        let type_factor = self.type_ as i32 * 16;
+        let direction_factor = if self.direction_x > 0 { 8 } else { 0 };
+        let anim_frame = if self.fire_timer < FIRE_INTERVAL {
+            5 + self.fire_timer / 4
+        } else {
+            1 + (ctx.timer / 4) % 4
+        };
+        self.image =
+            ctx.resources.robot_textures[(type_factor + direction_factor + anim_frame) as usize];
+    }
+}
+
+impl_body!(Robot);
+impl_gravity_body!(Robot);
+
+impl Entity for Robot {
+    fn image(&self) -> Texture2D {
+        self.image
+    }
+
+    fn is_alive(&self) -> bool {
+        self.alive
+    }
+}
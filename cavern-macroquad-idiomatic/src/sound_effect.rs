@@ -0,0 +1,65 @@
+/// Sounds are emitted as events during the update, and played afterwards; this way, the entities don't depend on
+/// the audio system. Effects with multiple variants play a random one.
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SoundEffect {
+    Blow,
+    Bonus,
+    Die,
+    Jump,
+    Laser,
+    Level,
+    Ouch,
+    Over,
+    Pop,
+    Score,
+    Trap,
+}
+
+impl SoundEffect {
+    pub const ALL: [SoundEffect; 11] = [
+        SoundEffect::Blow,
+        SoundEffect::Bonus,
+        SoundEffect::Die,
+        SoundEffect::Jump,
+        SoundEffect::Laser,
+        SoundEffect::Level,
+        SoundEffect::Ouch,
+        SoundEffect::Over,
+        SoundEffect::Pop,
+        SoundEffect::Score,
+        SoundEffect::Trap,
+    ];
+
+    /// Prefix of the sound files.
+    pub fn name(self) -> &'static str {
+        match self {
+            SoundEffect::Blow => "blow",
+            SoundEffect::Bonus => "bonus",
+            SoundEffect::Die => "die",
+            SoundEffect::Jump => "jump",
+            SoundEffect::Laser => "laser",
+            SoundEffect::Level => "level",
+            SoundEffect::Ouch => "ouch",
+            SoundEffect::Over => "over",
+            SoundEffect::Pop => "pop",
+            SoundEffect::Score => "score",
+            SoundEffect::Trap => "trap",
+        }
+    }
+
+    pub fn variants(self) -> u8 {
+        match self {
+            SoundEffect::Blow
+            | SoundEffect::Laser
+            | SoundEffect::Ouch
+            | SoundEffect::Pop
+            | SoundEffect::Trap => 4,
+            SoundEffect::Bonus
+            | SoundEffect::Die
+            | SoundEffect::Jump
+            | SoundEffect::Level
+            | SoundEffect::Over
+            | SoundEffect::Score => 1,
+        }
+    }
+}
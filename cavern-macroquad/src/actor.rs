@@ -1,76 +1,24 @@
-use macroquad::prelude::{draw_texture, Texture2D, WHITE};
+use macroquad::prelude::{draw_texture, Color, Texture2D, WHITE};
 
-#[derive(Clone, Copy)]
-pub enum Anchor {
-    Centre,
-    CentreBottom,
-}
-
-// Rust: A private trait could be used, but it doesn't help much.
-//
-fn top_left_pos(anchor: Anchor, x: i32, y: i32, image: &Texture2D) -> (i32, i32) {
-    let (image_width, image_height) = (image.width() as i32, image.height() as i32);
-
-    let (diff_x, diff_y) = match anchor {
-        Anchor::Centre => (image_width / 2, image_height / 2),
-        Anchor::CentreBottom => (image_width / 2, image_height),
-    };
+// Rust: The geometry is shared with the other ports, via the engine-agnostic core crate; the size of an actor
+// is the size of its image.
+pub use cavern_core::{Anchor, Body};
 
-    (x - diff_x, y - diff_y)
-}
-
-pub trait Actor {
-    // Rust: We can't use a tuple, because we'll need mutable access to one field; Vec2 would work,
-    // but it has f32 field. For simplicity, we separate x/y, which doesn't make any meaningful difference.
-    fn x(&self) -> i32;
-    fn x_mut(&mut self) -> &mut i32;
-    fn y(&self) -> i32;
-    fn y_mut(&mut self) -> &mut i32;
+pub trait Actor: Body {
     fn image(&self) -> Texture2D;
-    fn anchor(&self) -> Anchor;
-
-    // Rust: All the geometry methods below are meant to be conveniently implemented, not fast.
 
-    fn top(&self) -> i32 {
-        let image = self.image();
-        let top_left_pos = top_left_pos(self.anchor(), self.x(), self.y(), &image);
-
-        top_left_pos.1
-    }
-
-    fn bottom(&self) -> i32 {
-        self.top() + self.image().height() as i32
-    }
-
-    fn left(&self) -> i32 {
-        let image = self.image();
-        let top_left_pos = top_left_pos(self.anchor(), self.x(), self.y(), &image);
-
-        top_left_pos.0
-    }
-
-    fn right(&self) -> i32 {
-        self.left() + self.image().width() as i32
-    }
-
-    fn center(&self) -> (i32, i32) {
-        let center_x = self.left() + self.image().width() as i32 / 2;
-        let center_y = self.top() + self.image().height() as i32 / 2;
-
-        (center_x, center_y)
-    }
-
-    fn collidepoint(&self, pos: (i32, i32)) -> bool {
-        self.left() <= pos.0
-            && pos.0 <= self.right()
-            && self.top() <= pos.1
-            && pos.1 <= self.bottom()
+    // Rust: Not in the original code; used to tell the players apart in co-op mode.
+    fn tint(&self) -> Color {
+        WHITE
     }
 
     fn draw(&self) {
-        let image = self.image();
-        let top_left_pos = top_left_pos(self.anchor(), self.x(), self.y(), &image);
+        let (left, top) = self.top_left();
 
-        draw_texture(image, top_left_pos.0 as f32, top_left_pos.1 as f32, WHITE);
+        draw_texture(self.image(), left as f32, top as f32, self.tint());
     }
 }
+
+pub fn image_size(image: &Texture2D) -> (i32, i32) {
+    (image.width() as i32, image.height() as i32)
+}
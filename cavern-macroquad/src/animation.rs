@@ -0,0 +1,56 @@
+use macroquad::prelude::Texture2D;
+
+// Rust: Not in the original code, where the sprite frames are computed from the game timer, e.g.
+// `(game_timer / 8) % 4`. Actors own an animation, which is advanced once per update by the game loop; the actors
+// only choose which one to play.
+//
+// There is no flipping, since the sprites facing left and right are separate images; the direction is selected by
+// playing the corresponding frames.
+//
+#[derive(Default)]
+pub struct Animation {
+    frames: Vec<Texture2D>,
+    /// Number of updates each frame is displayed for.
+    frame_duration: i32,
+    /// If not looping, the animation stays on the last frame once finished.
+    looping: bool,
+    timer: i32,
+}
+
+impl Animation {
+    /// Switches to the given frames, starting from the first; if they're already playing, the animation carries on.
+    pub fn play(&mut self, frames: &[Texture2D], frame_duration: i32, looping: bool) {
+        if self.frames != frames {
+            self.frames = frames.to_vec();
+            self.timer = 0;
+        }
+
+        self.frame_duration = frame_duration;
+        self.looping = looping;
+    }
+
+    /// Displays a single frame; convenient for still images.
+    pub fn show(&mut self, frame: Texture2D) {
+        self.play(&[frame], 1, false);
+    }
+
+    pub fn restart(&mut self) {
+        self.timer = 0;
+    }
+
+    pub fn update(&mut self) {
+        self.timer += 1;
+    }
+
+    pub fn image(&self) -> Texture2D {
+        let frame_i = (self.timer / self.frame_duration) as usize;
+
+        let frame_i = if self.looping {
+            frame_i % self.frames.len()
+        } else {
+            frame_i.min(self.frames.len() - 1)
+        };
+
+        self.frames[frame_i]
+    }
+}
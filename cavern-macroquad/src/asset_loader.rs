@@ -0,0 +1,253 @@
+use std::{
+    future::Future,
+    pin::Pin,
+    sync::Arc,
+    task::{Context, Poll, Wake, Waker},
+};
+
+use macroquad::{
+    audio::{load_sound, load_sound_from_bytes, Sound},
+    file::load_file,
+    miniquad,
+    prelude::{load_image, load_texture, Image, Texture2D, MAGENTA},
+};
+
+use crate::panning::{self, PannedSound, PAN_POSITIONS};
+
+const PLACEHOLDER_SIZE: u16 = 16;
+
+// Rust: Not in the original code, where a missing file stops the game. Assets that can't be loaded are replaced
+// with placeholders (a magenta square, and silence), and collected, so that they can be reported on screen.
+//
+// Only missing/unreadable files are handled; Macroquad panics on files that exist but can't be decoded.
+//
+// The headless loader doesn't use the engine, which requires the window: the images are read directly from the
+// files, and the textures only have the size (the GPU is never involved). It only loads images and textures.
+//
+#[derive(Default)]
+pub struct AssetLoader {
+    /// Paths of the assets that couldn't be loaded, in loading order.
+    pub missing: Vec<String>,
+    /// Paths of the textures loaded from file, and the textures; used by the hot reload.
+    pub textures: Vec<(String, Texture2D)>,
+    /// Sounds loaded via `positional_sound()`.
+    pub panned_sounds: Vec<PannedSound>,
+    placeholder_texture: Option<Texture2D>,
+    placeholder_sound: Option<Sound>,
+    headless: bool,
+}
+
+impl AssetLoader {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn headless() -> Self {
+        Self {
+            headless: true,
+            ..Self::default()
+        }
+    }
+
+    pub fn is_headless(&self) -> bool {
+        self.headless
+    }
+
+    pub async fn texture(&mut self, path: &str) -> Texture2D {
+        if self.headless {
+            return match image::image_dimensions(path) {
+                Ok((width, height)) => headless_texture(width, height),
+                Err(error) => {
+                    self.report(path, error);
+                    self.placeholder_texture()
+                }
+            };
+        }
+
+        match load_texture(path).await {
+            Ok(texture) => {
+                self.textures.push((path.to_string(), texture));
+                texture
+            }
+            Err(error) => {
+                self.report(path, error);
+                self.placeholder_texture()
+            }
+        }
+    }
+
+    pub async fn image(&mut self, path: &str) -> Image {
+        if self.headless {
+            return match image::open(path) {
+                Ok(image) => {
+                    let image = image.to_rgba8();
+                    Image {
+                        width: image.width() as u16,
+                        height: image.height() as u16,
+                        bytes: image.into_raw(),
+                    }
+                }
+                Err(error) => {
+                    self.report(path, error);
+                    placeholder_image()
+                }
+            };
+        }
+
+        match load_image(path).await {
+            Ok(image) => image,
+            Err(error) => {
+                self.report(path, error);
+                placeholder_image()
+            }
+        }
+    }
+
+    pub async fn sound(&mut self, path: &str) -> Sound {
+        match load_sound(path).await {
+            Ok(sound) => sound,
+            Err(error) => {
+                self.report(path, error);
+                self.placeholder_sound().await
+            }
+        }
+    }
+
+    /// Loads a sound, and its panned variants; if the variants can't be created, the sound is played unpanned.
+    pub async fn positional_sound(&mut self, path: &str) -> Sound {
+        let bytes = match load_file(path).await {
+            Ok(bytes) => bytes,
+            Err(error) => {
+                self.report(path, error);
+                return self.placeholder_sound().await;
+            }
+        };
+
+        let sound = match load_sound_from_bytes(&bytes).await {
+            Ok(sound) => sound,
+            Err(error) => {
+                self.report(path, error);
+                return self.placeholder_sound().await;
+            }
+        };
+
+        match panning::panned_variants(&bytes) {
+            Ok(variants_bytes) => {
+                let mut variants = vec![];
+
+                for (pan, variant_bytes) in PAN_POSITIONS.iter().zip(variants_bytes) {
+                    let variant = if *pan == 0. {
+                        sound
+                    } else {
+                        load_sound_from_bytes(&variant_bytes)
+                            .await
+                            .expect("The panned variants are valid WAVs")
+                    };
+                    variants.push(variant);
+                }
+
+                self.panned_sounds.push(PannedSound { sound, variants });
+            }
+            Err(error) => eprintln!("Couldn't create the panned variants of {}: {}", path, error),
+        }
+
+        sound
+    }
+
+    pub fn texture_from_image(&self, image: &Image) -> Texture2D {
+        if self.headless {
+            headless_texture(image.width as u32, image.height as u32)
+        } else {
+            Texture2D::from_image(image)
+        }
+    }
+
+    fn report(&mut self, path: &str, error: impl std::fmt::Display) {
+        eprintln!("Couldn't load {}: {}", path, error);
+        self.missing.push(path.to_string());
+    }
+
+    fn placeholder_texture(&mut self) -> Texture2D {
+        match self.placeholder_texture {
+            Some(texture) => texture,
+            None => {
+                let texture = self.texture_from_image(&placeholder_image());
+                self.placeholder_texture = Some(texture);
+                texture
+            }
+        }
+    }
+
+    async fn placeholder_sound(&mut self) -> Sound {
+        if self.placeholder_sound.is_none() {
+            let sound = load_sound_from_bytes(&silent_wav())
+                .await
+                .expect("The placeholder sound is a valid WAV");
+            self.placeholder_sound = Some(sound);
+        }
+
+        self.placeholder_sound.unwrap()
+    }
+}
+
+/// Texture with the given size, but no GPU counterpart.
+fn headless_texture(width: u32, height: u32) -> Texture2D {
+    let mut texture = miniquad::Texture::empty();
+    texture.width = width;
+    texture.height = height;
+
+    Texture2D::from_miniquad_texture(texture)
+}
+
+struct NoopWaker;
+
+impl Wake for NoopWaker {
+    fn wake(self: Arc<Self>) {}
+}
+
+/// Runs a loading that doesn't wait for the engine, i.e. with the headless loader; the loading functions are async
+/// only because of the engine.
+pub fn complete_now<T>(future: impl Future<Output = T>) -> T {
+    let waker = Waker::from(Arc::new(NoopWaker));
+    let mut future = Box::pin(future);
+
+    match Pin::as_mut(&mut future).poll(&mut Context::from_waker(&waker)) {
+        Poll::Ready(output) => output,
+        Poll::Pending => panic!("The headless loading waited for the engine"),
+    }
+}
+
+fn placeholder_image() -> Image {
+    Image::gen_image_color(PLACEHOLDER_SIZE, PLACEHOLDER_SIZE, MAGENTA)
+}
+
+/// A WAV file holding a single silent sample (16 bit mono PCM).
+fn silent_wav() -> Vec<u8> {
+    wav(22050, 1, &[0])
+}
+
+/// Encodes the samples (16 bit PCM, interleaved) as WAV file.
+pub fn wav(sample_rate: u32, channels: u16, samples: &[i16]) -> Vec<u8> {
+    let data_size = (samples.len() * 2) as u32;
+    let block_align = channels * 2;
+
+    let mut wav = vec![];
+
+    wav.extend(b"RIFF");
+    wav.extend(&(36 + data_size).to_le_bytes());
+    wav.extend(b"WAVEfmt ");
+    wav.extend(&16_u32.to_le_bytes()); // Format chunk size
+    wav.extend(&1_u16.to_le_bytes()); // PCM
+    wav.extend(&channels.to_le_bytes());
+    wav.extend(&sample_rate.to_le_bytes());
+    wav.extend(&(sample_rate * block_align as u32).to_le_bytes()); // Byte rate
+    wav.extend(&block_align.to_le_bytes());
+    wav.extend(&16_u16.to_le_bytes()); // Bits per sample
+    wav.extend(b"data");
+    wav.extend(&data_size.to_le_bytes());
+    for sample in samples {
+        wav.extend(&sample.to_le_bytes());
+    }
+
+    wav
+}
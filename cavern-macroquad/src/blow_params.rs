@@ -0,0 +1,55 @@
+use macroquad::prelude::collections::storage;
+
+use crate::{physics::Physics, ticks::Ticks};
+
+/// Rise speed added to a fully charged orb.
+const MAX_CHARGE_RISE_BONUS: f32 = 2.;
+/// Lifetime added to a fully charged orb; since enemies are trapped until the orb pops, it's also the extra trap
+/// duration.
+const MAX_CHARGE_EXTRA_LIFETIME: f32 = 150.;
+
+// Rust: Not in the original game, where the orb limit and the blow cap are hardcoded. They're gathered, along with
+// the options that change the blowing, so that the player update receives them as a single system; the charge is
+// also capped by the blow cap.
+//
+#[derive(Clone, Copy)]
+pub struct BlowParams {
+    /// Maximum number of orbs on screen at once.
+    pub max_orbs: usize,
+    /// Frames added to the orb travel (or to the charge), for each frame blow is held.
+    pub blow_increment: Ticks,
+    /// Cap of the orb travel, and of the charge.
+    pub max_blown_frames: Ticks,
+    /// Wall bounces of each orb; zero unless the option is enabled.
+    pub wall_bounces: i32,
+    /// When set, holding blow charges the next orb, which is blown on release, instead of pushing the current one.
+    pub charge_shot: bool,
+}
+
+impl BlowParams {
+    /// The orb limit depends on the difficulty; the travel values are taken from the physics.
+    pub fn new(max_orbs: usize, wall_bounces: i32, charge_shot: bool) -> Self {
+        let physics = storage::get::<Physics>();
+
+        Self {
+            max_orbs,
+            blow_increment: physics.orb_blow_increment,
+            max_blown_frames: physics.max_orb_blown_frames,
+            wall_bounces,
+            charge_shot,
+        }
+    }
+
+    /// Between 0 and 1.
+    pub fn charge_fraction(&self, charge: Ticks) -> f32 {
+        (charge.get() as f32 / self.max_blown_frames.get() as f32).clamp(0., 1.)
+    }
+
+    pub fn charge_rise_bonus(&self, charge: Ticks) -> i32 {
+        (self.charge_fraction(charge) * MAX_CHARGE_RISE_BONUS).round() as i32
+    }
+
+    pub fn charge_extra_lifetime(&self, charge: Ticks) -> Ticks {
+        Ticks::new((self.charge_fraction(charge) * MAX_CHARGE_EXTRA_LIFETIME).round() as i32)
+    }
+}
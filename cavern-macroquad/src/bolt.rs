@@ -1,18 +1,21 @@
 use macroquad::prelude::{collections::storage, Texture2D};
 
+use cavern_core::{collide::COLLIDE_BODY_DEFAULT_ANCHOR, CollideBody};
+
 use crate::{
-    actor::{Actor, Anchor},
-    collide_actor::{CollideActor, COLLIDE_ACTOR_DEFAULT_ANCHOR},
-    orb::RcOrb,
+    actor::{image_size, Actor, Anchor, Body},
+    entity_store::EntityStore,
+    orb::Orb,
+    particle::{self, Particle},
     player::Player,
     resources::Resources,
 };
 
-const BOLT_SPEED: i32 = 7;
-
 pub struct Bolt {
     pub direction_x: i32,
     pub active: bool,
+    // Rust: In the original code, this is a constant; it now depends on the difficulty.
+    pub speed: i32,
 
     // Actor trait
     pub x: i32,
@@ -22,42 +25,50 @@ pub struct Bolt {
 }
 
 impl Bolt {
-    pub fn new(x: i32, y: i32, direction_x: i32) -> Self {
+    pub fn new(x: i32, y: i32, direction_x: i32, speed: i32) -> Self {
         Self {
             direction_x,
             active: true,
+            speed,
 
             x,
             y,
             image: storage::get::<Resources>().blank_texture,
-            anchor: COLLIDE_ACTOR_DEFAULT_ANCHOR,
+            anchor: COLLIDE_BODY_DEFAULT_ANCHOR,
         }
     }
 
+    /// Returns true if a player has been hit.
     pub fn update(
         &mut self,
-        orbs: &mut Vec<RcOrb>,
-        player: Option<&mut Player>,
+        orbs: &mut EntityStore<Orb>,
+        particles: &mut Vec<Particle>,
+        players: &mut [Player],
         game_timer: i32,
-        grid: &[&str],
-    ) {
+        grid: &[String],
+    ) -> bool {
+        let mut hit_player = false;
+
         // Move horizontally and check to see if we've collided with a block
-        if self.move_(self.direction_x, 0, BOLT_SPEED, grid) {
+        if self.move_(self.direction_x, 0, self.speed, grid) {
             // Collided
             self.active = false;
+            particle::emit_debris(particles, (self.x, self.y), self.direction_x);
         } else {
             // We didn't collide with a block - check to see if we collided with an orb or the player
-            for orb in orbs {
-                if orb.borrow_mut().hit_test(self) {
+            for orb in orbs.iter_mut() {
+                if orb.hit_test(self) {
                     self.active = false;
                     break;
                 }
             }
 
             if self.active {
-                if let Some(player) = player {
+                for player in players.iter_mut().filter(|p| p.is_active()) {
                     if player.hit_test(self) {
                         self.active = false;
+                        hit_player = true;
+                        break;
                     }
                 }
             }
@@ -67,10 +78,12 @@ impl Bolt {
         let timer_factor = (game_timer / 4) % 2;
         let image_i = (direction_factor + timer_factor) as usize;
         self.image = storage::get::<Resources>().bolt_textures[image_i];
+
+        hit_player
     }
 }
 
-impl Actor for Bolt {
+impl Body for Bolt {
     fn x(&self) -> i32 {
         self.x
     }
@@ -87,13 +100,19 @@ impl Actor for Bolt {
         &mut self.y
     }
 
-    fn image(&self) -> macroquad::prelude::Texture2D {
-        self.image
+    fn size(&self) -> (i32, i32) {
+        image_size(&self.image)
     }
 
-    fn anchor(&self) -> crate::actor::Anchor {
+    fn anchor(&self) -> Anchor {
         self.anchor
     }
 }
 
-impl CollideActor for Bolt {}
+impl Actor for Bolt {
+    fn image(&self) -> Texture2D {
+        self.image
+    }
+}
+
+impl CollideBody for Bolt {}
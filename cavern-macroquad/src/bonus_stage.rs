@@ -0,0 +1,86 @@
+use nanoserde::{DeRon, SerRon};
+
+use crate::drawing::draw_game_text;
+
+/// A bonus stage is played after every third level.
+const BONUS_STAGE_INTERVAL: i8 = 3;
+
+const DURATION: i32 = 20 * 60;
+/// Frames between the fruits falling from the top.
+pub const FRUIT_SPAWN_INTERVAL: i32 = 15;
+/// The fruits that aren't collected disappear sooner than in the levels, so that the stage doesn't fill up.
+pub const FRUIT_TIME_TO_LIVE: i32 = 180;
+/// Awarded to each player at the end of the stage, for each fruit collected (on top of the fruit value).
+const COLLECTION_BONUS: i32 = 50;
+
+pub const BONUS_STAGE_GRID: [&str; 17] = [
+    "XXXXXX                XXXXXX",
+    "",
+    "",
+    "",
+    "",
+    "    XXXXX          XXXXX    ",
+    "",
+    "",
+    "",
+    "          XXXXXXXX          ",
+    "",
+    "",
+    "",
+    "XXXXX                  XXXXX",
+    "",
+    "",
+    "",
+];
+
+// Rust: Not in the original game. In the bonus stages, there are no enemies; fruits fall from the top of the
+// screen, and the players collect as many as they can, within the time limit. The stages don't count as levels.
+//
+#[derive(Clone, Copy, DeRon, SerRon)]
+pub struct BonusStage {
+    pub time_left: i32,
+    /// By all the players.
+    pub fruits_collected: i32,
+}
+
+impl BonusStage {
+    pub fn new() -> Self {
+        Self {
+            time_left: DURATION,
+            fruits_collected: 0,
+        }
+    }
+
+    /// Whether a bonus stage is played after the given level (in both modes).
+    pub fn follows_level(level: i8) -> bool {
+        level >= 0 && (level + 1) % BONUS_STAGE_INTERVAL == 0
+    }
+
+    /// Returns true on the update in which the time runs out.
+    pub fn update(&mut self) -> bool {
+        if self.time_left == 0 {
+            return false;
+        }
+
+        self.time_left -= 1;
+        self.time_left == 0
+    }
+
+    pub fn is_over(&self) -> bool {
+        self.time_left == 0
+    }
+
+    pub fn bonus(&self) -> i32 {
+        self.fruits_collected * COLLECTION_BONUS
+    }
+
+    pub fn draw(&self) {
+        let seconds_left = (self.time_left + 59) / 60;
+
+        draw_game_text(
+            &format!("TIME {}   FRUITS {}", seconds_left, self.fruits_collected),
+            36,
+            None,
+        );
+    }
+}
@@ -0,0 +1,201 @@
+use macroquad::prelude::{
+    collections::storage, draw_rectangle, draw_rectangle_lines, draw_texture_ex, vec2, Color,
+    DrawTextureParams, DARKGRAY, RED, WHITE,
+};
+use nanoserde::{DeRon, SerRon};
+
+use crate::{
+    actor::Body, bolt::Bolt, entity_store::EntityStore, game_playback::play_game_random_sound_at,
+    orb::Orb, player::Player, resources::Resources, robot::RobotType, ticks::Ticks, WIDTH,
+};
+
+/// Boss levels are every fourth level.
+pub const BOSS_LEVEL_INTERVAL: i8 = 4;
+
+// The boss reuses the robot sprites, scaled up.
+const SPRITE_SCALE: f32 = 2.;
+const SPRITE_TYPE: RobotType = RobotType::Aggressive;
+const SPRITE_WIDTH: i32 = 50;
+const SPRITE_HEIGHT: i32 = 75;
+
+const MIN_X: i32 = 120;
+const MAX_X: i32 = 680;
+const BASE_Y: i32 = 130;
+
+const SPEED: i32 = 2;
+
+// Time between attacks; attacks alternate between the patterns.
+const ATTACK_INTERVAL: Ticks = Ticks::new(90);
+
+const HIT_FLASH_TIME: Ticks = Ticks::new(20);
+const HIT_FLASH_COLOUR: Color = Color::new(1.0, 0.4, 0.4, 1.0);
+
+const HEALTH_BAR_WIDTH: f32 = 300.;
+const HEALTH_BAR_HEIGHT: f32 = 12.;
+
+#[derive(Clone, Copy, PartialEq, DeRon, SerRon)]
+pub enum AttackPattern {
+    /// Bolts fired in both directions, at three heights.
+    Spread,
+    /// One bolt for each player, fired toward them at their height.
+    Aimed,
+}
+
+/// Large enemy, which floats over the level firing bolt patterns; it can't be trapped, but each orb hitting it
+/// takes away one health point.
+pub struct Boss {
+    pub health: i32,
+    pub max_health: i32,
+    pub direction_x: i32,
+    pub attack_timer: Ticks,
+    pub next_attack: AttackPattern,
+    pub hit_timer: Ticks,
+
+    pub x: i32,
+    pub y: i32,
+}
+
+impl Boss {
+    pub fn new(level: i8) -> Self {
+        // Each boss is tougher than the previous one
+        let max_health = 6 + 2 * (level / BOSS_LEVEL_INTERVAL) as i32;
+
+        Self {
+            health: max_health,
+            max_health,
+            direction_x: 1,
+            attack_timer: Ticks::ZERO,
+            next_attack: AttackPattern::Spread,
+            hit_timer: Ticks::ZERO,
+
+            x: WIDTH / 2,
+            y: -SPRITE_HEIGHT * SPRITE_SCALE as i32,
+        }
+    }
+
+    pub fn is_boss_level(level: i8) -> bool {
+        (level + 1) % BOSS_LEVEL_INTERVAL == 0
+    }
+
+    pub fn width(&self) -> i32 {
+        (SPRITE_WIDTH as f32 * SPRITE_SCALE) as i32
+    }
+
+    pub fn height(&self) -> i32 {
+        (SPRITE_HEIGHT as f32 * SPRITE_SCALE) as i32
+    }
+
+    fn collidepoint(&self, pos: (i32, i32)) -> bool {
+        (pos.0 - self.x).abs() <= self.width() / 2 && (pos.1 - self.y).abs() <= self.height() / 2
+    }
+
+    pub fn update(
+        &mut self,
+        bolts: &mut EntityStore<Bolt>,
+        orbs: &mut EntityStore<Orb>,
+        players: &[Player],
+        bolt_speed: i32,
+        game_timer: i32,
+    ) {
+        self.hit_timer.decrement();
+
+        // Descend into the level, then float from side to side, bobbing up and down
+        if self.y < BASE_Y {
+            self.y += SPEED;
+            return;
+        }
+
+        self.x += self.direction_x * SPEED;
+        if self.x <= MIN_X || self.x >= MAX_X {
+            self.direction_x = -self.direction_x;
+        }
+        self.y = BASE_Y + ((game_timer as f32 / 20.).sin() * 10.) as i32;
+
+        self.attack_timer.increment();
+
+        if self.attack_timer >= ATTACK_INTERVAL {
+            self.attack_timer = Ticks::ZERO;
+            self.attack(bolts, players, bolt_speed);
+        }
+
+        // Orbs hitting the boss pop immediately; trapped enemies float through it
+
+        for orb in orbs.iter_mut() {
+            if orb.trapped_enemy_type.is_none()
+                && self.hit_timer <= Ticks::ZERO
+                && self.collidepoint(orb.center())
+            {
+                orb.pop();
+                self.health -= 1;
+                self.hit_timer = HIT_FLASH_TIME;
+                play_game_random_sound_at(players.first(), |sounds| &sounds.ouch_sounds, self.x);
+            }
+        }
+    }
+
+    fn attack(&mut self, bolts: &mut EntityStore<Bolt>, players: &[Player], bolt_speed: i32) {
+        match self.next_attack {
+            AttackPattern::Spread => {
+                for dy in [-40, 0, 40] {
+                    for direction_x in [-1, 1] {
+                        let x = self.x + direction_x * self.width() / 2;
+                        bolts.insert(Bolt::new(x, self.y + dy, direction_x, bolt_speed));
+                    }
+                }
+                self.next_attack = AttackPattern::Aimed;
+            }
+            AttackPattern::Aimed => {
+                for player in players.iter().filter(|p| p.is_active()) {
+                    let direction_x = if player.x < self.x { -1 } else { 1 };
+                    let x = self.x + direction_x * self.width() / 2;
+                    bolts.insert(Bolt::new(x, player.y - 38, direction_x, bolt_speed));
+                }
+                self.next_attack = AttackPattern::Spread;
+            }
+        }
+
+        play_game_random_sound_at(players.first(), |sounds| &sounds.laser_sounds, self.x);
+    }
+
+    pub fn draw(&self, game_timer: i32) {
+        let resources = storage::get::<Resources>();
+
+        let type_factor = 16 * SPRITE_TYPE.val();
+        let direction_factor = if self.direction_x > 0 { 8 } else { 0 };
+        let fire_factor = if self.attack_timer < Ticks::new(12) {
+            5 + (self.attack_timer.get() / 4)
+        } else {
+            1 + ((game_timer / 4) % 4)
+        };
+        let image =
+            resources.robot_textures[(type_factor + direction_factor + fire_factor) as usize];
+
+        // Flash while hurt
+        let colour = if self.hit_timer > Ticks::ZERO && self.hit_timer.get() % 4 < 2 {
+            HIT_FLASH_COLOUR
+        } else {
+            WHITE
+        };
+
+        draw_texture_ex(
+            image,
+            (self.x - self.width() / 2) as f32,
+            (self.y - self.height() / 2) as f32,
+            colour,
+            DrawTextureParams {
+                dest_size: Some(vec2(self.width() as f32, self.height() as f32)),
+                ..Default::default()
+            },
+        );
+    }
+
+    pub fn draw_health_bar(&self) {
+        let x = (WIDTH as f32 - HEALTH_BAR_WIDTH) / 2.;
+        let y = 30.;
+        let fill_width = HEALTH_BAR_WIDTH * self.health.max(0) as f32 / self.max_health as f32;
+
+        draw_rectangle(x, y, HEALTH_BAR_WIDTH, HEALTH_BAR_HEIGHT, DARKGRAY);
+        draw_rectangle(x, y, fill_width, HEALTH_BAR_HEIGHT, RED);
+        draw_rectangle_lines(x, y, HEALTH_BAR_WIDTH, HEALTH_BAR_HEIGHT, 2., WHITE);
+    }
+}
@@ -0,0 +1,64 @@
+use macroquad::prelude::{draw_rectangle, draw_triangle, vec2, Color, GRAY};
+use nanoserde::{DeRon, SerRon};
+
+use crate::{actor::Body, levels::CHECKPOINTS, player::Player, GRID_BLOCK_SIZE, LEVEL_X_OFFSET};
+
+// Rust: Not in the original game. Flags placed on the platforms (see `levels::CHECKPOINTS`); once a player touches
+// one, they respawn there after losing a life, rather than falling from the top of the level.
+
+const POLE_HEIGHT: f32 = 40.;
+const FLAG_WIDTH: f32 = 20.;
+const FLAG_HEIGHT: f32 = 14.;
+const POLE_COLOUR: Color = Color::new(0.85, 0.85, 0.85, 1.0);
+const TOUCHED_COLOUR: Color = Color::new(0.2, 0.9, 0.3, 1.0);
+
+#[derive(Clone, Copy, DeRon, SerRon)]
+pub struct Checkpoint {
+    /// Bottom centre of the pole; also the respawn position.
+    pub x: i32,
+    pub y: i32,
+    pub touched: bool,
+}
+
+impl Checkpoint {
+    /// The flag stands on the block at the given grid position.
+    fn new(column: i32, row: i32) -> Self {
+        Self {
+            x: LEVEL_X_OFFSET + column * GRID_BLOCK_SIZE + GRID_BLOCK_SIZE / 2,
+            // Like the actors standing on a block, 1 pixel above it (see `CollideBody::move_()`).
+            y: row * GRID_BLOCK_SIZE - 1,
+            touched: false,
+        }
+    }
+
+    /// The checkpoints of the given (non-generated) level.
+    pub fn for_level(level: i8) -> Vec<Self> {
+        CHECKPOINTS[level as usize % CHECKPOINTS.len()]
+            .iter()
+            .map(|(column, row)| Self::new(*column, *row))
+            .collect()
+    }
+
+    /// Players falling after losing their health can't reach a checkpoint.
+    pub fn update(&mut self, players: &mut [Player]) {
+        for player in players.iter_mut().filter(|p| p.is_active() && p.health > 0) {
+            if player.collidepoint((self.x, self.y - POLE_HEIGHT as i32 / 2)) {
+                self.touched = true;
+                player.checkpoint = Some(*self);
+            }
+        }
+    }
+
+    pub fn draw(&self) {
+        let (x, y) = (self.x as f32, self.y as f32);
+        let flag_colour = if self.touched { TOUCHED_COLOUR } else { GRAY };
+
+        draw_rectangle(x - 1., y - POLE_HEIGHT, 2., POLE_HEIGHT, POLE_COLOUR);
+        draw_triangle(
+            vec2(x + 1., y - POLE_HEIGHT),
+            vec2(x + 1., y - POLE_HEIGHT + FLAG_HEIGHT),
+            vec2(x + 1. + FLAG_WIDTH, y - POLE_HEIGHT + FLAG_HEIGHT / 2.),
+            flag_colour,
+        );
+    }
+}
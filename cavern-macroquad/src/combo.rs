@@ -0,0 +1,51 @@
+use macroquad::prelude::{draw_rectangle, Color};
+
+use crate::{drawing::draw_game_text, WIDTH};
+
+// Rust: Not in the original game. Trapped enemies popped in quick succession form a chain, which awards escalating
+// bonus points.
+
+/// Frames within which the next trapped enemy must be popped, in order to extend the chain.
+const COMBO_WINDOW: i32 = 90;
+
+/// The second pop of a chain awards this bonus, the third twice as much, and so on.
+const COMBO_BONUS_STEP: i32 = 100;
+
+const METER_WIDTH: f32 = 120.;
+const METER_COLOUR: Color = Color::new(1.0, 0.85, 0.2, 1.0);
+
+#[derive(Default)]
+pub struct Combo {
+    count: i32,
+    timer: i32,
+}
+
+impl Combo {
+    pub fn update(&mut self) {
+        self.timer -= 1;
+
+        if self.timer <= 0 {
+            self.count = 0;
+        }
+    }
+
+    /// Registers the pop of a trapped enemy; returns the bonus points awarded (none for the first of a chain).
+    pub fn add_pop(&mut self) -> i32 {
+        self.count += 1;
+        self.timer = COMBO_WINDOW;
+
+        (self.count - 1) * COMBO_BONUS_STEP
+    }
+
+    /// The chain meter is displayed at the top of the screen, with a bar showing the time left to extend it.
+    pub fn draw(&self) {
+        if self.count < 2 {
+            return;
+        }
+
+        draw_game_text(&format!("CHAIN {}", self.count), 36, None);
+
+        let width = METER_WIDTH * self.timer as f32 / COMBO_WINDOW as f32;
+        draw_rectangle((WIDTH as f32 - width) / 2., 68., width, 4., METER_COLOUR);
+    }
+}
@@ -0,0 +1,123 @@
+use nanoserde::{DeRon, SerRon};
+
+use crate::{
+    controls::Controls,
+    input_state::{InputSource, InputState},
+};
+
+/// With auto fire, updates between the orbs fired while holding blow.
+const AUTO_FIRE_INTERVAL: i32 = 20;
+
+// Rust: Not in the original game. Accessibility profiles, selectable per player; they're applied to the input
+// state (or, for the one-handed profile, to the keys), so that the player logic is the same for all of them.
+//
+#[derive(Clone, Copy, Default, PartialEq, DeRon, SerRon)]
+pub enum ControlProfile {
+    #[default]
+    Standard,
+    /// Blow is moved next to the movement keys, so that all the actions are in reach of one hand.
+    OneHanded,
+    /// Tapping left/right starts running, until tapping the same direction again (or the other one).
+    ToggleRun,
+    /// Holding blow fires orbs repeatedly.
+    AutoFire,
+}
+
+impl ControlProfile {
+    pub fn name(&self) -> &'static str {
+        match self {
+            ControlProfile::Standard => "STANDARD",
+            ControlProfile::OneHanded => "ONE HANDED",
+            ControlProfile::ToggleRun => "TOGGLE RUN",
+            ControlProfile::AutoFire => "AUTO FIRE",
+        }
+    }
+
+    pub fn next(&self) -> Self {
+        match self {
+            ControlProfile::Standard => ControlProfile::OneHanded,
+            ControlProfile::OneHanded => ControlProfile::ToggleRun,
+            ControlProfile::ToggleRun => ControlProfile::AutoFire,
+            ControlProfile::AutoFire => ControlProfile::Standard,
+        }
+    }
+
+    /// The keys actually used, given the ones bound.
+    pub fn controls(&self, controls: Controls) -> Controls {
+        match self {
+            ControlProfile::OneHanded => controls.one_handed(),
+            _ => controls,
+        }
+    }
+}
+
+/// Applies the profile to the input of another source.
+pub struct ProfileInput {
+    source: Box<dyn InputSource>,
+    profile: ControlProfile,
+    /// Input of the source in the previous update.
+    previous: InputState,
+    /// Toggle run: -1 = left, 0 = stopped, 1 = right.
+    run_direction: i32,
+    /// Auto fire: updates for which blow has been held.
+    blow_held_time: i32,
+}
+
+impl ProfileInput {
+    pub fn new(source: Box<dyn InputSource>, profile: ControlProfile) -> Self {
+        Self {
+            source,
+            profile,
+            previous: InputState::default(),
+            run_direction: 0,
+            blow_held_time: 0,
+        }
+    }
+}
+
+impl InputSource for ProfileInput {
+    fn input_state(&mut self) -> InputState {
+        let input = self.source.input_state();
+        let mut state = input;
+
+        match self.profile {
+            ControlProfile::ToggleRun => {
+                let toggle = |direction, run_direction| {
+                    if run_direction == direction {
+                        0
+                    } else {
+                        direction
+                    }
+                };
+
+                if input.left && !self.previous.left {
+                    self.run_direction = toggle(-1, self.run_direction);
+                } else if input.right && !self.previous.right {
+                    self.run_direction = toggle(1, self.run_direction);
+                }
+
+                state.left = self.run_direction < 0;
+                state.right = self.run_direction > 0;
+            }
+            ControlProfile::AutoFire => {
+                self.blow_held_time = if input.blow {
+                    self.blow_held_time + 1
+                } else {
+                    0
+                };
+
+                state.blow_pressed |=
+                    self.blow_held_time > 0 && self.blow_held_time % AUTO_FIRE_INTERVAL == 0;
+            }
+            ControlProfile::Standard | ControlProfile::OneHanded => {}
+        }
+
+        self.previous = input;
+
+        state
+    }
+
+    fn rumble(&mut self, strength: f32) {
+        self.source.rumble(strength);
+    }
+}
@@ -0,0 +1,217 @@
+use std::str::Chars;
+
+use macroquad::prelude::KeyCode;
+use nanoserde::{DeRon, DeRonErr, DeRonState, SerRon, SerRonState};
+
+/// Keys controlling a player; each player in a co-op game has its own set.
+#[derive(Clone, Copy, PartialEq)]
+pub struct Controls {
+    pub left: KeyCode,
+    pub right: KeyCode,
+    pub jump: KeyCode,
+    pub blow: KeyCode,
+}
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum Action {
+    Left,
+    Right,
+    Jump,
+    Blow,
+}
+
+pub const ACTIONS: [(Action, &str); 4] = [
+    (Action::Left, "LEFT"),
+    (Action::Right, "RIGHT"),
+    (Action::Jump, "JUMP"),
+    (Action::Blow, "BLOW"),
+];
+
+pub const PLAYER_1_CONTROLS: Controls = Controls {
+    left: KeyCode::Left,
+    right: KeyCode::Right,
+    jump: KeyCode::Up,
+    blow: KeyCode::Space,
+};
+
+pub const PLAYER_2_CONTROLS: Controls = Controls {
+    left: KeyCode::A,
+    right: KeyCode::D,
+    jump: KeyCode::W,
+    blow: KeyCode::F,
+};
+
+/// Preset layouts, selectable on the options screen; the second one suits left-handed players.
+pub const LAYOUTS: [(&str, Controls); 2] =
+    [("ARROWS", PLAYER_1_CONTROLS), ("WASD", PLAYER_2_CONTROLS)];
+
+impl Controls {
+    /// None if the keys have been bound individually, and don't match any preset.
+    pub fn layout_name(&self) -> Option<&'static str> {
+        LAYOUTS
+            .iter()
+            .find(|(_, controls)| controls == self)
+            .map(|(name, _)| *name)
+    }
+
+    /// Custom bindings switch to the first layout.
+    pub fn next_layout(&self) -> Self {
+        let current_i = LAYOUTS.iter().position(|(_, controls)| controls == self);
+        let next_i = current_i.map_or(0, |i| (i + 1) % LAYOUTS.len());
+
+        LAYOUTS[next_i].1
+    }
+
+    /// Blow is moved to the key below jump (for the preset layouts, Down or S); with other keys, the bindings are
+    /// unchanged.
+    pub fn one_handed(&self) -> Self {
+        let blow = match self.jump {
+            KeyCode::Up => KeyCode::Down,
+            KeyCode::W => KeyCode::S,
+            KeyCode::I => KeyCode::K,
+            _ => self.blow,
+        };
+
+        Self { blow, ..*self }
+    }
+
+    pub fn key(&self, action: Action) -> KeyCode {
+        match action {
+            Action::Left => self.left,
+            Action::Right => self.right,
+            Action::Jump => self.jump,
+            Action::Blow => self.blow,
+        }
+    }
+
+    pub fn key_mut(&mut self, action: Action) -> &mut KeyCode {
+        match action {
+            Action::Left => &mut self.left,
+            Action::Right => &mut self.right,
+            Action::Jump => &mut self.jump,
+            Action::Blow => &mut self.blow,
+        }
+    }
+}
+
+// Keys that can be bound, along with their names, which are used both in the settings file and on the options
+// screen (therefore, they can only use the characters available in the game font).
+//
+const KEY_NAMES: [(KeyCode, &str); 56] = [
+    (KeyCode::Left, "LEFT"),
+    (KeyCode::Right, "RIGHT"),
+    (KeyCode::Up, "UP"),
+    (KeyCode::Down, "DOWN"),
+    (KeyCode::Space, "SPACE"),
+    (KeyCode::Enter, "ENTER"),
+    (KeyCode::Tab, "TAB"),
+    (KeyCode::Backspace, "BACKSPACE"),
+    (KeyCode::LeftShift, "LEFT SHIFT"),
+    (KeyCode::RightShift, "RIGHT SHIFT"),
+    (KeyCode::LeftControl, "LEFT CONTROL"),
+    (KeyCode::RightControl, "RIGHT CONTROL"),
+    (KeyCode::LeftAlt, "LEFT ALT"),
+    (KeyCode::RightAlt, "RIGHT ALT"),
+    (KeyCode::Insert, "INSERT"),
+    (KeyCode::Delete, "DELETE"),
+    (KeyCode::Home, "HOME"),
+    (KeyCode::End, "END"),
+    (KeyCode::PageUp, "PAGE UP"),
+    (KeyCode::PageDown, "PAGE DOWN"),
+    (KeyCode::Key0, "0"),
+    (KeyCode::Key1, "1"),
+    (KeyCode::Key2, "2"),
+    (KeyCode::Key3, "3"),
+    (KeyCode::Key4, "4"),
+    (KeyCode::Key5, "5"),
+    (KeyCode::Key6, "6"),
+    (KeyCode::Key7, "7"),
+    (KeyCode::Key8, "8"),
+    (KeyCode::Key9, "9"),
+    (KeyCode::A, "A"),
+    (KeyCode::B, "B"),
+    (KeyCode::C, "C"),
+    (KeyCode::D, "D"),
+    (KeyCode::E, "E"),
+    (KeyCode::F, "F"),
+    (KeyCode::G, "G"),
+    (KeyCode::H, "H"),
+    (KeyCode::I, "I"),
+    (KeyCode::J, "J"),
+    (KeyCode::K, "K"),
+    (KeyCode::L, "L"),
+    (KeyCode::M, "M"),
+    (KeyCode::N, "N"),
+    (KeyCode::O, "O"),
+    (KeyCode::P, "P"),
+    (KeyCode::Q, "Q"),
+    (KeyCode::R, "R"),
+    (KeyCode::S, "S"),
+    (KeyCode::T, "T"),
+    (KeyCode::U, "U"),
+    (KeyCode::V, "V"),
+    (KeyCode::W, "W"),
+    (KeyCode::X, "X"),
+    (KeyCode::Y, "Y"),
+    (KeyCode::Z, "Z"),
+];
+
+/// Returns None if the key can't be bound.
+pub fn key_name(key: KeyCode) -> Option<&'static str> {
+    KEY_NAMES
+        .iter()
+        .find(|(code, _)| *code == key)
+        .map(|(_, name)| *name)
+}
+
+fn key_from_name(name: &str) -> KeyCode {
+    KEY_NAMES
+        .iter()
+        .find(|(_, key_name)| *key_name == name)
+        .map_or(KeyCode::Unknown, |(code, _)| *code)
+}
+
+/// Representation of the controls in the settings file; KeyCode doesn't support serialization.
+#[derive(DeRon, SerRon)]
+struct ControlsNames {
+    left: String,
+    right: String,
+    jump: String,
+    blow: String,
+}
+
+impl From<&Controls> for ControlsNames {
+    fn from(controls: &Controls) -> Self {
+        let name = |key| key_name(key).unwrap_or_default().to_string();
+
+        Self {
+            left: name(controls.left),
+            right: name(controls.right),
+            jump: name(controls.jump),
+            blow: name(controls.blow),
+        }
+    }
+}
+
+impl From<&ControlsNames> for Controls {
+    fn from(names: &ControlsNames) -> Self {
+        Self {
+            left: key_from_name(&names.left),
+            right: key_from_name(&names.right),
+            jump: key_from_name(&names.jump),
+            blow: key_from_name(&names.blow),
+        }
+    }
+}
+
+impl SerRon for Controls {
+    fn ser_ron(&self, indent_level: usize, state: &mut SerRonState) {
+        ControlsNames::from(self).ser_ron(indent_level, state);
+    }
+}
+
+impl DeRon for Controls {
+    fn de_ron(state: &mut DeRonState, input: &mut Chars) -> Result<Self, DeRonErr> {
+        ControlsNames::de_ron(state, input).map(|names| Self::from(&names))
+    }
+}
@@ -0,0 +1,81 @@
+use macroquad::prelude::{
+    draw_circle, draw_rectangle, draw_rectangle_lines, draw_text, Color, BLACK, WHITE,
+};
+
+use crate::{
+    actor::{Actor, Body},
+    game::Game,
+    GRID_BLOCK_SIZE, LEVEL_X_OFFSET, NUM_COLUMNS, NUM_ROWS,
+};
+
+// Rust: Not in the original game. Overlay of the collision data, toggled with F3, for tuning the movement.
+//
+// Collisions with the level are tested on the actors position (the point drawn), against the solid grid cells;
+// collisions between actors are tested on the image rectangles.
+
+const SOLID_CELL_COLOUR: Color = Color::new(1.0, 0.0, 0.0, 0.3);
+const ACTOR_COLOUR: Color = Color::new(0.0, 1.0, 0.0, 0.8);
+const ORB_COLOUR: Color = Color::new(0.0, 1.0, 1.0, 0.8);
+const POSITION_COLOUR: Color = Color::new(1.0, 1.0, 0.0, 1.0);
+
+const FONT_SIZE: f32 = 18.;
+
+pub fn draw(game: &Game) {
+    // The first row is not solid; the last one is the copy of the first, and it's the floor.
+    for row_y in 1..NUM_ROWS {
+        for (column_x, block) in game.grid[row_y as usize].bytes().enumerate() {
+            if block != b' ' && (column_x as i32) < NUM_COLUMNS {
+                draw_rectangle(
+                    (LEVEL_X_OFFSET + column_x as i32 * GRID_BLOCK_SIZE) as f32,
+                    (row_y * GRID_BLOCK_SIZE) as f32,
+                    GRID_BLOCK_SIZE as f32,
+                    GRID_BLOCK_SIZE as f32,
+                    SOLID_CELL_COLOUR,
+                );
+            }
+        }
+    }
+
+    game.fruits.iter().for_each(|f| draw_actor(f, ACTOR_COLOUR));
+    game.bolts.iter().for_each(|b| draw_actor(b, ACTOR_COLOUR));
+    game.enemies
+        .iter()
+        .for_each(|e| draw_actor(e, ACTOR_COLOUR));
+    game.flying_enemies
+        .iter()
+        .for_each(|e| draw_actor(e, ACTOR_COLOUR));
+    game.orbs.iter().for_each(|o| draw_actor(o, ORB_COLOUR));
+
+    if let Some(boss) = &game.boss {
+        draw_rectangle_lines(
+            (boss.x - boss.width() / 2) as f32,
+            (boss.y - boss.height() / 2) as f32,
+            boss.width() as f32,
+            boss.height() as f32,
+            1.,
+            ACTOR_COLOUR,
+        );
+    }
+
+    for player in game.players.iter().filter(|p| p.is_active()) {
+        draw_actor(player, ACTOR_COLOUR);
+
+        let text = format!("VY {} LANDED {}", player.vel_y, player.landed);
+        let (x, y) = (player.right() as f32 + 4., player.top() as f32 + FONT_SIZE);
+
+        draw_text(&text, x + 1., y + 1., FONT_SIZE, BLACK);
+        draw_text(&text, x, y, FONT_SIZE, WHITE);
+    }
+}
+
+fn draw_actor(actor: &impl Actor, colour: Color) {
+    draw_rectangle_lines(
+        actor.left() as f32,
+        actor.top() as f32,
+        actor.image().width(),
+        actor.image().height(),
+        1.,
+        colour,
+    );
+    draw_circle(actor.x() as f32, actor.y() as f32, 2., POSITION_COLOUR);
+}
@@ -0,0 +1,80 @@
+use nanoserde::{DeRon, SerRon};
+
+use crate::ticks::Ticks;
+
+/// Not in the original game, which corresponds to the Normal difficulty.
+#[derive(Clone, Copy, Default, PartialEq, DeRon, SerRon)]
+pub enum Difficulty {
+    Easy,
+    #[default]
+    Normal,
+    Hard,
+}
+
+impl Difficulty {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Difficulty::Easy => "EASY",
+            Difficulty::Normal => "NORMAL",
+            Difficulty::Hard => "HARD",
+        }
+    }
+
+    pub fn next(&self) -> Self {
+        match self {
+            Difficulty::Easy => Difficulty::Normal,
+            Difficulty::Normal | Difficulty::Hard => Difficulty::Hard,
+        }
+    }
+
+    pub fn previous(&self) -> Self {
+        match self {
+            Difficulty::Easy | Difficulty::Normal => Difficulty::Easy,
+            Difficulty::Hard => Difficulty::Normal,
+        }
+    }
+
+    /// Multiplier of the robots' likelihood of firing.
+    pub fn fire_probability_factor(&self) -> f32 {
+        match self {
+            Difficulty::Easy => 0.5,
+            Difficulty::Normal => 1.,
+            Difficulty::Hard => 1.5,
+        }
+    }
+
+    /// Speed of the bolts, on the first levels.
+    pub fn bolt_speed(&self) -> i32 {
+        match self {
+            Difficulty::Easy => 5,
+            Difficulty::Normal => 7,
+            Difficulty::Hard => 9,
+        }
+    }
+
+    /// Minimum time between the shots of a robot, on the first levels; the original game uses 12 frames.
+    pub fn fire_interval(&self) -> Ticks {
+        match self {
+            Difficulty::Easy => Ticks::new(18),
+            Difficulty::Normal => Ticks::new(12),
+            Difficulty::Hard => Ticks::new(10),
+        }
+    }
+
+    /// Maximum number of orbs on screen at once.
+    pub fn max_orbs(&self) -> usize {
+        match self {
+            Difficulty::Easy => 7,
+            Difficulty::Normal => 5,
+            Difficulty::Hard => 4,
+        }
+    }
+
+    pub fn starting_lives(&self) -> i32 {
+        match self {
+            Difficulty::Easy => 4,
+            Difficulty::Normal => 2,
+            Difficulty::Hard => 1,
+        }
+    }
+}
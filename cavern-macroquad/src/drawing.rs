@@ -1,4 +1,4 @@
-use macroquad::prelude::{collections::storage, draw_texture, WHITE};
+use macroquad::prelude::{collections::storage, draw_texture, Color, WHITE};
 
 use phf::phf_map;
 
@@ -23,20 +23,30 @@ fn char_width(chr: &u8) -> i32 {
     CHAR_WIDTH[index]
 }
 
+// Rust: Not in the original code; used for positioning text relative to other objects.
+pub fn text_width(text: &str) -> i32 {
+    text.as_bytes().iter().map(char_width).sum()
+}
+
 // Differs from the original function name, due to clashing with the Macroquad API.
 pub fn draw_game_text(text: &str, y: i32, x: Option<i32>) {
-    let text = text.as_bytes();
+    draw_game_text_ex(text, y, x, WHITE);
+}
 
+// Rust: Variant of draw_game_text() allowing to tint the text, e.g. for highlighting menu entries.
+pub fn draw_game_text_ex(text: &str, y: i32, x: Option<i32>, colour: Color) {
     let mut x = x.unwrap_or_else(|| {
         // If no X pos specified, draw text in centre of the screen - must first work out total width of text
-        (WIDTH - text.iter().map(char_width).sum::<i32>()) / 2
+        (WIDTH - text_width(text)) / 2
     });
 
+    let text = text.as_bytes();
+
     let fonts = &storage::get::<Resources>().fonts;
 
     for chr in text {
         let font = fonts[chr];
-        draw_texture(font, x as f32, y as f32, WHITE);
+        draw_texture(font, x as f32, y as f32, colour);
         x += char_width(chr);
     }
 }
@@ -0,0 +1,117 @@
+use std::iter::FromIterator;
+
+use crate::actor::Actor;
+
+// Rust: Not in the original code. Storage for the entities of a given type (orbs, robots...), addressed by
+// generational indexes: when an entity is removed, its slot is reused, but the old ids stop resolving (since the
+// generation doesn't match anymore), so that other entities can safely keep references to it (e.g. the orb being
+// blown by the player).
+//
+// The update passes are per-type, since each type interacts with a different set of entities; drawing is generic.
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct EntityId {
+    index: usize,
+    generation: u32,
+}
+
+struct Slot<T> {
+    generation: u32,
+    entity: Option<T>,
+}
+
+pub struct EntityStore<T> {
+    slots: Vec<Slot<T>>,
+    /// Indexes of the empty slots.
+    free: Vec<usize>,
+}
+
+impl<T> Default for EntityStore<T> {
+    fn default() -> Self {
+        Self {
+            slots: vec![],
+            free: vec![],
+        }
+    }
+}
+
+impl<T> EntityStore<T> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn insert(&mut self, entity: T) -> EntityId {
+        if let Some(index) = self.free.pop() {
+            let slot = &mut self.slots[index];
+            slot.generation += 1;
+            slot.entity = Some(entity);
+
+            EntityId {
+                index,
+                generation: slot.generation,
+            }
+        } else {
+            self.slots.push(Slot {
+                generation: 0,
+                entity: Some(entity),
+            });
+
+            EntityId {
+                index: self.slots.len() - 1,
+                generation: 0,
+            }
+        }
+    }
+
+    /// Returns None if the entity has been removed in the meantime.
+    pub fn get_mut(&mut self, id: EntityId) -> Option<&mut T> {
+        self.slots
+            .get_mut(id.index)
+            .filter(|slot| slot.generation == id.generation)
+            .and_then(|slot| slot.entity.as_mut())
+    }
+
+    /// Removes the entities for which the predicate returns false.
+    pub fn retain(&mut self, mut predicate: impl FnMut(&T) -> bool) {
+        for (index, slot) in self.slots.iter_mut().enumerate() {
+            if slot
+                .entity
+                .as_ref()
+                .map_or(false, |entity| !predicate(entity))
+            {
+                slot.entity = None;
+                self.free.push(index);
+            }
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.slots.len() - self.free.len()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        self.slots.iter().filter_map(|slot| slot.entity.as_ref())
+    }
+
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = &mut T> {
+        self.slots
+            .iter_mut()
+            .filter_map(|slot| slot.entity.as_mut())
+    }
+}
+
+impl<T: Actor> EntityStore<T> {
+    pub fn draw(&self) {
+        self.iter().for_each(|entity| entity.draw());
+    }
+}
+
+impl<T> FromIterator<T> for EntityStore<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(entities: I) -> Self {
+        let mut store = Self::new();
+        for entity in entities {
+            store.insert(entity);
+        }
+        store
+    }
+}
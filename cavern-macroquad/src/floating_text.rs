@@ -0,0 +1,43 @@
+use macroquad::prelude::{Color, WHITE};
+
+use crate::drawing::{draw_game_text_ex, text_width};
+
+// Rust: Not in the original game. Text rising from a position (e.g. the bonus points awarded), and fading out.
+
+const LIFETIME: i32 = 60;
+const RISE_SPEED: i32 = 1;
+
+pub struct FloatingText {
+    text: String,
+    x: i32,
+    y: i32,
+    timer: i32,
+}
+
+impl FloatingText {
+    /// The text is centred on the given position.
+    pub fn new(text: String, x: i32, y: i32) -> Self {
+        Self {
+            x: x - text_width(&text) / 2,
+            text,
+            y,
+            timer: 0,
+        }
+    }
+
+    pub fn is_alive(&self) -> bool {
+        self.timer < LIFETIME
+    }
+
+    pub fn update(&mut self) {
+        self.timer += 1;
+        self.y -= RISE_SPEED;
+    }
+
+    pub fn draw(&self) {
+        let alpha = 1. - self.timer as f32 / LIFETIME as f32;
+        let colour = Color::new(WHITE.r, WHITE.g, WHITE.b, alpha);
+
+        draw_game_text_ex(&self.text, self.y, Some(self.x), colour);
+    }
+}
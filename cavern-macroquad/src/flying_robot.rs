@@ -0,0 +1,203 @@
+use macroquad::{
+    prelude::{collections::storage, Color, Texture2D},
+    rand::ChooseRandom,
+};
+use nanoserde::{DeRon, SerRon};
+
+use cavern_core::{collide::COLLIDE_BODY_DEFAULT_ANCHOR, CollideBody};
+
+use crate::{
+    actor::{image_size, Actor, Anchor, Body},
+    entity_store::EntityStore,
+    game_playback::play_game_random_sound_at,
+    orb::Orb,
+    particle::{self, Particle},
+    player::Player,
+    resources::Resources,
+    robot::RobotType,
+    ticks::Ticks,
+};
+
+// Heights at which flying robots patrol; they're in the middle of the gaps between the platforms of the levels.
+const CRUISE_YS: [i32; 3] = [90, 190, 290];
+
+const PATROL_SPEED: i32 = 2;
+const DIVE_SPEED: i32 = 3;
+const ENTER_SPEED: i32 = 2;
+
+// Time between the dive attempts, picked randomly in the range.
+const MIN_DIVE_INTERVAL: Ticks = Ticks::new(150);
+const MAX_DIVE_INTERVAL: Ticks = Ticks::new(300);
+
+// Flying robots don't have their own sprites; they reuse the normal robot ones, tinted.
+const FLYING_ROBOT_COLOUR: Color = Color::new(0.6, 0.8, 1.0, 1.0);
+const SPRITE_TYPE: RobotType = RobotType::Normal;
+
+// When trapped, flying robots count as the more powerful type (so they can drop power ups).
+pub const TRAPPED_TYPE: RobotType = RobotType::Aggressive;
+
+#[derive(Clone, Copy, PartialEq, DeRon, SerRon)]
+pub enum FlyingState {
+    /// Descending from the top of the screen to the cruise height; blocks are ignored.
+    Entering,
+    Patrolling,
+    /// Diving diagonally toward the y position the target player was at when the dive started.
+    Diving,
+    Returning,
+}
+
+/// Robot that ignores gravity: it patrols horizontally at a fixed height, and periodically dives toward the
+/// nearest player, hurting them on contact.
+pub struct FlyingRobot {
+    pub state: FlyingState,
+    pub cruise_y: i32,
+    pub direction_x: i32,
+    pub dive_timer: Ticks,
+    pub dive_target_y: i32,
+    pub alive: bool,
+
+    // Actor trait
+    pub x: i32,
+    pub y: i32,
+    pub image: Texture2D,
+    pub anchor: Anchor,
+}
+
+impl FlyingRobot {
+    pub fn new(x: i32, y: i32) -> Self {
+        Self {
+            state: FlyingState::Entering,
+            cruise_y: *CRUISE_YS.choose().unwrap(),
+            direction_x: *[-1, 1].choose().unwrap(),
+            dive_timer: Ticks::random(MIN_DIVE_INTERVAL, MAX_DIVE_INTERVAL),
+            dive_target_y: 0,
+            alive: true,
+
+            x,
+            y,
+            image: storage::get::<Resources>().blank_texture,
+            anchor: COLLIDE_BODY_DEFAULT_ANCHOR,
+        }
+    }
+
+    pub fn update(
+        &mut self,
+        orbs: &mut EntityStore<Orb>,
+        particles: &mut Vec<Particle>,
+        players: &mut [Player],
+        game_timer: i32,
+        grid: &[String],
+    ) {
+        match self.state {
+            FlyingState::Entering => {
+                self.y += ENTER_SPEED;
+                if self.y >= self.cruise_y {
+                    self.state = FlyingState::Patrolling;
+                }
+            }
+            FlyingState::Patrolling => {
+                // Turn around if we hit a wall
+                if self.move_(self.direction_x, 0, PATROL_SPEED, grid) {
+                    self.direction_x = -self.direction_x;
+                }
+
+                self.dive_timer.decrement();
+
+                if self.dive_timer <= Ticks::ZERO {
+                    // Dive toward the nearest player below us, if any; otherwise, wait for the next chance
+                    let target = players
+                        .iter()
+                        .filter(|p| p.is_active() && p.y() > self.y)
+                        .min_by_key(|p| (p.x() - self.x).abs());
+
+                    if let Some(target) = target {
+                        self.direction_x = (target.x() - self.x).signum();
+                        self.dive_target_y = target.y();
+                        self.state = FlyingState::Diving;
+                    }
+
+                    self.dive_timer = Ticks::random(MIN_DIVE_INTERVAL, MAX_DIVE_INTERVAL);
+                }
+            }
+            FlyingState::Diving => {
+                if self.move_(self.direction_x, 1, DIVE_SPEED, grid) || self.y >= self.dive_target_y
+                {
+                    self.state = FlyingState::Returning;
+                }
+            }
+            FlyingState::Returning => {
+                // Blocks are not checked when moving up, so this can't get stuck
+                self.move_(0, -1, PATROL_SPEED, grid);
+                if self.y <= self.cruise_y {
+                    self.state = FlyingState::Patrolling;
+                }
+            }
+        }
+
+        let center = self.center();
+
+        for player in players.iter_mut().filter(|p| p.is_active()) {
+            if player.hurt_timer < Ticks::ZERO && player.collidepoint(center) {
+                player.hurt(self.direction_x);
+            }
+        }
+
+        let resources = storage::get::<Resources>();
+
+        // Am I colliding with an orb? If so, become trapped by it
+        for orb in orbs.iter_mut() {
+            if orb.trapped_enemy_type.is_none() && self.collidepoint(orb.center()) {
+                self.alive = false;
+                orb.trap(TRAPPED_TYPE);
+                particle::emit_puff(particles, orb.center());
+                play_game_random_sound_at(players.first(), |sounds| &sounds.trap_sounds, self.x);
+                break;
+            }
+        }
+
+        // Choose and set sprite image; flying robots never fire, so they always use the moving frames
+        let type_factor = 16 * SPRITE_TYPE.val();
+        let direction_factor = if self.direction_x > 0 { 8 } else { 0 };
+        let anim_factor = 1 + ((game_timer / 4) % 4);
+        let image_i = (type_factor + direction_factor + anim_factor) as usize;
+        self.image = resources.robot_textures[image_i];
+    }
+}
+
+impl Body for FlyingRobot {
+    fn x(&self) -> i32 {
+        self.x
+    }
+
+    fn x_mut(&mut self) -> &mut i32 {
+        &mut self.x
+    }
+
+    fn y(&self) -> i32 {
+        self.y
+    }
+
+    fn y_mut(&mut self) -> &mut i32 {
+        &mut self.y
+    }
+
+    fn size(&self) -> (i32, i32) {
+        image_size(&self.image)
+    }
+
+    fn anchor(&self) -> Anchor {
+        self.anchor
+    }
+}
+
+impl Actor for FlyingRobot {
+    fn image(&self) -> Texture2D {
+        self.image
+    }
+
+    fn tint(&self) -> Color {
+        FLYING_ROBOT_COLOUR
+    }
+}
+
+impl CollideBody for FlyingRobot {}
@@ -0,0 +1,38 @@
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use macroquad::prelude::KeyCode;
+
+use crate::touch_controls;
+
+// Rust: Not in the original game. The engine reports the presses (keys, taps, gamepad buttons) for the whole frame
+// in which they happen, but a frame can run several updates, in order to catch up (see `main`). The presses are
+// seen only by the first update of each frame, so that they're handled once; the held keys are seen by all the
+// updates.
+static PRESSES_HANDLED: AtomicBool = AtomicBool::new(false);
+
+/// Must be invoked once per frame, before the updates.
+pub fn begin_frame() {
+    PRESSES_HANDLED.store(false, Ordering::Relaxed);
+}
+
+/// Must be invoked after each update.
+pub fn end_update() {
+    PRESSES_HANDLED.store(true, Ordering::Relaxed);
+}
+
+/// False for the catch-up updates of a frame.
+pub fn are_presses_new() -> bool {
+    !PRESSES_HANDLED.load(Ordering::Relaxed)
+}
+
+pub fn is_key_pressed(key_code: KeyCode) -> bool {
+    are_presses_new() && macroquad::prelude::is_key_pressed(key_code)
+}
+
+pub fn get_last_key_pressed() -> Option<KeyCode> {
+    macroquad::prelude::get_last_key_pressed().filter(|_| are_presses_new())
+}
+
+pub fn is_screen_tapped() -> bool {
+    are_presses_new() && touch_controls::is_screen_tapped()
+}
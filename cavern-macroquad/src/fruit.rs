@@ -1,26 +1,34 @@
 use macroquad::{
-    prelude::{collections::storage, Texture2D},
+    prelude::{collections::storage, Color, Texture2D, WHITE},
     rand::ChooseRandom,
 };
+use nanoserde::{DeRon, SerRon};
+
+use cavern_core::{gravity::GRAVITY_BODY_DEFAULT_ANCHOR, CollideBody, GravityBody};
 
 use crate::{
-    actor::{Actor, Anchor},
-    collide_actor::CollideActor,
+    actor::{image_size, Actor, Anchor, Body},
+    animation::Animation,
+    entity_store::EntityStore,
     game_playback::play_game_sound,
-    gravity_actor::{GravityActor, GRAVITY_ACTOR_DEFAULT_ANCHOR},
+    gravity_actor::GravityActor,
     player::Player,
     pop::Pop,
+    power_up::{PowerUp, POWER_UP_SPRITE_VAL},
     resources::Resources,
     robot::RobotType,
 };
 
-#[derive(Clone, Copy)]
+#[derive(Clone, Copy, DeRon, SerRon)]
 pub enum FruitType {
     Apple,
     Raspberry,
     Lemon,
     ExtraHealth,
     ExtraLife,
+    Shield,
+    RapidFire,
+    SpeedBoost,
 }
 
 impl FruitType {
@@ -31,14 +39,29 @@ impl FruitType {
             FruitType::Lemon => 2,
             FruitType::ExtraHealth => 3,
             FruitType::ExtraLife => 4,
+            FruitType::Shield | FruitType::RapidFire | FruitType::SpeedBoost => POWER_UP_SPRITE_VAL,
+        }
+    }
+
+    fn power_up(&self) -> Option<PowerUp> {
+        match self {
+            FruitType::Shield => Some(PowerUp::Shield),
+            FruitType::RapidFire => Some(PowerUp::RapidFire),
+            FruitType::SpeedBoost => Some(PowerUp::SpeedBoost),
+            _ => None,
         }
     }
 }
 
 pub struct Fruit {
     pub time_to_live: i32,
+    /// Rust: Not in the original game; set on the update in which a player collects the fruit.
+    pub collected: bool,
+    // Rust: Not read anywhere; kept for parity with the original code.
+    #[allow(dead_code)]
     pub trapped_enemy_type: Option<RobotType>,
     pub type_: FruitType,
+    pub animation: Animation,
 
     // Actor trait
     pub x: i32,
@@ -67,74 +90,89 @@ impl Fruit {
             let mut types = [FruitType::Apple, FruitType::Raspberry, FruitType::Lemon].repeat(10); // Each of these appear in the list 10 times
             types.extend([FruitType::ExtraHealth].repeat(9)); // This appears 9 times
             types.extend([FruitType::ExtraLife]); // This only appears once
+
+            // Rust: The temporary power ups are not in the original game
+            types.extend(
+                [
+                    FruitType::Shield,
+                    FruitType::RapidFire,
+                    FruitType::SpeedBoost,
+                ]
+                .repeat(3),
+            );
             *types.choose().unwrap() // Randomly choose one from the list
         };
 
         Self {
             time_to_live: 500, // Counts down to zero
+            collected: false,
             trapped_enemy_type,
             type_,
+            animation: Animation::default(),
 
             x,
             y,
             image: storage::get::<Resources>().blank_texture,
-            anchor: GRAVITY_ACTOR_DEFAULT_ANCHOR,
+            anchor: GRAVITY_BODY_DEFAULT_ANCHOR,
 
             vel_y: 0,
             landed: false,
         }
     }
 
-    pub fn update(
-        &mut self,
-        pops: &mut Vec<Pop>,
-        player: Option<&mut Player>,
-        game_timer: i32,
-        grid: &[&str],
-    ) {
+    pub fn update(&mut self, pops: &mut EntityStore<Pop>, players: &mut [Player], grid: &[String]) {
         GravityActor::update(self, true, grid);
 
-        // Does the player exist, and are they colliding with us?
+        // Does the player exist, and are they colliding with us? In co-op mode, either player can collect.
 
-        match player {
-            Some(player) if player.collidepoint(self.center()) => {
-                let resources = storage::get::<Resources>();
+        let center = self.center();
+        let collecting_player = players
+            .iter_mut()
+            .find(|p| p.is_active() && p.collidepoint(center));
 
+        match collecting_player {
+            Some(player) => {
                 match self.type_ {
                     FruitType::ExtraHealth => {
                         player.health = 3.min(player.health + 1);
-                        play_game_sound(Some(player), &resources.bonus_sound);
+                        play_game_sound(Some(player), |sounds| sounds.bonus_sound);
                     }
                     FruitType::ExtraLife => {
                         player.lives += 1;
-                        play_game_sound(Some(player), &resources.bonus_sound);
+                        play_game_sound(Some(player), |sounds| sounds.bonus_sound);
+                    }
+                    FruitType::Shield | FruitType::RapidFire | FruitType::SpeedBoost => {
+                        player.apply_power_up(self.type_.power_up().unwrap());
+                        play_game_sound(Some(player), |sounds| sounds.bonus_sound);
                     }
                     _ => {
                         player.score += (self.type_.val() + 1) * 100;
-                        play_game_sound(Some(player), &resources.score_sound);
+                        play_game_sound(Some(player), |sounds| sounds.score_sound);
                     }
                 }
 
                 self.time_to_live = 0; // Disappear
+                self.collected = true;
             }
-            _ => {
+            None => {
                 self.time_to_live -= 1;
             }
         }
 
         if self.time_to_live <= 0 {
             // Create 'pop' animation
-            pops.push(Pop::new(self.x, self.y - 27, 0));
+            pops.insert(Pop::new(self.x, self.y - 27, 0));
         }
 
-        let type_factor = self.type_.val() * 3;
-        let timer_factor = [0, 1, 2, 1][((game_timer / 6) % 4) as usize];
-        let image_i = (type_factor + timer_factor) as usize;
-        self.image = storage::get::<Resources>().fruit_textures[image_i];
+        let type_factor = (self.type_.val() * 3) as usize;
+        let fruit_textures = &storage::get::<Resources>().fruit_textures;
+        let frames = [0, 1, 2, 1].map(|frame_i| fruit_textures[type_factor + frame_i]);
+        self.animation.play(&frames, 6, true);
+        self.image = self.animation.image();
     }
 }
 
-impl Actor for Fruit {
+impl Body for Fruit {
     fn x(&self) -> i32 {
         self.x
     }
@@ -151,18 +189,30 @@ impl Actor for Fruit {
         &mut self.y
     }
 
-    fn image(&self) -> macroquad::prelude::Texture2D {
-        self.image
+    fn size(&self) -> (i32, i32) {
+        image_size(&self.image)
     }
 
-    fn anchor(&self) -> crate::actor::Anchor {
+    fn anchor(&self) -> Anchor {
         self.anchor
     }
 }
 
-impl CollideActor for Fruit {}
+impl Actor for Fruit {
+    fn image(&self) -> Texture2D {
+        self.image
+    }
+
+    fn tint(&self) -> Color {
+        self.type_
+            .power_up()
+            .map_or(WHITE, |power_up| power_up.colour())
+    }
+}
+
+impl CollideBody for Fruit {}
 
-impl GravityActor for Fruit {
+impl GravityBody for Fruit {
     fn vel_y(&self) -> i32 {
         self.vel_y
     }
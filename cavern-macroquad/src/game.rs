@@ -1,57 +1,164 @@
 use crate::actor::Actor;
+use crate::blow_params::BlowParams;
 use crate::bolt::Bolt;
+use crate::bonus_stage::{BonusStage, BONUS_STAGE_GRID, FRUIT_SPAWN_INTERVAL, FRUIT_TIME_TO_LIVE};
+use crate::boss::Boss;
+use crate::checkpoint::Checkpoint;
+use crate::combo::Combo;
+use crate::difficulty::Difficulty;
+use crate::entity_store::EntityStore;
+use crate::floating_text::FloatingText;
+use crate::flying_robot::FlyingRobot;
 use crate::fruit::Fruit;
+use crate::game_mode::GameMode;
 use crate::game_playback::play_game_sound;
-use crate::orb::RcOrb;
+use crate::orb::{Orb, MAX_WALL_BOUNCES};
+use crate::particle::Particle;
 use crate::pop::Pop;
 use crate::resources::Resources;
 use crate::robot::{Robot, RobotType};
-use crate::{levels::LEVELS, player::Player};
-use crate::{GRID_BLOCK_SIZE, LEVEL_X_OFFSET, NUM_COLUMNS, NUM_ROWS, WIDTH};
+use crate::screen_shake::{ScreenShake, BOLT_IMPACT_SHAKE, BOSS_HIT_SHAKE, ORB_POP_SHAKE};
+use crate::spawn_waves::{SpawnWaves, Wave};
+use crate::ticks::Ticks;
+use crate::{
+    level_generator,
+    levels::{AGGRESSION_CURVE, LEVELS},
+    player::Player,
+};
+use crate::{GRID_BLOCK_SIZE, HEIGHT, LEVEL_X_OFFSET, NUM_COLUMNS, NUM_ROWS, WIDTH};
 
 use macroquad::prelude::{draw_texture, WHITE};
 use macroquad::rand::gen_range;
 use macroquad::{prelude::collections::storage, rand::ChooseRandom};
 
+const BOSS_DEFEAT_SCORE: i32 = 2000;
+
+// Rust: In endless mode, the number of enemies grows faster, and more of them can be on screen at once.
+const ENDLESS_ENEMIES_PER_LEVEL: usize = 2;
+const ENDLESS_MAX_ENEMIES: i32 = 12;
+
+// The firing interval doesn't go below this, since the bolt is fired on the 8th frame of the firing animation.
+const MIN_FIRE_INTERVAL: Ticks = Ticks::new(9);
+
+// Orbs popping within this distance (on each axis) from a player shake the screen.
+const SHAKE_POP_DISTANCE: i32 = 100;
+
 #[derive(Default)]
 pub struct Game {
-    pub player: Option<Player>,
+    /// Empty while the title screen is showing the game in the background; two players in co-op mode.
+    pub players: Vec<Player>,
+    pub difficulty: Difficulty,
+    pub mode: GameMode,
     pub level_colour: i8,
     pub level: i8,
     pub timer: i32,
-    pub grid: Vec<&'static str>,
+    pub grid: Vec<String>,
 
-    pub fruits: Vec<Fruit>,
-    pub bolts: Vec<Bolt>,
-    pub enemies: Vec<Robot>,
+    pub fruits: EntityStore<Fruit>,
+    pub bolts: EntityStore<Bolt>,
+    pub enemies: EntityStore<Robot>,
     pub pending_enemies: Vec<RobotType>,
-    pub pops: Vec<Pop>,
-    pub orbs: Vec<RcOrb>,
+    pub flying_enemies: EntityStore<FlyingRobot>,
+    pub pending_flying_enemies: i32,
+    /// Rust: Not in the original game; waves still to be released, sorted by time.
+    pub pending_waves: Vec<Wave>,
+    pub boss: Option<Boss>,
+    pub pops: EntityStore<Pop>,
+    pub orbs: EntityStore<Orb>,
+    /// Rust: Not in the original game; not saved, since they're purely visual.
+    pub particles: Vec<Particle>,
+    /// Rust: Not in the original game; like the particles, they're not saved.
+    pub combo: Combo,
+    /// Rust: Not in the original game; there are none in endless mode.
+    pub checkpoints: Vec<Checkpoint>,
+    pub floating_texts: Vec<FloatingText>,
+    /// Rust: Not in the original game; set from the settings, so that the title and demo games are unaffected.
+    pub orb_wall_bounces: bool,
+    /// Rust: Not in the original game; set from the settings, like the orb wall bounces.
+    pub charge_shot: bool,
+    /// Rust: Not in the original game; set from the settings, like the orb wall bounces.
+    pub extra_life_score: i32,
+    /// Rust: Not in the original game.
+    pub screen_shake: ScreenShake,
+    /// Rust: Not in the original game; set while playing a bonus stage, in place of a level.
+    pub bonus_stage: Option<BonusStage>,
 }
 
 impl Game {
-    pub fn new(player: Option<Player>) -> Self {
+    pub fn new(players: Vec<Player>, difficulty: Difficulty, mode: GameMode) -> Self {
+        Self::new_at_level(players, difficulty, mode, 0)
+    }
+
+    // Rust: Not in the original game; used by the level select screen.
+    pub fn new_at_level(
+        players: Vec<Player>,
+        difficulty: Difficulty,
+        mode: GameMode,
+        level: i8,
+    ) -> Self {
         let mut game = Self {
-            player,
-            level_colour: -1,
-            level: -1,
+            players,
+            difficulty,
+            mode,
+            // Both are incremented when starting the level
+            level_colour: (level - 1).rem_euclid(4),
+            level: level - 1,
             timer: -1,
             ..Default::default()
         };
 
-        game.next_level();
+        game.start_level();
 
         game
     }
 
     pub fn fire_probability(&self) -> f32 {
         // Likelihood per frame of each robot firing a bolt - they fire more often on higher levels
-        0.001 + (0.0001 * 100.min(self.level) as f32)
+        (0.001 + (0.0001 * 100.min(self.level) as f32)) * self.difficulty.fire_probability_factor()
+    }
+
+    // Rust: Not in the original game; see AGGRESSION_CURVE.
+    fn aggression_factor(&self) -> f32 {
+        let level = self.level.max(0);
+
+        AGGRESSION_CURVE
+            .windows(2)
+            .find(|points| level < points[1].0)
+            .map_or(AGGRESSION_CURVE[AGGRESSION_CURVE.len() - 1].1, |points| {
+                let ((start_level, start_factor), (end_level, end_factor)) = (points[0], points[1]);
+                let progress = (level - start_level) as f32 / (end_level - start_level) as f32;
+                start_factor + (end_factor - start_factor) * progress
+            })
+    }
+
+    // Rust: Not in the original game, where the orb limit and blow cap are hardcoded.
+    fn blow_params(&self) -> BlowParams {
+        let wall_bounces = if self.orb_wall_bounces {
+            MAX_WALL_BOUNCES
+        } else {
+            0
+        };
+
+        BlowParams::new(self.difficulty.max_orbs(), wall_bounces, self.charge_shot)
+    }
+
+    pub fn bolt_speed(&self) -> i32 {
+        (self.difficulty.bolt_speed() as f32 * self.aggression_factor()).round() as i32
+    }
+
+    /// Minimum time between the shots of a robot.
+    pub fn fire_interval(&self) -> Ticks {
+        let fire_interval = self.difficulty.fire_interval().get() as f32 / self.aggression_factor();
+        Ticks::new(fire_interval.round() as i32).max(MIN_FIRE_INTERVAL)
     }
 
     pub fn max_enemies(&self) -> i32 {
         // Maximum number of enemies on-screen at once – increases as you progress through the levels
-        ((self.level + 6) / 2).min(8) as i32
+        let max_enemies = match self.mode {
+            GameMode::Classic => 8,
+            GameMode::Endless => ENDLESS_MAX_ENEMIES,
+        };
+        ((self.level as i32 + 6) / 2).min(max_enemies)
     }
 
     pub fn get_robot_spawn_x(&self) -> i32 {
@@ -73,62 +180,212 @@ impl Game {
         WIDTH / 2
     }
 
+    /// Enemies either on screen or still to be created.
+    fn remaining_enemies(&self) -> usize {
+        self.pending_enemies.len()
+            + self
+                .pending_waves
+                .iter()
+                .map(Wave::num_enemies)
+                .sum::<usize>()
+            + self.enemies.len()
+            + self.pending_flying_enemies as usize
+            + self.flying_enemies.len()
+            + self.boss.iter().count()
+    }
+
+    fn defeat_boss(&mut self) {
+        let boss = self.boss.take().unwrap();
+
+        // The boss bursts into a shower of pops and fruits; the fruits have a chance of being power ups
+        for (dx, dy) in [(-40, -50), (40, -50), (0, 0), (-40, 50), (40, 50)] {
+            let (x, y) = (boss.x + dx, boss.y + dy);
+            self.pops.insert(Pop::new(x, y, 1));
+            self.fruits
+                .insert(Fruit::new(x, y, Some(RobotType::Aggressive)));
+        }
+
+        for player in self.players.iter_mut().filter(|p| p.is_active()) {
+            player.score += BOSS_DEFEAT_SCORE;
+        }
+
+        play_game_sound(self.players.first(), |sounds| sounds.bonus_sound);
+    }
+
+    /// The game is over once every player has run out of lives.
+    pub fn is_over(&self) -> bool {
+        self.players.iter().all(|p| !p.is_active())
+    }
+
     pub fn update(&mut self) {
         self.timer += 1;
+        self.screen_shake.update();
 
         // Rust: We precompute this here, since it can't be done inside the enemies cycle, due to borrowing
         // rules.
         let fire_probability = self.fire_probability();
+        let fire_interval = self.fire_interval();
+        let bolt_speed = self.bolt_speed();
+
+        for checkpoint in &mut self.checkpoints {
+            checkpoint.update(&mut self.players);
+        }
+
+        // Rust: Advance the animations; the actors choose which one to play in their own update.
+        for p in &mut self.players {
+            p.animation.update();
+        }
+        for enemy in self.enemies.iter_mut() {
+            enemy.animation.update();
+        }
+        for fruit in self.fruits.iter_mut() {
+            fruit.animation.update();
+        }
 
         // Update all objects
-        for fruit in &mut self.fruits {
-            fruit.update(&mut self.pops, self.player.as_mut(), self.timer, &self.grid)
+        for fruit in self.fruits.iter_mut() {
+            fruit.update(&mut self.pops, &mut self.players, &self.grid)
         }
-        for bolt in &mut self.bolts {
-            bolt.update(&mut self.orbs, self.player.as_mut(), self.timer, &self.grid)
+        if let Some(bonus_stage) = &mut self.bonus_stage {
+            bonus_stage.fruits_collected +=
+                self.fruits.iter().filter(|f| f.collected).count() as i32;
         }
-        for enemy in &mut self.enemies {
+        for bolt in self.bolts.iter_mut() {
+            let hit_player = bolt.update(
+                &mut self.orbs,
+                &mut self.particles,
+                &mut self.players,
+                self.timer,
+                &self.grid,
+            );
+
+            if hit_player {
+                self.screen_shake.add(BOLT_IMPACT_SHAKE);
+            }
+        }
+        for enemy in self.enemies.iter_mut() {
             enemy.update(
                 &mut self.bolts,
                 &mut self.orbs,
-                self.player.as_ref(),
+                &mut self.particles,
+                &self.players,
                 fire_probability,
+                fire_interval,
+                bolt_speed,
+                &self.grid,
+            )
+        }
+        for enemy in self.flying_enemies.iter_mut() {
+            enemy.update(
+                &mut self.orbs,
+                &mut self.particles,
+                &mut self.players,
                 self.timer,
                 &self.grid,
             )
         }
+        if let Some(boss) = &mut self.boss {
+            let health_before = boss.health;
+
+            boss.update(
+                &mut self.bolts,
+                &mut self.orbs,
+                &self.players,
+                bolt_speed,
+                self.timer,
+            );
+
+            if boss.health < health_before {
+                self.screen_shake.add(BOSS_HIT_SHAKE);
+            }
+
+            if boss.health <= 0 {
+                self.defeat_boss();
+            }
+        }
         self.pops.iter_mut().for_each(|p| p.update());
-        if let Some(p) = &mut self.player {
-            p.update(&mut self.orbs, &self.grid, self.timer);
+        self.particles.iter_mut().for_each(|p| p.update());
+        self.floating_texts.iter_mut().for_each(|t| t.update());
+        let blow_params = self.blow_params();
+        for p in self.players.iter_mut().filter(|p| p.is_active()) {
+            let input = p.input_source.input_state();
+            p.update(&input, &mut self.orbs, &blow_params, &self.grid);
         }
-        for orb in &mut self.orbs {
-            orb.borrow_mut().update(
+        for orb in self.orbs.iter_mut() {
+            orb.update(
                 &mut self.fruits,
                 &mut self.pops,
-                self.player.as_ref(),
+                &mut self.particles,
+                self.players.first(),
                 &self.grid,
             )
         }
 
+        // Rust: Not in the original game. Orbs popping close to a player shake the screen.
+        for orb in self.orbs.iter().filter(|o| o.is_popped()) {
+            let near_player = self.players.iter().any(|p| {
+                p.is_active()
+                    && (p.x - orb.x).abs() < SHAKE_POP_DISTANCE
+                    && (p.y - orb.y).abs() < SHAKE_POP_DISTANCE
+            });
+
+            if near_player {
+                self.screen_shake.add(ORB_POP_SHAKE);
+            }
+        }
+
+        // Rust: Not in the original game. Trapped enemies popped in quick succession award a bonus to the players.
+        self.combo.update();
+        for orb in self
+            .orbs
+            .iter()
+            .filter(|o| o.is_popped() && o.trapped_enemy_type.is_some())
+        {
+            let bonus = self.combo.add_pop();
+
+            if bonus > 0 {
+                for player in self.players.iter_mut().filter(|p| p.is_active()) {
+                    player.score += bonus;
+                }
+                self.floating_texts
+                    .push(FloatingText::new(bonus.to_string(), orb.x, orb.y));
+            }
+        }
+
+        // Rust: Not in the original game. Checked once all the points of the frame have been scored.
+        for player in self.players.iter_mut().filter(|p| p.is_active()) {
+            if player.award_extra_lives(self.extra_life_score) {
+                play_game_sound(Some(player), |sounds| sounds.life_sound);
+            }
+        }
+
         // Remove objects which are no longer wanted from the lists. For example, we recreate
         // self.fruits such that it contains all existing fruits except those whose time_to_live counter has reached zero
         self.fruits.retain(|f| f.time_to_live > 0);
         self.bolts.retain(|b| b.active);
         self.enemies.retain(|e| e.alive);
+        self.flying_enemies.retain(|e| e.alive);
         self.pops.retain(|p| p.timer < 12);
-        self.orbs
-            .retain(|o| o.borrow().timer < 250 && o.borrow().y > -40);
+        self.particles.retain(|p| p.is_alive());
+        self.floating_texts.retain(|t| t.is_alive());
+        self.orbs.retain(|o| !o.is_popped());
 
         // Every 100 frames, create a random fruit (unless there are no remaining enemies on this level)
-        if self.timer % 100 == 0 && (self.pending_enemies.len() + self.enemies.len()) > 0 {
+        if self.timer % 100 == 0 && self.remaining_enemies() > 0 {
             // Create fruit at random position
-            self.fruits.push(Fruit::new(
+            self.fruits.insert(Fruit::new(
                 gen_range(70, 730 + 1),
                 gen_range(75, 400 + 1),
                 None,
             ));
         }
 
+        if self.bonus_stage.is_some() {
+            self.update_bonus_stage();
+        }
+
+        self.release_waves(self.timer);
+
         // Every 81 frames, if there is at least 1 pending enemy, and the number of active enemies is below the current
         // level's maximum enemies, create a robot
         if self.timer % 81 == 0
@@ -138,25 +395,35 @@ impl Game {
             // Retrieve and remove the last element from the pending enemies list
             let robot_type = self.pending_enemies.pop().unwrap();
             let (x, y) = (self.get_robot_spawn_x(), -30);
-            self.enemies.push(Robot::new(x, y, robot_type));
+            self.enemies.insert(Robot::new(x, y, robot_type));
         }
 
-        // End level if there are no enemies remaining to be created, no existing enemies, no fruit, no popping orbs,
-        // and no orbs containing trapped enemies. (We don't want to include orbs which don't contain trapped enemies,
-        // as the level would never end if the player kept firing new orbs)
-        if self.pending_enemies.len() + self.fruits.len() + self.enemies.len() + self.pops.len()
-            == 0
+        // Flying robots are spawned on their own, slower, cycle, and at most two at a time
+        if self.timer % 243 == 0 && self.pending_flying_enemies > 0 && self.flying_enemies.len() < 2
         {
-            if self
-                .orbs
-                .iter()
-                .all(|orb| orb.borrow().trapped_enemy_type.is_none())
-            {
-                self.next_level();
-            }
+            self.pending_flying_enemies -= 1;
+            let (x, y) = (self.get_robot_spawn_x(), -30);
+            self.flying_enemies.insert(FlyingRobot::new(x, y));
         }
     }
 
+    /// The level is complete if there are no enemies remaining to be created, no existing enemies, no fruit, no
+    /// popping orbs, and no orbs containing trapped enemies. (We don't want to include orbs which don't contain
+    /// trapped enemies, as the level would never end if the player kept firing new orbs)
+    ///
+    /// Rust: In the original game, the next level is started directly inside update(); here, it's up to the caller,
+    /// so that a transition can be played in between.
+    ///
+    /// Rust: Bonus stages (not in the original game) are complete once the time is over.
+    pub fn is_level_complete(&self) -> bool {
+        if let Some(bonus_stage) = &self.bonus_stage {
+            return bonus_stage.is_over();
+        }
+
+        self.remaining_enemies() + self.fruits.len() + self.pops.len() == 0
+            && self.orbs.iter().all(|orb| orb.trapped_enemy_type.is_none())
+    }
+
     pub fn draw(&self) {
         let resources = storage::get::<Resources>();
 
@@ -171,7 +438,7 @@ impl Game {
 
         // Display blocks
         for row_y in 0..NUM_ROWS {
-            let row = self.grid[row_y as usize];
+            let row = &self.grid[row_y as usize];
             if row.len() > 0 {
                 // Initial offset - large blocks at edge of level are 50 pixels wide
                 let mut x = LEVEL_X_OFFSET;
@@ -190,44 +457,160 @@ impl Game {
         }
 
         // Draw all objects
-        self.fruits.iter().for_each(|f| f.draw());
-        self.bolts.iter().for_each(|b| b.draw());
-        self.enemies.iter().for_each(|e| e.draw());
-        self.pops.iter().for_each(|p| p.draw());
-        self.orbs.iter().for_each(|o| o.borrow().draw());
-        if let Some(p) = &self.player {
-            p.draw();
+        self.checkpoints.iter().for_each(|c| c.draw());
+        self.fruits.draw();
+        self.bolts.draw();
+        self.enemies.draw();
+        self.flying_enemies.draw();
+        if let Some(boss) = &self.boss {
+            boss.draw(self.timer);
+        }
+        self.pops.draw();
+        self.orbs.draw();
+        self.players
+            .iter()
+            .filter(|p| p.is_active())
+            .for_each(|p| p.draw());
+        self.particles.iter().for_each(|p| p.draw());
+        self.floating_texts.iter().for_each(|t| t.draw());
+    }
+
+    /// Rust: Starts the next level, or a bonus stage, if one follows the level just completed.
+    pub fn next_level(&mut self) {
+        if self.bonus_stage.is_none() && BonusStage::follows_level(self.level) {
+            self.start_bonus_stage();
+        } else {
+            self.start_level();
         }
     }
 
-    fn next_level(&mut self) {
+    fn start_level(&mut self) {
+        self.bonus_stage = None;
         self.level_colour = (self.level_colour + 1) % 4;
         self.level += 1;
 
         // Set up grid
-        self.grid = LEVELS[(self.level as usize) % LEVELS.len()].to_vec();
+        // Rust: In endless mode, the grid is generated.
+        self.grid = match self.mode {
+            GameMode::Classic => LEVELS[(self.level as usize) % LEVELS.len()]
+                .iter()
+                .map(|row| row.to_string())
+                .collect(),
+            GameMode::Endless => level_generator::generate_grid(self.level),
+        };
 
         // The last row is a copy of the first row
-        self.grid.push(self.grid[0]);
+        self.grid.push(self.grid[0].clone());
+
+        self.reset_stage();
+
+        self.checkpoints = match self.mode {
+            GameMode::Classic => Checkpoint::for_level(self.level),
+            GameMode::Endless => vec![],
+        };
+
+        // Rust: Boss levels are not in the original game; the boss is the only enemy.
+        if self.mode == GameMode::Classic && Boss::is_boss_level(self.level) {
+            self.boss = Some(Boss::new(self.level));
+            self.pending_enemies = vec![];
+            self.pending_waves = vec![];
+            self.pending_flying_enemies = 0;
+        } else {
+            self.create_pending_enemies();
+        }
 
+        play_game_sound(self.players.first(), |sounds| sounds.level_sound);
+    }
+
+    // Rust: Not in the original game. The level number and colours stay the same as the level just completed.
+    fn start_bonus_stage(&mut self) {
+        self.bonus_stage = Some(BonusStage::new());
+
+        self.grid = BONUS_STAGE_GRID.iter().map(|row| row.to_string()).collect();
+        self.grid.push(self.grid[0].clone());
+
+        self.reset_stage();
+
+        self.checkpoints = vec![];
+        self.pending_enemies = vec![];
+        self.pending_waves = vec![];
+        self.pending_flying_enemies = 0;
+
+        play_game_sound(self.players.first(), |sounds| sounds.level_sound);
+    }
+
+    /// Spawns the falling fruits; at the end of the stage, the collection bonus is awarded.
+    fn update_bonus_stage(&mut self) {
+        let bonus_stage = self.bonus_stage.as_mut().unwrap();
+
+        if bonus_stage.update() {
+            let bonus = bonus_stage.bonus();
+
+            for player in self.players.iter_mut().filter(|p| p.is_active()) {
+                player.score += bonus;
+            }
+            if bonus > 0 {
+                self.floating_texts.push(FloatingText::new(
+                    bonus.to_string(),
+                    WIDTH / 2,
+                    HEIGHT / 2,
+                ));
+            }
+        } else if !bonus_stage.is_over() && self.timer % FRUIT_SPAWN_INTERVAL == 0 {
+            let mut fruit = Fruit::new(self.get_robot_spawn_x(), -30, Some(RobotType::Normal));
+            fruit.time_to_live = FRUIT_TIME_TO_LIVE;
+            self.fruits.insert(fruit);
+        }
+    }
+
+    /// Resets the players and clears the entities, for a new level or bonus stage.
+    fn reset_stage(&mut self) {
         self.timer = -1;
 
-        if let Some(player) = &mut self.player {
+        for player in &mut self.players {
             player.reset();
+            player.checkpoint = None;
         }
 
-        self.fruits = vec![];
-        self.bolts = vec![];
-        self.enemies = vec![];
-        self.pops = vec![];
-        self.orbs = vec![];
+        self.fruits = EntityStore::new();
+        self.bolts = EntityStore::new();
+        self.enemies = EntityStore::new();
+        self.flying_enemies = EntityStore::new();
+        self.boss = None;
+        self.pops = EntityStore::new();
+        self.orbs = EntityStore::new();
+        self.particles = vec![];
+        self.combo = Combo::default();
+        self.floating_texts = vec![];
+    }
+
+    fn create_pending_enemies(&mut self) {
+        // Rust: The classic mode levels in the waves file use the waves; the first ones are released immediately.
+        let level_waves = match self.mode {
+            GameMode::Classic => storage::get::<SpawnWaves>().level_waves(self.level),
+            GameMode::Endless => None,
+        };
+
+        if let Some(level_waves) = level_waves {
+            self.pending_enemies = vec![];
+            self.pending_flying_enemies = 0;
+            self.pending_waves = level_waves;
+            self.release_waves(0);
+            return;
+        }
+
+        self.pending_waves = vec![];
 
         // At the start of each level we create a list of pending enemies - enemies to be created as the level plays out.
         // When this list is empty, we have no more enemies left to create, and the level will end once we have destroyed
         // all enemies currently on-screen. Each element of the list will be either 0 or 1, where 0 corresponds to
         // a standard enemy, and 1 is a more powerful enemy.
         // First we work out how many total enemies and how many of each type to create
-        let num_enemies = 10 + self.level as usize;
+        let enemies_per_level = match self.mode {
+            GameMode::Classic => 1,
+            GameMode::Endless => ENDLESS_ENEMIES_PER_LEVEL,
+        };
+        let num_enemies = 10 + self.level as usize * enemies_per_level;
         let num_strong_enemies = 1 + (self.level as f32 / 1.5) as usize;
         let num_weak_enemies = num_enemies - num_strong_enemies;
 
@@ -241,9 +624,24 @@ impl Game {
         // Finally we shuffle the list so that the order is randomised
         self.pending_enemies.shuffle();
 
-        play_game_sound(
-            self.player.as_ref(),
-            &storage::get::<Resources>().level_sound,
-        );
+        // Rust: Flying robots are not in the original game; they start appearing from the second level.
+        self.pending_flying_enemies = ((self.level + 1) / 2) as i32;
+    }
+
+    // Rust: Not in the original game. The robots of each wave due are shuffled, and added to the pending ones;
+    // they're created before the ones already pending.
+    fn release_waves(&mut self, level_time: i32) {
+        while let Some(wave) = self.pending_waves.first() {
+            if wave.release_time() > level_time {
+                break;
+            }
+
+            let wave = self.pending_waves.remove(0);
+            let mut robots = wave.robots();
+            robots.shuffle();
+
+            self.pending_enemies.append(&mut robots);
+            self.pending_flying_enemies += wave.flying;
+        }
     }
 }
@@ -0,0 +1,20 @@
+use nanoserde::{DeRon, SerRon};
+
+/// Not in the original game, which corresponds to the Classic mode.
+#[derive(Clone, Copy, Default, PartialEq, DeRon, SerRon)]
+pub enum GameMode {
+    /// The levels of the original game, repeating.
+    #[default]
+    Classic,
+    /// Procedurally generated levels, with an increasing number of enemies, and no boss.
+    Endless,
+}
+
+impl GameMode {
+    pub fn name(&self) -> &'static str {
+        match self {
+            GameMode::Classic => "CLASSIC",
+            GameMode::Endless => "ENDLESS",
+        }
+    }
+}
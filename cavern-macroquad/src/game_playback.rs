@@ -1,22 +1,55 @@
-use macroquad::{
-    audio::{self, Sound},
-    rand::ChooseRandom,
-};
+use std::sync::atomic::{AtomicBool, Ordering};
 
-use crate::player::Player;
+use macroquad::{audio::Sound, prelude::collections::storage, rand::rand};
+
+use crate::{player::Player, resources::Sounds, sound_manager::SoundManager};
+
+// Rust: Not in the original game. Set while verifying a replay, which runs much faster than real time, and without
+// audio.
+static MUTED: AtomicBool = AtomicBool::new(false);
+
+pub fn set_muted(muted: bool) {
+    MUTED.store(muted, Ordering::Relaxed);
+}
+
+fn is_playing(player: Option<&Player>) -> bool {
+    player.is_some() && !MUTED.load(Ordering::Relaxed)
+}
 
 // Utility function for game audio playback.
 //
 // The second one is separate due to a current architectural shortcoming - in the original code, Game
 // is global, so this is a simple implementation before the redesign.
 // The first one fits in Game, but it would be ugly to have them in different locations.
+//
+// Rust: The sounds are selected from the `Sounds`, which are only accessed when playing.
 
-pub fn play_game_sound(player: Option<&Player>, sound: &Sound) {
-    if player.is_some() {
-        audio::play_sound_once(*sound);
+pub fn play_game_sound(player: Option<&Player>, sound: fn(&Sounds) -> Sound) {
+    if is_playing(player) {
+        let sound = sound(&storage::get::<Sounds>());
+        storage::get::<SoundManager>().play_sfx(sound);
     }
 }
 
-pub fn play_game_random_sound(player: Option<&Player>, sounds: &Vec<Sound>) {
-    play_game_sound(player, sounds.choose().unwrap())
+// Rust: Not in the original game. Variants for the sounds emitted at a position, which are panned; the random
+// sounds are all emitted by the actors, so they're always positional.
+
+pub fn play_game_sound_at(player: Option<&Player>, sound: fn(&Sounds) -> Sound, x: i32) {
+    if is_playing(player) {
+        let sound = sound(&storage::get::<Sounds>());
+        storage::get::<SoundManager>().play_sfx_at(sound, x);
+    }
+}
+
+/// The sound is picked even when it's not played, so that the random sequence (and the game) doesn't depend on the
+/// audio.
+pub fn play_game_random_sound_at(player: Option<&Player>, sounds: fn(&Sounds) -> &[Sound], x: i32) {
+    let random = rand() as usize;
+
+    if is_playing(player) {
+        let all_sounds = storage::get::<Sounds>();
+        let sounds = sounds(&all_sounds);
+        let sound = sounds[random % sounds.len()];
+        storage::get::<SoundManager>().play_sfx_at(sound, x);
+    }
 }
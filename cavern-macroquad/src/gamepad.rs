@@ -0,0 +1,129 @@
+use gilrs::{
+    ff::{BaseEffect, BaseEffectType, Effect, EffectBuilder, Replay, Ticks},
+    Axis, Button, Event, EventType, GamepadId, Gilrs,
+};
+
+use crate::controls::Action;
+
+/// Beyond this deflection, the stick is considered pushed.
+const STICK_THRESHOLD: f32 = 0.5;
+const RUMBLE_DURATION_MS: u32 = 200;
+
+// Rust: Not in the original game. Macroquad doesn't support gamepads, so they're handled via Gilrs; the gamepads
+// are assigned to the players in order of connection. The state is updated once per frame, from the main loop.
+//
+pub struct Gamepads {
+    /// None if the gamepad support couldn't be initialized (e.g. no permissions on the devices).
+    gilrs: Option<Gilrs>,
+    /// Buttons pressed since the previous frame.
+    pressed: Vec<(GamepadId, Button)>,
+    /// Effects stop when dropped, so the one playing is kept; there's at most one at a time.
+    rumble: Option<Effect>,
+}
+
+impl Gamepads {
+    pub fn new() -> Self {
+        let gilrs = Gilrs::new()
+            .map_err(|error| eprintln!("Couldn't initialize the gamepads: {}", error))
+            .ok();
+
+        Self {
+            gilrs,
+            pressed: vec![],
+            rumble: None,
+        }
+    }
+
+    /// Processes the gamepad events; must be invoked once per frame.
+    pub fn update(&mut self) {
+        self.pressed.clear();
+
+        if let Some(gilrs) = &mut self.gilrs {
+            while let Some(Event { id, event, .. }) = gilrs.next_event() {
+                if let EventType::ButtonPressed(button, _) = event {
+                    self.pressed.push((id, button));
+                }
+            }
+        }
+    }
+
+    fn gamepad_id(&self, gamepad_i: usize) -> Option<GamepadId> {
+        let gilrs = self.gilrs.as_ref()?;
+        gilrs.gamepads().nth(gamepad_i).map(|(id, _)| id)
+    }
+
+    pub fn is_action_down(&self, gamepad_i: usize, action: Action) -> bool {
+        let (Some(gilrs), Some(id)) = (&self.gilrs, self.gamepad_id(gamepad_i)) else {
+            return false;
+        };
+        let gamepad = gilrs.gamepad(id);
+
+        match action {
+            Action::Left => {
+                gamepad.is_pressed(Button::DPadLeft)
+                    || gamepad.value(Axis::LeftStickX) < -STICK_THRESHOLD
+            }
+            Action::Right => {
+                gamepad.is_pressed(Button::DPadRight)
+                    || gamepad.value(Axis::LeftStickX) > STICK_THRESHOLD
+            }
+            _ => action_buttons(action)
+                .iter()
+                .any(|button| gamepad.is_pressed(*button)),
+        }
+    }
+
+    /// Only the buttons are considered, since the sticks are used for the (held) movement.
+    pub fn is_action_pressed(&self, gamepad_i: usize, action: Action) -> bool {
+        let Some(id) = self.gamepad_id(gamepad_i) else {
+            return false;
+        };
+
+        self.pressed.iter().any(|(pressed_id, button)| {
+            *pressed_id == id && action_buttons(action).contains(button)
+        })
+    }
+
+    /// Plays a short pulse, with strength between 0 and 1; ignored if the gamepad doesn't support force feedback.
+    pub fn rumble(&mut self, gamepad_i: usize, strength: f32) {
+        let Some(id) = self.gamepad_id(gamepad_i) else {
+            return;
+        };
+        let Some(gilrs) = &mut self.gilrs else {
+            return;
+        };
+
+        if !gilrs.gamepad(id).is_ff_supported() {
+            return;
+        }
+
+        let effect = EffectBuilder::new()
+            .add_effect(BaseEffect {
+                kind: BaseEffectType::Strong {
+                    magnitude: (strength.clamp(0., 1.) * u16::MAX as f32) as u16,
+                },
+                scheduling: Replay {
+                    play_for: Ticks::from_ms(RUMBLE_DURATION_MS),
+                    ..Default::default()
+                },
+                envelope: Default::default(),
+            })
+            .gamepads(&[id])
+            .finish(gilrs)
+            .and_then(|effect| effect.play().map(|_| effect));
+
+        match effect {
+            Ok(effect) => self.rumble = Some(effect),
+            Err(error) => eprintln!("Couldn't play the rumble effect: {}", error),
+        }
+    }
+}
+
+fn action_buttons(action: Action) -> &'static [Button] {
+    match action {
+        Action::Left => &[Button::DPadLeft],
+        Action::Right => &[Button::DPadRight],
+        Action::Jump => &[Button::South, Button::DPadUp],
+        Action::Blow => &[Button::West, Button::East],
+    }
+}
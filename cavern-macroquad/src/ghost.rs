@@ -0,0 +1,88 @@
+use std::fs;
+
+use macroquad::prelude::{collections::storage, draw_texture, Color};
+use nanoserde::{DeRon, SerRon};
+
+use crate::{
+    actor::{Actor, Body},
+    player::Player,
+    resources::Resources,
+};
+
+const GHOSTS_DIR: &str = "ghosts";
+
+const GHOST_COLOUR: Color = Color::new(1., 1., 1., 0.4);
+
+/// Position (top left) and sprite of the player in one update; the sprite is the index in
+/// `Resources::player_textures()`.
+#[derive(Clone, Copy, DeRon, SerRon)]
+pub struct GhostFrame {
+    x: i32,
+    y: i32,
+    sprite: u8,
+}
+
+// Rust: Not in the original game. The best (fastest) run of each classic mode level is recorded, one frame per
+// update, and played back as a translucent player on the following attempts at the level.
+//
+#[derive(Default, DeRon, SerRon)]
+pub struct GhostRun {
+    frames: Vec<GhostFrame>,
+}
+
+impl GhostRun {
+    fn path(level: i8) -> String {
+        format!("{}/level_{}.ron", GHOSTS_DIR, level + 1)
+    }
+
+    /// None if there's no ghost for the level, or if the file is invalid.
+    pub fn load(level: i8) -> Option<Self> {
+        fs::read_to_string(Self::path(level))
+            .ok()
+            .and_then(|content| Self::deserialize_ron(&content).ok())
+    }
+
+    pub fn save(&self, level: i8) {
+        let result = fs::create_dir_all(GHOSTS_DIR)
+            .and_then(|_| fs::write(Self::path(level), self.serialize_ron()));
+
+        if let Err(error) = result {
+            eprintln!("Couldn't save the ghost: {}", error);
+        }
+    }
+
+    /// In updates.
+    pub fn time(&self) -> usize {
+        self.frames.len()
+    }
+
+    pub fn record(&mut self, player: &Player) {
+        let image = player.image();
+        let sprite = storage::get::<Resources>()
+            .player_textures()
+            .iter()
+            .position(|texture| *texture == image)
+            .unwrap_or(0);
+
+        self.frames.push(GhostFrame {
+            x: player.left(),
+            y: player.top(),
+            sprite: sprite as u8,
+        });
+    }
+
+    /// Draws the frame of the given update of the level; once the run is over, nothing is drawn.
+    pub fn draw(&self, level_timer: i32) {
+        if level_timer < 0 {
+            return;
+        }
+
+        if let Some(frame) = self.frames.get(level_timer as usize) {
+            let textures = storage::get::<Resources>().player_textures();
+
+            if let Some(texture) = textures.get(frame.sprite as usize) {
+                draw_texture(*texture, frame.x as f32, frame.y as f32, GHOST_COLOUR);
+            }
+        }
+    }
+}
@@ -0,0 +1,107 @@
+use std::{
+    collections::VecDeque,
+    error,
+    fs::{self, File},
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use gif::{Encoder, Frame, Repeat};
+use macroquad::prelude::{get_time, is_key_pressed, KeyCode};
+
+use crate::{virtual_screen::VirtualScreen, HEIGHT, WIDTH};
+
+// Rust: Not in the original game. While enabled (F9), the canvas is sampled at a reduced resolution and frame rate,
+// keeping the last seconds; F10 saves them as animated GIF.
+
+const RECORDINGS_DIR: &str = "recordings";
+
+const FRAMES_PER_SECOND: u16 = 15;
+const RECORDED_SECONDS: usize = 10;
+const MAX_FRAMES: usize = FRAMES_PER_SECOND as usize * RECORDED_SECONDS;
+
+// The canvas is downscaled by this factor, in order to limit the memory used.
+const SCALE_DOWN: usize = 2;
+const GIF_WIDTH: usize = WIDTH as usize / SCALE_DOWN;
+const GIF_HEIGHT: usize = HEIGHT as usize / SCALE_DOWN;
+
+// From 1 (best quality) to 30 (fastest).
+const QUANTIZATION_SPEED: i32 = 10;
+
+pub struct GifRecorder {
+    enabled: bool,
+    /// RGBA, top to bottom.
+    frames: VecDeque<Vec<u8>>,
+    last_capture_time: f64,
+}
+
+impl GifRecorder {
+    pub fn new() -> Self {
+        Self {
+            enabled: false,
+            frames: VecDeque::new(),
+            last_capture_time: 0.,
+        }
+    }
+
+    /// Like the screenshots, this must be invoked before drawing, since the canvas holds the previous frame.
+    pub fn update(&mut self, screen: &VirtualScreen) {
+        if is_key_pressed(KeyCode::F9) {
+            self.enabled = !self.enabled;
+            self.frames.clear();
+        }
+
+        if is_key_pressed(KeyCode::F10) && !self.frames.is_empty() {
+            if let Err(error) = self.save() {
+                eprintln!("Couldn't save the recording: {}", error);
+            }
+        }
+
+        if self.enabled && get_time() - self.last_capture_time >= 1. / FRAMES_PER_SECOND as f64 {
+            self.last_capture_time = get_time();
+            self.capture(screen);
+        }
+    }
+
+    fn capture(&mut self, screen: &VirtualScreen) {
+        let image = screen.capture();
+        let mut frame = Vec::with_capacity(GIF_WIDTH * GIF_HEIGHT * 4);
+
+        // Render targets are upside down; nearest neighbour sampling is good enough for pixel art.
+        for y in (0..GIF_HEIGHT).rev() {
+            for x in 0..GIF_WIDTH {
+                let offset = (y * SCALE_DOWN * WIDTH as usize + x * SCALE_DOWN) * 4;
+                frame.extend_from_slice(&image.bytes[offset..offset + 4]);
+            }
+        }
+
+        if self.frames.len() == MAX_FRAMES {
+            self.frames.pop_front();
+        }
+        self.frames.push_back(frame);
+    }
+
+    fn save(&self) -> Result<(), Box<dyn error::Error>> {
+        fs::create_dir_all(RECORDINGS_DIR)?;
+
+        let timestamp = SystemTime::now().duration_since(UNIX_EPOCH)?.as_millis();
+        let file = File::create(format!("{}/cavern_{}.gif", RECORDINGS_DIR, timestamp))?;
+
+        let mut encoder = Encoder::new(file, GIF_WIDTH as u16, GIF_HEIGHT as u16, &[])?;
+        encoder.set_repeat(Repeat::Infinite)?;
+
+        for frame in &self.frames {
+            let mut pixels = frame.clone();
+            let mut frame = Frame::from_rgba_speed(
+                GIF_WIDTH as u16,
+                GIF_HEIGHT as u16,
+                &mut pixels,
+                QUANTIZATION_SPEED,
+            );
+            // In hundredths of second.
+            frame.delay = 100 / FRAMES_PER_SECOND;
+            encoder.write_frame(&frame)?;
+        }
+
+        Ok(())
+    }
+}
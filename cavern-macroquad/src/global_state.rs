@@ -1,105 +1,793 @@
-use macroquad::prelude::{collections::storage, draw_texture, is_key_pressed, KeyCode, WHITE};
+use std::{cell::RefCell, env, rc::Rc};
 
+use macroquad::{
+    miniquad::date,
+    prelude::{
+        collections::storage, draw_rectangle, draw_text, draw_texture, draw_texture_ex,
+        measure_text, vec2, Color, DrawTextureParams, KeyCode, Vec2, WHITE,
+    },
+    rand::srand,
+};
+
+#[cfg(feature = "online_leaderboard")]
+use crate::online_leaderboard::OnlineLeaderboard;
 use crate::{
+    control_profile::ProfileInput,
+    debug_draw,
+    difficulty::Difficulty,
     drawing::{draw_game_text, CHAR_WIDTH, IMAGE_WIDTH},
+    frame_input::{self, get_last_key_pressed, is_key_pressed},
     game::Game,
+    game_mode::GameMode,
     game_playback::play_game_sound,
+    ghost::GhostRun,
+    high_scores::{HighScore, HighScores},
+    input_log::{InputLog, RecordingInput},
+    input_state::MacroquadInput,
+    level_select::{LevelSelect, LevelSelectItem},
+    level_stats::LevelStats,
+    name_entry::NameEntry,
+    options_menu::OptionsMenu,
+    pause_menu::{PauseMenu, PauseMenuItem},
+    playback::Playback,
     player::Player,
+    power_up::{PowerUp, POWER_UPS, POWER_UP_DURATION, POWER_UP_SPRITE_VAL},
     resources::Resources,
+    save_game::SavedGame,
+    settings::Settings,
+    speedrun::{PersonalBests, SpeedrunTimer},
     state::State,
-    WIDTH,
+    ticks::Ticks,
+    touch_controls, WIDTH,
 };
 
+// Rust: The co-op players are told apart by tinting player 2's sprites.
+const PLAYER_2_COLOUR: Color = Color::new(0.6, 1.0, 0.6, 1.0);
+
+// Power up indicators are drawn after the lives and health, as a small icon with a bar showing the time left.
+const POWER_UP_ICON_WIDTH: i32 = 22;
+const POWER_UP_ICON_HEIGHT: i32 = 22;
+
+// Spawn positions of the players, when playing in co-op mode.
+pub const COOP_START_XS: [i32; 2] = [WIDTH / 2 - 100, WIDTH / 2 + 100];
+
+// Rust: Not in the original game; the completed level stays on screen for this many frames.
+pub const LEVEL_TRANSITION_DURATION: i32 = 90;
+
+// On the "Game Over" screen; including the names, more entries don't fit the screen width.
+const NUM_HIGH_SCORES_DISPLAYED: usize = 2;
+// The online top scores use the default font, which is smaller, so they're displayed on two lines.
+const ONLINE_SCORES_PER_LINE: usize = 5;
+
+// Rust: The attract mode (not in the original game) starts after idling on the title screen for this many frames.
+const ATTRACT_MODE_DELAY: i32 = 600;
+
+// Single player games started from the title screen are recorded, so that they can be watched again; passing this
+// argument, they're also saved as demo.
+const RECORD_DEMO_ARG: &str = "--record-demo";
+const DEMO_RECORDING_FILE: &str = "demo.ron";
+const LAST_RUN_FILE: &str = "last_run.ron";
+
 pub struct GlobalState {
     state: State,
     game: Game,
+    pause_menu: PauseMenu,
+    options_menu: OptionsMenu,
+    name_entry: NameEntry,
+    level_select: LevelSelect,
+    settings: Settings,
+    /// Set once any touch is detected; from then on, the on-screen touch controls are displayed.
+    touch_used: bool,
+    high_scores: HighScores,
+    /// Indexes of the players whose score entered the table, and whose name is still to be entered.
+    pending_high_scores: Vec<usize>,
+    /// None if there's no leaderboard URL in the settings.
+    #[cfg(feature = "online_leaderboard")]
+    online_leaderboard: Option<OnlineLeaderboard>,
+    /// Scores entered in the current game over, submitted once all the names are entered.
+    #[cfg(feature = "online_leaderboard")]
+    online_scores: Vec<HighScore>,
+    speedrun_timer: SpeedrunTimer,
+    personal_bests: PersonalBests,
+    level_stats: LevelStats,
+    /// Total score of the players when the current level started.
+    level_start_score: i32,
+    /// Best run of the current level, if any, displayed while playing.
+    ghost: Option<GhostRun>,
+    /// Run of the current level, saved as ghost if it beats the best one.
+    ghost_recording: GhostRun,
+    idle_timer: i32,
+    /// Frames left before the next level starts.
+    transition_timer: i32,
+    record_demo: bool,
+    run_recording: Option<Rc<RefCell<InputLog>>>,
+    /// Set while the demo, or the replay of the last run, is played.
+    playback: Option<Playback>,
+    debug_draw: bool,
 }
 
 impl GlobalState {
-    pub fn new() -> Self {
+    pub fn new(settings: Settings) -> Self {
+        #[cfg(feature = "online_leaderboard")]
+        let online_leaderboard = (!settings.leaderboard_url.is_empty())
+            .then(|| OnlineLeaderboard::new(&settings.leaderboard_url));
+
         Self {
             // Set the initial game state
-            state: State::Menu,
-            game: Game::new(None),
+            state: State::Title,
+            game: Game::new(vec![], settings.difficulty, GameMode::Classic),
+            pause_menu: PauseMenu::new(),
+            options_menu: OptionsMenu::new(),
+            name_entry: NameEntry::new(),
+            level_select: LevelSelect::new(),
+            settings,
+            touch_used: false,
+            high_scores: HighScores::load(),
+            pending_high_scores: vec![],
+            #[cfg(feature = "online_leaderboard")]
+            online_leaderboard,
+            #[cfg(feature = "online_leaderboard")]
+            online_scores: vec![],
+            speedrun_timer: SpeedrunTimer::new(false),
+            personal_bests: PersonalBests::load(),
+            level_stats: LevelStats::load(),
+            level_start_score: 0,
+            ghost: None,
+            ghost_recording: GhostRun::default(),
+            idle_timer: 0,
+            transition_timer: 0,
+            record_demo: env::args().any(|arg| arg == RECORD_DEMO_ARG),
+            run_recording: None,
+            playback: None,
+            debug_draw: false,
+        }
+    }
+
+    pub fn toggle_debug_draw(&mut self) {
+        self.debug_draw = !self.debug_draw;
+    }
+
+    /// The game is seeded, so that it can be reproduced from the player input; must be invoked before creating the
+    /// game.
+    fn start_run_recording(&mut self, players: &mut [Player], mode: GameMode) {
+        let seed = date::now() as u64;
+        srand(seed);
+
+        let log = InputLog::new(seed, mode, &self.settings);
+        let log = Rc::new(RefCell::new(log));
+        let source = self.player_input(0);
+        players[0].input_source = Box::new(RecordingInput::new(Box::new(source), log.clone()));
+
+        self.run_recording = Some(log);
+    }
+
+    /// Starts a single player game, played back from the log.
+    fn start_playback(&mut self, log: &InputLog) {
+        let (playback, game) = Playback::start(log);
+
+        self.game = game;
+        self.playback = Some(playback);
+    }
+
+    /// Returns false if there is no demo available.
+    fn start_demo(&mut self) -> bool {
+        let demo_log = storage::get::<Resources>().demo_log.clone();
+
+        match demo_log {
+            Some(demo_log) => {
+                self.start_playback(&demo_log);
+                true
+            }
+            None => false,
         }
     }
 
+    /// Input from the devices, adapted to the player's control profile.
+    fn player_input(&self, player_i: usize) -> ProfileInput {
+        let controls = self.settings.player_controls(player_i);
+        let source = MacroquadInput::new(controls, player_i);
+
+        ProfileInput::new(Box::new(source), self.settings.control_profiles[player_i])
+    }
+
+    fn new_players(&self, num_players: usize, difficulty: Difficulty) -> Vec<Player> {
+        let colours = [WHITE, PLAYER_2_COLOUR];
+
+        let mut players = if num_players == 1 {
+            vec![Player::new(
+                Box::new(self.player_input(0)),
+                WIDTH / 2,
+                colours[0],
+            )]
+        } else {
+            (0..num_players)
+                .map(|i| {
+                    let input_source = self.player_input(i);
+                    Player::new(Box::new(input_source), COOP_START_XS[i], colours[i])
+                })
+                .collect()
+        };
+
+        for player in &mut players {
+            player.lives = difficulty.starting_lives();
+        }
+
+        players
+    }
+
+    /// Returns to the title screen, with a game without players in the background.
+    fn enter_title(&mut self) {
+        self.state = State::Title;
+        self.idle_timer = 0;
+        self.run_recording = None;
+        self.playback = None;
+        self.game = Game::new(vec![], self.settings.difficulty, GameMode::Classic);
+    }
+
+    fn enter_playing(&mut self, game: Game, speedrun_eligible: bool) {
+        self.state = State::Playing;
+        self.game = game;
+        self.game.orb_wall_bounces = self.settings.orb_wall_bounces;
+        self.game.charge_shot = self.settings.charge_shot;
+        self.game.extra_life_score = self.settings.extra_life_score;
+        self.speedrun_timer = SpeedrunTimer::new(speedrun_eligible);
+        self.level_start_score = self.total_score();
+        self.start_ghost();
+    }
+
+    fn total_score(&self) -> i32 {
+        self.game.players.iter().map(|p| p.score).sum()
+    }
+
+    /// Only the classic mode levels are tracked, since the endless mode ones are generated.
+    fn record_level_stats(&mut self) {
+        if self.game.mode == GameMode::Classic {
+            let score = self.total_score() - self.level_start_score;
+            let level = self.game.level as usize;
+
+            if self.level_stats.add(level, score, self.game.timer) {
+                self.level_stats.save();
+            }
+        }
+    }
+
+    /// Ghosts are only used in single player classic mode games, since the endless mode levels are generated.
+    fn ghost_eligible(&self) -> bool {
+        self.game.mode == GameMode::Classic
+            && self.game.players.len() == 1
+            && self.game.bonus_stage.is_none()
+    }
+
+    fn start_ghost(&mut self) {
+        self.ghost = if self.ghost_eligible() {
+            GhostRun::load(self.game.level)
+        } else {
+            None
+        };
+        self.ghost_recording = GhostRun::default();
+    }
+
+    /// The run is saved only if it's been recorded from the start of the level (i.e. not resumed from a saved game).
+    fn record_ghost(&mut self) {
+        let time = self.ghost_recording.time();
+        let complete = time as i32 == self.game.timer + 1;
+        let is_best = self
+            .ghost
+            .as_ref()
+            .map_or(true, |ghost| time < ghost.time());
+
+        if self.ghost_eligible() && complete && is_best {
+            self.ghost_recording.save(self.game.level);
+        }
+    }
+
+    fn enter_game_over(&mut self) {
+        let mode = self.game.mode;
+
+        self.pending_high_scores = (0..self.game.players.len())
+            .filter(|i| {
+                self.high_scores
+                    .qualifies(mode, self.game.players[*i].score)
+            })
+            .collect();
+
+        if self.pending_high_scores.is_empty() {
+            self.submit_online_scores();
+        }
+
+        if let Some(run_recording) = self.run_recording.take() {
+            let mut log = run_recording.borrow_mut();
+            log.final_score = Some(self.game.players[0].score);
+
+            log.save(LAST_RUN_FILE);
+            if self.record_demo {
+                log.save(DEMO_RECORDING_FILE);
+            }
+        }
+
+        if self.speedrun_timer.is_eligible()
+            && self.personal_bests.add(mode, self.speedrun_timer.splits())
+        {
+            self.personal_bests.save();
+        }
+
+        play_game_sound(self.game.players.first(), |sounds| sounds.over_sound);
+        self.state = State::GameOver;
+    }
+
+    // Rust: Only the scores entering the local table are submitted, since a name is entered only for those; if
+    // there are none, the online table is just refreshed.
+    #[cfg(feature = "online_leaderboard")]
+    fn submit_online_scores(&mut self) {
+        if let Some(online_leaderboard) = &mut self.online_leaderboard {
+            let scores = std::mem::take(&mut self.online_scores);
+            online_leaderboard.submit_and_fetch(self.game.mode, scores);
+        }
+    }
+
+    #[cfg(not(feature = "online_leaderboard"))]
+    fn submit_online_scores(&mut self) {}
+
     pub fn update(&mut self) {
+        self.touch_used |= touch_controls::is_touch_used();
+
+        #[cfg(feature = "online_leaderboard")]
+        if let Some(online_leaderboard) = &mut self.online_leaderboard {
+            online_leaderboard.update();
+        }
+
         match self.state {
-            State::Menu => {
-                if is_key_pressed(KeyCode::Space) {
-                    // Switch to play state, and create a new Game object, passing it a new Player object to use
-                    self.state = State::Play;
-                    self.game = Game::new(Some(Player::new()));
-                } else {
-                    self.game.update();
-                }
+            State::Title => self.update_title(),
+            State::Demo | State::Replay => self.update_demo(),
+            State::Options => self.update_options(),
+            State::LevelSelect => self.update_level_select(),
+            State::Playing => self.update_playing(),
+            State::Pause => self.update_pause(),
+            State::LevelTransition => self.update_level_transition(),
+            State::GameOver => self.update_game_over(),
+            State::HighScoreEntry => self.update_high_score_entry(),
+        }
+    }
+
+    fn update_title(&mut self) {
+        if get_last_key_pressed().is_some() || frame_input::is_screen_tapped() {
+            self.idle_timer = 0;
+        } else {
+            self.idle_timer += 1;
+        }
+
+        let difficulty = self.settings.difficulty;
+
+        if is_key_pressed(KeyCode::Space) || frame_input::is_screen_tapped() {
+            // Switch to play state, and create a new Game object, passing it a new Player object to use
+            let mut players = self.new_players(1, difficulty);
+            self.start_run_recording(&mut players, GameMode::Classic);
+            self.enter_playing(Game::new(players, difficulty, GameMode::Classic), true);
+        } else if is_key_pressed(KeyCode::Key2) {
+            // Same as above, for the co-op mode
+            let players = self.new_players(2, difficulty);
+            self.enter_playing(Game::new(players, difficulty, GameMode::Classic), true);
+        } else if is_key_pressed(KeyCode::E) {
+            // Same as above, for the endless mode
+            let mut players = self.new_players(1, difficulty);
+            self.start_run_recording(&mut players, GameMode::Endless);
+            self.enter_playing(Game::new(players, difficulty, GameMode::Endless), true);
+        } else if is_key_pressed(KeyCode::R) {
+            if let Some(saved_game) = SavedGame::load() {
+                let players = self.new_players(saved_game.num_players(), difficulty);
+                self.enter_playing(saved_game.restore(players), false);
             }
-            State::Play => {
-                if self.game.player.as_ref().unwrap().lives < 0 {
-                    play_game_sound(
-                        self.game.player.as_ref(),
-                        &storage::get::<Resources>().over_sound,
-                    );
-                    self.state = State::GameOver;
-                } else {
-                    self.game.update();
-                }
+        } else if is_key_pressed(KeyCode::O) {
+            self.state = State::Options;
+            self.options_menu = OptionsMenu::new();
+        } else if is_key_pressed(KeyCode::L) && self.level_stats.is_game_finished() {
+            self.state = State::LevelSelect;
+            self.level_select = LevelSelect::new();
+        } else if is_key_pressed(KeyCode::Left) || is_key_pressed(KeyCode::Right) {
+            let difficulty = &mut self.settings.difficulty;
+            *difficulty = if is_key_pressed(KeyCode::Left) {
+                difficulty.previous()
+            } else {
+                difficulty.next()
+            };
+            self.settings.save();
+        } else if is_key_pressed(KeyCode::W) {
+            if let Some(last_run) = InputLog::load(LAST_RUN_FILE) {
+                self.start_playback(&last_run);
+                self.state = State::Replay;
+            }
+        } else if self.idle_timer >= ATTRACT_MODE_DELAY && self.start_demo() {
+            self.state = State::Demo;
+        } else {
+            self.update_game_without_transition();
+        }
+    }
+
+    // Also used for the replay of the last run.
+    fn update_demo(&mut self) {
+        let playback = self.playback.as_mut().unwrap();
+
+        // Any key returns to the title screen, as well as the end of the demo
+        if get_last_key_pressed().is_some() || frame_input::is_screen_tapped() {
+            self.enter_title();
+        } else if playback.is_over(&self.game) {
+            if let Err(error) = playback.check_final_score(&self.game) {
+                eprintln!("The playback diverged from the recording: {}", error);
+            }
+            self.enter_title();
+        } else {
+            playback.update(&mut self.game);
+        }
+    }
+
+    /// On the title screen, the next level starts immediately, like in the original game.
+    fn update_game_without_transition(&mut self) {
+        self.game.update();
+
+        if self.game.is_level_complete() {
+            self.game.next_level();
+        }
+    }
+
+    fn update_options(&mut self) {
+        if self.options_menu.update(&mut self.settings) {
+            self.state = State::Title;
+        }
+    }
+
+    // Games started from a level are not eligible as speedrun personal bests.
+    fn update_level_select(&mut self) {
+        match self.level_select.update() {
+            Some(LevelSelectItem::Level(level)) => {
+                let difficulty = self.settings.difficulty;
+                let players = self.new_players(1, difficulty);
+                let game = Game::new_at_level(players, difficulty, GameMode::Classic, level as i8);
+                self.enter_playing(game, level == 0);
+            }
+            Some(LevelSelectItem::Back) => {
+                self.state = State::Title;
+            }
+            None => {}
+        }
+    }
+
+    fn update_playing(&mut self) {
+        if self.game.is_over() {
+            self.enter_game_over();
+        } else if is_key_pressed(KeyCode::Escape) {
+            // Freeze the game; the pause menu always starts with "Resume" selected
+            self.state = State::Pause;
+            self.pause_menu = PauseMenu::new();
+        } else {
+            self.game.update();
+            self.speedrun_timer.update();
+
+            if let [player] = self.game.players.as_slice() {
+                self.ghost_recording.record(player);
             }
-            State::GameOver => {
-                if is_key_pressed(KeyCode::Space) {
-                    self.state = State::Menu;
-                    self.game = Game::new(None);
+
+            // The bonus stages don't count as levels, so they're not tracked.
+            if self.game.is_level_complete() {
+                if self.game.bonus_stage.is_none() {
+                    self.speedrun_timer.split();
+                    self.record_level_stats();
+                    self.record_ghost();
                 }
+                self.state = State::LevelTransition;
+                self.transition_timer = LEVEL_TRANSITION_DURATION;
             }
         }
     }
 
-    pub fn draw(&self) {
-        let resources = storage::get::<Resources>();
+    fn update_pause(&mut self) {
+        match self.pause_menu.update() {
+            Some(PauseMenuItem::Resume) => {
+                self.state = State::Playing;
+            }
+            Some(PauseMenuItem::Restart) => {
+                self.run_recording = None;
+                let (num_players, difficulty, mode) = (
+                    self.game.players.len(),
+                    self.game.difficulty,
+                    self.game.mode,
+                );
+                let players = self.new_players(num_players, difficulty);
+                self.enter_playing(Game::new(players, difficulty, mode), true);
+            }
+            Some(PauseMenuItem::SaveAndQuit) => {
+                SavedGame::new(&self.game).save();
+                self.enter_title();
+            }
+            Some(PauseMenuItem::Quit) => {
+                self.enter_title();
+            }
+            None => {}
+        }
+    }
+
+    // The players can still move around, but the speedrun timer is stopped.
+    fn update_level_transition(&mut self) {
+        self.transition_timer -= 1;
+
+        if self.transition_timer <= 0 {
+            self.game.next_level();
+            self.level_start_score = self.total_score();
+            self.start_ghost();
+            self.state = State::Playing;
+        } else {
+            self.game.update();
+        }
+    }
+
+    fn update_game_over(&mut self) {
+        if is_key_pressed(KeyCode::Space) || frame_input::is_screen_tapped() {
+            if self.pending_high_scores.is_empty() {
+                self.enter_title();
+            } else {
+                self.state = State::HighScoreEntry;
+                self.name_entry = NameEntry::new();
+            }
+        }
+    }
+
+    // In co-op mode, the players enter their names in turn; the table is then displayed again.
+    fn update_high_score_entry(&mut self) {
+        if let Some(name) = self.name_entry.update() {
+            let player_i = self.pending_high_scores.remove(0);
+            let score = self.game.players[player_i].score;
+            self.high_scores.add(self.game.mode, &name, score);
+            #[cfg(feature = "online_leaderboard")]
+            self.online_scores.push(HighScore { name, score });
 
+            if self.pending_high_scores.is_empty() {
+                self.high_scores.save();
+                self.submit_online_scores();
+                self.state = State::GameOver;
+            } else {
+                self.name_entry = NameEntry::new();
+            }
+        }
+    }
+
+    /// Rust: Not in the original game. The game is frozen in the other states, so the shake would be, too.
+    pub fn shake_offset(&self) -> Vec2 {
+        if self.settings.screen_shake
+            && matches!(self.state, State::Playing | State::Demo | State::Replay)
+        {
+            self.game.screen_shake.offset()
+        } else {
+            Vec2::ZERO
+        }
+    }
+
+    pub fn draw(&self) {
         self.game.draw();
 
+        if self.debug_draw {
+            debug_draw::draw(&self.game);
+        }
+
         match self.state {
-            State::Menu => {
-                // Draw title screen
-                draw_texture(resources.title_texture, 0., 0., WHITE);
-
-                // Draw "Press SPACE" animation, which has 10 frames numbered 0 to 9
-                // The first part gives us a number between 0 and 159, based on the game timer
-                // Dividing by 4 means we go to a new animation frame every 4 frames
-                // We enclose this calculation in the min function, with the other argument being 9, which results in the
-                // animation staying on frame 9 for three quarters of the time. Adding 40 to the game timer is done to alter
-                // which stage the animation is at when the game first starts
-                let anim_frame = (((self.game.timer + 40) % 160) / 4).min(9) as usize;
-                draw_texture(resources.space_textures[anim_frame], 130., 280., WHITE);
-            }
-            State::Play => {
+            State::Title => self.draw_title(),
+            State::Demo | State::Replay => self.draw_demo(),
+            State::Options => self.options_menu.draw(&self.settings),
+            State::LevelSelect => self.level_select.draw(&self.level_stats),
+            State::Playing => self.draw_playing(),
+            State::Pause => {
                 self.draw_status();
+                self.pause_menu.draw();
             }
-            State::GameOver => {
-                self.draw_status();
-                // Display "Game Over" image
-                draw_texture(resources.over_texture, 0., 0., WHITE);
+            State::LevelTransition => self.draw_level_transition(),
+            State::GameOver => self.draw_game_over(),
+            State::HighScoreEntry => {
+                let player_i = self.pending_high_scores[0];
+                let score = self.game.players[player_i].score;
+                self.name_entry.draw(player_i + 1, score);
             }
         }
     }
 
+    fn draw_title(&self) {
+        let resources = storage::get::<Resources>();
+
+        // Draw title screen
+        draw_texture(resources.title_texture, 0., 0., WHITE);
+
+        // Draw "Press SPACE" animation, which has 10 frames numbered 0 to 9
+        // The first part gives us a number between 0 and 159, based on the game timer
+        // Dividing by 4 means we go to a new animation frame every 4 frames
+        // We enclose this calculation in the min function, with the other argument being 9, which results in the
+        // animation staying on frame 9 for three quarters of the time. Adding 40 to the game timer is done to alter
+        // which stage the animation is at when the game first starts
+        let anim_frame = (((self.game.timer + 40) % 160) / 4).min(9) as usize;
+        draw_texture(resources.space_textures[anim_frame], 130., 280., WHITE);
+
+        if SavedGame::exists() {
+            draw_game_text("PRESS R TO RESUME", 326, None);
+        }
+        draw_game_text("PRESS 2 FOR TWO PLAYERS", 356, None);
+        draw_game_text("PRESS E FOR ENDLESS MODE", 386, None);
+        // Rust: There's no room for more lines, so the level select and the replay are on the same line as the
+        // options.
+        let mut entries = vec!["O OPTIONS"];
+        if self.level_stats.is_game_finished() {
+            entries.push("L LEVELS");
+        }
+        if InputLog::exists(LAST_RUN_FILE) {
+            entries.push("W REPLAY");
+        }
+        if entries.len() > 1 {
+            draw_game_text(&entries.join("  "), 416, None);
+        } else {
+            draw_game_text("PRESS O FOR OPTIONS", 416, None);
+        }
+        draw_game_text(
+            &format!("DIFFICULTY {}", self.settings.difficulty.name()),
+            448,
+            None,
+        );
+    }
+
+    fn draw_demo(&self) {
+        self.draw_status();
+
+        let label = if self.state == State::Replay {
+            "REPLAY"
+        } else {
+            "DEMO"
+        };
+
+        // Blink, like the "Press SPACE" animation
+        if (self.game.timer / 30) % 2 == 0 {
+            draw_game_text(label, 60, None);
+        }
+    }
+
+    fn draw_playing(&self) {
+        if self.settings.ghost {
+            if let Some(ghost) = &self.ghost {
+                ghost.draw(self.game.timer);
+            }
+        }
+
+        self.draw_status();
+
+        if self.touch_used {
+            touch_controls::draw();
+        }
+    }
+
+    fn draw_level_transition(&self) {
+        self.draw_playing();
+
+        let text = if self.game.bonus_stage.is_some() {
+            "BONUS STAGE CLEAR".to_string()
+        } else {
+            format!("LEVEL {} CLEAR", self.game.level + 1)
+        };
+        draw_game_text(&text, 200, None);
+    }
+
+    fn draw_game_over(&self) {
+        self.draw_status();
+        // Display "Game Over" image
+        draw_texture(storage::get::<Resources>().over_texture, 0., 0., WHITE);
+        self.draw_high_scores();
+    }
+
+    // Rust: The top scores of the mode just played are displayed below the "Game Over" image.
+    fn draw_high_scores(&self) {
+        if let Some(top_scores) = self.online_top_scores() {
+            Self::draw_online_top_scores(top_scores);
+            return;
+        }
+
+        let title = if !self.pending_high_scores.is_empty() {
+            "NEW HIGH SCORE".to_string()
+        } else {
+            format!("{} HIGH SCORES", self.game.mode.name())
+        };
+        draw_game_text(&title, 384, None);
+
+        let top_scores = self
+            .high_scores
+            .table(self.game.mode)
+            .iter()
+            .take(NUM_HIGH_SCORES_DISPLAYED)
+            .map(|entry| format!("{} {}", entry.name, entry.score))
+            .collect::<Vec<_>>()
+            .join("   ");
+        draw_game_text(&top_scores, 420, None);
+    }
+
+    /// None while the online table is not available (or if not used); the local table is then displayed.
+    #[cfg(feature = "online_leaderboard")]
+    fn online_top_scores(&self) -> Option<&[HighScore]> {
+        self.online_leaderboard
+            .as_ref()
+            .and_then(|online_leaderboard| online_leaderboard.top_scores())
+    }
+
+    #[cfg(not(feature = "online_leaderboard"))]
+    fn online_top_scores(&self) -> Option<&[HighScore]> {
+        None
+    }
+
+    fn draw_online_top_scores(top_scores: &[HighScore]) {
+        draw_game_text("GLOBAL TOP TEN", 384, None);
+
+        let entries = top_scores
+            .iter()
+            .enumerate()
+            .map(|(i, entry)| format!("{}. {} {}", i + 1, entry.name, entry.score))
+            .collect::<Vec<_>>();
+
+        for (line_i, line_entries) in entries.chunks(ONLINE_SCORES_PER_LINE).enumerate() {
+            let line = line_entries.join("    ");
+            let width = measure_text(&line, None, 20, 1.).width;
+            let y = 428. + 20. * line_i as f32;
+            draw_text(&line, (WIDTH as f32 - width) / 2., y, 20., WHITE);
+        }
+    }
+
     fn draw_status(&self) {
-        // For Rust convenience
-        let player = self.game.player.as_ref().unwrap();
+        let players = &self.game.players;
+
+        // Display level number
+        if let Some(bonus_stage) = &self.game.bonus_stage {
+            draw_game_text("BONUS STAGE", 451, None);
+            bonus_stage.draw();
+        } else {
+            draw_game_text(&format!("LEVEL {}", self.game.level + 1), 451, None);
+        }
+
+        if let Some(boss) = &self.game.boss {
+            boss.draw_health_bar();
+        }
+
+        self.game.combo.draw();
+
+        if self.settings.speedrun_timer {
+            self.speedrun_timer
+                .draw(self.personal_bests.splits(self.game.mode));
+        }
+
+        if let [player] = players.as_slice() {
+            // Display score, right-justified at edge of screen
+            draw_game_text(
+                &player.score.to_string(),
+                451,
+                Some(Self::right_justified_x(player)),
+            );
+
+            Self::draw_lives_health(player, false);
+        } else {
+            // Rust: In co-op mode, the bottom line is shared between the two players' lives and health, so the
+            // scores are displayed at the top corners.
+            let (player_1, player_2) = (&players[0], &players[1]);
+
+            draw_game_text(&player_1.score.to_string(), 2, Some(2));
+            draw_game_text(
+                &player_2.score.to_string(),
+                2,
+                Some(Self::right_justified_x(player_2)),
+            );
+
+            Self::draw_lives_health(player_1, false);
+            Self::draw_lives_health(player_2, true);
+        }
+    }
 
-        // Display score, right-justified at edge of screen
+    fn right_justified_x(player: &Player) -> i32 {
         let number_width = CHAR_WIDTH[0];
         let s = player.score.to_string();
-        draw_game_text(&s, 451, Some(WIDTH - 2 - (number_width * s.len() as i32)));
-
-        // Display level number
-        draw_game_text(&format!("LEVEL {}", self.game.level + 1), 451, None);
+        WIDTH - 2 - (number_width * s.len() as i32)
+    }
 
+    fn draw_lives_health(player: &Player, right_justified: bool) {
         // Display lives and health
         // We only display a maximum of two lives - if there are more than two, a plus symbol is displayed
-        let mut lives_health = ["life"].repeat(2.min(player.lives as usize));
+        let mut lives_health = ["life"].repeat(2.min(player.lives.max(0) as usize));
         if player.lives > 2 {
             lives_health.push("plus");
         }
@@ -107,13 +795,61 @@ impl GlobalState {
             lives_health.extend(["health"].repeat(player.health as usize));
         };
 
+        let active_power_ups = POWER_UPS
+            .iter()
+            .copied()
+            .filter(|power_up| player.power_up_timer(*power_up) > Ticks::ZERO)
+            .collect::<Vec<_>>();
+
         let status_textures = &storage::get::<Resources>().status_textures;
 
-        let mut x = 0;
+        let mut x = if right_justified {
+            let lives_health_width = lives_health
+                .iter()
+                .map(|image| IMAGE_WIDTH[*image])
+                .sum::<i32>();
+            let power_ups_width = POWER_UP_ICON_WIDTH * active_power_ups.len() as i32;
+            WIDTH - lives_health_width - power_ups_width
+        } else {
+            0
+        };
+        // After an extra life is awarded, the lives indicator blinks
+        let lives_hidden =
+            player.extra_life_timer > Ticks::ZERO && (player.extra_life_timer.get() / 4) % 2 == 0;
+
         for image in lives_health {
-            let texture = status_textures[image];
-            draw_texture(texture, x as f32, 450., WHITE);
+            if image == "health" || !lives_hidden {
+                let texture = status_textures[image];
+                draw_texture(texture, x as f32, 450., player.colour);
+            }
             x += IMAGE_WIDTH[image];
         }
+        for power_up in active_power_ups {
+            Self::draw_power_up_indicator(power_up, player.power_up_timer(power_up), x);
+            x += POWER_UP_ICON_WIDTH;
+        }
+    }
+
+    fn draw_power_up_indicator(power_up: PowerUp, timer: Ticks, x: i32) {
+        let texture =
+            storage::get::<Resources>().fruit_textures[(POWER_UP_SPRITE_VAL * 3) as usize];
+
+        draw_texture_ex(
+            texture,
+            x as f32,
+            452.,
+            power_up.colour(),
+            DrawTextureParams {
+                dest_size: Some(vec2(
+                    POWER_UP_ICON_WIDTH as f32 - 2.,
+                    POWER_UP_ICON_HEIGHT as f32,
+                )),
+                ..Default::default()
+            },
+        );
+
+        let bar_width =
+            (POWER_UP_ICON_WIDTH - 2) as f32 * timer.get() as f32 / POWER_UP_DURATION.get() as f32;
+        draw_rectangle(x as f32, 475., bar_width, 3., power_up.colour());
     }
 }
@@ -0,0 +1,80 @@
+use std::fs;
+
+use nanoserde::{DeJson, DeRon, SerJson, SerRon};
+
+use crate::game_mode::GameMode;
+
+const HIGH_SCORES_FILE: &str = "highscores.ron";
+
+const MAX_HIGH_SCORES: usize = 10;
+
+// The JSON format is used by the online leaderboard.
+#[derive(Clone, DeJson, DeRon, SerJson, SerRon)]
+pub struct HighScore {
+    pub name: String,
+    pub score: i32,
+}
+
+/// Not in the original game. Each game mode has its own table, sorted from the highest score.
+#[derive(Default, DeRon, SerRon)]
+#[nserde(default)]
+pub struct HighScores {
+    classic: Vec<HighScore>,
+    endless: Vec<HighScore>,
+}
+
+impl HighScores {
+    /// If the file is missing or invalid, the tables start empty.
+    pub fn load() -> Self {
+        fs::read_to_string(HIGH_SCORES_FILE)
+            .ok()
+            .and_then(|content| Self::deserialize_ron(&content).ok())
+            .unwrap_or_default()
+    }
+
+    pub fn save(&self) {
+        if let Err(error) = fs::write(HIGH_SCORES_FILE, self.serialize_ron()) {
+            eprintln!("Couldn't save the high scores: {}", error);
+        }
+    }
+
+    pub fn table(&self, mode: GameMode) -> &[HighScore] {
+        match mode {
+            GameMode::Classic => &self.classic,
+            GameMode::Endless => &self.endless,
+        }
+    }
+
+    fn position(&self, mode: GameMode, score: i32) -> Option<usize> {
+        let table = self.table(mode);
+        let position = table
+            .iter()
+            .position(|entry| score > entry.score)
+            .unwrap_or(table.len());
+
+        (position < MAX_HIGH_SCORES && score > 0).then_some(position)
+    }
+
+    /// Whether the score would make it into the table.
+    pub fn qualifies(&self, mode: GameMode, score: i32) -> bool {
+        self.position(mode, score).is_some()
+    }
+
+    /// Returns whether the score made it into the table.
+    pub fn add(&mut self, mode: GameMode, name: &str, score: i32) -> bool {
+        let Some(position) = self.position(mode, score) else {
+            return false;
+        };
+
+        let table = match mode {
+            GameMode::Classic => &mut self.classic,
+            GameMode::Endless => &mut self.endless,
+        };
+
+        let name = name.to_string();
+        table.insert(position, HighScore { name, score });
+        table.truncate(MAX_HIGH_SCORES);
+
+        true
+    }
+}
@@ -0,0 +1,137 @@
+use std::{collections::HashMap, fs, time::SystemTime};
+
+use macroquad::prelude::{collections::storage, Image};
+use nanoserde::DeRon;
+
+use crate::{
+    physics::{Physics, PHYSICS_FILE},
+    resources::Resources,
+    spawn_waves::{SpawnWaves, WAVES_FILE},
+};
+
+/// Frames between the checks of the files.
+const CHECK_INTERVAL: i32 = 30;
+
+// Rust: Not in the original game. In debug builds, the textures and the data files (physics and waves) are
+// reloaded when they change on disk, so that they can be tweaked while the game is running. The files are polled,
+// which avoids a dependency on a file watching library; a change is applied once the file has been stable for a
+// check interval, so that files still being written are not loaded.
+//
+// The level layouts are compiled in, so they're not reloaded; the textures recoloured for the colourblind palette
+// aren't, either.
+//
+pub struct HotReload {
+    timer: i32,
+    /// Modification times of the watched files, as of the last check.
+    modified_times: HashMap<String, SystemTime>,
+    /// Files that changed since the check before the last.
+    changed: Vec<String>,
+}
+
+impl HotReload {
+    pub fn new() -> Self {
+        Self {
+            timer: 0,
+            modified_times: modified_times(),
+            changed: vec![],
+        }
+    }
+
+    /// Must be invoked once per frame.
+    pub fn update(&mut self) {
+        self.timer += 1;
+
+        if self.timer % CHECK_INTERVAL != 0 {
+            return;
+        }
+
+        let modified_times = modified_times();
+        let changed = modified_times
+            .iter()
+            .filter(|(path, time)| self.modified_times.get(*path) != Some(time))
+            .map(|(path, _)| path.clone())
+            .collect::<Vec<_>>();
+
+        for path in self.changed.iter().filter(|path| !changed.contains(path)) {
+            reload(path);
+        }
+
+        self.changed = changed;
+        self.modified_times = modified_times;
+    }
+}
+
+fn modified_times() -> HashMap<String, SystemTime> {
+    let resources = storage::get::<Resources>();
+    let texture_paths = resources
+        .loaded_textures
+        .iter()
+        .map(|(path, _)| path.as_str());
+
+    texture_paths
+        .chain([PHYSICS_FILE, WAVES_FILE])
+        .filter_map(|path| {
+            let time = fs::metadata(path).and_then(|metadata| metadata.modified());
+            time.ok().map(|time| (path.to_string(), time))
+        })
+        .collect()
+}
+
+fn reload(path: &str) {
+    match path {
+        PHYSICS_FILE => reload_data::<Physics>(path),
+        WAVES_FILE => reload_data::<SpawnWaves>(path),
+        _ => reload_texture(path),
+    }
+}
+
+/// The data replaces the one in storage; the waves apply from the next level.
+fn reload_data<T: DeRon + 'static>(path: &str) {
+    let data = fs::read_to_string(path)
+        .map_err(|error| error.to_string())
+        .and_then(|content| T::deserialize_ron(&content).map_err(|error| format!("{:?}", error)));
+
+    match data {
+        Ok(data) => {
+            *storage::get_mut::<T>() = data;
+            println!("Reloaded {}", path);
+        }
+        Err(error) => eprintln!("Couldn't reload {}: {}", path, error),
+    }
+}
+
+/// The texture is updated in place, so that all the entities using it are updated as well; therefore, its size
+/// can't change.
+fn reload_texture(path: &str) {
+    let bytes = match fs::read(path) {
+        Ok(bytes) => bytes,
+        Err(error) => {
+            eprintln!("Couldn't reload {}: {}", path, error);
+            return;
+        }
+    };
+    // Macroquad panics on invalid images, so they're decoded here; files being edited are commonly invalid.
+    let image = match image::load_from_memory(&bytes) {
+        Ok(image) => image.to_rgba8(),
+        Err(error) => {
+            eprintln!("Couldn't reload {}: {}", path, error);
+            return;
+        }
+    };
+    let image = Image {
+        width: image.width() as u16,
+        height: image.height() as u16,
+        bytes: image.into_raw(),
+    };
+
+    let resources = storage::get::<Resources>();
+
+    for (_, texture) in resources.loaded_textures.iter().filter(|(p, _)| p == path) {
+        if texture.width() as u16 == image.width && texture.height() as u16 == image.height {
+            texture.update(&image);
+            println!("Reloaded {}", path);
+        } else {
+            eprintln!("Couldn't reload {}: the size changed", path);
+        }
+    }
+}
@@ -0,0 +1,122 @@
+use std::{cell::RefCell, fs, path::Path, rc::Rc};
+
+use nanoserde::{DeRon, SerRon};
+
+use crate::{
+    difficulty::Difficulty,
+    game_mode::GameMode,
+    input_state::{InputSource, InputState},
+    settings::Settings,
+};
+
+// Rust: Not in the original game. Since the game logic only depends on the player input, on the random number
+// generator, and on the gameplay settings, a game can be reproduced from the seed, the settings, and the input of
+// each update.
+//
+// The game keeps being updated during the level transitions, so the playback goes through them as well; the logs
+// recorded before they were introduced start the next level immediately instead.
+
+/// Input repeated for a number of consecutive updates.
+#[derive(Clone, Copy, DeRon, SerRon)]
+pub struct InputRun {
+    pub frames: u32,
+    pub input: InputState,
+}
+
+/// Input of a single player game, run-length encoded. The fields added after the first demo recording default to
+/// the behavior of the time.
+#[derive(Clone, Default, DeRon, SerRon)]
+pub struct InputLog {
+    pub seed: u64,
+    pub difficulty: Difficulty,
+    #[nserde(default)]
+    pub mode: GameMode,
+    #[nserde(default)]
+    pub orb_wall_bounces: bool,
+    #[nserde(default)]
+    pub charge_shot: bool,
+    #[nserde(default)]
+    pub extra_life_score: i32,
+    /// Whether the game went through the level transitions.
+    #[nserde(default)]
+    pub level_transitions: bool,
+    pub runs: Vec<InputRun>,
+    /// Set at the end of the game; on playback, it's compared with the score reached, in order to detect desyncs.
+    #[nserde(default)]
+    pub final_score: Option<i32>,
+}
+
+impl InputLog {
+    pub fn new(seed: u64, mode: GameMode, settings: &Settings) -> Self {
+        Self {
+            seed,
+            difficulty: settings.difficulty,
+            mode,
+            orb_wall_bounces: settings.orb_wall_bounces,
+            charge_shot: settings.charge_shot,
+            extra_life_score: settings.extra_life_score,
+            level_transitions: true,
+            runs: vec![],
+            final_score: None,
+        }
+    }
+
+    /// None if the file is missing or invalid.
+    pub fn load(path: &str) -> Option<Self> {
+        fs::read_to_string(path)
+            .ok()
+            .and_then(|content| Self::deserialize_ron(&content).ok())
+    }
+
+    pub fn exists(path: &str) -> bool {
+        Path::new(path).exists()
+    }
+
+    pub fn push(&mut self, input: InputState) {
+        match self.runs.last_mut() {
+            Some(run) if run.input == input => run.frames += 1,
+            _ => self.runs.push(InputRun { frames: 1, input }),
+        }
+    }
+
+    pub fn frames(&self) -> u32 {
+        self.runs.iter().map(|run| run.frames).sum()
+    }
+
+    pub fn states(&self) -> Vec<InputState> {
+        self.runs
+            .iter()
+            .flat_map(|run| (0..run.frames).map(move |_| run.input))
+            .collect()
+    }
+
+    pub fn save(&self, path: &str) {
+        if let Err(error) = fs::write(path, self.serialize_ron()) {
+            eprintln!("Couldn't save the input log: {}", error);
+        }
+    }
+}
+
+/// Passes through the input of another source, logging it.
+pub struct RecordingInput {
+    source: Box<dyn InputSource>,
+    log: Rc<RefCell<InputLog>>,
+}
+
+impl RecordingInput {
+    pub fn new(source: Box<dyn InputSource>, log: Rc<RefCell<InputLog>>) -> Self {
+        Self { source, log }
+    }
+}
+
+impl InputSource for RecordingInput {
+    fn input_state(&mut self) -> InputState {
+        let input = self.source.input_state();
+        self.log.borrow_mut().push(input);
+        input
+    }
+
+    fn rumble(&mut self, strength: f32) {
+        self.source.rumble(strength);
+    }
+}
@@ -0,0 +1,123 @@
+#[cfg(feature = "gamepad")]
+use macroquad::prelude::collections::storage;
+use macroquad::prelude::{is_key_down, is_key_pressed};
+use nanoserde::{DeRon, SerRon};
+
+#[cfg(feature = "gamepad")]
+use crate::gamepad::Gamepads;
+use crate::{
+    controls::{Action, Controls},
+    frame_input, touch_controls,
+};
+
+// Rust: Not in the original code. The player input is sampled once per update into a plain struct, so that
+// Player::update() doesn't depend on the input devices, and can be driven by a script.
+
+/// The input of a player, for a single update. When deserializing, missing fields are false.
+#[derive(Clone, Copy, Default, PartialEq, DeRon, SerRon)]
+#[nserde(default)]
+pub struct InputState {
+    pub left: bool,
+    pub right: bool,
+    pub jump: bool,
+    pub blow: bool,
+    /// Blow has been pressed in this update (as opposed to being held).
+    pub blow_pressed: bool,
+}
+
+pub trait InputSource {
+    fn input_state(&mut self) -> InputState;
+
+    /// Force feedback, with strength between 0 and 1; sources without a physical device ignore it.
+    fn rumble(&mut self, _strength: f32) {}
+}
+
+/// Input read from the keyboard (and, for player 1, from the on-screen touch controls). When built with the
+/// `gamepad` feature, each player can also use the gamepad with the same index.
+pub struct MacroquadInput {
+    pub controls: Controls,
+    pub player_i: usize,
+}
+
+impl MacroquadInput {
+    pub fn new(controls: Controls, player_i: usize) -> Self {
+        Self { controls, player_i }
+    }
+
+    /// Only one player can use the touch controls.
+    fn is_touch_enabled(&self) -> bool {
+        self.player_i == 0
+    }
+
+    fn is_action_down(&self, action: Action) -> bool {
+        is_key_down(self.controls.key(action))
+            || (self.is_touch_enabled() && touch_controls::is_action_down(action))
+            || self.is_gamepad_action_down(action)
+    }
+
+    /// See `frame_input` for the presses seen by the catch-up updates.
+    fn is_action_pressed(&self, action: Action) -> bool {
+        frame_input::are_presses_new()
+            && (is_key_pressed(self.controls.key(action))
+                || (self.is_touch_enabled() && touch_controls::is_action_pressed(action))
+                || self.is_gamepad_action_pressed(action))
+    }
+
+    #[cfg(feature = "gamepad")]
+    fn is_gamepad_action_down(&self, action: Action) -> bool {
+        storage::get::<Gamepads>().is_action_down(self.player_i, action)
+    }
+
+    #[cfg(not(feature = "gamepad"))]
+    fn is_gamepad_action_down(&self, _action: Action) -> bool {
+        false
+    }
+
+    #[cfg(feature = "gamepad")]
+    fn is_gamepad_action_pressed(&self, action: Action) -> bool {
+        storage::get::<Gamepads>().is_action_pressed(self.player_i, action)
+    }
+
+    #[cfg(not(feature = "gamepad"))]
+    fn is_gamepad_action_pressed(&self, _action: Action) -> bool {
+        false
+    }
+}
+
+impl InputSource for MacroquadInput {
+    fn input_state(&mut self) -> InputState {
+        InputState {
+            left: self.is_action_down(Action::Left),
+            right: self.is_action_down(Action::Right),
+            jump: self.is_action_down(Action::Jump),
+            blow: self.is_action_down(Action::Blow),
+            blow_pressed: self.is_action_pressed(Action::Blow),
+        }
+    }
+
+    #[cfg(feature = "gamepad")]
+    fn rumble(&mut self, strength: f32) {
+        storage::get_mut::<Gamepads>().rumble(self.player_i, strength);
+    }
+}
+
+/// Input played back from a predefined sequence, one state per update; once the sequence is over, no input
+/// is reported.
+pub struct ScriptedInput {
+    states: Vec<InputState>,
+    next: usize,
+}
+
+impl ScriptedInput {
+    pub fn new(states: Vec<InputState>) -> Self {
+        Self { states, next: 0 }
+    }
+}
+
+impl InputSource for ScriptedInput {
+    fn input_state(&mut self) -> InputState {
+        let state = self.states.get(self.next).copied().unwrap_or_default();
+        self.next += 1;
+        state
+    }
+}
@@ -0,0 +1,222 @@
+use std::collections::VecDeque;
+
+use macroquad::rand::gen_range;
+
+use crate::{
+    global_state::COOP_START_XS, levels::LEVELS, GRID_BLOCK_SIZE, LEVEL_X_OFFSET, NUM_COLUMNS,
+    NUM_ROWS, WIDTH,
+};
+
+// Rust: Not in the original game. Generates the levels of the endless mode.
+//
+// A generated grid has the same format as the LEVELS entries (the last row, which is a copy of the first, is not
+// included), and it's checked against the collision rules of CollideActor/GravityActor:
+//
+// - blocks only stop actors that are falling, or walking into the row just above a platform; jumping up goes through
+//   them;
+// - the first row is not solid (it's the ceiling), but its copy at the bottom is the floor, and actors falling
+//   through the floor openings reappear at the top;
+// - a jump (initial velocity 16, gravity 1) rises 136 pixels, so platforms up to 5 rows above can be reached (4 are
+//   assumed, to leave some margin).
+
+const MAX_ATTEMPTS: usize = 100;
+
+const MAX_JUMP_ROWS: usize = 4;
+// Horizontal distance that can be covered while rising, and during a whole jump, in columns.
+const JUMP_REACH_COLUMNS: usize = 2;
+const JUMP_ACROSS_COLUMNS: usize = 4;
+// Robots spawn through the openings of the first row.
+const MIN_OPENING_WIDTH: usize = 3;
+const MIN_PLATFORM_WIDTH: usize = 3;
+
+const HALF_COLUMNS: usize = NUM_COLUMNS as usize / 2;
+// Including the floor, i.e. the copy of the first row.
+const NUM_GRID_ROWS: usize = NUM_ROWS as usize;
+
+/// Horizontal run of blocks; the columns are inclusive.
+#[derive(Clone, Copy, PartialEq)]
+struct Segment {
+    row: usize,
+    start: usize,
+    end: usize,
+}
+
+/// Generates a grid that passes the validation; if this fails (which is very unlikely), an original level is
+/// returned.
+pub fn generate_grid(level: i8) -> Vec<String> {
+    for _ in 0..MAX_ATTEMPTS {
+        let grid = random_grid();
+
+        if is_valid(&grid) {
+            return grid;
+        }
+    }
+
+    LEVELS[(level as usize) % LEVELS.len()]
+        .iter()
+        .map(|row| row.to_string())
+        .collect()
+}
+
+fn random_grid() -> Vec<String> {
+    let mut grid = vec![String::new(); NUM_GRID_ROWS - 1];
+
+    // The first row is solid, with an opening on each side.
+    let mut first_row = [true; HALF_COLUMNS];
+    let opening_width = gen_range(MIN_OPENING_WIDTH, 6);
+    let opening_start = gen_range(1, HALF_COLUMNS - opening_width);
+    first_row[opening_start..opening_start + opening_width].fill(false);
+    grid[0] = mirrored_row(&first_row);
+
+    // Platforms are at least 3 rows apart, so that they don't form walls.
+    let mut row_y = gen_range(4, 6);
+
+    while row_y < NUM_GRID_ROWS - 2 {
+        let mut row = [false; HALF_COLUMNS];
+        let mut x = gen_range(0, 3);
+
+        while x < HALF_COLUMNS {
+            let length = gen_range(MIN_PLATFORM_WIDTH, 9);
+            let end = (x + length).min(HALF_COLUMNS);
+            row[x..end].fill(true);
+            x = end + gen_range(2, 6);
+        }
+
+        grid[row_y] = mirrored_row(&row);
+        row_y += gen_range(3, MAX_JUMP_ROWS + 1);
+    }
+
+    grid
+}
+
+// The levels are symmetric, like the original ones.
+fn mirrored_row(left_half: &[bool; HALF_COLUMNS]) -> String {
+    left_half
+        .iter()
+        .chain(left_half.iter().rev())
+        .map(|block| if *block { 'X' } else { ' ' })
+        .collect()
+}
+
+/// Checks that the grid is well formed, that robots can spawn, that the players land when spawning, and that
+/// every platform can be reached by the player.
+pub fn is_valid(grid: &[String]) -> bool {
+    let well_formed = grid.len() == NUM_GRID_ROWS - 1
+        && grid.iter().all(|row| {
+            (row.is_empty() || row.len() == NUM_COLUMNS as usize)
+                && row.bytes().all(|block| block == b'X' || block == b' ')
+        });
+
+    if !well_formed {
+        return false;
+    }
+
+    let has_opening = grid[0]
+        .split('X')
+        .any(|opening| opening.len() >= MIN_OPENING_WIDTH);
+
+    if !has_opening {
+        return false;
+    }
+
+    let segments = segments(grid);
+
+    let spawn_segments = [WIDTH / 2, COOP_START_XS[0], COOP_START_XS[1]]
+        .iter()
+        .map(|x| {
+            landing_segment(
+                &segments,
+                ((x - LEVEL_X_OFFSET) / GRID_BLOCK_SIZE) as usize,
+                0,
+            )
+        })
+        .collect::<Option<Vec<_>>>();
+
+    let Some(spawn_segments) = spawn_segments else {
+        return false;
+    };
+
+    // Breadth-first visit of the segments, starting from where the first player lands.
+    let mut reached = vec![false; segments.len()];
+    let mut queue = VecDeque::from([spawn_segments[0]]);
+    reached[spawn_segments[0]] = true;
+
+    while let Some(current) = queue.pop_front() {
+        let segment = segments[current];
+
+        // Walking off either end, and falling.
+        let mut next = [segment.start.checked_sub(1), Some(segment.end + 1)]
+            .iter()
+            .flatten()
+            .copied()
+            .filter(|x| *x < NUM_COLUMNS as usize)
+            .filter_map(|x| landing_segment(&segments, x, segment.row))
+            .collect::<Vec<_>>();
+
+        // Jumping onto a platform above, or across a gap.
+        next.extend(segments.iter().enumerate().filter_map(|(i, other)| {
+            let reachable = if other.row < segment.row {
+                segment.row - other.row <= MAX_JUMP_ROWS
+                    && other.start <= segment.end + JUMP_REACH_COLUMNS
+                    && segment.start <= other.end + JUMP_REACH_COLUMNS
+            } else {
+                other.start <= segment.end + JUMP_ACROSS_COLUMNS
+                    && segment.start <= other.end + JUMP_ACROSS_COLUMNS
+            };
+
+            reachable.then_some(i)
+        }));
+
+        for i in next {
+            if !reached[i] {
+                reached[i] = true;
+                queue.push_back(i);
+            }
+        }
+    }
+
+    reached.into_iter().all(|r| r)
+}
+
+// The first row is excluded, since it's not solid; the floor is included instead.
+fn segments(grid: &[String]) -> Vec<Segment> {
+    let mut segments = vec![];
+
+    for row_y in 1..NUM_GRID_ROWS {
+        let row = grid[row_y % grid.len()].as_bytes();
+        let mut x = 0;
+
+        while x < row.len() {
+            if row[x] == b'X' {
+                let start = x;
+                while x + 1 < row.len() && row[x + 1] == b'X' {
+                    x += 1;
+                }
+                segments.push(Segment {
+                    row: row_y,
+                    start,
+                    end: x,
+                });
+            }
+            x += 1;
+        }
+    }
+
+    segments
+}
+
+/// Index of the segment where an actor falling from the given row lands, wrapping around the bottom of the
+/// level; None if the column is empty (the actor would fall forever).
+fn landing_segment(segments: &[Segment], x: usize, from_row: usize) -> Option<usize> {
+    let column_segments = || {
+        segments
+            .iter()
+            .enumerate()
+            .filter(move |(_, s)| s.start <= x && x <= s.end)
+    };
+
+    column_segments()
+        .find(|(_, s)| s.row > from_row)
+        .or_else(|| column_segments().next())
+        .map(|(i, _)| i)
+}
@@ -0,0 +1,90 @@
+use macroquad::prelude::{draw_rectangle, Color, KeyCode, BLACK, GRAY, WHITE};
+
+use crate::{
+    drawing::{draw_game_text, draw_game_text_ex},
+    frame_input::is_key_pressed,
+    level_stats::{LevelStats, NUM_SELECTABLE_LEVELS},
+    ticks::TICKS_PER_SECOND,
+    HEIGHT, WIDTH,
+};
+
+const DIM_COLOUR: Color = Color::new(BLACK.r, BLACK.g, BLACK.b, 0.8);
+
+const LEVEL_X: i32 = 100;
+const SCORE_X: i32 = 360;
+const TIME_X: i32 = 560;
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum LevelSelectItem {
+    Level(usize),
+    Back,
+}
+
+// Rust: Not in the original game. Available once the game has been finished; the last level listed is the boss.
+//
+pub struct LevelSelect {
+    selected: usize,
+}
+
+impl LevelSelect {
+    pub fn new() -> Self {
+        Self { selected: 0 }
+    }
+
+    fn items() -> Vec<LevelSelectItem> {
+        let mut items = (0..NUM_SELECTABLE_LEVELS)
+            .map(LevelSelectItem::Level)
+            .collect::<Vec<_>>();
+        items.push(LevelSelectItem::Back);
+        items
+    }
+
+    /// Returns the item chosen by the player, if any. Esc is a shortcut for going back.
+    pub fn update(&mut self) -> Option<LevelSelectItem> {
+        let items = Self::items();
+
+        if is_key_pressed(KeyCode::Escape) {
+            return Some(LevelSelectItem::Back);
+        }
+
+        if is_key_pressed(KeyCode::Up) {
+            self.selected = (self.selected + items.len() - 1) % items.len();
+        } else if is_key_pressed(KeyCode::Down) {
+            self.selected = (self.selected + 1) % items.len();
+        } else if is_key_pressed(KeyCode::Space) || is_key_pressed(KeyCode::Enter) {
+            return Some(items[self.selected]);
+        }
+
+        None
+    }
+
+    pub fn draw(&self, level_stats: &LevelStats) {
+        draw_rectangle(0., 0., WIDTH as f32, HEIGHT as f32, DIM_COLOUR);
+
+        draw_game_text("LEVEL SELECT", 40, None);
+        draw_game_text_ex("BEST", 100, Some(SCORE_X), GRAY);
+        draw_game_text_ex("TIME", 100, Some(TIME_X), GRAY);
+
+        for (i, item) in Self::items().into_iter().enumerate() {
+            let colour = if i == self.selected { WHITE } else { GRAY };
+            let y = 150 + 50 * i as i32;
+
+            match item {
+                LevelSelectItem::Level(level) => {
+                    draw_game_text_ex(&format!("LEVEL {}", level + 1), y, Some(LEVEL_X), colour);
+
+                    // The font has no punctuation, so the time is displayed in (whole) seconds
+                    if let Some(record) = level_stats.record(level) {
+                        let seconds = record.best_time / TICKS_PER_SECOND;
+
+                        draw_game_text_ex(&record.best_score.to_string(), y, Some(SCORE_X), colour);
+                        draw_game_text_ex(&format!("{}S", seconds), y, Some(TIME_X), colour);
+                    }
+                }
+                LevelSelectItem::Back => {
+                    draw_game_text_ex("BACK", y, None, colour);
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,80 @@
+use std::fs;
+
+use nanoserde::{DeRon, SerRon};
+
+use crate::boss::BOSS_LEVEL_INTERVAL;
+
+const LEVEL_STATS_FILE: &str = "level_stats.ron";
+
+/// The classic mode levels, up to the first boss (included); beyond it, the levels repeat. Once they've all been
+/// completed, the game counts as finished, and they can be played individually from the level select screen.
+pub const NUM_SELECTABLE_LEVELS: usize = BOSS_LEVEL_INTERVAL as usize;
+
+#[derive(Clone, Copy, DeRon, SerRon)]
+pub struct LevelRecord {
+    /// Points scored within the level (by both players, in co-op mode).
+    pub best_score: i32,
+    /// In updates.
+    pub best_time: i32,
+}
+
+/// Not in the original game. Best score and time of each classic mode level; the two records are independent.
+#[derive(Default, DeRon, SerRon)]
+#[nserde(default)]
+pub struct LevelStats {
+    levels: Vec<Option<LevelRecord>>,
+}
+
+impl LevelStats {
+    /// If the file is missing or invalid, there are no records.
+    pub fn load() -> Self {
+        fs::read_to_string(LEVEL_STATS_FILE)
+            .ok()
+            .and_then(|content| Self::deserialize_ron(&content).ok())
+            .unwrap_or_default()
+    }
+
+    pub fn save(&self) {
+        if let Err(error) = fs::write(LEVEL_STATS_FILE, self.serialize_ron()) {
+            eprintln!("Couldn't save the level stats: {}", error);
+        }
+    }
+
+    pub fn record(&self, level: usize) -> Option<LevelRecord> {
+        self.levels.get(level).copied().flatten()
+    }
+
+    pub fn is_game_finished(&self) -> bool {
+        (0..NUM_SELECTABLE_LEVELS).all(|level| self.record(level).is_some())
+    }
+
+    /// Returns whether any record has been beaten; the levels beyond the selectable ones are ignored.
+    pub fn add(&mut self, level: usize, score: i32, time: i32) -> bool {
+        if level >= NUM_SELECTABLE_LEVELS {
+            return false;
+        }
+
+        if self.levels.len() <= level {
+            self.levels.resize(level + 1, None);
+        }
+
+        match &mut self.levels[level] {
+            Some(record) => {
+                let improved = score > record.best_score || time < record.best_time;
+
+                record.best_score = record.best_score.max(score);
+                record.best_time = record.best_time.min(time);
+
+                improved
+            }
+            slot => {
+                *slot = Some(LevelRecord {
+                    best_score: score,
+                    best_time: time,
+                });
+
+                true
+            }
+        }
+    }
+}
@@ -57,3 +57,15 @@ pub const LEVELS: [[&str; 17]; 3] = [
         "",
     ],
 ];
+
+// Rust: Not in the original game. Checkpoint flags of each level, as (column, row) of the block they stand on.
+pub const CHECKPOINTS: [&[(i32, i32)]; 3] = [
+    &[(5, 13), (22, 13)],
+    &[(3, 9), (24, 9)],
+    &[(6, 13), (21, 13)],
+];
+
+// Rust: Not in the original game. Ramp of the robots' aggressiveness over the levels, as (level, factor) points; the
+// factor is interpolated linearly between the points, and stays constant after the last one. The bolt speed is
+// multiplied by the factor, and the firing interval divided by it.
+pub const AGGRESSION_CURVE: [(i8, f32); 4] = [(0, 1.), (4, 1.), (12, 1.25), (24, 1.5)];
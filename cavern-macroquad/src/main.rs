@@ -2,56 +2,117 @@
 #![deny(clippy::correctness)]
 
 mod actor;
+mod animation;
+mod asset_loader;
+mod blow_params;
 mod bolt;
-mod collide_actor;
+mod bonus_stage;
+mod boss;
+mod checkpoint;
+mod combo;
+mod control_profile;
+mod controls;
+mod debug_draw;
+mod difficulty;
 mod drawing;
+mod entity_store;
+mod floating_text;
+mod flying_robot;
+mod frame_input;
 mod fruit;
 mod game;
+mod game_mode;
 mod game_playback;
+#[cfg(feature = "gamepad")]
+mod gamepad;
+mod ghost;
+#[cfg(feature = "gif_recorder")]
+mod gif_recorder;
 mod global_state;
 mod gravity_actor;
+mod high_scores;
+#[cfg(all(debug_assertions, not(target_arch = "wasm32")))]
+mod hot_reload;
+mod input_log;
+mod input_state;
+mod level_generator;
+mod level_select;
+mod level_stats;
 mod levels;
+mod name_entry;
+#[cfg(feature = "online_leaderboard")]
+mod online_leaderboard;
+mod options_menu;
 mod orb;
+mod palette;
+mod panning;
+mod particle;
+mod pause_menu;
+mod physics;
+mod playback;
 mod player;
 mod pop;
+mod power_up;
 mod resources;
 mod robot;
+mod save_game;
+mod screen_shake;
+mod settings;
+mod sound_manager;
+mod spawn_waves;
+mod speedrun;
 mod state;
+mod ticks;
+mod touch_controls;
+mod virtual_screen;
 
-use macroquad::{
-    audio::{self, PlaySoundParams},
-    prelude::{collections::storage, coroutines::start_coroutine, *},
-};
+use macroquad::prelude::{collections::storage, coroutines::start_coroutine, *};
 
 use global_state::GlobalState;
-use resources::Resources;
+use input_log::InputLog;
+use physics::Physics;
+use resources::{Resources, Sounds};
+use settings::Settings;
+use sound_manager::SoundManager;
+use spawn_waves::SpawnWaves;
+use virtual_screen::VirtualScreen;
 
 use std::error;
 
-pub const WIDTH: i32 = 800;
-pub const HEIGHT: i32 = 480;
+pub use cavern_core::{GRID_BLOCK_SIZE, HEIGHT, LEVEL_X_OFFSET, NUM_COLUMNS, NUM_ROWS, WIDTH};
+
 pub const TITLE: &str = "Cavern Macroquad Naive";
 
-pub const NUM_ROWS: i32 = 18;
-pub const NUM_COLUMNS: i32 = 28;
+// Rust: The original game relies on Pygame Zero running the update at 60 Hz; here, the update rate is
+// decoupled from the display refresh rate, so that the timers (which are counted in updates; see `Ticks`) run
+// at the same speed on any display.
+const UPDATE_INTERVAL: f64 = 1. / ticks::TICKS_PER_SECOND as f64;
+// Cap on the updates run per rendered frame, so that a long stall doesn't cause a burst of updates.
+const MAX_UPDATES_PER_FRAME: u32 = 5;
+
+const MAX_MISSING_ASSETS_LISTED: usize = 12;
 
-pub const LEVEL_X_OFFSET: i32 = 50;
-pub const GRID_BLOCK_SIZE: i32 = 25;
+// Passing this argument, followed by an input log file, the run is replayed without opening the window (see
+// `verify_replay()`), and the final score is checked against the recorded one; the exit code is 1 on mismatch.
+const VERIFY_REPLAY_ARG: &str = "--verify-replay";
 
 fn window_conf() -> Conf {
     Conf {
         window_title: TITLE.into(),
         window_width: WIDTH,
         window_height: HEIGHT,
-        window_resizable: false,
+        window_resizable: true,
         ..Default::default()
     }
 }
 
 async fn load_resources() -> Result<(), Box<dyn error::Error>> {
     let resources_loading = start_coroutine(async move {
-        let resources = Resources::new().await.unwrap();
+        let (resources, sounds) = Resources::new().await;
         storage::store(resources);
+        storage::store(sounds);
+        storage::store(Physics::load().await);
+        storage::store(SpawnWaves::load().await);
     });
 
     while !resources_loading.is_done() {
@@ -74,25 +135,171 @@ async fn load_resources() -> Result<(), Box<dyn error::Error>> {
     Ok(())
 }
 
-#[macroquad::main(window_conf())]
-async fn main() -> Result<(), Box<dyn error::Error>> {
+// Rust: Not in the original code. Lists the assets that couldn't be loaded; the player can carry on with the
+// placeholders, or quit. Returns false in the latter case.
+async fn report_missing_assets() -> bool {
+    let missing_assets = storage::get::<Resources>().missing_assets.clone();
+
+    if missing_assets.is_empty() {
+        return true;
+    }
+
+    loop {
+        if is_key_pressed(KeyCode::Space) || is_key_pressed(KeyCode::Enter) {
+            // Skip to the next frame, so that the key press is not seen by the title screen
+            next_frame().await;
+            return true;
+        } else if is_key_pressed(KeyCode::Escape) {
+            return false;
+        }
+
+        clear_background(BLACK);
+
+        let mut y = 40.;
+        draw_text("Some assets couldn't be loaded:", 20., y, 30., RED);
+
+        for path in missing_assets.iter().take(MAX_MISSING_ASSETS_LISTED) {
+            y += 24.;
+            draw_text(path, 40., y, 24., WHITE);
+        }
+        if missing_assets.len() > MAX_MISSING_ASSETS_LISTED {
+            y += 24.;
+            let text = format!(
+                "...and {} more",
+                missing_assets.len() - MAX_MISSING_ASSETS_LISTED
+            );
+            draw_text(&text, 40., y, 24., WHITE);
+        }
+
+        draw_text(
+            "Press Space to play anyway, or Esc to quit",
+            20.,
+            screen_height() - 30.,
+            30.,
+            YELLOW,
+        );
+
+        next_frame().await;
+    }
+}
+
+// Rust: Not in the original code. The replay verification only runs the game logic, so it doesn't need the engine;
+// the resources are loaded without it (the sounds are skipped), and the sounds are muted.
+fn verify_replay(path: &str) -> Result<i32, String> {
+    storage::store(Resources::headless());
+    storage::store(Physics::load_headless());
+    storage::store(SpawnWaves::load_headless());
+    game_playback::set_muted(true);
+
+    let log = InputLog::load(path).ok_or_else(|| format!("Couldn't load {}", path))?;
+
+    playback::verify(&log)
+}
+
+fn main() {
+    let args = std::env::args().collect::<Vec<_>>();
+
+    if let Some(arg_i) = args.iter().position(|arg| arg == VERIFY_REPLAY_ARG) {
+        let result = match args.get(arg_i + 1) {
+            Some(path) => verify_replay(path),
+            None => Err("Missing replay file".to_string()),
+        };
+
+        match result {
+            Ok(score) => println!("Replay verified; final score: {}", score),
+            Err(error) => {
+                eprintln!("{}", error);
+                std::process::exit(1);
+            }
+        }
+    } else {
+        macroquad::Window::from_config(window_conf(), async {
+            if let Err(error) = run().await {
+                error!("Error: {:?}", error);
+            }
+        });
+    }
+}
+
+async fn run() -> Result<(), Box<dyn error::Error>> {
     load_resources().await?;
 
-    let mut state = GlobalState::new();
+    if !report_missing_assets().await {
+        return Ok(());
+    }
+
+    let settings = Settings::load();
+    storage::get_mut::<Resources>().set_palette(settings.palette);
+    let music = storage::get::<Sounds>().music;
+    storage::store(SoundManager::new(music, &settings));
+
+    #[cfg(feature = "gamepad")]
+    storage::store(gamepad::Gamepads::new());
+
+    let mut state = GlobalState::new(settings);
 
     // Start music
-    let music = audio::load_sound("resources/music/theme.ogg").await?;
-    audio::play_sound(
-        music,
-        PlaySoundParams {
-            looped: true,
-            volume: 0.3,
-        },
-    );
+    storage::get::<SoundManager>().play_music();
+
+    #[cfg(all(debug_assertions, not(target_arch = "wasm32")))]
+    let mut hot_reload = hot_reload::HotReload::new();
+
+    let mut screen = VirtualScreen::new();
+    #[cfg(feature = "gif_recorder")]
+    let mut gif_recorder = gif_recorder::GifRecorder::new();
+
+    let mut accumulator = 0.;
+    let mut last_time = get_time();
 
     loop {
-        state.update();
+        let current_time = get_time();
+        accumulator += current_time - last_time;
+        last_time = current_time;
+
+        let mut updates = 0;
+
+        // Presses are only reported for the frame in which they happen, so on displays faster than the
+        // update rate, an update is brought forward rather than dropping the input; the accumulator
+        // debt is repaid on the following frames.
+        #[cfg(feature = "gamepad")]
+        storage::get_mut::<gamepad::Gamepads>().update();
+        #[cfg(all(debug_assertions, not(target_arch = "wasm32")))]
+        hot_reload.update();
+
+        let input_pressed = get_last_key_pressed().is_some() || touch_controls::is_screen_tapped();
+
+        frame_input::begin_frame();
+        while (accumulator >= UPDATE_INTERVAL || (input_pressed && updates == 0))
+            && updates < MAX_UPDATES_PER_FRAME
+        {
+            state.update();
+            frame_input::end_update();
+            accumulator -= UPDATE_INTERVAL;
+            updates += 1;
+        }
+
+        if updates == MAX_UPDATES_PER_FRAME {
+            accumulator = 0.;
+        } else {
+            accumulator = accumulator.max(-UPDATE_INTERVAL);
+        }
+
+        if is_key_pressed(KeyCode::F11) {
+            screen.toggle_fullscreen();
+        }
+        if is_key_pressed(KeyCode::F3) {
+            state.toggle_debug_draw();
+        }
+
+        if is_key_pressed(KeyCode::F12) {
+            screen.save_screenshot();
+        }
+        #[cfg(feature = "gif_recorder")]
+        gif_recorder.update(&screen);
+
+        screen.begin(state.shake_offset());
         state.draw();
+        screen.end();
 
         next_frame().await
     }
@@ -0,0 +1,86 @@
+use macroquad::prelude::{draw_rectangle, get_char_pressed, Color, KeyCode, BLACK, GRAY, WHITE};
+
+use crate::{
+    drawing::{draw_game_text, draw_game_text_ex},
+    frame_input::{self, is_key_pressed},
+    HEIGHT, WIDTH,
+};
+
+const DIM_COLOUR: Color = Color::new(BLACK.r, BLACK.g, BLACK.b, 0.6);
+
+const NAME_LENGTH: usize = 3;
+
+// Distance between the letters; wider than the widest letter (W).
+const LETTER_SPACING: i32 = 44;
+
+// Rust: Not in the original game. Entry of the name of a high score, arcade style: Up/Down change the selected
+// letter, and Left/Right move between the letters; letters can also be typed directly.
+//
+pub struct NameEntry {
+    letters: [u8; NAME_LENGTH],
+    selected: usize,
+}
+
+impl NameEntry {
+    pub fn new() -> Self {
+        // Discard the characters typed while playing.
+        while get_char_pressed().is_some() {}
+
+        Self {
+            letters: [b'A'; NAME_LENGTH],
+            selected: 0,
+        }
+    }
+
+    fn name(&self) -> String {
+        String::from_utf8_lossy(&self.letters).into_owned()
+    }
+
+    /// Returns the name, once confirmed.
+    pub fn update(&mut self) -> Option<String> {
+        let letter = &mut self.letters[self.selected];
+
+        if let Some(chr) = get_char_pressed().filter(char::is_ascii_alphabetic) {
+            *letter = chr.to_ascii_uppercase() as u8;
+            self.selected = (self.selected + 1).min(NAME_LENGTH - 1);
+        } else if is_key_pressed(KeyCode::Up) {
+            *letter = if *letter == b'Z' { b'A' } else { *letter + 1 };
+        } else if is_key_pressed(KeyCode::Down) {
+            *letter = if *letter == b'A' { b'Z' } else { *letter - 1 };
+        } else if is_key_pressed(KeyCode::Left) || is_key_pressed(KeyCode::Backspace) {
+            self.selected = self.selected.saturating_sub(1);
+        } else if is_key_pressed(KeyCode::Right) {
+            self.selected = (self.selected + 1).min(NAME_LENGTH - 1);
+        } else if is_key_pressed(KeyCode::Enter)
+            || is_key_pressed(KeyCode::Space)
+            || frame_input::is_screen_tapped()
+        {
+            return Some(self.name());
+        }
+
+        None
+    }
+
+    pub fn draw(&self, player_number: usize, score: i32) {
+        draw_rectangle(0., 0., WIDTH as f32, HEIGHT as f32, DIM_COLOUR);
+
+        draw_game_text("NEW HIGH SCORE", 120, None);
+        draw_game_text(&format!("PLAYER {}   {}", player_number, score), 170, None);
+        draw_game_text("ENTER YOUR NAME", 220, None);
+
+        let start_x = (WIDTH - LETTER_SPACING * NAME_LENGTH as i32) / 2;
+
+        for (i, letter) in self.letters.iter().enumerate() {
+            let colour = if i == self.selected { WHITE } else { GRAY };
+            let x = start_x + LETTER_SPACING * i as i32;
+            let text = (*letter as char).to_string();
+
+            draw_game_text_ex(&text, 290, Some(x), colour);
+
+            // Cursor, below the (28 pixels high) letter
+            if i == self.selected {
+                draw_rectangle(x as f32, 322., 26., 4., WHITE);
+            }
+        }
+    }
+}
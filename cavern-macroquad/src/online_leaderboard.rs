@@ -0,0 +1,107 @@
+use std::{
+    sync::mpsc::{self, Receiver},
+    thread,
+};
+
+use nanoserde::{DeJson, SerJson};
+
+use crate::{game_mode::GameMode, high_scores::HighScore};
+
+const TOP_SIZE: usize = 10;
+const REQUEST_TIMEOUT_SECS: u64 = 5;
+
+// Rust: Not in the original game. Client of an online leaderboard, exposing, for each game mode, the endpoint
+// `<leaderboard_url>/<mode>`:
+//
+// - GET: returns the top scores, as JSON array of `{"name": ..., "score": ...}`, sorted from the highest;
+// - POST: submits a score, in the same format.
+//
+// The requests are blocking, so they're performed on a separate thread; until the response arrives, or if the
+// server can't be reached, the table is not available, and the local one is displayed instead.
+//
+pub struct OnlineLeaderboard {
+    url: String,
+    response: Option<Receiver<Result<Vec<HighScore>, String>>>,
+    top_scores: Option<Vec<HighScore>>,
+}
+
+impl OnlineLeaderboard {
+    pub fn new(url: &str) -> Self {
+        Self {
+            url: url.trim_end_matches('/').to_string(),
+            response: None,
+            top_scores: None,
+        }
+    }
+
+    /// Submits the given scores (if any), then downloads the top scores. The current table is discarded.
+    pub fn submit_and_fetch(&mut self, mode: GameMode, scores: Vec<HighScore>) {
+        let endpoint = format!("{}/{}", self.url, mode.name().to_lowercase());
+        let (sender, receiver) = mpsc::channel();
+
+        thread::spawn(move || {
+            let result = scores
+                .iter()
+                .try_for_each(|score| submit(&endpoint, score))
+                .and_then(|_| fetch(&endpoint));
+
+            // The receiver is gone if another request has been made in the meantime.
+            let _ = sender.send(result);
+        });
+
+        self.response = Some(receiver);
+        self.top_scores = None;
+    }
+
+    /// Checks if the response has arrived; must be invoked every frame.
+    pub fn update(&mut self) {
+        if let Some(response) = &self.response {
+            if let Ok(result) = response.try_recv() {
+                match result {
+                    Ok(top_scores) => self.top_scores = Some(top_scores),
+                    Err(error) => eprintln!("Couldn't reach the online leaderboard: {}", error),
+                }
+                self.response = None;
+            }
+        }
+    }
+
+    pub fn top_scores(&self) -> Option<&[HighScore]> {
+        self.top_scores.as_deref()
+    }
+}
+
+fn submit(endpoint: &str, score: &HighScore) -> Result<(), String> {
+    let response = minreq::post(endpoint)
+        .with_header("Content-Type", "application/json")
+        .with_body(score.serialize_json())
+        .with_timeout(REQUEST_TIMEOUT_SECS)
+        .send()
+        .map_err(|error| error.to_string())?;
+
+    check_status(&response)
+}
+
+fn fetch(endpoint: &str) -> Result<Vec<HighScore>, String> {
+    let response = minreq::get(endpoint)
+        .with_timeout(REQUEST_TIMEOUT_SECS)
+        .send()
+        .map_err(|error| error.to_string())?;
+
+    check_status(&response)?;
+
+    let body = response.as_str().map_err(|error| error.to_string())?;
+    let mut top_scores =
+        Vec::<HighScore>::deserialize_json(body).map_err(|error| error.to_string())?;
+    top_scores.truncate(TOP_SIZE);
+
+    Ok(top_scores)
+}
+
+fn check_status(response: &minreq::Response) -> Result<(), String> {
+    if (200..300).contains(&response.status_code) {
+        Ok(())
+    } else {
+        Err(format!("HTTP status {}", response.status_code))
+    }
+}
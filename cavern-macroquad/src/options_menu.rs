@@ -0,0 +1,330 @@
+use macroquad::prelude::{
+    collections::storage, draw_rectangle, draw_rectangle_lines, Color, KeyCode, BLACK, GRAY, WHITE,
+};
+
+use crate::{
+    controls::{key_name, Action, ACTIONS},
+    drawing::{draw_game_text, draw_game_text_ex},
+    frame_input::{get_last_key_pressed, is_key_pressed},
+    resources::Resources,
+    settings::Settings,
+    sound_manager::SoundManager,
+    HEIGHT, WIDTH,
+};
+
+const DIM_COLOUR: Color = Color::new(BLACK.r, BLACK.g, BLACK.b, 0.8);
+
+const LABEL_X: i32 = 100;
+const VALUE_X: i32 = 460;
+
+// The items beyond this number don't fit the screen, so the list scrolls.
+const VISIBLE_ITEMS: usize = 11;
+const ITEM_SPACING: i32 = 34;
+
+/// Choices for the extra life score; zero disables the extra lives.
+const EXTRA_LIFE_SCORES: [i32; 4] = [0, 5000, 10000, 20000];
+
+/// Change of a volume (in percent) for each press of Left/Right.
+const VOLUME_STEP: i32 = 10;
+
+// Volume sliders
+const SLIDER_WIDTH: f32 = 200.;
+const SLIDER_HEIGHT: f32 = 16.;
+/// Offset from the top of the text line.
+const SLIDER_Y_OFFSET: f32 = 6.;
+
+#[derive(Clone, Copy, PartialEq)]
+enum OptionsItem {
+    /// Player index; selects a preset layout for all the actions.
+    Layout(usize),
+    /// Player index; selects the accessibility profile.
+    Profile(usize),
+    /// Player index, and the action bound.
+    Binding(usize, Action),
+    SpeedrunTimer,
+    Palette,
+    OrbWallBounces,
+    ChargeShot,
+    ExtraLifeScore,
+    ScreenShake,
+    Ghost,
+    MasterVolume,
+    MusicVolume,
+    SfxVolume,
+    Mute,
+    Back,
+}
+
+pub struct OptionsMenu {
+    selected: usize,
+    /// When set, the next key pressed is bound to the selected action.
+    rebinding: bool,
+}
+
+impl OptionsMenu {
+    pub fn new() -> Self {
+        Self {
+            selected: 0,
+            rebinding: false,
+        }
+    }
+
+    fn items(settings: &Settings) -> Vec<OptionsItem> {
+        let mut items = vec![];
+
+        for player_i in 0..settings.controls.len() {
+            items.push(OptionsItem::Layout(player_i));
+            items.push(OptionsItem::Profile(player_i));
+            items.extend(
+                ACTIONS
+                    .iter()
+                    .map(|(action, _)| OptionsItem::Binding(player_i, *action)),
+            );
+        }
+        items.push(OptionsItem::SpeedrunTimer);
+        items.push(OptionsItem::Palette);
+        items.push(OptionsItem::OrbWallBounces);
+        items.push(OptionsItem::ChargeShot);
+        items.push(OptionsItem::ExtraLifeScore);
+        items.push(OptionsItem::ScreenShake);
+        items.push(OptionsItem::Ghost);
+        items.push(OptionsItem::MasterVolume);
+        items.push(OptionsItem::MusicVolume);
+        items.push(OptionsItem::SfxVolume);
+        items.push(OptionsItem::Mute);
+        items.push(OptionsItem::Back);
+
+        items
+    }
+
+    /// Returns true when the player leaves the options screen; the settings are saved at that point.
+    pub fn update(&mut self, settings: &mut Settings) -> bool {
+        let items = Self::items(settings);
+
+        if self.rebinding {
+            if let Some(key) = get_last_key_pressed() {
+                // Esc cancels the rebinding; keys without a name can't be bound
+                if key != KeyCode::Escape && key_name(key).is_some() {
+                    if let OptionsItem::Binding(player_i, action) = items[self.selected] {
+                        *settings.controls[player_i].key_mut(action) = key;
+                    }
+                }
+                self.rebinding = false;
+            }
+
+            return false;
+        }
+
+        if is_key_pressed(KeyCode::Up) {
+            self.selected = (self.selected + items.len() - 1) % items.len();
+        } else if is_key_pressed(KeyCode::Down) {
+            self.selected = (self.selected + 1) % items.len();
+        } else if is_key_pressed(KeyCode::Left) || is_key_pressed(KeyCode::Right) {
+            let step = if is_key_pressed(KeyCode::Left) {
+                -VOLUME_STEP
+            } else {
+                VOLUME_STEP
+            };
+
+            // Only the sliders are adjusted with Left/Right.
+            let volume = match items[self.selected] {
+                OptionsItem::MasterVolume => Some(&mut settings.master_volume),
+                OptionsItem::MusicVolume => Some(&mut settings.music_volume),
+                OptionsItem::SfxVolume => Some(&mut settings.sfx_volume),
+                _ => None,
+            };
+
+            if let Some(volume) = volume {
+                *volume = (*volume + step).clamp(0, 100);
+                storage::get_mut::<SoundManager>().apply_settings(settings);
+            }
+        } else if is_key_pressed(KeyCode::Space) || is_key_pressed(KeyCode::Enter) {
+            match items[self.selected] {
+                OptionsItem::Layout(player_i) => {
+                    let controls = settings.controls[player_i].next_layout();
+
+                    // Players can't share the keys, so if the other player is using the layout, they swap.
+                    let previous = settings.controls[player_i];
+                    for other_controls in settings.controls.iter_mut() {
+                        if *other_controls == controls {
+                            *other_controls = previous;
+                        }
+                    }
+                    settings.controls[player_i] = controls;
+                }
+                OptionsItem::Profile(player_i) => {
+                    let profile = &mut settings.control_profiles[player_i];
+                    *profile = profile.next();
+                }
+                OptionsItem::Binding(..) => self.rebinding = true,
+                OptionsItem::SpeedrunTimer => settings.speedrun_timer = !settings.speedrun_timer,
+                OptionsItem::Palette => {
+                    settings.palette = settings.palette.next();
+                    storage::get_mut::<Resources>().set_palette(settings.palette);
+                }
+                OptionsItem::OrbWallBounces => {
+                    settings.orb_wall_bounces = !settings.orb_wall_bounces
+                }
+                OptionsItem::ChargeShot => settings.charge_shot = !settings.charge_shot,
+                OptionsItem::ExtraLifeScore => {
+                    let current_i = EXTRA_LIFE_SCORES
+                        .iter()
+                        .position(|score| *score == settings.extra_life_score);
+                    // A value not in the list (e.g. edited in the file) restarts from the first choice
+                    let next_i = current_i.map_or(0, |i| (i + 1) % EXTRA_LIFE_SCORES.len());
+                    settings.extra_life_score = EXTRA_LIFE_SCORES[next_i];
+                }
+                OptionsItem::ScreenShake => settings.screen_shake = !settings.screen_shake,
+                OptionsItem::Ghost => settings.ghost = !settings.ghost,
+                OptionsItem::MasterVolume | OptionsItem::MusicVolume | OptionsItem::SfxVolume => {}
+                OptionsItem::Mute => {
+                    settings.muted = !settings.muted;
+                    storage::get_mut::<SoundManager>().apply_settings(settings);
+                }
+                OptionsItem::Back => {
+                    settings.save();
+                    return true;
+                }
+            }
+        } else if is_key_pressed(KeyCode::Escape) {
+            settings.save();
+            return true;
+        }
+
+        false
+    }
+
+    pub fn draw(&self, settings: &Settings) {
+        draw_rectangle(0., 0., WIDTH as f32, HEIGHT as f32, DIM_COLOUR);
+
+        draw_game_text("OPTIONS", 10, None);
+
+        // The list scrolls once the selection goes past the last visible item.
+        let first_visible = self.selected.saturating_sub(VISIBLE_ITEMS - 1);
+
+        for (i, item) in Self::items(settings)
+            .into_iter()
+            .enumerate()
+            .skip(first_visible)
+            .take(VISIBLE_ITEMS)
+        {
+            let colour = if i == self.selected { WHITE } else { GRAY };
+            let y = 48 + ITEM_SPACING * (i - first_visible) as i32;
+
+            match item {
+                OptionsItem::Layout(player_i) => {
+                    let label = format!("P{} LAYOUT", player_i + 1);
+                    let value = settings.controls[player_i]
+                        .layout_name()
+                        .unwrap_or("CUSTOM");
+
+                    draw_game_text_ex(&label, y, Some(LABEL_X), colour);
+                    draw_game_text_ex(value, y, Some(VALUE_X), colour);
+                }
+                OptionsItem::Profile(player_i) => {
+                    let label = format!("P{} PROFILE", player_i + 1);
+                    let value = settings.control_profiles[player_i].name();
+
+                    draw_game_text_ex(&label, y, Some(LABEL_X), colour);
+                    draw_game_text_ex(value, y, Some(VALUE_X), colour);
+                }
+                OptionsItem::Binding(player_i, action) => {
+                    let (_, action_name) = ACTIONS.iter().find(|(a, _)| *a == action).unwrap();
+                    let label = format!("P{} {}", player_i + 1, action_name);
+
+                    let value = if self.rebinding && i == self.selected {
+                        "PRESS A KEY"
+                    } else {
+                        // The keys actually used are displayed, e.g. blow with the one handed profile.
+                        key_name(settings.player_controls(player_i).key(action)).unwrap_or("NONE")
+                    };
+
+                    draw_game_text_ex(&label, y, Some(LABEL_X), colour);
+                    draw_game_text_ex(value, y, Some(VALUE_X), colour);
+                }
+                OptionsItem::SpeedrunTimer => {
+                    let value = if settings.speedrun_timer { "ON" } else { "OFF" };
+
+                    draw_game_text_ex("SPEEDRUN TIMER", y, Some(LABEL_X), colour);
+                    draw_game_text_ex(value, y, Some(VALUE_X), colour);
+                }
+                OptionsItem::Palette => {
+                    draw_game_text_ex("PALETTE", y, Some(LABEL_X), colour);
+                    draw_game_text_ex(settings.palette.name(), y, Some(VALUE_X), colour);
+                }
+                OptionsItem::OrbWallBounces => {
+                    let value = if settings.orb_wall_bounces {
+                        "ON"
+                    } else {
+                        "OFF"
+                    };
+
+                    draw_game_text_ex("ORB BOUNCES", y, Some(LABEL_X), colour);
+                    draw_game_text_ex(value, y, Some(VALUE_X), colour);
+                }
+                OptionsItem::ChargeShot => {
+                    let value = if settings.charge_shot { "ON" } else { "OFF" };
+
+                    draw_game_text_ex("CHARGE SHOT", y, Some(LABEL_X), colour);
+                    draw_game_text_ex(value, y, Some(VALUE_X), colour);
+                }
+                OptionsItem::ExtraLifeScore => {
+                    let value = if settings.extra_life_score > 0 {
+                        settings.extra_life_score.to_string()
+                    } else {
+                        "OFF".to_string()
+                    };
+
+                    draw_game_text_ex("EXTRA LIFE", y, Some(LABEL_X), colour);
+                    draw_game_text_ex(&value, y, Some(VALUE_X), colour);
+                }
+                OptionsItem::ScreenShake => {
+                    let value = if settings.screen_shake { "ON" } else { "OFF" };
+
+                    draw_game_text_ex("SCREEN SHAKE", y, Some(LABEL_X), colour);
+                    draw_game_text_ex(value, y, Some(VALUE_X), colour);
+                }
+                OptionsItem::Ghost => {
+                    let value = if settings.ghost { "ON" } else { "OFF" };
+
+                    draw_game_text_ex("GHOST", y, Some(LABEL_X), colour);
+                    draw_game_text_ex(value, y, Some(VALUE_X), colour);
+                }
+                OptionsItem::MasterVolume => {
+                    draw_game_text_ex("MASTER VOLUME", y, Some(LABEL_X), colour);
+                    draw_slider(settings.master_volume, y, colour);
+                }
+                OptionsItem::MusicVolume => {
+                    draw_game_text_ex("MUSIC VOLUME", y, Some(LABEL_X), colour);
+                    draw_slider(settings.music_volume, y, colour);
+                }
+                OptionsItem::SfxVolume => {
+                    draw_game_text_ex("SFX VOLUME", y, Some(LABEL_X), colour);
+                    draw_slider(settings.sfx_volume, y, colour);
+                }
+                OptionsItem::Mute => {
+                    let value = if settings.muted { "ON" } else { "OFF" };
+
+                    draw_game_text_ex("MUTE", y, Some(LABEL_X), colour);
+                    draw_game_text_ex(value, y, Some(VALUE_X), colour);
+                }
+                OptionsItem::Back => {
+                    draw_game_text_ex("BACK", y, None, colour);
+                }
+            }
+        }
+
+        if !self.rebinding {
+            draw_game_text("ESC TO GO BACK", 440, None);
+        }
+    }
+}
+
+/// Draws a horizontal bar, filled according to the volume (in percent).
+fn draw_slider(volume: i32, y: i32, colour: Color) {
+    let (x, y) = (VALUE_X as f32, y as f32 + SLIDER_Y_OFFSET);
+    let filled_width = SLIDER_WIDTH * volume as f32 / 100.;
+
+    draw_rectangle(x, y, filled_width, SLIDER_HEIGHT, colour);
+    draw_rectangle_lines(x, y, SLIDER_WIDTH, SLIDER_HEIGHT, 2., colour);
+}
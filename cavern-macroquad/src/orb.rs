@@ -1,40 +1,54 @@
-use std::{
-    cell::RefCell,
-    rc::{Rc, Weak},
-};
-
 use macroquad::{
     prelude::{collections::storage, Texture2D},
     rand::gen_range,
 };
 
+use cavern_core::{
+    orb::{self, OrbImage},
+    CollideBody, OrbBody,
+};
+
 use crate::{
-    actor::{Actor, Anchor},
+    actor::{image_size, Actor, Anchor, Body},
     bolt::Bolt,
-    collide_actor::CollideActor,
+    entity_store::EntityStore,
     fruit::Fruit,
-    game_playback::play_game_random_sound,
+    game_playback::play_game_random_sound_at,
+    particle::{self, Particle},
+    physics::Physics,
     player::Player,
     pop::Pop,
     resources::Resources,
     robot::RobotType,
+    ticks::Ticks,
 };
 
-const MAX_TIMER: i32 = 250;
+const MAX_TIMER: Ticks = Ticks::new(orb::MAX_TIMER);
+const INITIAL_BLOWN_FRAMES: Ticks = Ticks::new(orb::INITIAL_BLOWN_FRAMES);
 
-pub type RcOrb = Rc<RefCell<Orb>>;
-pub type WkOrb = Weak<RefCell<Orb>>;
+/// Number of wall bounces of each orb, when the option is enabled.
+pub const MAX_WALL_BOUNCES: i32 = 3;
+/// Horizontal speed of a bounced orb, while rising.
+const DRIFT_SPEED: i32 = 1;
 
 #[derive(Clone, Copy)]
 
 pub struct Orb {
     pub direction_x: i32,
-    pub timer: i32,
+    pub timer: Ticks,
     pub floating: bool,
-    /// Number of frames during which we will be pushed horizontally
-    pub blown_frames: i32,
+    /// Time during which we will be pushed horizontally
+    pub blown_frames: Ticks,
     /// Type of enemy trapped in this bubble
     pub trapped_enemy_type: Option<RobotType>,
+    /// Rust: Not in the original game. Remaining wall bounces; zero unless the option is enabled.
+    pub bounces_left: i32,
+    /// Rust: Not in the original game. Bounced orbs keep moving sideways while rising.
+    pub drifting: bool,
+    /// Rust: Not in the original game. Added to the rise speed, and to the lifetime, of charged orbs; zero unless
+    /// the charge shot option is enabled.
+    pub rise_bonus: i32,
+    pub extra_lifetime: Ticks,
 
     // Actor trait
     pub x: i32,
@@ -47,10 +61,14 @@ impl Orb {
     pub fn new(x: i32, y: i32, direction_x: i32) -> Self {
         Self {
             direction_x, // Orbs are initially blown horizontally, then start floating upwards
-            timer: -1,
+            timer: Ticks::new(-1),
             floating: false,
-            blown_frames: 6,
+            blown_frames: INITIAL_BLOWN_FRAMES,
             trapped_enemy_type: None,
+            bounces_left: 0,
+            drifting: false,
+            rise_bonus: 0,
+            extra_lifetime: Ticks::ZERO,
             x,
             y,
             image: storage::get::<Resources>().blank_texture,
@@ -62,65 +80,88 @@ impl Orb {
         // Check for collision with a bolt
         let collided = self.collidepoint((bolt.x, bolt.y));
         if collided {
-            self.timer = MAX_TIMER - 1;
+            self.pop();
         }
         collided
     }
 
+    /// Popped orbs are removed at the end of the game update.
+    pub fn is_popped(&self) -> bool {
+        // The extra lifetime is discounted, so that the original lifetime check applies
+        orb::is_popped((self.timer - self.extra_lifetime).get(), self.y)
+    }
+
+    /// Traps an enemy; trapped orbs stop drifting, and rise straight up.
+    pub fn trap(&mut self, enemy_type: RobotType) {
+        self.floating = true;
+        self.trapped_enemy_type = Some(enemy_type);
+        self.drifting = false;
+    }
+
+    /// Invoked when hitting a wall; if there are bounces left, the direction is reversed, otherwise the orb stops
+    /// moving sideways.
+    fn bounce(&mut self) {
+        if self.bounces_left > 0 {
+            self.bounces_left -= 1;
+            self.direction_x = -self.direction_x;
+            self.drifting = true;
+        } else {
+            self.drifting = false;
+        }
+    }
+
+    /// Makes the orb pop on the next update.
+    pub fn pop(&mut self) {
+        self.timer = MAX_TIMER + self.extra_lifetime - Ticks::new(1);
+    }
+
     pub fn update(
         &mut self,
-        fruits: &mut Vec<Fruit>,
-        pops: &mut Vec<Pop>,
+        fruits: &mut EntityStore<Fruit>,
+        pops: &mut EntityStore<Pop>,
+        particles: &mut Vec<Particle>,
         player: Option<&Player>,
-        grid: &[&str],
+        grid: &[String],
     ) {
-        self.timer += 1;
+        self.timer.increment();
 
-        if self.floating {
-            // Float upwards
-            self.move_(0, -1, gen_range(1, 3), grid);
-        } else {
-            // Move horizontally
-            if self.move_(self.direction_x, 0, 4, grid) {
-                // If we hit a block, start floating
-                self.floating = true;
-            }
+        let was_floating = self.floating;
+        let orb_speed = storage::get::<Physics>().orb_speed;
+        let rise_bonus = self.rise_bonus;
+
+        if self.step(|| gen_range(1, 3) + rise_bonus, orb_speed, grid) {
+            self.bounce();
+        }
+
+        if was_floating && self.drifting && self.move_(self.direction_x, 0, DRIFT_SPEED, grid) {
+            self.bounce();
         }
 
         if self.timer == self.blown_frames {
             self.floating = true;
-        } else if self.timer >= MAX_TIMER || self.y <= -40 {
+        } else if self.is_popped() {
             // Pop if our lifetime has run out or if we have gone off the top of the screen
-            pops.push(Pop::new(self.x, self.y, 1));
+            pops.insert(Pop::new(self.x, self.y, 1));
+            particle::emit_sparkles(particles, (self.x, self.y));
             if let Some(trapped_enemy_type) = self.trapped_enemy_type {
                 // trapped_enemy_type is either zero or one. A value of one means there's a chance of creating a
                 // powerup such as an extra life or extra health
-                fruits.push(Fruit::new(self.x, self.y, Some(trapped_enemy_type)));
+                fruits.insert(Fruit::new(self.x, self.y, Some(trapped_enemy_type)));
             }
-            play_game_random_sound(player, &storage::get::<Resources>().pop_sounds);
+            play_game_random_sound_at(player, |sounds| &sounds.pop_sounds, self.x);
         }
 
         let resources = storage::get::<Resources>();
+        let trapped_enemy_type = self.trapped_enemy_type.map(|enemy_type| enemy_type.val());
 
-        if self.timer < 9 {
-            // Orb grows to full size over the course of 9 frames - the animation frame updating every 3 frames
-            let timer_factor = self.timer / 3;
-            self.image = resources.orb_textures[timer_factor as usize];
-        } else {
-            if let Some(trapped_enemy_type) = self.trapped_enemy_type {
-                let enemy_type_factor = trapped_enemy_type.val() * 8;
-                let timer_factor = (self.timer / 4) % 8;
-                let image_i = (enemy_type_factor + timer_factor) as usize;
-                self.image = resources.trap_textures[image_i];
-            } else {
-                let timer_factor = 3 + (((self.timer - 9) / 8) % 4);
-                self.image = resources.orb_textures[timer_factor as usize];
-            }
-        }
+        self.image = match orb::image(self.timer.get(), trapped_enemy_type) {
+            OrbImage::Orb(image_i) => resources.orb_textures[image_i],
+            OrbImage::Trap(image_i) => resources.trap_textures[image_i],
+        };
     }
 }
 
-impl Actor for Orb {
+impl Body for Orb {
     fn x(&self) -> i32 {
         self.x
     }
@@ -137,13 +178,33 @@ impl Actor for Orb {
         &mut self.y
     }
 
-    fn image(&self) -> macroquad::prelude::Texture2D {
-        self.image
+    fn size(&self) -> (i32, i32) {
+        image_size(&self.image)
     }
 
-    fn anchor(&self) -> crate::actor::Anchor {
+    fn anchor(&self) -> Anchor {
         self.anchor
     }
 }
 
-impl CollideActor for Orb {}
+impl Actor for Orb {
+    fn image(&self) -> Texture2D {
+        self.image
+    }
+}
+
+impl CollideBody for Orb {}
+
+impl OrbBody for Orb {
+    fn direction_x(&self) -> i32 {
+        self.direction_x
+    }
+
+    fn floating(&self) -> bool {
+        self.floating
+    }
+
+    fn floating_mut(&mut self) -> &mut bool {
+        &mut self.floating
+    }
+}
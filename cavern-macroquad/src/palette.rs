@@ -0,0 +1,36 @@
+use macroquad::prelude::Color;
+use nanoserde::{DeRon, SerRon};
+
+// Rust: Not in the original game. The colourblind palette is based on the Okabe-Ito colours, which are
+// distinguishable with the common forms of colour blindness.
+
+pub const SKY_BLUE: Color = Color::new(0.34, 0.71, 0.91, 1.0);
+pub const YELLOW: Color = Color::new(0.94, 0.89, 0.26, 1.0);
+pub const VERMILLION: Color = Color::new(0.84, 0.37, 0.0, 1.0);
+pub const REDDISH_PURPLE: Color = Color::new(0.8, 0.47, 0.65, 1.0);
+pub const BLUISH_GREEN: Color = Color::new(0.0, 0.62, 0.45, 1.0);
+pub const ORANGE: Color = Color::new(0.9, 0.62, 0.0, 1.0);
+
+#[derive(Clone, Copy, Default, PartialEq, DeRon, SerRon)]
+pub enum Palette {
+    #[default]
+    Standard,
+    /// Orbs, bolts and fruits are recoloured with high contrast colours.
+    Colourblind,
+}
+
+impl Palette {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Palette::Standard => "STANDARD",
+            Palette::Colourblind => "COLOURBLIND",
+        }
+    }
+
+    pub fn next(&self) -> Self {
+        match self {
+            Palette::Standard => Palette::Colourblind,
+            Palette::Colourblind => Palette::Standard,
+        }
+    }
+}
@@ -0,0 +1,89 @@
+use std::{
+    f32::consts::{FRAC_PI_4, SQRT_2},
+    io::Cursor,
+};
+
+use lewton::inside_ogg::OggStreamReader;
+use macroquad::audio::Sound;
+
+use crate::{asset_loader::wav, WIDTH};
+
+/// Pan positions (-1 = left, 1 = right) of the variants of the positional sounds; the centre one is the original
+/// sound.
+pub const PAN_POSITIONS: [f32; 5] = [-1., -0.5, 0., 0.5, 1.];
+
+/// At the edges of the screen, the volume is reduced by this fraction.
+const EDGE_ATTENUATION: f32 = 0.4;
+
+// Rust: Not in the original game. The effects emitted by the actors are panned according to their position. Since
+// Macroquad doesn't support panning, each positional sound is decoded on load, and stored as a few variants with
+// the panning applied; on playback, the closest one is used.
+
+/// A positional sound, and its variants, in the order of `PAN_POSITIONS`.
+pub struct PannedSound {
+    pub sound: Sound,
+    pub variants: Vec<Sound>,
+}
+
+impl PannedSound {
+    pub fn variant(&self, pan: f32) -> Sound {
+        let variant_i = ((pan + 1.) / 2. * (PAN_POSITIONS.len() - 1) as f32).round() as usize;
+        self.variants[variant_i.min(PAN_POSITIONS.len() - 1)]
+    }
+}
+
+/// Pan position of a sound emitted at the given horizontal position.
+pub fn pan(x: i32) -> f32 {
+    let half_width = WIDTH as f32 / 2.;
+    ((x as f32 - half_width) / half_width).clamp(-1., 1.)
+}
+
+/// Volume factor of a sound, given its pan; the farther from the centre of the screen, the quieter.
+pub fn attenuation(pan: f32) -> f32 {
+    1. - EDGE_ATTENUATION * pan.abs()
+}
+
+/// Decodes an Ogg Vorbis file, and returns, for each pan position, the sound with the panning applied, as WAV
+/// file (16 bit stereo). The sound is downmixed to mono first.
+pub fn panned_variants(ogg_bytes: &[u8]) -> Result<Vec<Vec<u8>>, String> {
+    let mut reader =
+        OggStreamReader::new(Cursor::new(ogg_bytes)).map_err(|error| error.to_string())?;
+    let channels = reader.ident_hdr.audio_channels as usize;
+    let sample_rate = reader.ident_hdr.audio_sample_rate;
+
+    let mut mono_samples = vec![];
+
+    while let Some(samples) = reader
+        .read_dec_packet_itl()
+        .map_err(|error| error.to_string())?
+    {
+        mono_samples.extend(samples.chunks(channels).map(|frame| {
+            let sum = frame.iter().map(|sample| *sample as i32).sum::<i32>();
+            (sum / channels as i32) as i16
+        }));
+    }
+
+    let variants = PAN_POSITIONS
+        .iter()
+        .map(|pan| {
+            // Equal power panning, scaled so that the centre keeps the original volume.
+            let angle = (pan + 1.) * FRAC_PI_4;
+            let (left_gain, right_gain) = (
+                (angle.cos() * SQRT_2).min(1.),
+                (angle.sin() * SQRT_2).min(1.),
+            );
+
+            let stereo_samples = mono_samples
+                .iter()
+                .flat_map(|sample| {
+                    let sample = *sample as f32;
+                    [(sample * left_gain) as i16, (sample * right_gain) as i16]
+                })
+                .collect::<Vec<_>>();
+
+            wav(sample_rate, 2, &stereo_samples)
+        })
+        .collect();
+
+    Ok(variants)
+}
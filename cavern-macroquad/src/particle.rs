@@ -0,0 +1,124 @@
+use std::f32::consts::TAU;
+
+use macroquad::{
+    prelude::{draw_rectangle, Color},
+    rand::gen_range,
+};
+
+// Rust: Not in the original game. Particles are purely visual: they don't interact with the other objects.
+
+const SPARKLE_COLOUR: Color = Color::new(1.0, 1.0, 0.75, 1.0);
+const PUFF_COLOUR: Color = Color::new(0.85, 0.85, 0.85, 1.0);
+const DEBRIS_COLOUR: Color = Color::new(1.0, 0.55, 0.2, 1.0);
+
+pub struct Particle {
+    x: f32,
+    y: f32,
+    vel_x: f32,
+    vel_y: f32,
+    gravity: f32,
+    size: f32,
+    colour: Color,
+    timer: i32,
+    lifetime: i32,
+}
+
+impl Particle {
+    pub fn is_alive(&self) -> bool {
+        self.timer < self.lifetime
+    }
+
+    pub fn update(&mut self) {
+        self.timer += 1;
+        self.vel_y += self.gravity;
+        self.x += self.vel_x;
+        self.y += self.vel_y;
+    }
+
+    /// Particles fade out over their lifetime.
+    pub fn draw(&self) {
+        let alpha = 1. - self.timer as f32 / self.lifetime as f32;
+        let colour = Color::new(self.colour.r, self.colour.g, self.colour.b, alpha);
+
+        draw_rectangle(
+            self.x - self.size / 2.,
+            self.y - self.size / 2.,
+            self.size,
+            self.size,
+            colour,
+        );
+    }
+}
+
+fn emit_radial(
+    particles: &mut Vec<Particle>,
+    (x, y): (i32, i32),
+    count: usize,
+    speed: (f32, f32),
+    gravity: f32,
+    size: f32,
+    colour: Color,
+    lifetime: (i32, i32),
+) {
+    for _ in 0..count {
+        let angle = gen_range(0., TAU);
+        let speed = gen_range(speed.0, speed.1);
+
+        particles.push(Particle {
+            x: x as f32,
+            y: y as f32,
+            vel_x: angle.cos() * speed,
+            vel_y: angle.sin() * speed,
+            gravity,
+            size,
+            colour,
+            timer: 0,
+            lifetime: gen_range(lifetime.0, lifetime.1 + 1),
+        });
+    }
+}
+
+/// When an orb pops.
+pub fn emit_sparkles(particles: &mut Vec<Particle>, position: (i32, i32)) {
+    emit_radial(
+        particles,
+        position,
+        12,
+        (1.5, 3.5),
+        0.,
+        3.,
+        SPARKLE_COLOUR,
+        (15, 25),
+    );
+}
+
+/// When an enemy is trapped in an orb.
+pub fn emit_puff(particles: &mut Vec<Particle>, position: (i32, i32)) {
+    emit_radial(
+        particles,
+        position,
+        8,
+        (0.5, 1.5),
+        -0.05,
+        6.,
+        PUFF_COLOUR,
+        (20, 30),
+    );
+}
+
+/// When a bolt hits a wall; the debris bounces back, in the opposite direction of the bolt.
+pub fn emit_debris(particles: &mut Vec<Particle>, (x, y): (i32, i32), direction_x: i32) {
+    for _ in 0..6 {
+        particles.push(Particle {
+            x: x as f32,
+            y: y as f32,
+            vel_x: -direction_x as f32 * gen_range(1., 3.),
+            vel_y: gen_range(-3., -1.),
+            gravity: 0.25,
+            size: 3.,
+            colour: DEBRIS_COLOUR,
+            timer: 0,
+            lifetime: gen_range(15, 25 + 1),
+        });
+    }
+}
@@ -0,0 +1,63 @@
+use macroquad::prelude::{draw_rectangle, Color, KeyCode, BLACK, GRAY, WHITE};
+
+use crate::{
+    drawing::{draw_game_text, draw_game_text_ex},
+    frame_input::is_key_pressed,
+    HEIGHT, WIDTH,
+};
+
+const DIM_COLOUR: Color = Color::new(BLACK.r, BLACK.g, BLACK.b, 0.6);
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum PauseMenuItem {
+    Resume,
+    Restart,
+    SaveAndQuit,
+    Quit,
+}
+
+const ITEMS: [(PauseMenuItem, &str); 4] = [
+    (PauseMenuItem::Resume, "RESUME"),
+    (PauseMenuItem::Restart, "RESTART"),
+    (PauseMenuItem::SaveAndQuit, "SAVE AND QUIT"),
+    (PauseMenuItem::Quit, "QUIT"),
+];
+
+pub struct PauseMenu {
+    selected: usize,
+}
+
+impl PauseMenu {
+    pub fn new() -> Self {
+        Self { selected: 0 }
+    }
+
+    /// Returns the item chosen by the player, if any. Pressing Esc again is a shortcut for resuming.
+    pub fn update(&mut self) -> Option<PauseMenuItem> {
+        if is_key_pressed(KeyCode::Escape) {
+            return Some(PauseMenuItem::Resume);
+        }
+
+        if is_key_pressed(KeyCode::Up) {
+            self.selected = (self.selected + ITEMS.len() - 1) % ITEMS.len();
+        } else if is_key_pressed(KeyCode::Down) {
+            self.selected = (self.selected + 1) % ITEMS.len();
+        } else if is_key_pressed(KeyCode::Space) || is_key_pressed(KeyCode::Enter) {
+            return Some(ITEMS[self.selected].0);
+        }
+
+        None
+    }
+
+    pub fn draw(&self) {
+        // Dim the (frozen) game in the background
+        draw_rectangle(0., 0., WIDTH as f32, HEIGHT as f32, DIM_COLOUR);
+
+        draw_game_text("PAUSED", 120, None);
+
+        for (i, (_, text)) in ITEMS.iter().enumerate() {
+            let colour = if i == self.selected { WHITE } else { GRAY };
+            draw_game_text_ex(text, 220 + 50 * i as i32, None, colour);
+        }
+    }
+}
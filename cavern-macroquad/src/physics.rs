@@ -0,0 +1,98 @@
+use std::fs;
+
+use macroquad::file::load_string;
+use nanoserde::{DeRon, SerRon};
+
+use crate::ticks::Ticks;
+
+// Rust: Not in the original game, where these values are hardcoded. They're loaded at startup, so that the movement
+// can be tuned without recompiling; values missing from the file take the original value.
+
+pub const PHYSICS_FILE: &str = "resources/physics.ron";
+
+/// Speeds are in pixels per update. The field attributes must match `Default`, since Nanoserde doesn't use it for the
+/// missing fields.
+#[derive(DeRon, SerRon)]
+pub struct Physics {
+    #[nserde(default = 1)]
+    pub gravity: i32,
+    #[nserde(default = 10)]
+    pub max_fall_speed: i32,
+    #[nserde(default = "-16")]
+    pub jump_velocity: i32,
+    #[nserde(default = 4)]
+    pub walk_speed: i32,
+    /// With the speed boost power up.
+    #[nserde(default = 6)]
+    pub boosted_walk_speed: i32,
+    /// Vertical velocity, and horizontal speed, of the player when knocked back by a hit.
+    #[nserde(default = "-12")]
+    pub knockback_velocity: i32,
+    #[nserde(default = 4)]
+    pub knockback_speed: i32,
+    /// The player can't move for this time after blowing an orb.
+    #[nserde(default = "Ticks::new(10)")]
+    pub blow_move_hold_off: Ticks,
+    /// Minimum time between orbs.
+    #[nserde(default = "Ticks::new(20)")]
+    pub blow_hold_off: Ticks,
+    /// With the rapid fire power up.
+    #[nserde(default = "Ticks::new(10)")]
+    pub rapid_blow_hold_off: Ticks,
+    #[nserde(default = 4)]
+    pub orb_speed: i32,
+    /// Frames added to the orb travel, for each frame blow is held.
+    #[nserde(default = "Ticks::new(4)")]
+    pub orb_blow_increment: Ticks,
+    #[nserde(default = "Ticks::new(120)")]
+    pub max_orb_blown_frames: Ticks,
+    /// The player is knocked back while the hurt timer is above the invulnerability time.
+    #[nserde(default = "Ticks::new(200)")]
+    pub hurt_time: Ticks,
+    #[nserde(default = "Ticks::new(100)")]
+    pub invulnerable_time: Ticks,
+}
+
+impl Default for Physics {
+    fn default() -> Self {
+        Self {
+            gravity: 1,
+            max_fall_speed: 10,
+            jump_velocity: -16,
+            walk_speed: 4,
+            boosted_walk_speed: 6,
+            knockback_velocity: -12,
+            knockback_speed: 4,
+            blow_move_hold_off: Ticks::new(10),
+            blow_hold_off: Ticks::new(20),
+            rapid_blow_hold_off: Ticks::new(10),
+            orb_speed: 4,
+            orb_blow_increment: Ticks::new(4),
+            max_orb_blown_frames: Ticks::new(120),
+            hurt_time: Ticks::new(200),
+            invulnerable_time: Ticks::new(100),
+        }
+    }
+}
+
+impl Physics {
+    /// If the file is missing or invalid, the original values are used.
+    pub async fn load() -> Self {
+        Self::from_content(load_string(PHYSICS_FILE).await.ok())
+    }
+
+    /// Loads without the engine (see `Resources::headless()`).
+    pub fn load_headless() -> Self {
+        Self::from_content(fs::read_to_string(PHYSICS_FILE).ok())
+    }
+
+    fn from_content(content: Option<String>) -> Self {
+        match content {
+            Some(content) => Self::deserialize_ron(&content).unwrap_or_else(|error| {
+                eprintln!("Invalid physics file; using the defaults: {:?}", error);
+                Self::default()
+            }),
+            None => Self::default(),
+        }
+    }
+}
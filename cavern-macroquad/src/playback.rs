@@ -0,0 +1,106 @@
+use macroquad::{prelude::WHITE, rand::srand};
+
+use crate::{
+    game::Game, global_state::LEVEL_TRANSITION_DURATION, input_log::InputLog,
+    input_state::ScriptedInput, player::Player, WIDTH,
+};
+
+// Rust: Not in the original game. Plays back a single player game from its input log; used by the demo, by the
+// replay of the last run, and by the replay verification. The playback only depends on the game logic, so it can
+// run without the window (see `verify`).
+
+pub struct Playback {
+    /// Frames left before the playback ends.
+    timer: i32,
+    /// Frames left before the next level starts.
+    transition_timer: i32,
+    /// Score expected at the end of the playback, if known.
+    final_score: Option<i32>,
+    /// Whether the playback goes through the level transitions, like the recorded game.
+    level_transitions: bool,
+}
+
+impl Playback {
+    /// Starts the game played back from the log.
+    pub fn start(log: &InputLog) -> (Self, Game) {
+        srand(log.seed);
+
+        let mut player = Player::new(Box::new(ScriptedInput::new(log.states())), WIDTH / 2, WHITE);
+        player.lives = log.difficulty.starting_lives();
+
+        let mut game = Game::new(vec![player], log.difficulty, log.mode);
+        game.orb_wall_bounces = log.orb_wall_bounces;
+        game.charge_shot = log.charge_shot;
+        game.extra_life_score = log.extra_life_score;
+
+        let playback = Self {
+            timer: log.frames() as i32,
+            transition_timer: 0,
+            final_score: log.final_score,
+            level_transitions: log.level_transitions,
+        };
+
+        (playback, game)
+    }
+
+    pub fn is_over(&self, game: &Game) -> bool {
+        game.is_over() || self.timer <= 0
+    }
+
+    /// Plays back one update of the log. The level transitions are reproduced like in
+    /// `GlobalState::update_level_transition()`: the game is updated during the countdown, except on the frame when
+    /// the next level starts, which doesn't consume any input. The logs recorded without level transitions start the
+    /// next level immediately.
+    pub fn update(&mut self, game: &mut Game) {
+        if !self.level_transitions {
+            self.timer -= 1;
+            game.update();
+
+            if game.is_level_complete() {
+                game.next_level();
+            }
+            return;
+        }
+
+        if self.transition_timer > 0 {
+            self.transition_timer -= 1;
+
+            if self.transition_timer <= 0 {
+                game.next_level();
+                return;
+            }
+        }
+
+        self.timer -= 1;
+        game.update();
+
+        if self.transition_timer == 0 && game.is_level_complete() {
+            self.transition_timer = LEVEL_TRANSITION_DURATION;
+        }
+    }
+
+    /// Checks the score reached against the recorded one, if known; returns the score reached.
+    pub fn check_final_score(&self, game: &Game) -> Result<i32, String> {
+        let score = game.players[0].score;
+
+        match self.final_score {
+            Some(final_score) if final_score != score => Err(format!(
+                "Final score mismatch: expected {}, got {}",
+                final_score, score
+            )),
+            _ => Ok(score),
+        }
+    }
+}
+
+/// Replays the given log as fast as possible, and checks the final score; used to verify that changes to the game
+/// logic preserve the replays. Requires the headless resources to be stored (see `Resources::headless()`).
+pub fn verify(log: &InputLog) -> Result<i32, String> {
+    let (mut playback, mut game) = Playback::start(log);
+
+    while !playback.is_over(&game) {
+        playback.update(&mut game);
+    }
+
+    playback.check_final_score(&game)
+}
@@ -1,34 +1,65 @@
-use std::{
-    cell::RefCell,
-    rc::{Rc, Weak},
+use macroquad::prelude::{
+    collections::storage, draw_rectangle, draw_texture, Color, Texture2D, YELLOW,
 };
 
-use macroquad::prelude::{collections::storage, is_key_down, is_key_pressed, KeyCode, Texture2D};
+use cavern_core::{gravity::GRAVITY_BODY_DEFAULT_ANCHOR, CollideBody, GravityBody};
 
 use crate::{
-    actor::{Actor, Anchor},
+    actor::{image_size, Actor, Anchor, Body},
+    animation::Animation,
+    blow_params::BlowParams,
     bolt::Bolt,
-    collide_actor::CollideActor,
-    game_playback::{play_game_random_sound, play_game_sound},
-    gravity_actor::{GravityActor, GRAVITY_ACTOR_DEFAULT_ANCHOR},
-    orb::{Orb, RcOrb, WkOrb},
+    checkpoint::Checkpoint,
+    entity_store::{EntityId, EntityStore},
+    game_playback::{play_game_random_sound_at, play_game_sound, play_game_sound_at},
+    gravity_actor::GravityActor,
+    input_state::{InputSource, InputState},
+    orb::Orb,
+    physics::Physics,
+    power_up::{PowerUp, POWER_UP_DURATION},
     resources::Resources,
-    HEIGHT, WIDTH,
+    ticks::Ticks,
+    HEIGHT,
 };
 
+/// Duration of the lives indicator flashing, after an extra life is awarded.
+const EXTRA_LIFE_FLASH_TIME: Ticks = Ticks::from_secs(2);
+/// Invulnerability after a hit absorbed by the shield.
+const SHIELD_INVULNERABLE_TIME: Ticks = Ticks::from_secs(1);
+
+// Charge indicator, drawn above the player.
+const CHARGE_BAR_WIDTH: f32 = 30.;
+const CHARGE_BAR_HEIGHT: f32 = 4.;
+const CHARGE_BAR_SPACING: f32 = 4.;
+const CHARGE_BAR_BACKGROUND: Color = Color::new(0., 0., 0., 0.5);
+
 pub struct Player {
+    pub input_source: Box<dyn InputSource>,
+    /// Horizontal position where the player (re)spawns; players in a co-op game start apart.
+    pub start_x: i32,
+    pub colour: Color,
     pub lives: i32,
     pub score: i32,
     pub direction_x: i32, // -1 = left, 1 = right
-    pub fire_timer: i32,
-    pub hurt_timer: i32,
+    pub fire_timer: Ticks,
+    pub hurt_timer: Ticks,
     pub health: i32,
-    /// There are different approaches to keeping a reference to the blowing orb (in the existing Orbs
-    /// array). This is the simplest in terms of management (although it makes access verbose). An alternative
-    /// is to store here the index instead; it's considerably less verbose, but it introduces manual
-    /// management and coupling (when an Orb is removed, this reference to be updated accordingly).
-    /// There's not best solution, but a compromise.
-    pub blowing_orb: WkOrb,
+    // Rust: Frames left for each temporary power up (not in the original game).
+    pub shield_timer: Ticks,
+    pub rapid_fire_timer: Ticks,
+    pub speed_boost_timer: Ticks,
+    /// Id of the blowing orb, in the game's orbs store. Once the orb is removed, the id doesn't resolve anymore,
+    /// so there's no need to keep it in sync.
+    pub blowing_orb: Option<EntityId>,
+    /// Rust: Charge of the next orb, while blow is held with the charge shot option (not in the original game).
+    pub charge: Option<Ticks>,
+    pub animation: Animation,
+    /// Rust: Last checkpoint touched in the current level, if any (not in the original game).
+    pub checkpoint: Option<Checkpoint>,
+    /// Rust: Extra lives awarded for the score thresholds crossed, and frames left of the lives indicator flashing
+    /// (not in the original game).
+    pub extra_lives_awarded: i32,
+    pub extra_life_timer: Ticks,
 
     // Actor trait
     pub x: i32,
@@ -42,67 +73,157 @@ pub struct Player {
 }
 
 impl Player {
-    pub fn new() -> Self {
+    pub fn new(input_source: Box<dyn InputSource>, start_x: i32, colour: Color) -> Self {
         Self {
+            input_source,
+            start_x,
+            colour,
             lives: 2,
             score: 0,
             direction_x: 0,
-            fire_timer: 0,
-            hurt_timer: 0,
+            fire_timer: Ticks::ZERO,
+            hurt_timer: Ticks::ZERO,
             health: 0,
-            blowing_orb: Weak::new(),
+            shield_timer: Ticks::ZERO,
+            rapid_fire_timer: Ticks::ZERO,
+            speed_boost_timer: Ticks::ZERO,
+            blowing_orb: None,
+            charge: None,
+            animation: Animation::default(),
+            checkpoint: None,
+            extra_lives_awarded: 0,
+            extra_life_timer: Ticks::ZERO,
 
             x: 0,
             y: 0,
             image: storage::get::<Resources>().blank_texture,
-            anchor: GRAVITY_ACTOR_DEFAULT_ANCHOR,
+            anchor: GRAVITY_BODY_DEFAULT_ANCHOR,
 
             vel_y: 0,
             landed: false,
         }
     }
 
+    /// A player is out of the game once they've lost their last life; in co-op, the other player
+    /// carries on alone.
+    pub fn is_active(&self) -> bool {
+        self.lives >= 0
+    }
+
     pub fn reset(&mut self) {
-        self.x = WIDTH / 2;
+        self.x = self.start_x;
         self.y = 100;
         self.vel_y = 0;
         self.direction_x = 1; // -1 = left, 1 = right
-        self.fire_timer = 0;
-        self.hurt_timer = 100; // Invulnerable for this many frames
+        self.fire_timer = Ticks::ZERO;
+        self.hurt_timer = storage::get::<Physics>().invulnerable_time; // Invulnerable for this long
         self.health = 3;
-        self.blowing_orb = Weak::new();
+        self.shield_timer = Ticks::ZERO;
+        self.rapid_fire_timer = Ticks::ZERO;
+        self.speed_boost_timer = Ticks::ZERO;
+        self.blowing_orb = None;
+        self.charge = None;
+    }
+
+    /// Awards a life for each multiple of `extra_life_score` reached since the last invocation, so that thresholds
+    /// crossed together (e.g. by the boss bonus) all count. Returns true if any life was awarded.
+    pub fn award_extra_lives(&mut self, extra_life_score: i32) -> bool {
+        if extra_life_score <= 0 {
+            return false;
+        }
+
+        let thresholds_reached = self.score / extra_life_score;
+        let new_lives = thresholds_reached - self.extra_lives_awarded;
+
+        if new_lives <= 0 {
+            return false;
+        }
+
+        self.lives += new_lives;
+        self.extra_lives_awarded = thresholds_reached;
+        self.extra_life_timer = EXTRA_LIFE_FLASH_TIME;
+
+        true
     }
 
     pub fn hit_test(&mut self, other: &Bolt) -> bool {
         // Check for collision between player and bolt - called from Bolt.update. Also check hurt_timer - after being hurt,
         // there is a period during which the player cannot be hurt again
-        if self.collidepoint((other.x, other.y)) && self.hurt_timer < 0 {
-            // Player loses 1 health, is knocked in the direction the bolt had been moving, and can't be hurt again
-            // for a while
-            self.hurt_timer = 200;
-            self.health -= 1;
-            self.vel_y = -12;
-            self.landed = false;
-            self.direction_x = other.direction_x;
-            let resources = storage::get::<Resources>();
-            if self.health > 0 {
-                play_game_random_sound(Some(self), &resources.ouch_sounds);
-            } else {
-                play_game_sound(Some(self), &resources.die_sound);
-            }
+        if self.collidepoint((other.x, other.y)) && self.hurt_timer < Ticks::ZERO {
+            self.hurt(other.direction_x);
             true
         } else {
             false
         }
     }
 
-    pub fn update(&mut self, orbs: &mut Vec<RcOrb>, grid: &[&str], game_timer: i32) {
+    // Rust: Extracted from hit_test(), so that enemies other than bolts can hurt the player.
+    pub fn hurt(&mut self, direction_x: i32) {
+        // A shield absorbs the hit, and makes the player briefly invulnerable
+        if self.shield_timer > Ticks::ZERO {
+            self.shield_timer = Ticks::ZERO;
+            self.hurt_timer = SHIELD_INVULNERABLE_TIME;
+            play_game_sound(Some(self), |sounds| sounds.bonus_sound);
+            return;
+        }
+
+        // Player loses 1 health, is knocked in the direction the bolt had been moving, and can't be hurt again
+        // for a while
+        let physics = storage::get::<Physics>();
+        self.hurt_timer = physics.hurt_time;
+        self.health -= 1;
+        self.charge = None;
+        self.vel_y = physics.knockback_velocity;
+        self.landed = false;
+        self.direction_x = direction_x;
+        // Rust: Not in the original game. The lower the health left, the stronger the rumble.
+        self.input_source.rumble((4 - self.health) as f32 / 4.);
+        if self.health > 0 {
+            play_game_random_sound_at(Some(self), |sounds| &sounds.ouch_sounds, self.x);
+        } else {
+            play_game_sound_at(Some(self), |sounds| sounds.die_sound, self.x);
+        }
+    }
+
+    pub fn apply_power_up(&mut self, power_up: PowerUp) {
+        *self.power_up_timer_mut(power_up) = POWER_UP_DURATION;
+    }
+
+    pub fn power_up_timer(&self, power_up: PowerUp) -> Ticks {
+        match power_up {
+            PowerUp::Shield => self.shield_timer,
+            PowerUp::RapidFire => self.rapid_fire_timer,
+            PowerUp::SpeedBoost => self.speed_boost_timer,
+        }
+    }
+
+    fn power_up_timer_mut(&mut self, power_up: PowerUp) -> &mut Ticks {
+        match power_up {
+            PowerUp::Shield => &mut self.shield_timer,
+            PowerUp::RapidFire => &mut self.rapid_fire_timer,
+            PowerUp::SpeedBoost => &mut self.speed_boost_timer,
+        }
+    }
+
+    pub fn update(
+        &mut self,
+        input: &InputState,
+        orbs: &mut EntityStore<Orb>,
+        blow_params: &BlowParams,
+        grid: &[String],
+    ) {
         // Call GravityActor.update - parameter is whether we want to perform collision detection as we fall. If health
         // is zero, we want the player to just fall out of the level
         GravityActor::update(self, self.health > 0, grid);
 
-        self.fire_timer -= 1;
-        self.hurt_timer -= 1;
+        let physics = storage::get::<Physics>();
+
+        self.fire_timer.decrement();
+        self.hurt_timer.decrement();
+        self.shield_timer.decrement();
+        self.rapid_fire_timer.decrement();
+        self.speed_boost_timer.decrement();
+        self.extra_life_timer.decrement();
 
         // Get keyboard input. dx represents the direction the player is facing
         // Rust: In the original code, this is (inappropriately but functionally) inside the else block, which, in static
@@ -111,28 +232,33 @@ impl Player {
 
         if self.landed {
             // Hurt timer starts at 200, but drops to 100 once the player has landed
-            self.hurt_timer = self.hurt_timer.min(100);
+            self.hurt_timer = self.hurt_timer.min(physics.invulnerable_time);
         }
 
-        if self.hurt_timer > 100 {
+        if self.hurt_timer > physics.invulnerable_time {
             // We've just been hurt. Either carry out the sideways motion from being knocked by a bolt, or if health is
             // zero, we're dropping out of the level, so check for our sprite reaching a certain Y coordinate before
             // reducing our lives count and responding the player. We check for the Y coordinate being the screen height
             // plus 50%, rather than simply the screen height, because the former effectively gives us a short delay
             // before the player respawns.
             if self.health > 0 {
-                self.move_(self.direction_x, 0, 4, grid);
+                self.move_(self.direction_x, 0, physics.knockback_speed, grid);
             } else {
                 if self.top() >= (HEIGHT as f32 * 1.5) as i32 {
                     self.lives -= 1;
                     self.reset();
+
+                    if let Some(checkpoint) = self.checkpoint {
+                        self.x = checkpoint.x;
+                        self.y = checkpoint.y;
+                    }
                 }
             }
         } else {
             // We're not hurt
-            if is_key_down(KeyCode::Left) {
+            if input.left {
                 dx = -1;
-            } else if is_key_down(KeyCode::Right) {
+            } else if input.right {
                 dx = 1;
             }
 
@@ -140,78 +266,124 @@ impl Player {
                 self.direction_x = dx;
 
                 // If we haven't just fired an orb, carry out horizontal movement
-                if self.fire_timer < 10 {
-                    self.move_(dx, 0, 4, grid);
+                if self.fire_timer < physics.blow_move_hold_off {
+                    let speed = if self.speed_boost_timer > Ticks::ZERO {
+                        physics.boosted_walk_speed
+                    } else {
+                        physics.walk_speed
+                    };
+                    self.move_(dx, 0, speed, grid);
                 }
             }
 
-            let resources = storage::get::<Resources>();
-
             // Do we need to create a new orb? Space must have been pressed and released, the minimum time between
-            // orbs must have passed, and there is a limit of 5 orbs.
-            if is_key_pressed(KeyCode::Space) && self.fire_timer <= 0 && orbs.len() < 5 {
-                // x position will be 38 pixels in front of the player position, while ensuring it is within the
-                // bounds of the level
-                let x = (self.x() + self.direction_x * 38).clamp(70, 730);
-                let y = self.y() - 35;
-                let new_orb = Rc::new(RefCell::new(Orb::new(x, y, self.direction_x)));
-                self.blowing_orb = Rc::downgrade(&new_orb);
-                orbs.push(new_orb);
-                play_game_random_sound(Some(self), &resources.blow_sounds);
-                self.fire_timer = 20;
+            // orbs must have passed, and there is a limit of orbs (5, on the normal difficulty).
+            let can_blow = self.fire_timer <= Ticks::ZERO && orbs.len() < blow_params.max_orbs;
+
+            // Rust: With the charge shot option, the orb is blown when space is released, charged for as long as it
+            // has been held; if it can't be blown anymore at that point (e.g. the other player reached the limit),
+            // the charge is lost.
+            if blow_params.charge_shot {
+                if let Some(charge) = self.charge {
+                    if input.blow {
+                        let charge = charge + blow_params.blow_increment;
+                        self.charge = Some(charge.min(blow_params.max_blown_frames));
+                    } else {
+                        self.charge = None;
+                        if can_blow {
+                            self.blow_orb(orbs, blow_params, charge);
+                        }
+                    }
+                } else if input.blow_pressed && can_blow {
+                    self.charge = Some(Ticks::ZERO);
+                }
+            } else if input.blow_pressed && can_blow {
+                self.blowing_orb = Some(self.blow_orb(orbs, blow_params, Ticks::ZERO));
             }
 
-            if is_key_down(KeyCode::Up) && self.vel_y == 0 && self.landed {
+            if input.jump && self.vel_y == 0 && self.landed {
                 // Jump
-                self.vel_y = -16;
+                self.vel_y = physics.jump_velocity;
                 self.landed = false;
-                play_game_sound(Some(self), &resources.jump_sound);
+                play_game_sound_at(Some(self), |sounds| sounds.jump_sound, self.x);
             }
         }
 
         // Holding down space causes the current orb (if there is one) to be blown further
-        if is_key_down(KeyCode::Space) {
-            if let Some(blowing_orb) = Weak::upgrade(&self.blowing_orb) {
-                let mut blowing_orb = blowing_orb.borrow_mut();
+        if input.blow {
+            if let Some(blowing_orb) = self.blowing_orb.and_then(|id| orbs.get_mut(id)) {
                 // Increase blown distance up to a maximum of 120
-                blowing_orb.blown_frames += 4;
-                if blowing_orb.blown_frames >= 120 {
+                blowing_orb.blown_frames += blow_params.blow_increment;
+                if blowing_orb.blown_frames >= blow_params.max_blown_frames {
                     // Can't be blown any further
-                    self.blowing_orb = Weak::new();
+                    self.blowing_orb = None;
                 }
             }
         } else {
             // If we let go of space, we relinquish control over the current orb - it can't be blown any further
-            self.blowing_orb = Weak::new();
+            self.blowing_orb = None;
         }
 
         let resources = storage::get::<Resources>();
 
         // Set sprite image. If we're currently hurt, the sprite will flash on and off on alternate frames.
-        self.image = resources.blank_texture;
-        if self.hurt_timer <= 0 || self.hurt_timer % 2 == 1 {
-            let dir_index = if self.direction_x > 0 { 1 } else { 0 };
-            if self.hurt_timer > 100 {
-                if self.health > 0 {
-                    self.image = resources.recoil_textures[dir_index];
-                } else {
-                    let image_i = (game_timer / 4) % 2;
-                    self.image = resources.fall_textures[image_i as usize];
-                }
-            } else if self.fire_timer > 0 {
-                self.image = resources.blow_textures[dir_index];
-            } else if dx == 0 {
-                self.image = resources.still_texture;
+        // Rust: The animation is chosen also while hidden, so that it isn't interrupted by the flashing.
+        let dir_index = if self.direction_x > 0 { 1 } else { 0 };
+        if self.hurt_timer > physics.invulnerable_time {
+            if self.health > 0 {
+                self.animation.show(resources.recoil_textures[dir_index]);
             } else {
-                let direction_factor = dir_index * 4;
-                let image_i = direction_factor + ((game_timer / 8) % 4) as usize;
-                self.image = resources.run_textures[image_i];
+                self.animation.play(&resources.fall_textures, 4, true);
             }
+        } else if self.fire_timer > Ticks::ZERO {
+            self.animation.show(resources.blow_textures[dir_index]);
+        } else if dx == 0 {
+            self.animation.show(resources.still_texture);
+        } else {
+            let direction_factor = dir_index * 4;
+            let frames = &resources.run_textures[direction_factor..direction_factor + 4];
+            self.animation.play(frames, 8, true);
         }
+
+        self.image = if self.hurt_timer <= Ticks::ZERO || self.hurt_timer.get() % 2 == 1 {
+            self.animation.image()
+        } else {
+            resources.blank_texture
+        };
+    }
+
+    /// Rust: Extracted from update(), for the charge shot. Returns the id of the new orb.
+    fn blow_orb(
+        &mut self,
+        orbs: &mut EntityStore<Orb>,
+        blow_params: &BlowParams,
+        charge: Ticks,
+    ) -> EntityId {
+        let physics = storage::get::<Physics>();
+
+        // x position will be 38 pixels in front of the player position, while ensuring it is within the
+        // bounds of the level
+        let x = (self.x() + self.direction_x * 38).clamp(70, 730);
+        let y = self.y() - 35;
+        let mut orb = Orb::new(x, y, self.direction_x);
+        orb.bounces_left = blow_params.wall_bounces;
+        // A charged orb travels as far as if blow had been held for the same time
+        orb.blown_frames += charge;
+        orb.rise_bonus = blow_params.charge_rise_bonus(charge);
+        orb.extra_lifetime = blow_params.charge_extra_lifetime(charge);
+        let orb_id = orbs.insert(orb);
+        play_game_random_sound_at(Some(self), |sounds| &sounds.blow_sounds, self.x);
+        self.fire_timer = if self.rapid_fire_timer > Ticks::ZERO {
+            physics.rapid_blow_hold_off
+        } else {
+            physics.blow_hold_off
+        };
+
+        orb_id
     }
 }
 
-impl Actor for Player {
+impl Body for Player {
     fn x(&self) -> i32 {
         self.x
     }
@@ -228,8 +400,8 @@ impl Actor for Player {
         &mut self.y
     }
 
-    fn image(&self) -> Texture2D {
-        self.image
+    fn size(&self) -> (i32, i32) {
+        image_size(&self.image)
     }
 
     fn anchor(&self) -> Anchor {
@@ -237,9 +409,49 @@ impl Actor for Player {
     }
 }
 
-impl CollideActor for Player {}
+impl Actor for Player {
+    fn image(&self) -> Texture2D {
+        self.image
+    }
+
+    fn tint(&self) -> Color {
+        self.colour
+    }
+
+    fn draw(&self) {
+        let (left, top) = self.top_left();
+
+        draw_texture(self.image(), left as f32, top as f32, self.tint());
+
+        // Rust: Not in the original game. While charging, a bar above the player fills up; it turns yellow once the
+        // charge is full.
+        if let Some(charge) = self.charge {
+            let max_charge = storage::get::<Physics>().max_orb_blown_frames;
+            let fraction = (charge.get() as f32 / max_charge.get() as f32).min(1.);
+            let colour = if charge >= max_charge {
+                YELLOW
+            } else {
+                self.colour
+            };
+
+            let x = self.x as f32 - CHARGE_BAR_WIDTH / 2.;
+            let y = self.top() as f32 - CHARGE_BAR_SPACING - CHARGE_BAR_HEIGHT;
+
+            draw_rectangle(
+                x,
+                y,
+                CHARGE_BAR_WIDTH,
+                CHARGE_BAR_HEIGHT,
+                CHARGE_BAR_BACKGROUND,
+            );
+            draw_rectangle(x, y, CHARGE_BAR_WIDTH * fraction, CHARGE_BAR_HEIGHT, colour);
+        }
+    }
+}
+
+impl CollideBody for Player {}
 
-impl GravityActor for Player {
+impl GravityBody for Player {
     fn vel_y(&self) -> i32 {
         self.vel_y
     }
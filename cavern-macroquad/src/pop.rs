@@ -1,7 +1,7 @@
 use macroquad::prelude::{collections::storage, Texture2D};
 
 use crate::{
-    actor::{Actor, Anchor},
+    actor::{image_size, Actor, Anchor, Body},
     resources::Resources,
 };
 
@@ -38,7 +38,7 @@ impl Pop {
     }
 }
 
-impl Actor for Pop {
+impl Body for Pop {
     fn x(&self) -> i32 {
         self.x
     }
@@ -55,11 +55,17 @@ impl Actor for Pop {
         &mut self.y
     }
 
-    fn image(&self) -> macroquad::prelude::Texture2D {
-        self.image
+    fn size(&self) -> (i32, i32) {
+        image_size(&self.image)
     }
 
-    fn anchor(&self) -> crate::actor::Anchor {
+    fn anchor(&self) -> Anchor {
         self.anchor
     }
 }
+
+impl Actor for Pop {
+    fn image(&self) -> Texture2D {
+        self.image
+    }
+}
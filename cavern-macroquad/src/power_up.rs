@@ -0,0 +1,43 @@
+use macroquad::prelude::{collections::storage, Color};
+use nanoserde::{DeRon, SerRon};
+
+use crate::{
+    palette::{Palette, BLUISH_GREEN, ORANGE, SKY_BLUE},
+    resources::Resources,
+    ticks::Ticks,
+};
+
+/// Time a power up lasts for.
+pub const POWER_UP_DURATION: Ticks = Ticks::from_secs(10);
+
+// Power ups reuse the extra health sprite (index in the fruit textures), tinted according to the type.
+pub const POWER_UP_SPRITE_VAL: i32 = 3;
+
+/// Temporary power ups; not in the original game.
+#[derive(Clone, Copy, PartialEq, DeRon, SerRon)]
+pub enum PowerUp {
+    /// Absorbs one hit.
+    Shield,
+    /// Halves the time between orbs.
+    RapidFire,
+    SpeedBoost,
+}
+
+pub const POWER_UPS: [PowerUp; 3] = [PowerUp::Shield, PowerUp::RapidFire, PowerUp::SpeedBoost];
+
+impl PowerUp {
+    pub fn colour(&self) -> Color {
+        match storage::get::<Resources>().palette() {
+            Palette::Standard => match self {
+                PowerUp::Shield => Color::new(0.5, 0.7, 1.0, 1.0),
+                PowerUp::RapidFire => Color::new(1.0, 0.7, 0.3, 1.0),
+                PowerUp::SpeedBoost => Color::new(0.5, 1.0, 0.5, 1.0),
+            },
+            Palette::Colourblind => match self {
+                PowerUp::Shield => SKY_BLUE,
+                PowerUp::RapidFire => ORANGE,
+                PowerUp::SpeedBoost => BLUISH_GREEN,
+            },
+        }
+    }
+}
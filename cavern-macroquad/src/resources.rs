@@ -1,11 +1,20 @@
 // Rust: The data should be loaded from the on-disk list of files, rather than each type individually.
 // The file naming actually helps, since "map" textures don't have an index.
 
-use std::{collections::HashMap, error};
+use std::{collections::HashMap, mem};
 
 use macroquad::{
-    audio::{self, load_sound, Sound},
-    prelude::{load_texture, Texture2D},
+    audio::Sound,
+    file::load_string,
+    prelude::{Color, Texture2D},
+};
+use nanoserde::DeRon;
+
+use crate::{
+    asset_loader::{self, AssetLoader},
+    input_log::InputLog,
+    palette::{Palette, BLUISH_GREEN, REDDISH_PURPLE, SKY_BLUE, VERMILLION, YELLOW},
+    panning::PannedSound,
 };
 
 const AVAILABLE_FONTS: [u8; 37] = [
@@ -16,60 +25,148 @@ const AVAILABLE_FONTS: [u8; 37] = [
 // Async blocks are (as of Jun/2021) unstable, so cycles are used where required.
 //
 async fn load_textures_list(
+    loader: &mut AssetLoader,
     name_prefix: &str,
     number: u8,
-) -> Result<Vec<Texture2D>, Box<dyn error::Error>> {
+) -> Vec<Texture2D> {
     let mut textures = vec![];
 
     for i in 0..number {
-        textures.push(load_texture(&format!("resources/images/{}{}.png", name_prefix, i)).await?);
+        textures.push(
+            loader
+                .texture(&format!("resources/images/{}{}.png", name_prefix, i))
+                .await,
+        );
     }
 
-    Ok(textures)
+    textures
 }
 
 async fn load_textures_map(
+    loader: &mut AssetLoader,
     names: &[&'static str],
-) -> Result<HashMap<&'static str, Texture2D>, Box<dyn error::Error>> {
+) -> HashMap<&'static str, Texture2D> {
     let mut textures = HashMap::new();
 
     for name in names {
         let filename = format!("resources/images/{}.png", name);
-        let texture = load_texture(&filename).await?;
+        let texture = loader.texture(&filename).await;
         textures.insert(*name, texture);
     }
 
-    Ok(textures)
+    textures
 }
 
 /// Rust: Load texture that have multiple states, e.g. Robots of two types, with two directions each.
 /// The textures are stored in a contiguous array, respecting the order of the states passed.
 async fn load_multi_state_textures(
+    loader: &mut AssetLoader,
     name_prefix: &str,
     states: &[&str],
     state_number: u8,
-) -> Result<Vec<Texture2D>, Box<dyn error::Error>> {
+) -> Vec<Texture2D> {
     let mut textures = vec![];
 
     for state in states {
         let prefix = &format!("{}{}", name_prefix, state);
-        textures.extend(load_textures_list(prefix, state_number).await?);
+        textures.extend(load_textures_list(loader, prefix, state_number).await);
     }
 
-    Ok(textures)
+    textures
 }
 
-async fn load_sounds_list(
+/// Rust: Load textures recoloured with the given colour, preserving the shading (the luminance of each pixel);
+/// used for the colourblind palette.
+async fn load_recoloured_textures_list(
+    loader: &mut AssetLoader,
     name_prefix: &str,
     number: u8,
-) -> Result<Vec<Sound>, Box<dyn error::Error>> {
+    colour: Color,
+) -> Vec<Texture2D> {
+    let mut textures = vec![];
+
+    for i in 0..number {
+        let mut image = loader
+            .image(&format!("resources/images/{}{}.png", name_prefix, i))
+            .await;
+
+        for pixel in image.get_image_data_mut() {
+            let [r, g, b, a] = *pixel;
+            let luminance = (0.299 * r as f32 + 0.587 * g as f32 + 0.114 * b as f32) / 255.;
+            let shade = (0.25 + luminance).min(1.);
+
+            *pixel = [
+                (colour.r * shade * 255.) as u8,
+                (colour.g * shade * 255.) as u8,
+                (colour.b * shade * 255.) as u8,
+                a,
+            ];
+        }
+
+        textures.push(loader.texture_from_image(&image));
+    }
+
+    textures
+}
+
+/// The sounds in lists are all emitted by the actors, so they're loaded as positional.
+async fn load_sounds_list(loader: &mut AssetLoader, name_prefix: &str, number: u8) -> Vec<Sound> {
     let mut sounds = vec![];
 
     for i in 0..number {
-        sounds.push(load_sound(&format!("resources/sounds/{}{}.ogg", name_prefix, i)).await?);
+        sounds.push(
+            loader
+                .positional_sound(&format!("resources/sounds/{}{}.ogg", name_prefix, i))
+                .await,
+        );
     }
 
-    Ok(sounds)
+    sounds
+}
+
+/// Textures that differ between the palettes.
+struct PaletteTextures {
+    orb_textures: Vec<Texture2D>,
+    trap_textures: Vec<Texture2D>,
+    bolt_textures: Vec<Texture2D>,
+    fruit_textures: Vec<Texture2D>,
+}
+
+impl PaletteTextures {
+    async fn new_colourblind(loader: &mut AssetLoader) -> Self {
+        let orb_textures = load_recoloured_textures_list(loader, "orb", 7, SKY_BLUE).await;
+
+        let mut trap_textures = vec![];
+        for state in ["0", "1"] {
+            let prefix = format!("trap{}", state);
+            trap_textures.extend(load_recoloured_textures_list(loader, &prefix, 8, SKY_BLUE).await);
+        }
+
+        let mut bolt_textures = vec![];
+        for state in ["0", "1"] {
+            let prefix = format!("bolt{}", state);
+            bolt_textures.extend(load_recoloured_textures_list(loader, &prefix, 2, YELLOW).await);
+        }
+
+        // Apple, raspberry and lemon are recoloured; the extra health and life are not colour coded.
+        let mut fruit_textures = vec![];
+        for (state, colour) in [
+            ("0", VERMILLION),
+            ("1", REDDISH_PURPLE),
+            ("2", BLUISH_GREEN),
+        ] {
+            let prefix = format!("fruit{}", state);
+            fruit_textures.extend(load_recoloured_textures_list(loader, &prefix, 3, colour).await);
+        }
+        fruit_textures.extend(load_multi_state_textures(loader, "fruit", &["3", "4"], 3).await);
+
+        Self {
+            orb_textures,
+            trap_textures,
+            bolt_textures,
+            fruit_textures,
+        }
+    }
 }
 
 pub struct Resources {
@@ -94,6 +191,27 @@ pub struct Resources {
     pub pop_textures: Vec<Texture2D>,
     pub fruit_textures: Vec<Texture2D>,
 
+    pub fonts: HashMap<u8, Texture2D>,
+
+    /// Input played back in the attract mode; the game works without it.
+    pub demo_log: Option<InputLog>,
+
+    palette: Palette,
+    /// The palette textures not in use; they're swapped with the ones above when changing palette.
+    alternate_textures: PaletteTextures,
+
+    /// Paths of the assets replaced by placeholders; reported on startup.
+    pub missing_assets: Vec<String>,
+    /// Paths of the textures loaded, and the textures; only used by the hot reload, in debug builds.
+    #[allow(dead_code)]
+    pub loaded_textures: Vec<(String, Texture2D)>,
+}
+
+// Rust: The sounds are stored separately from the other resources, since the game logic only needs the latter (the
+// sizes of the textures are the sizes of the actors); this allows running the logic without audio (see
+// `Resources::headless()`).
+//
+pub struct Sounds {
     pub over_sound: Sound,
     pub level_sound: Sound,
     pub pop_sounds: Vec<Sound>,
@@ -105,53 +223,83 @@ pub struct Resources {
     pub jump_sound: Sound,
     pub bonus_sound: Sound,
     pub score_sound: Sound,
+    pub life_sound: Sound,
+    pub music: Sound,
 
-    pub fonts: HashMap<u8, Texture2D>,
+    pub panned_sounds: Vec<PannedSound>,
 }
 
 impl Resources {
-    pub async fn new() -> Result<Resources, Box<dyn error::Error>> {
-        let title_texture = load_texture("resources/images/title.png").await?;
-        let over_texture = load_texture("resources/images/over.png").await?;
-        let space_textures = load_textures_list("space", 10).await?;
-        let status_textures = load_textures_map(&["life", "plus", "health"]).await?;
-        let background_textures = load_textures_list("bg", 4).await?;
-        let block_textures = load_textures_list("block", 4).await?;
-        let blank_texture = load_texture("resources/images/blank.png").await?;
+    /// Never fails: the assets that can't be loaded are replaced by placeholders, and listed in `missing_assets`.
+    pub async fn new() -> (Resources, Sounds) {
+        let mut loader = AssetLoader::new();
+
+        let sounds = Sounds::new(&mut loader).await;
+        let resources = Self::load(&mut loader).await;
+
+        (resources, sounds)
+    }
+
+    /// Rust: Not in the original game. Resources for running the game logic without the window (e.g. when verifying
+    /// a replay); the textures only have the size, and there is no demo.
+    pub fn headless() -> Resources {
+        let mut loader = AssetLoader::headless();
+
+        asset_loader::complete_now(Self::load(&mut loader))
+    }
+
+    async fn load(loader: &mut AssetLoader) -> Resources {
+        let space_textures = load_textures_list(loader, "space", 10).await;
+        let status_textures = load_textures_map(loader, &["life", "plus", "health"]).await;
+        let block_textures = load_textures_list(loader, "block", 4).await;
+        let blank_texture = loader.texture("resources/images/blank.png").await;
         let robot_textures =
-            load_multi_state_textures("robot", &["00", "01", "10", "11"], 8).await?;
-        let recoil_textures = load_textures_list("recoil", 2).await?;
-        let fall_textures = load_textures_list("fall", 2).await?;
-        let blow_textures = load_textures_list("blow", 2).await?;
-        let still_texture: Texture2D = load_texture("resources/images/still.png").await?;
-        let run_textures = load_multi_state_textures("run", &["0", "1"], 4).await?;
-        let orb_textures = load_textures_list("orb", 7).await?;
-        let trap_textures = load_multi_state_textures("trap", &["0", "1"], 8).await?;
-        let bolt_textures = load_multi_state_textures("bolt", &["0", "1"], 2).await?;
-        let pop_textures = load_multi_state_textures("pop", &["0", "1"], 7).await?;
+            load_multi_state_textures(loader, "robot", &["00", "01", "10", "11"], 8).await;
+        let recoil_textures = load_textures_list(loader, "recoil", 2).await;
+        let fall_textures = load_textures_list(loader, "fall", 2).await;
+        let blow_textures = load_textures_list(loader, "blow", 2).await;
+        let still_texture = loader.texture("resources/images/still.png").await;
+        let run_textures = load_multi_state_textures(loader, "run", &["0", "1"], 4).await;
+        let orb_textures = load_textures_list(loader, "orb", 7).await;
+        let trap_textures = load_multi_state_textures(loader, "trap", &["0", "1"], 8).await;
+        let bolt_textures = load_multi_state_textures(loader, "bolt", &["0", "1"], 2).await;
+        let pop_textures = load_multi_state_textures(loader, "pop", &["0", "1"], 7).await;
         let fruit_textures =
-            load_multi_state_textures("fruit", &["0", "1", "2", "3", "4"], 3).await?;
-
-        let over_sound = audio::load_sound("resources/sounds/over0.ogg").await?;
-        let level_sound = audio::load_sound("resources/sounds/level0.ogg").await?;
-        let pop_sounds = load_sounds_list("pop", 4).await?;
-        let ouch_sounds = load_sounds_list("ouch", 4).await?;
-        let die_sound = audio::load_sound("resources/sounds/die0.ogg").await?;
-        let laser_sounds = load_sounds_list("laser", 4).await?;
-        let trap_sounds = load_sounds_list("trap", 4).await?;
-        let blow_sounds = load_sounds_list("blow", 4).await?;
-        let jump_sound = audio::load_sound("resources/sounds/jump0.ogg").await?;
-        let bonus_sound = audio::load_sound("resources/sounds/bonus0.ogg").await?;
-        let score_sound = audio::load_sound("resources/sounds/score0.ogg").await?;
+            load_multi_state_textures(loader, "fruit", &["0", "1", "2", "3", "4"], 3).await;
+
+        let alternate_textures = PaletteTextures::new_colourblind(loader).await;
+
+        let demo_log = if loader.is_headless() {
+            None
+        } else {
+            load_string("resources/demo.ron")
+                .await
+                .ok()
+                .and_then(|content| InputLog::deserialize_ron(&content).ok())
+        };
 
         let mut fonts = HashMap::new();
         for chr in AVAILABLE_FONTS {
             let filename = format!("resources/images/font0{:02}.png", chr);
-            let font = load_texture(&filename).await?;
+            let font = loader.texture(&filename).await;
             fonts.insert(chr, font);
         }
 
-        Ok(Resources {
+        // Rust: Not in the original code. All the textures loaded up to this point are packed into a single
+        // texture, which Macroquad then uses (via source rectangles) when drawing them; since the sprites share the
+        // texture, the draw calls are batched, which considerably reduces the state changes, especially on WASM.
+        // The full-screen images are drawn once per frame, so they're loaded afterwards, keeping the atlas small.
+        // In debug builds, the textures are hot reloaded, which the atlas wouldn't reflect, so it's not built.
+        #[cfg(not(debug_assertions))]
+        if !loader.is_headless() {
+            macroquad::prelude::build_textures_atlas();
+        }
+
+        let title_texture = loader.texture("resources/images/title.png").await;
+        let over_texture = loader.texture("resources/images/over.png").await;
+        let background_textures = load_textures_list(loader, "bg", 4).await;
+
+        Resources {
             title_texture,
             over_texture,
             space_textures,
@@ -171,6 +319,62 @@ impl Resources {
             pop_textures,
             fruit_textures,
 
+            fonts,
+
+            demo_log,
+
+            palette: Palette::Standard,
+            alternate_textures,
+            missing_assets: mem::take(&mut loader.missing),
+            loaded_textures: mem::take(&mut loader.textures),
+        }
+    }
+
+    pub fn palette(&self) -> Palette {
+        self.palette
+    }
+
+    /// All the player sprites, in a fixed order; the index identifies them in the ghost files.
+    pub fn player_textures(&self) -> Vec<Texture2D> {
+        let mut textures = vec![self.blank_texture, self.still_texture];
+        textures.extend(&self.run_textures);
+        textures.extend(&self.blow_textures);
+        textures.extend(&self.recoil_textures);
+        textures.extend(&self.fall_textures);
+        textures
+    }
+
+    pub fn set_palette(&mut self, palette: Palette) {
+        if palette != self.palette {
+            let alternate = &mut self.alternate_textures;
+
+            mem::swap(&mut self.orb_textures, &mut alternate.orb_textures);
+            mem::swap(&mut self.trap_textures, &mut alternate.trap_textures);
+            mem::swap(&mut self.bolt_textures, &mut alternate.bolt_textures);
+            mem::swap(&mut self.fruit_textures, &mut alternate.fruit_textures);
+
+            self.palette = palette;
+        }
+    }
+}
+
+impl Sounds {
+    async fn new(loader: &mut AssetLoader) -> Sounds {
+        let over_sound = loader.sound("resources/sounds/over0.ogg").await;
+        let level_sound = loader.sound("resources/sounds/level0.ogg").await;
+        let pop_sounds = load_sounds_list(loader, "pop", 4).await;
+        let ouch_sounds = load_sounds_list(loader, "ouch", 4).await;
+        let die_sound = loader.positional_sound("resources/sounds/die0.ogg").await;
+        let laser_sounds = load_sounds_list(loader, "laser", 4).await;
+        let trap_sounds = load_sounds_list(loader, "trap", 4).await;
+        let blow_sounds = load_sounds_list(loader, "blow", 4).await;
+        let jump_sound = loader.positional_sound("resources/sounds/jump0.ogg").await;
+        let bonus_sound = loader.sound("resources/sounds/bonus0.ogg").await;
+        let score_sound = loader.sound("resources/sounds/score0.ogg").await;
+        let life_sound = loader.sound("resources/sounds/life0.ogg").await;
+        let music = loader.sound("resources/music/theme.ogg").await;
+
+        Sounds {
             over_sound,
             level_sound,
             pop_sounds,
@@ -182,8 +386,18 @@ impl Resources {
             jump_sound,
             bonus_sound,
             score_sound,
+            life_sound,
+            music,
 
-            fonts,
-        })
+            panned_sounds: mem::take(&mut loader.panned_sounds),
+        }
+    }
+
+    /// The variant of the sound closest to the pan; sounds without variants are returned as they are.
+    pub fn panned_variant(&self, sound: Sound, pan: f32) -> Sound {
+        self.panned_sounds
+            .iter()
+            .find(|panned_sound| panned_sound.sound == sound)
+            .map_or(sound, |panned_sound| panned_sound.variant(pan))
     }
 }
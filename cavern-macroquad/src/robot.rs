@@ -2,18 +2,39 @@ use macroquad::{
     prelude::{collections::storage, Texture2D},
     rand::{gen_range, ChooseRandom},
 };
+use nanoserde::{DeRon, SerRon};
+
+use cavern_core::{collide, CollideBody, GravityBody};
 
 use crate::{
-    actor::{Actor, Anchor},
-    collide_actor::CollideActor,
+    actor::{image_size, Actor, Anchor, Body},
+    animation::Animation,
+    entity_store::EntityStore,
     gravity_actor::GravityActor,
-    orb::RcOrb,
+    orb::Orb,
+    particle::{self, Particle},
     player::Player,
     resources::Resources,
+    ticks::Ticks,
+    GRID_BLOCK_SIZE, LEVEL_X_OFFSET, NUM_COLUMNS,
 };
-use crate::{bolt::Bolt, game_playback::play_game_random_sound};
+use crate::{bolt::Bolt, game_playback::play_game_random_sound_at};
+
+// Rust: While chasing a player, aggressive robots reconsider their direction more often.
+const CHASE_MIN_CHANGE_DIR_TIME: Ticks = Ticks::new(30);
+const CHASE_MAX_CHANGE_DIR_TIME: Ticks = Ticks::new(60);
+
+const MIN_CHANGE_DIR_TIME: Ticks = Ticks::new(100);
+const MAX_CHANGE_DIR_TIME: Ticks = Ticks::new(250);
+/// Time before a new robot can fire; the fire timer counts up from zero, starting from the last shot.
+const INITIAL_FIRE_TIMER: Ticks = Ticks::new(100);
+/// Minimum time between shots at orbs, for aggressive robots.
+const ORB_FIRE_INTERVAL: Ticks = Ticks::new(24);
+/// Frame of the firing animation when the bolt is released.
+const BOLT_RELEASE_TIME: Ticks = Ticks::new(8);
+const FIRE_ANIMATION_TIME: Ticks = Ticks::new(12);
 
-#[derive(Clone, Copy)]
+#[derive(Clone, Copy, DeRon, SerRon)]
 pub enum RobotType {
     Aggressive,
     Normal,
@@ -33,8 +54,9 @@ pub struct Robot {
     pub speed: i32,
     pub direction_x: i32,
     pub alive: bool,
-    pub change_dir_timer: i32,
-    pub fire_timer: i32,
+    pub change_dir_timer: Ticks,
+    pub fire_timer: Ticks,
+    pub animation: Animation,
 
     // Actor trait
     pub x: i32,
@@ -58,8 +80,9 @@ impl Robot {
             speed: gen_range(1, 4),
             direction_x: 1,
             alive: true,
-            change_dir_timer: 0,
-            fire_timer: 100,
+            change_dir_timer: Ticks::ZERO,
+            fire_timer: INITIAL_FIRE_TIMER,
+            animation: Animation::default(),
             vel_y: 0,
             landed: false,
         }
@@ -67,80 +90,103 @@ impl Robot {
 
     pub fn update(
         &mut self,
-        bolts: &mut Vec<Bolt>,
-        orbs: &mut [RcOrb],
-        player: Option<&Player>,
+        bolts: &mut EntityStore<Bolt>,
+        orbs: &mut EntityStore<Orb>,
+        particles: &mut Vec<Particle>,
+        players: &[Player],
         mut fire_probability: f32,
-        game_timer: i32,
-        grid: &[&str],
+        fire_interval: Ticks,
+        bolt_speed: i32,
+        grid: &[String],
     ) {
         GravityActor::update(self, true, grid);
 
-        self.change_dir_timer -= 1;
-        self.fire_timer += 1;
+        // Rust: Used for the sound playback, which only needs to know if there's a game in progress.
+        let player = players.first();
+
+        self.change_dir_timer.decrement();
+        self.fire_timer.increment();
 
         // Move in current direction - turn around if we hit a wall
         if self.move_(self.direction_x, 0, self.speed, grid) {
-            self.change_dir_timer = 0;
+            self.change_dir_timer = Ticks::ZERO;
         }
 
-        if self.change_dir_timer <= 0 {
-            // Randomly choose a direction to move in
-            // If there's a player, there's a two thirds chance that we'll move towards them
-            let mut directions = vec![-1, 1];
-            if let Some(player) = player {
-                directions.push((player.x() - self.x()).signum());
+        if self.change_dir_timer <= Ticks::ZERO {
+            // In co-op mode, we go after the nearest player.
+            let target = players
+                .iter()
+                .filter(|p| p.is_active())
+                .min_by_key(|p| (p.x() - self.x()).abs());
+
+            let chase_direction_x = match self.type_ {
+                RobotType::Aggressive => {
+                    target.and_then(|player| self.chase_direction_x(player, grid))
+                }
+                RobotType::Normal => None,
+            };
+
+            if let Some(direction_x) = chase_direction_x {
+                self.direction_x = direction_x;
+                self.change_dir_timer =
+                    Ticks::random(CHASE_MIN_CHANGE_DIR_TIME, CHASE_MAX_CHANGE_DIR_TIME);
+            } else {
+                // Randomly choose a direction to move in
+                // If there's a player, there's a two thirds chance that we'll move towards them.
+                let mut directions = vec![-1, 1];
+                if let Some(player) = target {
+                    directions.push((player.x() - self.x()).signum());
+                }
+                self.direction_x = *directions.choose().unwrap();
+                self.change_dir_timer = Ticks::random(MIN_CHANGE_DIR_TIME, MAX_CHANGE_DIR_TIME);
             }
-            self.direction_x = *directions.choose().unwrap();
-            self.change_dir_timer = gen_range(100, 250 + 1);
         }
 
         // The more powerful type of robot can deliberately shoot at orbs - turning to face them if necessary
-        if matches!(self.type_, RobotType::Aggressive) && self.fire_timer >= 24 {
+        if matches!(self.type_, RobotType::Aggressive) && self.fire_timer >= ORB_FIRE_INTERVAL {
             // Go through all orbs to see if any can be shot at
-            for orb in orbs.iter_mut() {
-                let orb = orb.borrow();
+            for orb in orbs.iter() {
                 // The orb must be at our height, and within 200 pixels on the x axis
                 if orb.y >= self.top() && orb.y < self.bottom() && (orb.x() - self.x()).abs() < 200
                 {
                     self.direction_x = (orb.x() - self.x()).signum();
-                    self.fire_timer = 0;
+                    self.fire_timer = Ticks::ZERO;
                     break;
                 }
             }
         }
 
-        let resources = storage::get::<Resources>();
-
         // Check to see if we can fire at player
-        if self.fire_timer >= 12 {
+        if self.fire_timer >= fire_interval {
             // Random chance of firing each frame. Likelihood increases 10 times if player is at the same height as us
-            if let Some(player) = player {
-                if self.top() < player.bottom() && self.bottom() > player.top() {
-                    fire_probability *= 10.;
-                }
+            if players
+                .iter()
+                .filter(|p| p.is_active())
+                .any(|p| self.top() < p.bottom() && self.bottom() > p.top())
+            {
+                fire_probability *= 10.;
             }
             if gen_range(0., 1.) < fire_probability {
-                self.fire_timer = 0;
-                play_game_random_sound(player, &resources.laser_sounds);
+                self.fire_timer = Ticks::ZERO;
+                play_game_random_sound_at(player, |sounds| &sounds.laser_sounds, self.x);
             }
-        } else if self.fire_timer == 8 {
+        } else if self.fire_timer == BOLT_RELEASE_TIME {
             //  Once the fire timer has been set to 0, it will count up - frame 8 of the animation is when the actual bolt is fired
-            bolts.push(Bolt::new(
+            bolts.insert(Bolt::new(
                 self.x() + self.direction_x * 20,
                 self.y() - 38,
                 self.direction_x,
+                bolt_speed,
             ));
         }
 
         // Am I colliding with an orb? If so, become trapped by it
         for orb in orbs.iter_mut() {
-            let mut orb = orb.borrow_mut();
             if orb.trapped_enemy_type.is_none() && self.collidepoint(orb.center()) {
                 self.alive = false;
-                orb.floating = true;
-                orb.trapped_enemy_type = Some(self.type_);
-                play_game_random_sound(player, &resources.trap_sounds);
+                orb.trap(self.type_);
+                particle::emit_puff(particles, orb.center());
+                play_game_random_sound_at(player, |sounds| &sounds.trap_sounds, self.x);
                 break;
             }
         }
@@ -148,17 +194,80 @@ impl Robot {
         // Choose and set sprite image
         let type_factor = 16 * self.type_.val();
         let direction_factor = if self.direction_x > 0 { 8 } else { 0 };
-        let fire_factor = if self.fire_timer < 12 {
-            5 + (self.fire_timer / 4)
+        let frames_start = (type_factor + direction_factor) as usize;
+        let robot_textures = &storage::get::<Resources>().robot_textures;
+        if self.fire_timer < FIRE_ANIMATION_TIME {
+            let frames = &robot_textures[frames_start + 5..frames_start + 8];
+            self.animation.play(frames, 4, false);
+            // A new bolt may be fired while the previous firing animation is still playing
+            if self.fire_timer == Ticks::ZERO {
+                self.animation.restart();
+            }
+        } else {
+            let frames = &robot_textures[frames_start + 1..frames_start + 5];
+            self.animation.play(frames, 4, true);
+        }
+        self.image = self.animation.image();
+    }
+
+    // Rust: Not in the original game. Returns the direction to take in order to approach the player, based on the
+    // level layout: if the player is on the same platform (with no walls in between), we walk towards them; if they're
+    // below, we walk towards the nearest gap in the floor, in order to drop through it. Otherwise (e.g. the player is
+    // above, or we're falling), there's no clear way, and None is returned.
+    fn chase_direction_x(&self, player: &Player, grid: &[String]) -> Option<i32> {
+        if self.vel_y != 0 {
+            return None;
+        }
+
+        if self.top() < player.bottom() && self.bottom() > player.top() {
+            let direction_x = (player.x() - self.x()).signum();
+            let distance = (player.x() - self.x()).abs();
+
+            // Directly above or below us; there are no columns in between.
+            if direction_x == 0 {
+                return None;
+            }
+
+            let wall_in_between = self
+                .columns_xs(direction_x)
+                .take_while(|x| (x - self.x()).abs() < distance)
+                .any(|x| collide::block(x, self.y(), grid));
+
+            (!wall_in_between).then_some(direction_x)
+        } else if player.y() > self.y() {
+            // Our feet are 1 pixel above the floor (see CollideActor::move_())
+            let gap_distance = |direction_x| {
+                self.columns_xs(direction_x)
+                    .take_while(|x| !collide::block(*x, self.y(), grid))
+                    .find(|x| !collide::block(*x, self.y() + 1, grid))
+                    .map(|x| (x - self.x()).abs())
+            };
+
+            match (gap_distance(-1), gap_distance(1)) {
+                (Some(left), Some(right)) => Some(if left <= right { -1 } else { 1 }),
+                (Some(_), None) => Some(-1),
+                (None, Some(_)) => Some(1),
+                (None, None) => None,
+            }
         } else {
-            1 + ((game_timer / 4) % 4)
-        };
-        let image_i = (type_factor + direction_factor + fire_factor) as usize;
-        self.image = storage::get::<Resources>().robot_textures[image_i];
+            None
+        }
+    }
+
+    /// Centres of the grid columns, starting from the one next to ours, in the given direction (-1 or 1), up to the
+    /// edge of the level.
+    fn columns_xs(&self, direction_x: i32) -> impl Iterator<Item = i32> {
+        let column = (self.x() - LEVEL_X_OFFSET) / GRID_BLOCK_SIZE;
+
+        (1..)
+            .map(move |i| column + direction_x * i)
+            .take_while(|column| (0..NUM_COLUMNS).contains(column))
+            .map(|column| LEVEL_X_OFFSET + column * GRID_BLOCK_SIZE + GRID_BLOCK_SIZE / 2)
+            .filter(|x| (70..=730).contains(x))
     }
 }
 
-impl Actor for Robot {
+impl Body for Robot {
     fn x(&self) -> i32 {
         self.x
     }
@@ -175,8 +284,8 @@ impl Actor for Robot {
         &mut self.y
     }
 
-    fn image(&self) -> macroquad::prelude::Texture2D {
-        self.image
+    fn size(&self) -> (i32, i32) {
+        image_size(&self.image)
     }
 
     fn anchor(&self) -> Anchor {
@@ -184,9 +293,15 @@ impl Actor for Robot {
     }
 }
 
-impl CollideActor for Robot {}
+impl Actor for Robot {
+    fn image(&self) -> Texture2D {
+        self.image
+    }
+}
+
+impl CollideBody for Robot {}
 
-impl GravityActor for Robot {
+impl GravityBody for Robot {
     fn vel_y(&self) -> i32 {
         self.vel_y
     }
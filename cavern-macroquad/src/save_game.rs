@@ -0,0 +1,453 @@
+use std::{fs, path::Path};
+
+use nanoserde::{DeRon, SerRon};
+
+use crate::{
+    bolt::Bolt,
+    bonus_stage::BonusStage,
+    boss::{AttackPattern, Boss},
+    checkpoint::Checkpoint,
+    difficulty::Difficulty,
+    flying_robot::{FlyingRobot, FlyingState},
+    fruit::{Fruit, FruitType},
+    game::Game,
+    game_mode::GameMode,
+    orb::Orb,
+    player::Player,
+    pop::Pop,
+    robot::{Robot, RobotType},
+    spawn_waves::Wave,
+    ticks::Ticks,
+};
+
+const SAVE_GAME_FILE: &str = "savegame.ron";
+
+// Rust: The entities can't be serialized directly, since they hold textures, so their state is copied into plain
+// snapshot structs (the checkpoints are the exception). Textures are recomputed on the first update after restoring.
+// The orb being blown by a player is not stored; on resume, the player simply relinquishes control over it.
+
+#[derive(DeRon, SerRon)]
+struct SavedPlayer {
+    lives: i32,
+    score: i32,
+    direction_x: i32,
+    fire_timer: Ticks,
+    hurt_timer: Ticks,
+    health: i32,
+    shield_timer: Ticks,
+    rapid_fire_timer: Ticks,
+    speed_boost_timer: Ticks,
+    x: i32,
+    y: i32,
+    vel_y: i32,
+    landed: bool,
+    checkpoint: Option<Checkpoint>,
+    // Saves made before the extra life thresholds were introduced don't include them.
+    #[nserde(default)]
+    extra_lives_awarded: i32,
+}
+
+#[derive(DeRon, SerRon)]
+struct SavedFruit {
+    time_to_live: i32,
+    trapped_enemy_type: Option<RobotType>,
+    type_: FruitType,
+    x: i32,
+    y: i32,
+    vel_y: i32,
+    landed: bool,
+}
+
+#[derive(DeRon, SerRon)]
+struct SavedBolt {
+    direction_x: i32,
+    active: bool,
+    speed: i32,
+    x: i32,
+    y: i32,
+}
+
+#[derive(DeRon, SerRon)]
+struct SavedRobot {
+    type_: RobotType,
+    speed: i32,
+    direction_x: i32,
+    alive: bool,
+    change_dir_timer: Ticks,
+    fire_timer: Ticks,
+    x: i32,
+    y: i32,
+    vel_y: i32,
+    landed: bool,
+}
+
+#[derive(DeRon, SerRon)]
+struct SavedFlyingRobot {
+    state: FlyingState,
+    cruise_y: i32,
+    direction_x: i32,
+    dive_timer: Ticks,
+    dive_target_y: i32,
+    alive: bool,
+    x: i32,
+    y: i32,
+}
+
+#[derive(DeRon, SerRon)]
+struct SavedBoss {
+    health: i32,
+    max_health: i32,
+    direction_x: i32,
+    attack_timer: Ticks,
+    next_attack: AttackPattern,
+    hit_timer: Ticks,
+    x: i32,
+    y: i32,
+}
+
+#[derive(DeRon, SerRon)]
+struct SavedPop {
+    timer: i32,
+    type_: i32,
+    x: i32,
+    y: i32,
+}
+
+#[derive(DeRon, SerRon)]
+struct SavedOrb {
+    direction_x: i32,
+    timer: Ticks,
+    floating: bool,
+    blown_frames: Ticks,
+    trapped_enemy_type: Option<RobotType>,
+    // Saves made before the wall bounces were introduced don't include them.
+    #[nserde(default)]
+    bounces_left: i32,
+    #[nserde(default)]
+    drifting: bool,
+    // Same for the charge shot.
+    #[nserde(default)]
+    rise_bonus: i32,
+    #[nserde(default)]
+    extra_lifetime: Ticks,
+    x: i32,
+    y: i32,
+}
+
+/// Snapshot of a game in progress. The grid is stored, since in endless mode it's generated.
+#[derive(DeRon, SerRon)]
+pub struct SavedGame {
+    players: Vec<SavedPlayer>,
+    // Saves made before the difficulty settings were introduced were played at the original (Normal) one.
+    #[nserde(default)]
+    difficulty: Difficulty,
+    // Saves made before the endless mode was introduced are of the classic one.
+    #[nserde(default)]
+    mode: GameMode,
+    level_colour: i8,
+    level: i8,
+    timer: i32,
+    grid: Vec<String>,
+    fruits: Vec<SavedFruit>,
+    bolts: Vec<SavedBolt>,
+    enemies: Vec<SavedRobot>,
+    pending_enemies: Vec<RobotType>,
+    // Saves made before the flying robots were introduced don't include them.
+    #[nserde(default)]
+    flying_enemies: Vec<SavedFlyingRobot>,
+    #[nserde(default)]
+    pending_flying_enemies: i32,
+    boss: Option<SavedBoss>,
+    pops: Vec<SavedPop>,
+    orbs: Vec<SavedOrb>,
+    // Saves made before checkpoints were introduced don't include them.
+    #[nserde(default)]
+    checkpoints: Vec<Checkpoint>,
+    #[nserde(default)]
+    bonus_stage: Option<BonusStage>,
+    #[nserde(default)]
+    pending_waves: Vec<Wave>,
+}
+
+impl SavedGame {
+    pub fn new(game: &Game) -> Self {
+        Self {
+            players: game
+                .players
+                .iter()
+                .map(|p| SavedPlayer {
+                    lives: p.lives,
+                    score: p.score,
+                    direction_x: p.direction_x,
+                    fire_timer: p.fire_timer,
+                    hurt_timer: p.hurt_timer,
+                    health: p.health,
+                    shield_timer: p.shield_timer,
+                    rapid_fire_timer: p.rapid_fire_timer,
+                    speed_boost_timer: p.speed_boost_timer,
+                    x: p.x,
+                    y: p.y,
+                    vel_y: p.vel_y,
+                    landed: p.landed,
+                    checkpoint: p.checkpoint,
+                    extra_lives_awarded: p.extra_lives_awarded,
+                })
+                .collect(),
+            difficulty: game.difficulty,
+            mode: game.mode,
+            level_colour: game.level_colour,
+            level: game.level,
+            timer: game.timer,
+            grid: game.grid.clone(),
+            fruits: game
+                .fruits
+                .iter()
+                .map(|f| SavedFruit {
+                    time_to_live: f.time_to_live,
+                    trapped_enemy_type: f.trapped_enemy_type,
+                    type_: f.type_,
+                    x: f.x,
+                    y: f.y,
+                    vel_y: f.vel_y,
+                    landed: f.landed,
+                })
+                .collect(),
+            bolts: game
+                .bolts
+                .iter()
+                .map(|b| SavedBolt {
+                    direction_x: b.direction_x,
+                    active: b.active,
+                    speed: b.speed,
+                    x: b.x,
+                    y: b.y,
+                })
+                .collect(),
+            enemies: game
+                .enemies
+                .iter()
+                .map(|r| SavedRobot {
+                    type_: r.type_,
+                    speed: r.speed,
+                    direction_x: r.direction_x,
+                    alive: r.alive,
+                    change_dir_timer: r.change_dir_timer,
+                    fire_timer: r.fire_timer,
+                    x: r.x,
+                    y: r.y,
+                    vel_y: r.vel_y,
+                    landed: r.landed,
+                })
+                .collect(),
+            pending_enemies: game.pending_enemies.clone(),
+            flying_enemies: game
+                .flying_enemies
+                .iter()
+                .map(|r| SavedFlyingRobot {
+                    state: r.state,
+                    cruise_y: r.cruise_y,
+                    direction_x: r.direction_x,
+                    dive_timer: r.dive_timer,
+                    dive_target_y: r.dive_target_y,
+                    alive: r.alive,
+                    x: r.x,
+                    y: r.y,
+                })
+                .collect(),
+            pending_flying_enemies: game.pending_flying_enemies,
+            boss: game.boss.as_ref().map(|b| SavedBoss {
+                health: b.health,
+                max_health: b.max_health,
+                direction_x: b.direction_x,
+                attack_timer: b.attack_timer,
+                next_attack: b.next_attack,
+                hit_timer: b.hit_timer,
+                x: b.x,
+                y: b.y,
+            }),
+            pops: game
+                .pops
+                .iter()
+                .map(|p| SavedPop {
+                    timer: p.timer,
+                    type_: p.type_,
+                    x: p.x,
+                    y: p.y,
+                })
+                .collect(),
+            orbs: game
+                .orbs
+                .iter()
+                .map(|o| SavedOrb {
+                    direction_x: o.direction_x,
+                    timer: o.timer,
+                    floating: o.floating,
+                    blown_frames: o.blown_frames,
+                    trapped_enemy_type: o.trapped_enemy_type,
+                    bounces_left: o.bounces_left,
+                    drifting: o.drifting,
+                    rise_bonus: o.rise_bonus,
+                    extra_lifetime: o.extra_lifetime,
+                    x: o.x,
+                    y: o.y,
+                })
+                .collect(),
+            checkpoints: game.checkpoints.clone(),
+            bonus_stage: game.bonus_stage,
+            pending_waves: game.pending_waves.clone(),
+        }
+    }
+
+    pub fn num_players(&self) -> usize {
+        self.players.len()
+    }
+
+    /// The players are passed by the caller, since their controls and appearance are not part of the game state.
+    pub fn restore(&self, mut players: Vec<Player>) -> Game {
+        for (player, saved) in players.iter_mut().zip(&self.players) {
+            player.lives = saved.lives;
+            player.score = saved.score;
+            player.direction_x = saved.direction_x;
+            player.fire_timer = saved.fire_timer;
+            player.hurt_timer = saved.hurt_timer;
+            player.health = saved.health;
+            player.shield_timer = saved.shield_timer;
+            player.rapid_fire_timer = saved.rapid_fire_timer;
+            player.speed_boost_timer = saved.speed_boost_timer;
+            player.x = saved.x;
+            player.y = saved.y;
+            player.vel_y = saved.vel_y;
+            player.landed = saved.landed;
+            player.checkpoint = saved.checkpoint;
+            player.extra_lives_awarded = saved.extra_lives_awarded;
+        }
+
+        Game {
+            players,
+            difficulty: self.difficulty,
+            mode: self.mode,
+            level_colour: self.level_colour,
+            level: self.level,
+            timer: self.timer,
+            grid: self.grid.clone(),
+            fruits: self
+                .fruits
+                .iter()
+                .map(|saved| {
+                    let mut fruit = Fruit::new(saved.x, saved.y, saved.trapped_enemy_type);
+                    fruit.time_to_live = saved.time_to_live;
+                    fruit.type_ = saved.type_;
+                    fruit.vel_y = saved.vel_y;
+                    fruit.landed = saved.landed;
+                    fruit
+                })
+                .collect(),
+            bolts: self
+                .bolts
+                .iter()
+                .map(|saved| {
+                    let mut bolt = Bolt::new(saved.x, saved.y, saved.direction_x, saved.speed);
+                    bolt.active = saved.active;
+                    bolt
+                })
+                .collect(),
+            enemies: self
+                .enemies
+                .iter()
+                .map(|saved| {
+                    let mut robot = Robot::new(saved.x, saved.y, saved.type_);
+                    robot.speed = saved.speed;
+                    robot.direction_x = saved.direction_x;
+                    robot.alive = saved.alive;
+                    robot.change_dir_timer = saved.change_dir_timer;
+                    robot.fire_timer = saved.fire_timer;
+                    robot.vel_y = saved.vel_y;
+                    robot.landed = saved.landed;
+                    robot
+                })
+                .collect(),
+            pending_enemies: self.pending_enemies.clone(),
+            flying_enemies: self
+                .flying_enemies
+                .iter()
+                .map(|saved| {
+                    let mut robot = FlyingRobot::new(saved.x, saved.y);
+                    robot.state = saved.state;
+                    robot.cruise_y = saved.cruise_y;
+                    robot.direction_x = saved.direction_x;
+                    robot.dive_timer = saved.dive_timer;
+                    robot.dive_target_y = saved.dive_target_y;
+                    robot.alive = saved.alive;
+                    robot
+                })
+                .collect(),
+            pending_flying_enemies: self.pending_flying_enemies,
+            boss: self.boss.as_ref().map(|saved| Boss {
+                health: saved.health,
+                max_health: saved.max_health,
+                direction_x: saved.direction_x,
+                attack_timer: saved.attack_timer,
+                next_attack: saved.next_attack,
+                hit_timer: saved.hit_timer,
+                x: saved.x,
+                y: saved.y,
+            }),
+            pops: self
+                .pops
+                .iter()
+                .map(|saved| {
+                    let mut pop = Pop::new(saved.x, saved.y, saved.type_);
+                    pop.timer = saved.timer;
+                    pop
+                })
+                .collect(),
+            orbs: self
+                .orbs
+                .iter()
+                .map(|saved| {
+                    let mut orb = Orb::new(saved.x, saved.y, saved.direction_x);
+                    orb.timer = saved.timer;
+                    orb.floating = saved.floating;
+                    orb.blown_frames = saved.blown_frames;
+                    orb.trapped_enemy_type = saved.trapped_enemy_type;
+                    orb.bounces_left = saved.bounces_left;
+                    orb.drifting = saved.drifting;
+                    orb.rise_bonus = saved.rise_bonus;
+                    orb.extra_lifetime = saved.extra_lifetime;
+                    orb
+                })
+                .collect(),
+            checkpoints: self.checkpoints.clone(),
+            bonus_stage: self.bonus_stage,
+            pending_waves: self.pending_waves.clone(),
+            ..Default::default()
+        }
+    }
+
+    pub fn exists() -> bool {
+        Path::new(SAVE_GAME_FILE).exists()
+    }
+
+    /// Loading consumes the save, so that a run can only be resumed once. A save that can't be parsed is left in
+    /// place.
+    pub fn load() -> Option<Self> {
+        let content = fs::read_to_string(SAVE_GAME_FILE).ok()?;
+        let saved_game = match Self::deserialize_ron(&content) {
+            Ok(saved_game) => saved_game,
+            Err(error) => {
+                eprintln!("Couldn't load the saved game: {:?}", error);
+                return None;
+            }
+        };
+
+        let _ = fs::remove_file(SAVE_GAME_FILE);
+
+        Some(saved_game)
+    }
+
+    pub fn save(&self) {
+        if let Err(error) = fs::write(SAVE_GAME_FILE, self.serialize_ron()) {
+            eprintln!("Couldn't save the game: {}", error);
+        }
+    }
+}
@@ -0,0 +1,43 @@
+use macroquad::prelude::{vec2, Vec2};
+
+// Magnitudes (in pixels) of the shake, for each event.
+pub const ORB_POP_SHAKE: f32 = 3.;
+pub const BOLT_IMPACT_SHAKE: f32 = 6.;
+pub const BOSS_HIT_SHAKE: f32 = 8.;
+
+const MAX_MAGNITUDE: f32 = 12.;
+/// Fraction of the magnitude retained on each update.
+const DECAY: f32 = 0.85;
+/// Below this magnitude, the shake stops.
+const MIN_MAGNITUDE: f32 = 0.5;
+
+// Rust: Not in the original game. The canvas is offset on explosions and impacts; the offset follows a fixed
+// oscillation rather than a random one, so that the game random sequence is not affected (which would break the
+// demo playback).
+//
+#[derive(Default)]
+pub struct ScreenShake {
+    magnitude: f32,
+    timer: i32,
+}
+
+impl ScreenShake {
+    /// Shakes combine, up to a maximum.
+    pub fn add(&mut self, magnitude: f32) {
+        self.magnitude = (self.magnitude + magnitude).min(MAX_MAGNITUDE);
+    }
+
+    pub fn update(&mut self) {
+        self.timer += 1;
+        self.magnitude *= DECAY;
+
+        if self.magnitude < MIN_MAGNITUDE {
+            self.magnitude = 0.;
+        }
+    }
+
+    pub fn offset(&self) -> Vec2 {
+        let t = self.timer as f32;
+        vec2((t * 1.7).sin(), (t * 2.3).cos()) * self.magnitude
+    }
+}
@@ -0,0 +1,102 @@
+use std::fs;
+
+use nanoserde::{DeRon, SerRon};
+
+use crate::{
+    control_profile::ControlProfile,
+    controls::{Controls, PLAYER_1_CONTROLS, PLAYER_2_CONTROLS},
+    difficulty::Difficulty,
+    palette::Palette,
+};
+
+const SETTINGS_FILE: &str = "settings.ron";
+
+/// User settings, persisted across runs. Fields missing from the file (e.g. when it's been written by a previous
+/// version) take the default value of their type, unless they have their own attribute; those must match `Default`,
+/// since Nanoserde doesn't use it for the missing fields.
+#[derive(Clone, DeRon, SerRon)]
+#[nserde(default)]
+pub struct Settings {
+    /// One entry per player.
+    pub controls: Vec<Controls>,
+    /// One entry per player.
+    pub control_profiles: Vec<ControlProfile>,
+    pub difficulty: Difficulty,
+    pub speedrun_timer: bool,
+    pub palette: Palette,
+    /// Orbs bounce off the walls a few times, instead of stopping.
+    pub orb_wall_bounces: bool,
+    /// Holding blow charges the next orb, which rises faster and lasts longer, instead of blowing the current one
+    /// further.
+    pub charge_shot: bool,
+    /// An extra life is awarded every time the score reaches a multiple of this; zero disables them.
+    #[nserde(default = 10000)]
+    pub extra_life_score: i32,
+    /// Base URL of the online leaderboard; empty if not used. Only read when built with the `online_leaderboard`
+    /// feature.
+    pub leaderboard_url: String,
+    #[nserde(default = "true")]
+    pub screen_shake: bool,
+    /// Display the best run of each level, while playing it.
+    pub ghost: bool,
+    /// Volumes, in percent; the master volume scales the other two.
+    #[nserde(default = 100)]
+    pub master_volume: i32,
+    #[nserde(default = 100)]
+    pub music_volume: i32,
+    #[nserde(default = 100)]
+    pub sfx_volume: i32,
+    pub muted: bool,
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Self {
+            controls: vec![PLAYER_1_CONTROLS, PLAYER_2_CONTROLS],
+            control_profiles: vec![ControlProfile::Standard; 2],
+            difficulty: Difficulty::default(),
+            speedrun_timer: false,
+            palette: Palette::default(),
+            orb_wall_bounces: false,
+            charge_shot: false,
+            extra_life_score: 10000,
+            leaderboard_url: String::new(),
+            screen_shake: true,
+            ghost: false,
+            master_volume: 100,
+            music_volume: 100,
+            sfx_volume: 100,
+            muted: false,
+        }
+    }
+}
+
+impl Settings {
+    /// If the file is missing or invalid, the default settings are used.
+    pub fn load() -> Self {
+        let mut settings = fs::read_to_string(SETTINGS_FILE)
+            .ok()
+            .and_then(|content| Settings::deserialize_ron(&content).ok())
+            .unwrap_or_default();
+
+        if settings.controls.len() != 2 {
+            settings.controls = Self::default().controls;
+        }
+        if settings.control_profiles.len() != 2 {
+            settings.control_profiles = Self::default().control_profiles;
+        }
+
+        settings
+    }
+
+    /// The keys actually used by the player, according to their profile.
+    pub fn player_controls(&self, player_i: usize) -> Controls {
+        self.control_profiles[player_i].controls(self.controls[player_i])
+    }
+
+    pub fn save(&self) {
+        if let Err(error) = fs::write(SETTINGS_FILE, self.serialize_ron()) {
+            eprintln!("Couldn't save the settings: {}", error);
+        }
+    }
+}
@@ -0,0 +1,79 @@
+use macroquad::{
+    audio::{self, PlaySoundParams, Sound},
+    prelude::collections::storage,
+};
+
+use crate::{panning, resources::Sounds, settings::Settings};
+
+/// Volume of the music at full setting, relative to the sound effects; the original game plays it at 0.3.
+const MUSIC_BASE_VOLUME: f32 = 0.3;
+
+// Rust: Not in the original game. Plays the music and the sound effects, applying the volumes from the settings;
+// stored as global, so that the sound effects can be played from anywhere (see `game_playback`).
+//
+pub struct SoundManager {
+    music: Sound,
+    /// Final volumes (0 to 1), including the master volume and the mute.
+    music_volume: f32,
+    sfx_volume: f32,
+}
+
+impl SoundManager {
+    pub fn new(music: Sound, settings: &Settings) -> Self {
+        let mut sound_manager = Self {
+            music,
+            music_volume: 0.,
+            sfx_volume: 0.,
+        };
+        sound_manager.apply_settings(settings);
+        sound_manager
+    }
+
+    /// Must be invoked when the volume settings change; the music volume is updated while playing.
+    pub fn apply_settings(&mut self, settings: &Settings) {
+        let master_volume = if settings.muted {
+            0.
+        } else {
+            settings.master_volume as f32 / 100.
+        };
+
+        self.music_volume = master_volume * settings.music_volume as f32 / 100. * MUSIC_BASE_VOLUME;
+        self.sfx_volume = master_volume * settings.sfx_volume as f32 / 100.;
+
+        audio::set_sound_volume(self.music, self.music_volume);
+    }
+
+    pub fn play_music(&self) {
+        audio::play_sound(
+            self.music,
+            PlaySoundParams {
+                looped: true,
+                volume: self.music_volume,
+            },
+        );
+    }
+
+    pub fn play_sfx(&self, sound: Sound) {
+        Self::play_once(sound, self.sfx_volume);
+    }
+
+    /// Plays a sound emitted at the given horizontal position, panned and attenuated accordingly.
+    pub fn play_sfx_at(&self, sound: Sound, x: i32) {
+        let pan = panning::pan(x);
+        let variant = storage::get::<Sounds>().panned_variant(sound, pan);
+
+        Self::play_once(variant, self.sfx_volume * panning::attenuation(pan));
+    }
+
+    fn play_once(sound: Sound, volume: f32) {
+        if volume > 0. {
+            audio::play_sound(
+                sound,
+                PlaySoundParams {
+                    looped: false,
+                    volume,
+                },
+            );
+        }
+    }
+}
@@ -0,0 +1,81 @@
+use std::fs;
+
+use macroquad::file::load_string;
+use nanoserde::{DeRon, SerRon};
+
+use crate::{robot::RobotType, ticks::TICKS_PER_SECOND};
+
+// Rust: Not in the original game, where the enemies of each level are computed by a formula. The enemies of the
+// classic mode levels are loaded from a file, as waves released at given times; levels not in the file (and the
+// endless mode ones) use the formula.
+
+pub const WAVES_FILE: &str = "resources/waves.ron";
+
+/// Enemies added to the pending ones, once the time (in seconds from the start of the level) is reached.
+#[derive(Clone, Copy, Default, DeRon, SerRon)]
+#[nserde(default)]
+pub struct Wave {
+    pub time: i32,
+    pub normal: i32,
+    pub aggressive: i32,
+    pub flying: i32,
+}
+
+impl Wave {
+    /// In updates from the start of the level.
+    pub fn release_time(&self) -> i32 {
+        self.time * TICKS_PER_SECOND
+    }
+
+    /// The robots, not shuffled.
+    pub fn robots(&self) -> Vec<RobotType> {
+        let mut robots = [RobotType::Aggressive].repeat(self.aggressive as usize);
+        robots.append(&mut [RobotType::Normal].repeat(self.normal as usize));
+        robots
+    }
+
+    pub fn num_enemies(&self) -> usize {
+        (self.normal + self.aggressive + self.flying) as usize
+    }
+}
+
+#[derive(Clone, Default, DeRon, SerRon)]
+pub struct LevelWaves {
+    pub waves: Vec<Wave>,
+}
+
+/// Waves of each classic mode level, starting from the first.
+#[derive(Default, DeRon, SerRon)]
+#[nserde(default)]
+pub struct SpawnWaves {
+    pub levels: Vec<LevelWaves>,
+}
+
+impl SpawnWaves {
+    /// If the file is missing or invalid, all the levels use the formula.
+    pub async fn load() -> Self {
+        Self::from_content(load_string(WAVES_FILE).await.ok())
+    }
+
+    /// Loads without the engine (see `Resources::headless()`).
+    pub fn load_headless() -> Self {
+        Self::from_content(fs::read_to_string(WAVES_FILE).ok())
+    }
+
+    fn from_content(content: Option<String>) -> Self {
+        match content {
+            Some(content) => Self::deserialize_ron(&content).unwrap_or_else(|error| {
+                eprintln!("Invalid waves file; using the formula: {:?}", error);
+                Self::default()
+            }),
+            None => Self::default(),
+        }
+    }
+
+    /// None if the level is not in the file; the waves are sorted by time.
+    pub fn level_waves(&self, level: i8) -> Option<Vec<Wave>> {
+        let mut waves = self.levels.get(level as usize)?.waves.clone();
+        waves.sort_by_key(|wave| wave.time);
+        Some(waves)
+    }
+}
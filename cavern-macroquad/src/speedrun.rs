@@ -0,0 +1,158 @@
+use std::fs;
+
+use macroquad::prelude::{draw_text, measure_text, Color, BLACK, GREEN, RED, WHITE};
+use nanoserde::{DeRon, SerRon};
+
+use crate::{game_mode::GameMode, ticks::TICKS_PER_SECOND, WIDTH};
+
+// Rust: Not in the original game. Times are measured in updates, so that they don't depend on the performance of
+// the machine.
+
+const PERSONAL_BESTS_FILE: &str = "personal_bests.ron";
+
+// How long the comparison with the personal best is displayed, after completing a level.
+const SPLIT_DISPLAY_TIME: i32 = 180;
+
+const FONT_SIZE: f32 = 24.;
+const RIGHT_MARGIN: f32 = 10.;
+// Below the co-op scores.
+const FIRST_LINE_Y: f32 = 56.;
+const LINE_HEIGHT: f32 = 22.;
+
+/// Personal best run of each game mode, as the total time at the end of each level. A run is better than another if
+/// it completes more levels, or the same levels in less time.
+#[derive(Default, DeRon, SerRon)]
+#[nserde(default)]
+pub struct PersonalBests {
+    classic: Vec<i32>,
+    endless: Vec<i32>,
+}
+
+impl PersonalBests {
+    /// If the file is missing or invalid, there are no personal bests.
+    pub fn load() -> Self {
+        fs::read_to_string(PERSONAL_BESTS_FILE)
+            .ok()
+            .and_then(|content| Self::deserialize_ron(&content).ok())
+            .unwrap_or_default()
+    }
+
+    pub fn save(&self) {
+        if let Err(error) = fs::write(PERSONAL_BESTS_FILE, self.serialize_ron()) {
+            eprintln!("Couldn't save the personal bests: {}", error);
+        }
+    }
+
+    pub fn splits(&self, mode: GameMode) -> &[i32] {
+        match mode {
+            GameMode::Classic => &self.classic,
+            GameMode::Endless => &self.endless,
+        }
+    }
+
+    /// Returns whether the run is a new personal best.
+    pub fn add(&mut self, mode: GameMode, splits: &[i32]) -> bool {
+        let best = match mode {
+            GameMode::Classic => &mut self.classic,
+            GameMode::Endless => &mut self.endless,
+        };
+
+        let is_better = splits.len() > best.len()
+            || (splits.len() == best.len() && !splits.is_empty() && splits.last() < best.last());
+
+        if is_better {
+            *best = splits.to_vec();
+        }
+
+        is_better
+    }
+}
+
+pub struct SpeedrunTimer {
+    total_time: i32,
+    level_time: i32,
+    /// Total time at the end of each completed level.
+    splits: Vec<i32>,
+    /// Runs resumed from a saved game are timed, but they're not eligible as personal bests.
+    eligible: bool,
+    split_display_timer: i32,
+}
+
+impl SpeedrunTimer {
+    pub fn new(eligible: bool) -> Self {
+        Self {
+            total_time: 0,
+            level_time: 0,
+            splits: vec![],
+            eligible,
+            split_display_timer: 0,
+        }
+    }
+
+    pub fn splits(&self) -> &[i32] {
+        &self.splits
+    }
+
+    pub fn is_eligible(&self) -> bool {
+        self.eligible
+    }
+
+    pub fn update(&mut self) {
+        self.total_time += 1;
+        self.level_time += 1;
+        self.split_display_timer -= 1;
+    }
+
+    pub fn split(&mut self) {
+        self.splits.push(self.total_time);
+        self.level_time = 0;
+        self.split_display_timer = SPLIT_DISPLAY_TIME;
+    }
+
+    /// Draws the total and level times, right-justified; after completing a level, the difference with the personal
+    /// best at the same point is displayed, green if ahead, red if behind.
+    pub fn draw(&self, best_splits: &[i32]) {
+        draw_right_justified(&format_time(self.total_time), FIRST_LINE_Y, WHITE);
+        draw_right_justified(
+            &format!("LEVEL {}", format_time(self.level_time)),
+            FIRST_LINE_Y + LINE_HEIGHT,
+            WHITE,
+        );
+
+        if self.split_display_timer > 0 {
+            let level_i = self.splits.len() - 1;
+
+            if let Some(best_split) = best_splits.get(level_i) {
+                let difference = self.splits[level_i] - best_split;
+                let (sign, colour) = if difference <= 0 {
+                    ("-", GREEN)
+                } else {
+                    ("+", RED)
+                };
+                let text = format!("{}{}", sign, format_time(difference.abs()));
+
+                draw_right_justified(&text, FIRST_LINE_Y + 2. * LINE_HEIGHT, colour);
+            }
+        }
+    }
+}
+
+/// Formats as M:SS.CC (minutes, seconds, hundredths).
+fn format_time(updates: i32) -> String {
+    let hundredths = updates * 100 / TICKS_PER_SECOND;
+
+    format!(
+        "{}:{:02}.{:02}",
+        hundredths / 6000,
+        (hundredths / 100) % 60,
+        hundredths % 100
+    )
+}
+
+// The default font is used, since the game font doesn't have the punctuation.
+fn draw_right_justified(text: &str, y: f32, colour: Color) {
+    let x = WIDTH as f32 - RIGHT_MARGIN - measure_text(text, None, FONT_SIZE as u16, 1.).width;
+
+    draw_text(text, x + 2., y + 2., FONT_SIZE, BLACK);
+    draw_text(text, x, y, FONT_SIZE, colour);
+}
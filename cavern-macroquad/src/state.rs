@@ -1,6 +1,21 @@
+// Rust: Each state has its own update/draw functions in GlobalState; the game in the background is drawn in all
+// of them.
+//
 #[derive(Clone, Copy, PartialEq)]
 pub enum State {
-    Menu,
-    Play,
+    Title,
+    /// Attract mode; not in the original game.
+    Demo,
+    /// Playback of the last run; not in the original game.
+    Replay,
+    Options,
+    /// Not in the original game.
+    LevelSelect,
+    Playing,
+    Pause,
+    /// Between the completion of a level and the start of the next one; not in the original game.
+    LevelTransition,
     GameOver,
+    /// Not in the original game.
+    HighScoreEntry,
 }
@@ -0,0 +1,89 @@
+use std::{
+    ops::{Add, AddAssign, Sub, SubAssign},
+    str::Chars,
+};
+
+use macroquad::rand::gen_range;
+use nanoserde::{DeRon, DeRonErr, DeRonState, SerRon, SerRonState};
+
+/// Rate of the fixed timestep; see the main loop.
+pub const TICKS_PER_SECOND: i32 = 60;
+
+// Rust: Not in the original code, where the timers are plain frame counters. The game is updated at a fixed rate,
+// independently of the display refresh, so a number of updates (ticks) is the same duration on any machine; the
+// wrapper makes the unit explicit, and keeps the timers from being mixed up with positions and speeds.
+//
+/// A duration, in updates. Timers count it either down (e.g. time left) or up (e.g. time since an event).
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Ticks(i32);
+
+impl Ticks {
+    pub const ZERO: Self = Self(0);
+
+    pub const fn new(ticks: i32) -> Self {
+        Self(ticks)
+    }
+
+    pub const fn from_secs(secs: i32) -> Self {
+        Self(secs * TICKS_PER_SECOND)
+    }
+
+    /// Random duration between the given ones (both included).
+    pub fn random(min: Self, max: Self) -> Self {
+        Self(gen_range(min.0, max.0 + 1))
+    }
+
+    pub const fn get(self) -> i32 {
+        self.0
+    }
+
+    pub fn increment(&mut self) {
+        self.0 += 1;
+    }
+
+    pub fn decrement(&mut self) {
+        self.0 -= 1;
+    }
+}
+
+impl Add for Ticks {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self(self.0 + other.0)
+    }
+}
+
+impl AddAssign for Ticks {
+    fn add_assign(&mut self, other: Self) {
+        self.0 += other.0;
+    }
+}
+
+impl Sub for Ticks {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        Self(self.0 - other.0)
+    }
+}
+
+impl SubAssign for Ticks {
+    fn sub_assign(&mut self, other: Self) {
+        self.0 -= other.0;
+    }
+}
+
+// Serialized as plain number, so that the files written before the type was introduced are still valid.
+
+impl SerRon for Ticks {
+    fn ser_ron(&self, indent_level: usize, state: &mut SerRonState) {
+        self.0.ser_ron(indent_level, state);
+    }
+}
+
+impl DeRon for Ticks {
+    fn de_ron(state: &mut DeRonState, input: &mut Chars) -> Result<Self, DeRonErr> {
+        i32::de_ron(state, input).map(Self)
+    }
+}
@@ -0,0 +1,116 @@
+use macroquad::prelude::{
+    draw_rectangle, draw_rectangle_lines, touches, Color, Rect, TouchPhase, Vec2,
+};
+
+use crate::{controls::Action, drawing::draw_game_text_ex, virtual_screen};
+
+// On-screen zones, for touch devices (i.e. the web build on phones/tablets); they're laid out on the bottom
+// corners, so that they can be operated with the thumbs.
+//
+const ZONES: [(Action, Rect, &str); 4] = [
+    (
+        Action::Left,
+        Rect {
+            x: 0.,
+            y: 360.,
+            w: 110.,
+            h: 120.,
+        },
+        "L",
+    ),
+    (
+        Action::Right,
+        Rect {
+            x: 110.,
+            y: 360.,
+            w: 110.,
+            h: 120.,
+        },
+        "R",
+    ),
+    (
+        Action::Jump,
+        Rect {
+            x: 580.,
+            y: 360.,
+            w: 110.,
+            h: 120.,
+        },
+        "J",
+    ),
+    (
+        Action::Blow,
+        Rect {
+            x: 690.,
+            y: 360.,
+            w: 110.,
+            h: 120.,
+        },
+        "B",
+    ),
+];
+
+const ZONE_COLOUR: Color = Color::new(1.0, 1.0, 1.0, 0.15);
+const ZONE_ACTIVE_COLOUR: Color = Color::new(1.0, 1.0, 1.0, 0.35);
+const ZONE_BORDER_COLOUR: Color = Color::new(1.0, 1.0, 1.0, 0.5);
+
+fn zone(action: Action) -> Rect {
+    ZONES.iter().find(|(a, _, _)| *a == action).unwrap().1
+}
+
+fn touching(action: Action, phases: &[TouchPhase]) -> bool {
+    let zone = zone(action);
+
+    touches().iter().any(|touch| {
+        phases.contains(&touch.phase) && zone.contains(virtual_screen::to_virtual(touch.position))
+    })
+}
+
+/// Equivalent of is_key_down(), for the touch zones.
+pub fn is_action_down(action: Action) -> bool {
+    touching(
+        action,
+        &[
+            TouchPhase::Started,
+            TouchPhase::Stationary,
+            TouchPhase::Moved,
+        ],
+    )
+}
+
+/// Equivalent of is_key_pressed(), for the touch zones.
+pub fn is_action_pressed(action: Action) -> bool {
+    touching(action, &[TouchPhase::Started])
+}
+
+/// Any touch on the screen, e.g. for starting the game.
+pub fn is_screen_tapped() -> bool {
+    touches()
+        .iter()
+        .any(|touch| touch.phase == TouchPhase::Started)
+}
+
+pub fn is_touch_used() -> bool {
+    !touches().is_empty()
+}
+
+pub fn draw() {
+    for (action, zone, label) in ZONES {
+        let colour = if is_action_down(action) {
+            ZONE_ACTIVE_COLOUR
+        } else {
+            ZONE_COLOUR
+        };
+
+        draw_rectangle(zone.x, zone.y, zone.w, zone.h, colour);
+        draw_rectangle_lines(zone.x, zone.y, zone.w, zone.h, 2., ZONE_BORDER_COLOUR);
+
+        let label_pos = zone.center() - Vec2::new(13., 14.);
+        draw_game_text_ex(
+            label,
+            label_pos.y as i32,
+            Some(label_pos.x as i32),
+            ZONE_BORDER_COLOUR,
+        );
+    }
+}
@@ -0,0 +1,115 @@
+use std::{
+    fs,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use macroquad::prelude::{
+    clear_background, draw_texture_ex, render_target, screen_height, screen_width, set_camera,
+    set_default_camera, set_fullscreen, vec2, Camera2D, DrawTextureParams, FilterMode, Image, Rect,
+    RenderTarget, Vec2, BLACK, WHITE,
+};
+
+use crate::{HEIGHT, WIDTH};
+
+const SCREENSHOTS_DIR: &str = "screenshots";
+
+// Rust: Not in the original code (Pygame Zero windows are not resizable). The game is rendered to a canvas of
+// fixed size, which is then scaled to the window, preserving the aspect ratio (the remaining area is filled
+// with black bars).
+//
+pub struct VirtualScreen {
+    render_target: RenderTarget,
+    camera: Camera2D,
+    fullscreen: bool,
+}
+
+impl VirtualScreen {
+    pub fn new() -> Self {
+        let render_target = render_target(WIDTH as u32, HEIGHT as u32);
+        render_target.texture.set_filter(FilterMode::Nearest);
+
+        let mut camera =
+            Camera2D::from_display_rect(Rect::new(0., 0., WIDTH as f32, HEIGHT as f32));
+        camera.render_target = Some(render_target);
+
+        Self {
+            render_target,
+            camera,
+            fullscreen: false,
+        }
+    }
+
+    pub fn toggle_fullscreen(&mut self) {
+        self.fullscreen = !self.fullscreen;
+        set_fullscreen(self.fullscreen);
+    }
+
+    /// Saves the canvas, at its own resolution, to a timestamped PNG. Since the drawing is performed at the end of
+    /// the frame, the canvas holds the previous frame, so this must be invoked before drawing.
+    pub fn save_screenshot(&self) {
+        if let Err(error) = fs::create_dir_all(SCREENSHOTS_DIR) {
+            eprintln!("Couldn't create the screenshots directory: {}", error);
+            return;
+        }
+
+        let timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |duration| duration.as_millis());
+        let path = format!("{}/cavern_{}.png", SCREENSHOTS_DIR, timestamp);
+
+        // The image is flipped back on export.
+        self.capture().export_png(&path);
+    }
+
+    /// Returns the content of the canvas (see `save_screenshot()`); like all the render targets, it's upside down.
+    pub fn capture(&self) -> Image {
+        self.render_target.texture.get_texture_data()
+    }
+
+    /// Sets the canvas as drawing target; must be invoked before drawing the game. The content is shifted by the
+    /// given offset (for the screen shake).
+    pub fn begin(&self, offset: Vec2) {
+        set_camera(&Camera2D {
+            target: self.camera.target - offset,
+            ..self.camera
+        });
+    }
+
+    /// Draws the canvas on the window.
+    pub fn end(&self) {
+        set_default_camera();
+        clear_background(BLACK);
+
+        let (scale, offset) = scale_and_offset();
+
+        draw_texture_ex(
+            self.render_target.texture,
+            offset.x,
+            offset.y,
+            WHITE,
+            DrawTextureParams {
+                dest_size: Some(vec2(WIDTH as f32 * scale, HEIGHT as f32 * scale)),
+                // Render targets are upside down.
+                flip_y: true,
+                ..Default::default()
+            },
+        )
+    }
+}
+
+fn scale_and_offset() -> (f32, Vec2) {
+    let scale = (screen_width() / WIDTH as f32).min(screen_height() / HEIGHT as f32);
+    let offset = vec2(
+        (screen_width() - WIDTH as f32 * scale) / 2.,
+        (screen_height() - HEIGHT as f32 * scale) / 2.,
+    );
+
+    (scale, offset)
+}
+
+/// Converts a window position (e.g. a touch) to a position on the canvas.
+pub fn to_virtual(position: Vec2) -> Vec2 {
+    let (scale, offset) = scale_and_offset();
+
+    (position - offset) / scale
+}
@@ -0,0 +1,16 @@
+// Replays a recorded run, which goes through a couple of level transitions, and checks that the final score matches
+// the recorded one. The verification doesn't open the game window, so it runs without a display.
+
+use std::process::Command;
+
+const REPLAY_FIXTURE: &str = "tests/fixtures/replay.ron";
+
+#[test]
+fn replay_reaches_the_recorded_score() {
+    let status = Command::new(env!("CARGO_BIN_EXE_cavern-macroquad"))
+        .args(["--verify-replay", REPLAY_FIXTURE])
+        .status()
+        .expect("Couldn't run the game");
+
+    assert!(status.success(), "The replay diverged from the recording");
+}